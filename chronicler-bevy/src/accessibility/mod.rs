@@ -0,0 +1,12 @@
+//! Accessibility settings for the interface.
+//!
+//! Handles text scaling, the high-contrast theme, the dyslexia-friendly font
+//! option, and their persistence.
+
+mod persistence;
+mod plugin;
+mod settings;
+
+pub use persistence::load_settings;
+pub use plugin::AccessibilityPlugin;
+pub use settings::{AccessibilitySettings, UI_SCALE_RANGE};
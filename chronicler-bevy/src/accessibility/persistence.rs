@@ -0,0 +1,48 @@
+//! Accessibility settings persistence (load/save to disk).
+
+use super::settings::AccessibilitySettings;
+
+/// Get the settings file path for the given saves directory.
+fn settings_path(saves_path: &str) -> String {
+    format!("{}/accessibility_settings.json", saves_path)
+}
+
+/// Load accessibility settings from disk.
+pub fn load_settings(saves_path: &str) -> AccessibilitySettings {
+    let path = settings_path(saves_path);
+    let path = std::path::Path::new(&path);
+    if path.exists() {
+        if let Ok(contents) = std::fs::read_to_string(path) {
+            if let Ok(data) = serde_json::from_str::<serde_json::Value>(&contents) {
+                let ui_scale = data
+                    .get("ui_scale")
+                    .and_then(|v| v.as_f64())
+                    .map(|v| v as f32)
+                    .unwrap_or(1.0);
+                let high_contrast = data
+                    .get("high_contrast")
+                    .and_then(|v| v.as_bool())
+                    .unwrap_or(false);
+                let dyslexia_font = data
+                    .get("dyslexia_font")
+                    .and_then(|v| v.as_bool())
+                    .unwrap_or(false);
+                return AccessibilitySettings::new(ui_scale, high_contrast, dyslexia_font);
+            }
+        }
+    }
+    AccessibilitySettings::default()
+}
+
+/// Save accessibility settings to disk.
+pub fn save_settings(settings: &mut AccessibilitySettings, saves_path: &str) {
+    let data = serde_json::json!({
+        "ui_scale": settings.ui_scale,
+        "high_contrast": settings.high_contrast,
+        "dyslexia_font": settings.dyslexia_font
+    });
+    if let Ok(contents) = serde_json::to_string_pretty(&data) {
+        let _ = std::fs::write(settings_path(saves_path), contents);
+    }
+    settings.clear_changed();
+}
@@ -0,0 +1,24 @@
+//! Accessibility plugin and systems.
+
+use bevy::prelude::*;
+
+use super::persistence::save_settings;
+use super::settings::AccessibilitySettings;
+use crate::AppConfig;
+
+/// Plugin to persist accessibility settings.
+pub struct AccessibilityPlugin;
+
+impl Plugin for AccessibilityPlugin {
+    fn build(&self, app: &mut App) {
+        // AccessibilitySettings is inserted by main() after loading from disk
+        app.add_systems(Update, auto_save_settings);
+    }
+}
+
+/// Persist accessibility settings when changed.
+fn auto_save_settings(mut settings: ResMut<AccessibilitySettings>, config: Res<AppConfig>) {
+    if settings.needs_save() {
+        save_settings(&mut settings, &config.saves_path);
+    }
+}
@@ -0,0 +1,57 @@
+//! Accessibility settings state management.
+
+use bevy::prelude::*;
+
+/// Smallest and largest text scale offered in the settings overlay.
+pub const UI_SCALE_RANGE: std::ops::RangeInclusive<f32> = 0.75..=2.0;
+
+/// Resource controlling text size and legibility options.
+#[derive(Resource, Clone)]
+pub struct AccessibilitySettings {
+    /// Multiplier applied to every text size (1.0 is the default size)
+    pub ui_scale: f32,
+    /// Swap the parchment palette for a high-contrast black and white one
+    pub high_contrast: bool,
+    /// Use evenly spaced letterforms with wider spacing for easier reading
+    pub dyslexia_font: bool,
+    /// Track if settings changed (for auto-save)
+    changed: bool,
+}
+
+impl Default for AccessibilitySettings {
+    fn default() -> Self {
+        Self {
+            ui_scale: 1.0,
+            high_contrast: false,
+            dyslexia_font: false,
+            changed: false,
+        }
+    }
+}
+
+impl AccessibilitySettings {
+    /// Create settings with specific values.
+    pub fn new(ui_scale: f32, high_contrast: bool, dyslexia_font: bool) -> Self {
+        Self {
+            ui_scale: ui_scale.clamp(*UI_SCALE_RANGE.start(), *UI_SCALE_RANGE.end()),
+            high_contrast,
+            dyslexia_font,
+            changed: false,
+        }
+    }
+
+    /// Mark settings as changed (will trigger auto-save).
+    pub fn mark_changed(&mut self) {
+        self.changed = true;
+    }
+
+    /// Check if settings need saving.
+    pub fn needs_save(&self) -> bool {
+        self.changed
+    }
+
+    /// Clear the changed flag after saving.
+    pub fn clear_changed(&mut self) {
+        self.changed = false;
+    }
+}
@@ -0,0 +1,12 @@
+//! Periodic auto-saving of the game in progress.
+//!
+//! Handles the auto-save interval setting, its persistence, and the timer
+//! that sends save requests to the worker.
+
+mod persistence;
+mod plugin;
+mod settings;
+
+pub use persistence::load_settings;
+pub use plugin::AutoSavePlugin;
+pub use settings::AutoSaveSettings;
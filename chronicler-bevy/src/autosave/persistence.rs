@@ -0,0 +1,36 @@
+//! Auto-save settings persistence (load/save to disk).
+
+use super::settings::AutoSaveSettings;
+
+/// Get the settings file path for the given saves directory.
+fn settings_path(saves_path: &str) -> String {
+    format!("{}/autosave_settings.json", saves_path)
+}
+
+/// Load auto-save settings from disk.
+pub fn load_settings(saves_path: &str) -> AutoSaveSettings {
+    let path = settings_path(saves_path);
+    let path = std::path::Path::new(&path);
+    if path.exists() {
+        if let Ok(contents) = std::fs::read_to_string(path) {
+            if let Ok(data) = serde_json::from_str::<serde_json::Value>(&contents) {
+                if let Some(interval) = data.get("auto_save_interval_secs").and_then(|v| v.as_u64())
+                {
+                    return AutoSaveSettings::new(u32::try_from(interval).unwrap_or(u32::MAX));
+                }
+            }
+        }
+    }
+    AutoSaveSettings::default()
+}
+
+/// Save auto-save settings to disk.
+pub fn save_settings(settings: &mut AutoSaveSettings, saves_path: &str) {
+    let data = serde_json::json!({
+        "auto_save_interval_secs": settings.interval_secs
+    });
+    if let Ok(contents) = serde_json::to_string_pretty(&data) {
+        let _ = std::fs::write(settings_path(saves_path), contents);
+    }
+    settings.clear_changed();
+}
@@ -0,0 +1,62 @@
+//! Auto-save plugin and systems.
+
+use bevy::prelude::*;
+
+use super::persistence::save_settings;
+use super::settings::{auto_save_due, AutoSaveSettings};
+use crate::state::{AppState, GamePhase, WorkerRequest};
+use crate::AppConfig;
+
+/// Plugin to periodically save the game in progress.
+pub struct AutoSavePlugin;
+
+impl Plugin for AutoSavePlugin {
+    fn build(&self, app: &mut App) {
+        // AutoSaveSettings is inserted by main() after loading from disk
+        app.add_systems(Update, (auto_save_game, auto_save_settings));
+    }
+}
+
+/// Send a save request to the worker whenever the auto-save interval elapses.
+fn auto_save_game(
+    settings: Res<AutoSaveSettings>,
+    mut app_state: ResMut<AppState>,
+    game_phase: Res<State<GamePhase>>,
+    config: Res<AppConfig>,
+    time: Res<Time>,
+    mut last_save: Local<Option<f64>>,
+) {
+    let now = time.elapsed_secs_f64();
+
+    // Restart the timer whenever there's no game running
+    if *game_phase.get() != GamePhase::Playing || !app_state.has_session() {
+        *last_save = None;
+        return;
+    }
+    let last = *last_save.get_or_insert(now);
+
+    if app_state.is_saving
+        || app_state.is_processing
+        || !auto_save_due(settings.interval_secs, now, last)
+    {
+        return;
+    }
+
+    if let Some(tx) = &app_state.request_tx {
+        let path = chronicler_core::persist::auto_save_path(
+            &config.saves_path,
+            &app_state.world.campaign_name,
+        );
+        let _ = tx.try_send(WorkerRequest::Save(path));
+        app_state.is_saving = true;
+        app_state.set_status_persistent("Auto-saving...");
+        *last_save = Some(now);
+    }
+}
+
+/// Persist auto-save settings when changed.
+fn auto_save_settings(mut settings: ResMut<AutoSaveSettings>, config: Res<AppConfig>) {
+    if settings.needs_save() {
+        save_settings(&mut settings, &config.saves_path);
+    }
+}
@@ -0,0 +1,76 @@
+//! Auto-save settings state management.
+
+use bevy::prelude::*;
+
+/// Resource controlling how often the game auto-saves.
+#[derive(Resource, Clone)]
+pub struct AutoSaveSettings {
+    /// Seconds between auto-saves (0 disables auto-saving)
+    pub interval_secs: u32,
+    /// Track if settings changed (for auto-save)
+    changed: bool,
+}
+
+impl Default for AutoSaveSettings {
+    fn default() -> Self {
+        Self {
+            interval_secs: 300,
+            changed: false,
+        }
+    }
+}
+
+impl AutoSaveSettings {
+    /// Create settings with a specific interval.
+    pub fn new(interval_secs: u32) -> Self {
+        Self {
+            interval_secs,
+            changed: false,
+        }
+    }
+
+    /// Mark settings as changed (will trigger auto-save).
+    pub fn mark_changed(&mut self) {
+        self.changed = true;
+    }
+
+    /// Check if settings need saving.
+    pub fn needs_save(&self) -> bool {
+        self.changed
+    }
+
+    /// Clear the changed flag after saving.
+    pub fn clear_changed(&mut self) {
+        self.changed = false;
+    }
+}
+
+/// Whether an auto-save is due, given the interval and the times (in
+/// seconds) now and of the last save. A last save later than now is never
+/// due, so a clock that jumps backwards can't trigger a burst of saves.
+pub fn auto_save_due(interval_secs: u32, now: f64, last_save: f64) -> bool {
+    interval_secs > 0 && now - last_save >= f64::from(interval_secs)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_zero_interval_never_saves() {
+        assert!(!auto_save_due(0, 1_000.0, 0.0));
+        assert!(!auto_save_due(0, 0.0, 0.0));
+    }
+
+    #[test]
+    fn test_due_once_interval_has_elapsed() {
+        assert!(!auto_save_due(300, 399.9, 100.0));
+        assert!(auto_save_due(300, 400.0, 100.0));
+        assert!(auto_save_due(300, 1_000.0, 100.0));
+    }
+
+    #[test]
+    fn test_last_save_in_the_future_is_not_due() {
+        assert!(!auto_save_due(300, 100.0, 500.0));
+    }
+}
@@ -8,7 +8,8 @@ use chronicler_core::character_builder::point_buy_cost;
 use chronicler_core::spells::{get_spell, spells_by_level, SpellClass};
 use chronicler_core::world::{
     Ability, AbilityScores, Background, Character, CharacterClass, ClassLevel, HitPoints,
-    ProficiencyLevel, Race, RaceType, Skill, SlotInfo, Speed, SpellSlots, SpellcastingData,
+    PactMagic, ProficiencyLevel, Race, RaceType, Skill, SlotInfo, Speed, SpellSlots,
+    SpellcastingData,
 };
 use chronicler_core::{AbilityMethod, CharacterBuilder};
 use std::collections::HashSet;
@@ -179,6 +180,8 @@ impl CharacterCreation {
                         spells_prepared: self.selected_spells.clone(), // For simplicity, prepared = known at level 1
                         cantrips_known: self.selected_cantrips.clone(),
                         spell_slots: create_level_1_spell_slots(class),
+                        pact_slots: PactMagic::for_warlock_level(1)
+                            .filter(|_| class == CharacterClass::Warlock),
                     });
                 }
             }
@@ -268,6 +271,8 @@ impl CharacterCreation {
                         spells_prepared: self.selected_spells.clone(),
                         cantrips_known: self.selected_cantrips.clone(),
                         spell_slots: create_level_1_spell_slots(class),
+                        pact_slots: PactMagic::for_warlock_level(1)
+                            .filter(|_| class == CharacterClass::Warlock),
                     });
                 }
             }
@@ -285,7 +290,7 @@ fn create_level_1_spell_slots(class: CharacterClass) -> SpellSlots {
         | CharacterClass::Druid
         | CharacterClass::Sorcerer
         | CharacterClass::Wizard => 2,
-        CharacterClass::Warlock => 1, // Pact Magic
+        // Warlocks use Pact Magic slots instead
         _ => 0,
     };
 
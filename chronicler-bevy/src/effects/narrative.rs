@@ -3,7 +3,7 @@
 //! This module converts game effects into human-readable narrative strings.
 
 use chronicler_core::rules::Effect;
-use chronicler_core::world::NarrativeType;
+use chronicler_core::world::{NarrativeType, ReputationTier};
 
 /// Represents narrative output from an effect.
 pub struct NarrativeOutput {
@@ -98,6 +98,12 @@ pub fn narrative_for_effect(effect: &Effect) -> Option<NarrativeOutput> {
             })
         }
 
+        Effect::TempHpGranted { new_temporary, .. } => Some(NarrativeOutput {
+            text: format!("Gains {new_temporary} temporary HP!"),
+            narrative_type: NarrativeType::System,
+            status: None,
+        }),
+
         Effect::ConditionApplied {
             condition, source, ..
         } => Some(NarrativeOutput {
@@ -106,12 +112,31 @@ pub fn narrative_for_effect(effect: &Effect) -> Option<NarrativeOutput> {
             status: None,
         }),
 
+        Effect::ExhaustionChanged { new_level, .. } => Some(NarrativeOutput {
+            text: if *new_level == 0 {
+                "No longer exhausted.".to_string()
+            } else {
+                format!("Exhaustion level {new_level}!")
+            },
+            narrative_type: NarrativeType::Combat,
+            status: None,
+        }),
+
         Effect::ConditionRemoved { condition, .. } => Some(NarrativeOutput {
             text: format!("No longer {condition}."),
             narrative_type: NarrativeType::System,
             status: None,
         }),
 
+        Effect::OngoingEffectApplied { effect, .. } => Some(NarrativeOutput {
+            text: format!(
+                "{} for {} rounds.",
+                effect.name, effect.remaining_rounds
+            ),
+            narrative_type: NarrativeType::System,
+            status: None,
+        }),
+
         Effect::CombatStarted => Some(NarrativeOutput {
             text: "Combat begins!".to_string(),
             narrative_type: NarrativeType::Combat,
@@ -168,12 +193,42 @@ pub fn narrative_for_effect(effect: &Effect) -> Option<NarrativeOutput> {
             })
         }
 
+        Effect::WeatherChanged { weather } => Some(NarrativeOutput {
+            text: format!("Weather: {}", weather.name()),
+            narrative_type: NarrativeType::System,
+            status: None,
+        }),
+
+        Effect::SkillProficiencyGained { skill, .. } => Some(NarrativeOutput {
+            text: format!("Now proficient in {}!", skill.name()),
+            narrative_type: NarrativeType::System,
+            status: None,
+        }),
+
         Effect::ExperienceGained { amount, new_total } => Some(NarrativeOutput {
             text: format!("Gained {amount} XP! (Total: {new_total} XP)"),
             narrative_type: NarrativeType::System,
             status: None,
         }),
 
+        Effect::InspirationGranted { .. } => Some(NarrativeOutput {
+            text: "You gain Heroic Inspiration!".to_string(),
+            narrative_type: NarrativeType::System,
+            status: None,
+        }),
+
+        Effect::InspirationSpent { .. } => Some(NarrativeOutput {
+            text: "Inspiration spent - your next roll has advantage.".to_string(),
+            narrative_type: NarrativeType::System,
+            status: None,
+        }),
+
+        Effect::InspirationConsumed { .. } => Some(NarrativeOutput {
+            text: "Inspired - rolling with advantage.".to_string(),
+            narrative_type: NarrativeType::System,
+            status: None,
+        }),
+
         Effect::LevelUp { new_level } => Some(NarrativeOutput {
             text: format!("LEVEL UP! You are now level {new_level}!"),
             narrative_type: NarrativeType::System,
@@ -183,13 +238,16 @@ pub fn narrative_for_effect(effect: &Effect) -> Option<NarrativeOutput> {
         Effect::FeatureUsed {
             feature_name,
             uses_remaining,
+            ..
         } => Some(NarrativeOutput {
             text: format!("Used {feature_name}. ({uses_remaining} uses remaining)"),
             narrative_type: NarrativeType::System,
             status: None,
         }),
 
-        Effect::SpellSlotUsed { level, remaining } => Some(NarrativeOutput {
+        Effect::SpellSlotUsed {
+            level, remaining, ..
+        } => Some(NarrativeOutput {
             text: format!("Used a level {level} spell slot. ({remaining} remaining)"),
             narrative_type: NarrativeType::System,
             status: None,
@@ -207,6 +265,14 @@ pub fn narrative_for_effect(effect: &Effect) -> Option<NarrativeOutput> {
             })
         }
 
+        Effect::HitDiceSpent {
+            die_type, count, ..
+        } => Some(NarrativeOutput {
+            text: format!("Spent {count} d{} hit dice.", die_type.sides()),
+            narrative_type: NarrativeType::System,
+            status: None,
+        }),
+
         Effect::CheckSucceeded {
             check_type,
             roll,
@@ -292,6 +358,18 @@ pub fn narrative_for_effect(effect: &Effect) -> Option<NarrativeOutput> {
             status: None,
         }),
 
+        Effect::ItemAttuned { item_name } => Some(NarrativeOutput {
+            text: format!("Attuned to {item_name}."),
+            narrative_type: NarrativeType::System,
+            status: None,
+        }),
+
+        Effect::ItemUnattuned { item_name } => Some(NarrativeOutput {
+            text: format!("Ended attunement to {item_name}."),
+            narrative_type: NarrativeType::System,
+            status: None,
+        }),
+
         Effect::ItemUsed { item_name, result } => Some(NarrativeOutput {
             text: format!("Used {item_name}. {result}"),
             narrative_type: NarrativeType::System,
@@ -387,6 +465,85 @@ pub fn narrative_for_effect(effect: &Effect) -> Option<NarrativeOutput> {
             status: Some("Stabilized - unconscious but stable".to_string()),
         }),
 
+        Effect::DodgeActive { .. } => Some(NarrativeOutput {
+            text: "Dodging - attacks have disadvantage until next turn.".to_string(),
+            narrative_type: NarrativeType::Combat,
+            status: None,
+        }),
+
+        Effect::HelpGranted { .. } => Some(NarrativeOutput {
+            text: "Helped - advantage on the next check or attack.".to_string(),
+            narrative_type: NarrativeType::Combat,
+            status: None,
+        }),
+
+        Effect::HelpConsumed { .. } => Some(NarrativeOutput {
+            text: "Help used - rolling with advantage.".to_string(),
+            narrative_type: NarrativeType::System,
+            status: None,
+        }),
+
+        Effect::LairActionAdded { action } => Some(NarrativeOutput {
+            text: format!("The lair can act on initiative 20: {}.", action.name),
+            narrative_type: NarrativeType::System,
+            status: None,
+        }),
+
+        Effect::LairActionTriggered { name } => Some(NarrativeOutput {
+            text: format!("The lair stirs: {name}!"),
+            narrative_type: NarrativeType::Combat,
+            status: None,
+        }),
+
+        Effect::LegendaryActionUsed {
+            action_name,
+            remaining,
+            ..
+        } => Some(NarrativeOutput {
+            text: format!("Legendary action: {action_name} ({remaining} remaining)."),
+            narrative_type: NarrativeType::Combat,
+            status: None,
+        }),
+
+        Effect::SpellCountered { success } => Some(NarrativeOutput {
+            text: if *success {
+                "The spell fizzles - countered!".to_string()
+            } else {
+                "Counterspell fails - the spell goes off.".to_string()
+            },
+            narrative_type: NarrativeType::Combat,
+            status: None,
+        }),
+
+        Effect::ReactionUsed { .. } => Some(NarrativeOutput {
+            text: "Reaction used.".to_string(),
+            narrative_type: NarrativeType::System,
+            status: None,
+        }),
+
+        Effect::BonusActionUsed { .. } => Some(NarrativeOutput {
+            text: "Bonus action used.".to_string(),
+            narrative_type: NarrativeType::System,
+            status: None,
+        }),
+
+        Effect::ConcentrationStarted { spell_name, .. } => Some(NarrativeOutput {
+            text: format!("Concentrating on {spell_name}."),
+            narrative_type: NarrativeType::System,
+            status: None,
+        }),
+
+        // Concentration ended voluntarily (e.g., by casting another concentration spell)
+        Effect::ConcentrationBroken {
+            spell_name,
+            damage_taken: 0,
+            ..
+        } => Some(NarrativeOutput {
+            text: format!("Concentration on {spell_name} ends."),
+            narrative_type: NarrativeType::System,
+            status: None,
+        }),
+
         Effect::ConcentrationBroken {
             spell_name,
             damage_taken,
@@ -541,6 +698,25 @@ pub fn narrative_for_effect(effect: &Effect) -> Option<NarrativeOutput> {
             status: None,
         }),
 
+        Effect::ReputationChanged {
+            faction,
+            previous,
+            current,
+            ..
+        } => {
+            let tier = ReputationTier::from_score(*current);
+            let text = if tier != ReputationTier::from_score(*previous) {
+                format!("Reputation with {faction}: {current} (now {})", tier.name())
+            } else {
+                format!("Reputation with {faction}: {current}")
+            };
+            Some(NarrativeOutput {
+                text,
+                narrative_type: NarrativeType::System,
+                status: None,
+            })
+        }
+
         Effect::LocationCreated {
             name,
             location_type,
@@ -554,13 +730,16 @@ pub fn narrative_for_effect(effect: &Effect) -> Option<NarrativeOutput> {
             from,
             to,
             direction,
+            bidirectional,
+            ..
         } => {
+            let arrow = if *bidirectional { "↔" } else { "→" };
             let dir_text = direction
                 .as_ref()
                 .map(|d| format!(" ({d})"))
                 .unwrap_or_default();
             Some(NarrativeOutput {
-                text: format!("Path discovered: {from} ↔ {to}{dir_text}"),
+                text: format!("Path discovered: {from} {arrow} {to}{dir_text}"),
                 narrative_type: NarrativeType::System,
                 status: None,
             })
@@ -596,6 +775,11 @@ pub fn narrative_for_effect(effect: &Effect) -> Option<NarrativeOutput> {
             narrative_type: NarrativeType::System,
             status: None,
         }),
+        Effect::SpellsPrepared { spells } => Some(NarrativeOutput {
+            text: format!("Spells prepared: {}", spells.join(", ")),
+            narrative_type: NarrativeType::System,
+            status: None,
+        }),
 
         Effect::StateAsserted {
             entity_name,
@@ -2,6 +2,7 @@
 //!
 //! This module determines which sound to play for each game effect.
 
+use chronicler_core::dice::{DieType, RollResult};
 use chronicler_core::rules::Effect;
 
 use crate::sound::SoundEffect;
@@ -9,7 +10,7 @@ use crate::sound::SoundEffect;
 /// Determines which sound effect (if any) should play for a given game effect.
 pub fn sound_for_effect(effect: &Effect) -> Option<SoundEffect> {
     match effect {
-        Effect::DiceRolled { .. } => Some(SoundEffect::DiceRoll),
+        Effect::DiceRolled { roll, .. } => Some(sound_for_roll(roll)),
 
         Effect::AttackHit { is_critical, .. } => {
             if *is_critical {
@@ -38,16 +39,25 @@ pub fn sound_for_effect(effect: &Effect) -> Option<SoundEffect> {
         Effect::CharacterDied { .. } => Some(SoundEffect::Death),
 
         // Effects with no associated sound
-        Effect::ConditionApplied { .. }
+        Effect::TempHpGranted { .. }
+        | Effect::ConditionApplied { .. }
         | Effect::ConditionRemoved { .. }
+        | Effect::OngoingEffectApplied { .. }
+        | Effect::ExhaustionChanged { .. }
         | Effect::CombatEnded
         | Effect::TurnAdvanced { .. }
         | Effect::InitiativeRolled { .. }
         | Effect::CombatantAdded { .. }
         | Effect::TimeAdvanced { .. }
+        | Effect::WeatherChanged { .. }
+        | Effect::SkillProficiencyGained { .. }
         | Effect::ExperienceGained { .. }
+        | Effect::InspirationGranted { .. }
+        | Effect::InspirationSpent { .. }
+        | Effect::InspirationConsumed { .. }
         | Effect::FeatureUsed { .. }
         | Effect::RestCompleted { .. }
+        | Effect::HitDiceSpent { .. }
         | Effect::CheckSucceeded { .. }
         | Effect::CheckFailed { .. }
         | Effect::FactRemembered { .. }
@@ -57,6 +67,8 @@ pub fn sound_for_effect(effect: &Effect) -> Option<SoundEffect> {
         | Effect::ItemRemoved { .. }
         | Effect::ItemEquipped { .. }
         | Effect::ItemUnequipped { .. }
+        | Effect::ItemAttuned { .. }
+        | Effect::ItemUnattuned { .. }
         | Effect::ItemUsed { .. }
         | Effect::GoldChanged { .. }
         | Effect::SilverChanged { .. }
@@ -65,6 +77,16 @@ pub fn sound_for_effect(effect: &Effect) -> Option<SoundEffect> {
         | Effect::DeathSavesReset { .. }
         | Effect::DeathSaveSuccess { .. }
         | Effect::Stabilized { .. }
+        | Effect::DodgeActive { .. }
+        | Effect::HelpGranted { .. }
+        | Effect::HelpConsumed { .. }
+        | Effect::ReactionUsed { .. }
+        | Effect::BonusActionUsed { .. }
+        | Effect::SpellCountered { .. }
+        | Effect::LegendaryActionUsed { .. }
+        | Effect::LairActionAdded { .. }
+        | Effect::LairActionTriggered { .. }
+        | Effect::ConcentrationStarted { .. }
         | Effect::ConcentrationBroken { .. }
         | Effect::ConcentrationMaintained { .. }
         | Effect::LocationChanged { .. }
@@ -83,11 +105,13 @@ pub fn sound_for_effect(effect: &Effect) -> Option<SoundEffect> {
         | Effect::NpcUpdated { .. }
         | Effect::NpcMoved { .. }
         | Effect::NpcRemoved { .. }
+        | Effect::ReputationChanged { .. }
         | Effect::LocationCreated { .. }
         | Effect::LocationsConnected { .. }
         | Effect::LocationUpdated { .. }
         | Effect::AbilityScoreModified { .. }
         | Effect::SpellSlotRestored { .. }
+        | Effect::SpellsPrepared { .. }
         | Effect::StateAsserted { .. }
         | Effect::KnowledgeShared { .. }
         | Effect::EventScheduled { .. }
@@ -95,3 +119,64 @@ pub fn sound_for_effect(effect: &Effect) -> Option<SoundEffect> {
         | Effect::EventTriggered { .. } => None,
     }
 }
+
+/// Pick a dice sound from the largest die rolled, with a flourish for a
+/// natural 20 and a groan for a natural 1.
+pub fn sound_for_roll(roll: &RollResult) -> SoundEffect {
+    if roll.natural_20 {
+        return SoundEffect::NaturalTwenty;
+    }
+    if roll.natural_1 {
+        return SoundEffect::NaturalOne;
+    }
+    let largest = roll
+        .component_results
+        .iter()
+        .map(|c| c.die_type)
+        .max_by_key(DieType::sides);
+    match largest {
+        Some(DieType::D20 | DieType::D100) => SoundEffect::DiceRollD20,
+        Some(DieType::D4 | DieType::D6 | DieType::Fudge) => SoundEffect::DiceRollSmall,
+        _ => SoundEffect::DiceRoll,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use chronicler_core::dice::DiceExpression;
+
+    fn roll(notation: &str) -> RollResult {
+        let mut roll = DiceExpression::parse(notation).unwrap().roll();
+        roll.natural_20 = false;
+        roll.natural_1 = false;
+        roll
+    }
+
+    #[test]
+    fn test_natural_rolls_get_their_own_sounds() {
+        let mut crit = roll("1d20+5");
+        crit.natural_20 = true;
+        assert!(matches!(sound_for_roll(&crit), SoundEffect::NaturalTwenty));
+
+        let mut fumble = roll("1d20+5");
+        fumble.natural_1 = true;
+        assert!(matches!(sound_for_roll(&fumble), SoundEffect::NaturalOne));
+    }
+
+    #[test]
+    fn test_plain_rolls_pick_sound_by_largest_die() {
+        assert!(matches!(
+            sound_for_roll(&roll("1d20+5")),
+            SoundEffect::DiceRollD20
+        ));
+        assert!(matches!(
+            sound_for_roll(&roll("2d6")),
+            SoundEffect::DiceRollSmall
+        ));
+        assert!(matches!(
+            sound_for_roll(&roll("1d8")),
+            SoundEffect::DiceRoll
+        ));
+    }
+}
@@ -8,7 +8,9 @@
 //! - Combat effects
 //! - Character creation wizard
 
+mod accessibility;
 mod animations;
+mod autosave;
 mod character_creation;
 mod effects;
 mod runtime;
@@ -75,6 +77,8 @@ fn main() {
     // Load settings from disk
     let window_settings = window::load_settings(&saves_path);
     let sound_settings = sound::load_settings(&saves_path);
+    let autosave_settings = autosave::load_settings(&saves_path);
+    let accessibility_settings = accessibility::load_settings(&saves_path);
     let onboarding_state = OnboardingState::load(&saves_path);
 
     // Always use windowed mode (fullscreen disabled due to macOS issues)
@@ -101,9 +105,13 @@ fn main() {
         .add_plugins(EguiPlugin)
         .add_plugins(sound::SoundPlugin)
         .add_plugins(window::WindowSettingsPlugin)
+        .add_plugins(autosave::AutoSavePlugin)
+        .add_plugins(accessibility::AccessibilityPlugin)
         .insert_resource(app_config)
         .insert_resource(window_settings)
         .insert_resource(sound_settings)
+        .insert_resource(autosave_settings)
+        .insert_resource(accessibility_settings)
         .insert_resource(onboarding_state)
         // App state
         .init_state::<GamePhase>()
@@ -141,6 +149,7 @@ fn main() {
                 state::check_pending_character_list,
                 state::check_pending_game_list,
                 state::check_pending_game_load,
+                state::check_player_death,
                 state::clear_old_status,
                 handle_ready_to_start,
             ),
@@ -5,8 +5,16 @@ use bevy::prelude::*;
 /// Sound effect types that can be played.
 #[derive(Event, Clone, Copy, Debug)]
 pub enum SoundEffect {
-    /// Dice rolling sound
+    /// Dice rolling sound (d8-d12)
     DiceRoll,
+    /// Lighter rattle for small dice (d4, d6)
+    DiceRollSmall,
+    /// Heavier roll for a d20 or percentile dice
+    DiceRollD20,
+    /// Flourish for a natural 20
+    NaturalTwenty,
+    /// Groan for a natural 1
+    NaturalOne,
     /// Attack hits target
     Hit,
     /// Attack misses
@@ -64,18 +64,23 @@ pub fn play_sounds(
     }
 
     for event in events.read() {
-        let handle = match event {
-            SoundEffect::DiceRoll => sounds.dice_roll.clone(),
-            SoundEffect::Hit => sounds.hit.clone(),
-            SoundEffect::Miss => sounds.miss.clone(),
-            SoundEffect::CriticalHit => sounds.critical_hit.clone(),
-            SoundEffect::Damage => sounds.damage.clone(),
-            SoundEffect::Heal => sounds.heal.clone(),
-            SoundEffect::SpellCast => sounds.spell_cast.clone(),
-            SoundEffect::LevelUp => sounds.level_up.clone(),
-            SoundEffect::CombatStart => sounds.combat_start.clone(),
-            SoundEffect::Death => sounds.death.clone(),
-            SoundEffect::Click => sounds.click.clone(),
+        // Dice variants reuse the base clips, pitched by playback speed
+        let (handle, speed) = match event {
+            SoundEffect::DiceRoll => (sounds.dice_roll.clone(), 1.0),
+            SoundEffect::DiceRollSmall => (sounds.dice_roll.clone(), 1.4),
+            SoundEffect::DiceRollD20 => (sounds.dice_roll.clone(), 0.8),
+            SoundEffect::NaturalTwenty => (sounds.level_up.clone(), 1.2),
+            SoundEffect::NaturalOne => (sounds.miss.clone(), 0.7),
+            SoundEffect::Hit => (sounds.hit.clone(), 1.0),
+            SoundEffect::Miss => (sounds.miss.clone(), 1.0),
+            SoundEffect::CriticalHit => (sounds.critical_hit.clone(), 1.0),
+            SoundEffect::Damage => (sounds.damage.clone(), 1.0),
+            SoundEffect::Heal => (sounds.heal.clone(), 1.0),
+            SoundEffect::SpellCast => (sounds.spell_cast.clone(), 1.0),
+            SoundEffect::LevelUp => (sounds.level_up.clone(), 1.0),
+            SoundEffect::CombatStart => (sounds.combat_start.clone(), 1.0),
+            SoundEffect::Death => (sounds.death.clone(), 1.0),
+            SoundEffect::Click => (sounds.click.clone(), 1.0),
         };
 
         if let Some(source) = handle {
@@ -84,6 +89,7 @@ pub fn play_sounds(
                 PlaybackSettings {
                     mode: bevy::audio::PlaybackMode::Despawn,
                     volume: bevy::audio::Volume::new(settings.volume),
+                    speed,
                     ..default()
                 },
             ));
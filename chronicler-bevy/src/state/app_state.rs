@@ -1,10 +1,10 @@
 //! Main application state resource.
 
 use bevy::prelude::*;
-use chronicler_core::world::NarrativeType;
+use chronicler_core::world::{CharacterId, NarrativeType};
 use tokio::sync::mpsc;
 
-use super::{WorkerRequest, WorkerResponse, WorldUpdate};
+use super::{NarrativeSearch, WorkerRequest, WorkerResponse, WorldUpdate};
 
 /// A narrative entry with styling.
 #[derive(Debug, Clone)]
@@ -23,6 +23,7 @@ pub enum ActiveOverlay {
     Inventory,
     CharacterSheet,
     QuestLog,
+    Map,
     Help,
     Settings,
     LoadCharacter,
@@ -80,6 +81,12 @@ pub struct AppState {
     pub pending_sounds: Vec<crate::sound::SoundEffect>,
     /// Height of the location panel (resizable).
     pub location_panel_height: f32,
+    /// Open search over the narrative log (None when the search bar is closed).
+    pub narrative_search: Option<NarrativeSearch>,
+    /// Whether number keys currently pick a target from the initiative list.
+    pub targeting: bool,
+    /// Combatant the player's next action is directed at.
+    pub selected_target: Option<CharacterId>,
 }
 
 impl Default for AppState {
@@ -108,6 +115,9 @@ impl Default for AppState {
             viewing_spell: None,
             pending_sounds: Vec::new(),
             location_panel_height: 60.0, // Default compact height
+            narrative_search: None,
+            targeting: false,
+            selected_target: None,
         }
     }
 }
@@ -153,11 +163,19 @@ impl AppState {
         }
     }
 
-    /// Send a player action to the AI worker.
+    /// Send a player action to the AI worker, directed at the selected target if any.
     pub fn send_action(&mut self, action: String) {
         if let Some(tx) = &self.request_tx {
             if !action.trim().is_empty() && !self.is_processing {
-                let _ = tx.try_send(WorkerRequest::PlayerAction(action));
+                let action = match self.target_name() {
+                    Some(name) => format!("{action} (targeting {name})"),
+                    None => action,
+                };
+                let target = self.selected_target.take();
+                let _ = tx.try_send(WorkerRequest::PlayerAction {
+                    input: action,
+                    target,
+                });
                 self.is_processing = true;
                 self.streaming_text.clear();
             }
@@ -220,6 +238,41 @@ impl AppState {
         }
     }
 
+    /// Open the narrative search bar, or close it if already open.
+    pub fn toggle_search(&mut self) {
+        if self.narrative_search.take().is_none() {
+            self.narrative_search = Some(NarrativeSearch::new());
+        }
+    }
+
+    /// Pick the combatant at a 1-based position in the initiative list as the
+    /// target of the next action. Returns false if there's no such combatant.
+    pub fn select_target(&mut self, position: usize) -> bool {
+        let target = self
+            .world
+            .combat
+            .as_ref()
+            .and_then(|combat| combat.combatant_at_position(position));
+        if target.is_some() {
+            self.selected_target = target;
+            self.targeting = false;
+        }
+        target.is_some()
+    }
+
+    /// Leave targeting mode and forget the selected target.
+    pub fn cancel_targeting(&mut self) {
+        self.targeting = false;
+        self.selected_target = None;
+    }
+
+    /// Name of the selected target, if it's still in the fight.
+    pub fn target_name(&self) -> Option<&str> {
+        let id = self.selected_target?;
+        let combat = self.world.combat.as_ref()?;
+        combat.combatant(id).map(|c| c.name.as_str())
+    }
+
     /// Queue a click sound to be played.
     pub fn play_click(&mut self) {
         self.pending_sounds.push(crate::sound::SoundEffect::Click);
@@ -8,6 +8,7 @@ mod app_state;
 mod game_phase;
 mod onboarding;
 mod save_lists;
+mod search;
 mod systems;
 mod worker;
 mod world_update;
@@ -20,9 +21,11 @@ pub use save_lists::{
     CharacterSaveList, GameSaveInfo, GameSaveList, PendingCharacterList, PendingGameList,
     PendingGameLoad, PendingSession,
 };
+pub use search::NarrativeSearch;
 pub use systems::{
     check_pending_character_list, check_pending_game_list, check_pending_game_load,
-    check_pending_session, clear_old_status, handle_worker_responses, process_pending_sounds,
+    check_pending_session, check_player_death, clear_old_status, handle_worker_responses,
+    process_pending_sounds,
 };
 pub use worker::{spawn_worker, WorkerRequest, WorkerResponse};
 pub use world_update::WorldUpdate;
@@ -25,6 +25,8 @@ pub struct GameSaveInfo {
     pub campaign_name: String,
     pub character_name: String,
     pub character_level: u8,
+    pub location: String,
+    pub game_date: String,
     pub saved_at: String,
 }
 
@@ -54,10 +56,13 @@ pub struct PendingGameList {
     pub receiver: std::sync::Mutex<std::sync::mpsc::Receiver<Result<Vec<GameSaveInfo>, String>>>,
 }
 
+/// A loaded game session and, if one could be written, a recap of the story so far.
+pub type LoadedGame = (GameSession, Option<String>);
+
 /// Pending game session load from a save file.
 #[derive(Resource)]
 pub struct PendingGameLoad {
-    pub receiver: std::sync::Mutex<std::sync::mpsc::Receiver<Result<GameSession, String>>>,
+    pub receiver: std::sync::Mutex<std::sync::mpsc::Receiver<Result<LoadedGame, String>>>,
 }
 
 /// Pending session creation - holds the receiver for async session creation.
@@ -0,0 +1,135 @@
+//! Text search over the narrative log.
+
+use super::app_state::NarrativeEntry;
+
+/// Indices of narrative entries containing `query`, ignoring case, in log order.
+pub fn find_narrative_matches(entries: &[NarrativeEntry], query: &str) -> Vec<usize> {
+    let query = query.trim().to_lowercase();
+    if query.is_empty() {
+        return Vec::new();
+    }
+    entries
+        .iter()
+        .enumerate()
+        .filter(|(_, entry)| entry.text.to_lowercase().contains(&query))
+        .map(|(index, _)| index)
+        .collect()
+}
+
+/// An open search over the narrative log.
+#[derive(Debug, Clone, Default)]
+pub struct NarrativeSearch {
+    /// Text being searched for.
+    pub query: String,
+    /// Narrative entry indices that match the query.
+    pub matches: Vec<usize>,
+    /// Position in `matches` of the focused match.
+    pub current: usize,
+    /// Whether the log should scroll to the focused match this frame.
+    pub scroll_pending: bool,
+    /// Whether the query field should take keyboard focus this frame.
+    pub focus_pending: bool,
+}
+
+impl NarrativeSearch {
+    /// A new, empty search with the query field focused.
+    pub fn new() -> Self {
+        Self {
+            focus_pending: true,
+            ..Self::default()
+        }
+    }
+
+    /// Recompute matches against the log, keeping the focus in range.
+    pub fn refresh(&mut self, entries: &[NarrativeEntry]) {
+        let matches = find_narrative_matches(entries, &self.query);
+        if matches != self.matches {
+            self.matches = matches;
+            self.current = 0;
+            self.scroll_pending = !self.matches.is_empty();
+        }
+    }
+
+    /// Narrative index of the focused match.
+    pub fn current_match(&self) -> Option<usize> {
+        self.matches.get(self.current).copied()
+    }
+
+    /// Move to the next match, wrapping to the first.
+    pub fn next(&mut self) {
+        if !self.matches.is_empty() {
+            self.current = (self.current + 1) % self.matches.len();
+            self.scroll_pending = true;
+        }
+    }
+
+    /// Move to the previous match, wrapping to the last.
+    pub fn previous(&mut self) {
+        if !self.matches.is_empty() {
+            self.current = (self.current + self.matches.len() - 1) % self.matches.len();
+            self.scroll_pending = true;
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use chronicler_core::world::NarrativeType;
+
+    fn log(lines: &[&str]) -> Vec<NarrativeEntry> {
+        lines
+            .iter()
+            .map(|text| NarrativeEntry {
+                text: text.to_string(),
+                entry_type: NarrativeType::DmNarration,
+                timestamp: 0.0,
+            })
+            .collect()
+    }
+
+    #[test]
+    fn test_matches_ignore_case_in_log_order() {
+        let entries = log(&[
+            "Mira greets you at the door.",
+            "The rain keeps falling.",
+            "You ask MIRA about the amulet.",
+        ]);
+        assert_eq!(find_narrative_matches(&entries, "mira"), vec![0, 2]);
+        assert_eq!(find_narrative_matches(&entries, "  Rain "), vec![1]);
+        assert!(find_narrative_matches(&entries, "dragon").is_empty());
+    }
+
+    #[test]
+    fn test_empty_query_matches_nothing() {
+        let entries = log(&["Mira greets you at the door."]);
+        assert!(find_narrative_matches(&entries, "").is_empty());
+        assert!(find_narrative_matches(&entries, "   ").is_empty());
+    }
+
+    #[test]
+    fn test_next_and_previous_wrap_at_both_ends() {
+        let entries = log(&["goblin", "orc", "goblin", "goblin"]);
+        let mut search = NarrativeSearch::new();
+        search.query = "goblin".to_string();
+        search.refresh(&entries);
+        assert_eq!(search.current_match(), Some(0));
+
+        // Stepping back from the first match wraps to the last
+        search.previous();
+        assert_eq!(search.current_match(), Some(3));
+
+        // Stepping forward from the last match wraps to the first
+        search.next();
+        assert_eq!(search.current_match(), Some(0));
+        search.next();
+        assert_eq!(search.current_match(), Some(2));
+
+        // With no matches there's nowhere to go
+        search.query = "dragon".to_string();
+        search.refresh(&entries);
+        search.next();
+        search.previous();
+        assert_eq!(search.current_match(), None);
+    }
+}
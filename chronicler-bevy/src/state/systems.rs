@@ -76,6 +76,9 @@ pub fn handle_worker_responses(
                 app_state.in_combat = in_combat;
                 app_state.is_player_turn = is_player_turn;
                 app_state.is_processing = false;
+                if !in_combat {
+                    app_state.cancel_targeting();
+                }
             }
             WorkerResponse::Cancelled => {
                 app_state.is_processing = false;
@@ -96,6 +99,42 @@ pub fn handle_worker_responses(
                     }
                 }
             }
+            WorkerResponse::ExportComplete(result) => match result {
+                Ok(path) => {
+                    app_state.set_status(
+                        format!("Exported character to {path:?}"),
+                        time.elapsed_secs_f64(),
+                    );
+                }
+                Err(e) => {
+                    app_state.error_message = Some(format!("Export failed: {e}"));
+                }
+            },
+            WorkerResponse::HistoryRestored { redo, world_update } => {
+                let action = if redo { "redo" } else { "undo" };
+                match world_update {
+                    Some(world_update) => {
+                        app_state.in_combat = world_update.combat.is_some();
+                        app_state.is_player_turn = world_update
+                            .combat
+                            .as_ref()
+                            .and_then(|c| c.current_combatant())
+                            .map(|c| c.is_player)
+                            .unwrap_or(false);
+                        app_state.world = world_update;
+                        let message = if redo {
+                            "Redid last action"
+                        } else {
+                            "Undid last action"
+                        };
+                        app_state.set_status(message, time.elapsed_secs_f64());
+                    }
+                    None => {
+                        app_state
+                            .set_status(format!("Nothing to {action}"), time.elapsed_secs_f64());
+                    }
+                }
+            }
             WorkerResponse::LoadComplete(result) => {
                 app_state.is_loading = false;
                 match result {
@@ -112,6 +151,17 @@ pub fn handle_worker_responses(
     }
 }
 
+/// System to end the game once the player character has died.
+pub fn check_player_death(
+    app_state: Res<AppState>,
+    phase: Res<State<GamePhase>>,
+    mut next_phase: ResMut<NextState<GamePhase>>,
+) {
+    if *phase.get() == GamePhase::Playing && app_state.world.life_state.is_dead() {
+        next_phase.set(GamePhase::GameOver);
+    }
+}
+
 /// System to check for pending game list load.
 pub fn check_pending_game_list(
     mut commands: Commands,
@@ -160,6 +210,7 @@ pub fn check_pending_game_load(
     pending: Option<Res<PendingGameLoad>>,
     mut app_state: ResMut<AppState>,
     mut next_phase: ResMut<NextState<GamePhase>>,
+    time: Res<Time>,
 ) {
     let Some(pending) = pending else { return };
 
@@ -172,13 +223,23 @@ pub fn check_pending_game_load(
     };
 
     match result {
-        Ok(Ok(session)) => {
+        Ok(Ok((session, recap))) => {
             // Session loaded successfully - spawn the worker
+            let warnings = session.world().validate();
             let (request_tx, response_rx, initial_world) = spawn_worker(session);
             app_state.request_tx = Some(request_tx);
             app_state.response_rx = Some(response_rx);
             app_state.world = initial_world;
-            app_state.set_status_persistent("Game loaded!");
+            if let Some(recap) = recap.filter(|r| !r.is_empty()) {
+                app_state.add_narrative(recap, NarrativeType::DmNarration, time.elapsed_secs_f64());
+            }
+            match warnings.first() {
+                None => app_state.set_status_persistent("Game loaded!"),
+                Some(first) => app_state.set_status_persistent(format!(
+                    "Game loaded with {} integrity warning(s): {first}",
+                    warnings.len()
+                )),
+            }
             app_state.overlay = ActiveOverlay::None;
 
             // Transition to playing
@@ -1,6 +1,7 @@
 //! Worker thread communication types.
 
 use chronicler_core::rules::Effect;
+use chronicler_core::world::CharacterId;
 use chronicler_core::GameSession;
 use tokio::sync::mpsc;
 
@@ -10,14 +11,24 @@ use super::WorldUpdate;
 #[derive(Debug)]
 #[allow(dead_code)]
 pub enum WorkerRequest {
-    /// Process a player action.
-    PlayerAction(String),
+    /// Process a player action, directed at the combatant the player
+    /// selected if any.
+    PlayerAction {
+        input: String,
+        target: Option<CharacterId>,
+    },
     /// Cancel the current processing.
     Cancel,
     /// Save the game to a file.
     Save(std::path::PathBuf),
     /// Load a game from a file.
     Load(std::path::PathBuf),
+    /// Export the player character's sheet (JSON plus Markdown) to a file.
+    ExportCharacter(std::path::PathBuf),
+    /// Revert the most recently applied action.
+    Undo,
+    /// Reapply the most recently undone action.
+    Redo,
     /// Shutdown the worker.
     Shutdown,
 }
@@ -51,6 +62,15 @@ pub enum WorkerResponse {
     SaveComplete(Result<std::path::PathBuf, String>),
     /// Load operation completed with new world state.
     LoadComplete(Result<WorldUpdate, String>),
+    /// Character export completed.
+    ExportComplete(Result<std::path::PathBuf, String>),
+    /// Undo or redo finished.
+    HistoryRestored {
+        /// Whether this was a redo rather than an undo.
+        redo: bool,
+        /// The restored world state, or None if there was nothing to restore.
+        world_update: Option<WorldUpdate>,
+    },
 }
 
 /// Spawn the AI worker and return channel endpoints.
@@ -83,8 +103,8 @@ async fn worker_loop(
 ) {
     loop {
         match request_rx.recv().await {
-            Some(WorkerRequest::PlayerAction(input)) => {
-                process_player_action(&mut session, &input, &response_tx).await;
+            Some(WorkerRequest::PlayerAction { input, target }) => {
+                process_player_action(&mut session, &input, target, &response_tx).await;
             }
             Some(WorkerRequest::Cancel) => {
                 let _ = response_tx.send(WorkerResponse::Cancelled).await;
@@ -111,6 +131,22 @@ async fn worker_loop(
                         .await;
                 }
             },
+            Some(WorkerRequest::ExportCharacter(path)) => {
+                let result = export_character(&session, &path)
+                    .map(|()| path)
+                    .map_err(|e| e.to_string());
+                let _ = response_tx
+                    .send(WorkerResponse::ExportComplete(result))
+                    .await;
+            }
+            Some(request @ (WorkerRequest::Undo | WorkerRequest::Redo)) => {
+                let redo = matches!(request, WorkerRequest::Redo);
+                let restored = if redo { session.redo() } else { session.undo() };
+                let world_update = restored.then(|| WorldUpdate::from_session(&session));
+                let _ = response_tx
+                    .send(WorkerResponse::HistoryRestored { redo, world_update })
+                    .await;
+            }
             Some(WorkerRequest::Shutdown) | None => {
                 break;
             }
@@ -118,10 +154,21 @@ async fn worker_loop(
     }
 }
 
+/// Write the player character's JSON sheet to `path` and a Markdown copy beside it.
+fn export_character(session: &GameSession, path: &std::path::Path) -> std::io::Result<()> {
+    let character = &session.world().player_character;
+    if let Some(dir) = path.parent() {
+        std::fs::create_dir_all(dir)?;
+    }
+    std::fs::write(path, character.to_sheet_json())?;
+    std::fs::write(path.with_extension("md"), character.to_markdown())
+}
+
 /// Process a player action and send responses with streaming.
 async fn process_player_action(
     session: &mut GameSession,
     input: &str,
+    target: Option<CharacterId>,
     response_tx: &mpsc::Sender<WorkerResponse>,
 ) {
     let input = input.trim();
@@ -135,6 +182,8 @@ async fn process_player_action(
     let result = session
         .player_action_streaming_with_effects(
             input,
+            // Picking one of two same-named goblins hits the right one
+            target,
             |text| {
                 let _ = stream_tx.try_send(WorkerResponse::StreamChunk(text.to_string()));
             },
@@ -1,8 +1,8 @@
 //! World state snapshot for UI rendering.
 
 use chronicler_core::world::{
-    AbilityScores, CombatState, Condition, DeathSaves, GameMode, GameTime, HitPoints, Item, Quest,
-    Skill,
+    AbilityScores, CombatState, Condition, DeathSaves, Encumbrance, GameMode, GameTime, HitPoints,
+    Item, LifeState, LocationMap, Quest, Skill, Weather,
 };
 use chronicler_core::GameSession;
 use std::collections::HashMap;
@@ -19,6 +19,8 @@ pub struct WorldUpdate {
     pub mode: GameMode,
     /// Current game time.
     pub game_time: GameTime,
+    /// Current weather.
+    pub weather: Weather,
     /// Player name.
     pub player_name: String,
     /// Player class.
@@ -35,12 +37,18 @@ pub struct WorldUpdate {
     pub current_location: String,
     /// Current location description.
     pub location_description: Option<String>,
+    /// Known locations and the paths between them.
+    pub location_map: LocationMap,
     /// Campaign name.
     pub campaign_name: String,
     /// Active conditions affecting the player.
     pub conditions: Vec<Condition>,
+    /// Reputation with each known faction, sorted by name.
+    pub factions: Vec<(String, i32)>,
     /// Death save progress (when at 0 HP).
     pub death_saves: DeathSaves,
+    /// Whether the player is alive, dying, stable, or dead.
+    pub life_state: LifeState,
     /// Player's gold pieces.
     pub gold: i32,
     /// Player's silver pieces.
@@ -51,6 +59,14 @@ pub struct WorldUpdate {
     pub equipped_armor: Option<String>,
     /// Inventory items.
     pub inventory_items: Vec<Item>,
+    /// Total weight carried, in pounds.
+    pub carried_weight: f32,
+    /// Maximum weight the player can carry, in pounds.
+    pub carrying_capacity: f32,
+    /// How weighed down the player is.
+    pub encumbrance: Encumbrance,
+    /// Magic items the player is attuned to.
+    pub attuned_items: Vec<String>,
     /// Ability scores.
     pub ability_scores: AbilityScores,
     /// Skill proficiencies (skill -> proficiency level string).
@@ -80,6 +96,7 @@ impl Default for WorldUpdate {
             combat: None,
             mode: GameMode::Exploration,
             game_time: GameTime::default(),
+            weather: Weather::default(),
             player_name: "???".to_string(),
             player_class: None,
             player_level: 1,
@@ -88,14 +105,21 @@ impl Default for WorldUpdate {
             player_speed: 30,
             current_location: "Unknown".to_string(),
             location_description: None,
+            location_map: LocationMap::default(),
             campaign_name: "New Campaign".to_string(),
             conditions: Vec::new(),
+            factions: Vec::new(),
             death_saves: DeathSaves::default(),
+            life_state: LifeState::Alive,
             gold: 0,
             silver: 0,
             equipped_weapon: None,
             equipped_armor: None,
             inventory_items: Vec::new(),
+            carried_weight: 0.0,
+            carrying_capacity: 150.0,
+            encumbrance: Encumbrance::Unencumbered,
+            attuned_items: Vec::new(),
             ability_scores: AbilityScores::default(),
             skill_proficiencies: HashMap::new(),
             proficiency_bonus: 2,
@@ -120,6 +144,7 @@ impl WorldUpdate {
             combat: world.combat.clone(),
             mode: world.mode,
             game_time: world.game_time.clone(),
+            weather: world.weather,
             player_name: character.name.clone(),
             player_class: character
                 .classes
@@ -128,16 +153,27 @@ impl WorldUpdate {
             player_level: character.level,
             player_ac: character.current_ac(),
             player_initiative: character.initiative_modifier(),
-            player_speed: character.speed.walk,
+            player_speed: character.walking_speed(),
             current_location: world.current_location.name.clone(),
             location_description: if world.current_location.description.is_empty() {
                 None
             } else {
                 Some(world.current_location.description.clone())
             },
+            location_map: LocationMap::from_world(world),
             campaign_name: world.campaign_name.clone(),
             conditions: character.conditions.iter().map(|c| c.condition).collect(),
+            factions: {
+                let mut factions: Vec<_> = world
+                    .factions
+                    .iter()
+                    .map(|(name, score)| (name.clone(), *score))
+                    .collect();
+                factions.sort();
+                factions
+            },
             death_saves: character.death_saves.clone(),
+            life_state: character.life_state,
             gold: character.inventory.gold,
             silver: character.inventory.silver,
             equipped_weapon: character
@@ -151,6 +187,10 @@ impl WorldUpdate {
                 .as_ref()
                 .map(|a| a.base.name.clone()),
             inventory_items: character.inventory.items.clone(),
+            carried_weight: character.carried_weight(),
+            carrying_capacity: character.carrying_capacity(),
+            encumbrance: character.encumbrance(),
+            attuned_items: character.attuned.clone(),
             ability_scores: character.ability_scores.clone(),
             skill_proficiencies: character
                 .skill_proficiencies
@@ -163,10 +203,18 @@ impl WorldUpdate {
                 .spellcasting
                 .as_ref()
                 .map(|sc| {
+                    // Pact Magic slots are shown alongside regular slots of the same level
                     sc.spell_slots
                         .slots
                         .iter()
-                        .map(|slot| (slot.available(), slot.total))
+                        .zip(1u8..)
+                        .map(|(slot, level)| {
+                            let pact_total = sc
+                                .pact_slots
+                                .filter(|pact| pact.slot_level == level)
+                                .map_or(0, |pact| pact.total);
+                            (sc.available_slots(level), slot.total + pact_total)
+                        })
                         .collect()
                 })
                 .unwrap_or_default(),
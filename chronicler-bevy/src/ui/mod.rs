@@ -7,6 +7,8 @@ mod panels;
 use bevy::prelude::*;
 use bevy_egui::{egui, EguiContexts};
 
+use crate::accessibility::AccessibilitySettings;
+use crate::autosave::AutoSaveSettings;
 use crate::character_creation::{CharacterCreation, ReadyToStart};
 use crate::sound::SoundSettings;
 use crate::state::{
@@ -16,6 +18,9 @@ use crate::state::{
 use crate::window::WindowSettings;
 use crate::AppConfig;
 
+/// How many story facts the recap shown after loading a game draws on.
+const RECAP_FACTS: usize = 8;
+
 /// Main UI system - renders all egui panels.
 #[allow(clippy::too_many_arguments)]
 pub fn main_ui_system(
@@ -30,13 +35,15 @@ pub fn main_ui_system(
     mut onboarding: ResMut<OnboardingState>,
     mut sound_settings: ResMut<SoundSettings>,
     mut window_settings: ResMut<WindowSettings>,
+    mut autosave_settings: ResMut<AutoSaveSettings>,
+    mut accessibility_settings: ResMut<AccessibilitySettings>,
     config: Res<AppConfig>,
     time: Res<Time>,
 ) {
     let ctx = contexts.ctx_mut();
 
     // Configure egui style
-    configure_style(ctx);
+    configure_style(ctx, &accessibility_settings);
 
     match game_phase.get() {
         GamePhase::MainMenu => {
@@ -58,6 +65,8 @@ pub fn main_ui_system(
                         &mut app_state,
                         Some(sound_settings.as_mut()),
                         Some(window_settings.as_mut()),
+                        Some(autosave_settings.as_mut()),
+                        Some(accessibility_settings.as_mut()),
                         &config.saves_path,
                     );
                 }
@@ -119,9 +128,12 @@ pub fn main_ui_system(
                             let (tx, rx) = std::sync::mpsc::channel();
                             std::thread::spawn(move || {
                                 let result = crate::runtime::RUNTIME.block_on(async {
-                                    chronicler_core::GameSession::load(&path)
+                                    let session = chronicler_core::GameSession::load(&path)
                                         .await
-                                        .map_err(|e| e.to_string())
+                                        .map_err(|e| e.to_string())?;
+                                    // The game still loads if the recap can't be written
+                                    let recap = session.generate_recap(RECAP_FACTS).await.ok();
+                                    Ok((session, recap))
                                 });
                                 let _ = tx.send(result);
                             });
@@ -160,7 +172,7 @@ pub fn main_ui_system(
             panels::render_character_panel(ctx, &mut app_state);
             input::render_input_panel(ctx, &mut app_state);
             // CentralPanel must come after side/top/bottom panels
-            panels::render_narrative_panel(ctx, &app_state, time.elapsed_secs_f64());
+            panels::render_narrative_panel(ctx, &mut app_state, time.elapsed_secs_f64());
             // Windows can be rendered anytime (they float)
             panels::render_combat_panel(ctx, &app_state);
 
@@ -169,9 +181,10 @@ pub fn main_ui_system(
                 ActiveOverlay::None => {}
                 ActiveOverlay::Inventory => overlays::render_inventory(ctx, &app_state),
                 ActiveOverlay::CharacterSheet => {
-                    overlays::render_character_sheet(ctx, &mut app_state)
+                    overlays::render_character_sheet(ctx, &mut app_state, &config.saves_path)
                 }
                 ActiveOverlay::QuestLog => overlays::render_quest_log(ctx, &app_state),
+                ActiveOverlay::Map => overlays::render_map(ctx, &app_state),
                 ActiveOverlay::Help => overlays::render_help(ctx),
                 ActiveOverlay::Settings => {
                     if overlays::render_settings(
@@ -179,6 +192,8 @@ pub fn main_ui_system(
                         &mut app_state,
                         Some(sound_settings.as_mut()),
                         Some(window_settings.as_mut()),
+                        Some(autosave_settings.as_mut()),
+                        Some(accessibility_settings.as_mut()),
                         &config.saves_path,
                     ) {
                         // User clicked "Return to Main Menu"
@@ -210,22 +225,60 @@ pub fn main_ui_system(
 }
 
 /// Configure egui visual style.
-fn configure_style(ctx: &egui::Context) {
-    let mut style = (*ctx.style()).clone();
+fn configure_style(ctx: &egui::Context, accessibility: &AccessibilitySettings) {
+    // Start from the stock style each frame so nothing compounds or lingers
+    // after a setting is turned off
+    ctx.set_style(build_style(&egui::Style::default(), accessibility));
+}
 
-    // Increase default font sizes
-    use egui::{FontId, TextStyle};
+/// Build the egui style from a base style and the accessibility settings.
+fn build_style(base: &egui::Style, accessibility: &AccessibilitySettings) -> egui::Style {
+    let mut style = base.clone();
+
+    // Increase default font sizes, scaled by the accessibility setting.
+    // The dyslexia-friendly option uses evenly spaced letterforms throughout.
+    use egui::{FontFamily, FontId, TextStyle};
+    let scale = accessibility.ui_scale;
+    let body_family = if accessibility.dyslexia_font {
+        FontFamily::Monospace
+    } else {
+        FontFamily::Proportional
+    };
     style.text_styles = [
-        (TextStyle::Small, FontId::proportional(14.0)),
-        (TextStyle::Body, FontId::proportional(16.0)),
-        (TextStyle::Monospace, FontId::monospace(15.0)),
-        (TextStyle::Button, FontId::proportional(16.0)),
-        (TextStyle::Heading, FontId::proportional(22.0)),
+        (
+            TextStyle::Small,
+            FontId::new(14.0 * scale, body_family.clone()),
+        ),
+        (
+            TextStyle::Body,
+            FontId::new(16.0 * scale, body_family.clone()),
+        ),
+        (TextStyle::Monospace, FontId::monospace(15.0 * scale)),
+        (
+            TextStyle::Button,
+            FontId::new(16.0 * scale, body_family.clone()),
+        ),
+        (TextStyle::Heading, FontId::new(22.0 * scale, body_family)),
     ]
     .into();
+    let item_spacing = egui::Style::default().spacing.item_spacing.y;
+    style.spacing.item_spacing.y = if accessibility.dyslexia_font {
+        item_spacing * 1.5
+    } else {
+        item_spacing
+    };
+
+    if accessibility.high_contrast {
+        apply_high_contrast_visuals(&mut style.visuals);
+    } else {
+        apply_parchment_visuals(&mut style.visuals);
+    }
 
-    // Dark theme with D&D colors
-    let visuals = &mut style.visuals;
+    style
+}
+
+/// Dark theme with D&D colors.
+fn apply_parchment_visuals(visuals: &mut egui::Visuals) {
     visuals.dark_mode = true;
     visuals.override_text_color = Some(egui::Color32::from_rgb(230, 220, 200)); // Parchment
     visuals.window_fill = egui::Color32::from_rgb(30, 25, 20); // Dark brown
@@ -249,9 +302,35 @@ fn configure_style(ctx: &egui::Context) {
 
     // Active/pressed state
     visuals.widgets.active.bg_fill = egui::Color32::from_rgb(139, 69, 19); // Saddle brown
-    visuals.widgets.active.bg_stroke = egui::Stroke::new(1.0, egui::Color32::from_rgb(255, 215, 0)); // Bright gold stroke
+    visuals.widgets.active.bg_stroke = egui::Stroke::new(1.0, egui::Color32::GOLD);
+}
 
-    ctx.set_style(style);
+/// High-contrast theme: white text on black with bright yellow accents.
+fn apply_high_contrast_visuals(visuals: &mut egui::Visuals) {
+    visuals.dark_mode = true;
+    visuals.override_text_color = Some(egui::Color32::WHITE);
+    visuals.window_fill = egui::Color32::BLACK;
+    visuals.panel_fill = egui::Color32::BLACK;
+    visuals.faint_bg_color = egui::Color32::from_rgb(25, 25, 25);
+    visuals.extreme_bg_color = egui::Color32::BLACK;
+    visuals.window_stroke = egui::Stroke::new(2.0, egui::Color32::WHITE);
+
+    // Accent colors
+    visuals.selection.bg_fill = egui::Color32::from_rgb(0, 90, 200);
+    visuals.hyperlink_color = egui::Color32::YELLOW;
+
+    // Widget colors, outlined so every control stands out from the background
+    visuals.widgets.noninteractive.bg_fill = egui::Color32::BLACK;
+    visuals.widgets.noninteractive.bg_stroke = egui::Stroke::new(1.0, egui::Color32::WHITE);
+    visuals.widgets.inactive.bg_fill = egui::Color32::from_rgb(30, 30, 30);
+    visuals.widgets.inactive.bg_stroke = egui::Stroke::new(1.0, egui::Color32::WHITE);
+
+    // Hover and pressed states
+    visuals.widgets.hovered.bg_fill = egui::Color32::from_rgb(60, 60, 0);
+    visuals.widgets.hovered.bg_stroke = egui::Stroke::new(2.0, egui::Color32::YELLOW);
+    visuals.widgets.hovered.expansion = 1.0;
+    visuals.widgets.active.bg_fill = egui::Color32::from_rgb(0, 90, 200);
+    visuals.widgets.active.bg_stroke = egui::Stroke::new(2.0, egui::Color32::YELLOW);
 }
 
 /// Render error popup.
@@ -302,10 +381,20 @@ pub fn handle_keyboard_input(
         std::process::exit(0);
     }
 
-    // Close overlays with Escape (works in any phase)
-    if keys.just_pressed(KeyCode::Escape) && app_state.overlay != ActiveOverlay::None {
-        app_state.overlay = ActiveOverlay::None;
-        return;
+    // Close overlays with Escape (works in any phase), then the search bar
+    if keys.just_pressed(KeyCode::Escape) {
+        if app_state.overlay != ActiveOverlay::None {
+            app_state.overlay = ActiveOverlay::None;
+            return;
+        }
+        if app_state.narrative_search.is_some() {
+            app_state.narrative_search = None;
+            return;
+        }
+        if app_state.targeting || app_state.selected_target.is_some() {
+            app_state.cancel_targeting();
+            return;
+        }
     }
 
     // Only handle other shortcuts during gameplay
@@ -331,11 +420,67 @@ pub fn handle_keyboard_input(
         }
     }
 
+    // Ctrl+F to search the adventure log (works even while typing)
+    if ctrl_pressed && keys.just_pressed(KeyCode::KeyF) {
+        app_state.toggle_search();
+    }
+
     // Don't handle other shortcuts if egui wants keyboard input (user is typing)
     if ctx.wants_keyboard_input() {
         return;
     }
 
+    // Ctrl+Z to undo the last applied action, Ctrl+Shift+Z / Ctrl+Y to redo
+    // (only outside text fields, which have their own undo)
+    if ctrl_pressed && !app_state.is_processing && app_state.has_session() {
+        let shift_pressed = keys.pressed(KeyCode::ShiftLeft) || keys.pressed(KeyCode::ShiftRight);
+        let request = if keys.just_pressed(KeyCode::KeyY)
+            || (keys.just_pressed(KeyCode::KeyZ) && shift_pressed)
+        {
+            Some(crate::state::WorkerRequest::Redo)
+        } else if keys.just_pressed(KeyCode::KeyZ) {
+            Some(crate::state::WorkerRequest::Undo)
+        } else {
+            None
+        };
+        if let (Some(request), Some(tx)) = (request, &app_state.request_tx) {
+            let _ = tx.try_send(request);
+        }
+    }
+
+    // n / N jump between search matches
+    if let Some(search) = app_state.narrative_search.as_mut() {
+        if keys.just_pressed(KeyCode::KeyN) {
+            if keys.pressed(KeyCode::ShiftLeft) || keys.pressed(KeyCode::ShiftRight) {
+                search.previous();
+            } else {
+                search.next();
+            }
+        }
+    }
+
+    // T starts picking a combat target; a number key then chooses from the initiative list
+    if app_state.in_combat && app_state.overlay == ActiveOverlay::None {
+        if keys.just_pressed(KeyCode::KeyT) {
+            app_state.targeting = !app_state.targeting;
+        } else if app_state.targeting {
+            const NUMBER_KEYS: [KeyCode; 9] = [
+                KeyCode::Digit1,
+                KeyCode::Digit2,
+                KeyCode::Digit3,
+                KeyCode::Digit4,
+                KeyCode::Digit5,
+                KeyCode::Digit6,
+                KeyCode::Digit7,
+                KeyCode::Digit8,
+                KeyCode::Digit9,
+            ];
+            if let Some(index) = NUMBER_KEYS.iter().position(|k| keys.just_pressed(*k)) {
+                app_state.select_target(index + 1);
+            }
+        }
+    }
+
     // Toggle overlays with hotkeys (when no overlay is open)
     if app_state.overlay == ActiveOverlay::None {
         if keys.just_pressed(KeyCode::KeyI) {
@@ -347,6 +492,9 @@ pub fn handle_keyboard_input(
         if keys.just_pressed(KeyCode::KeyQ) && keys.pressed(KeyCode::ShiftLeft) {
             app_state.toggle_overlay(ActiveOverlay::QuestLog);
         }
+        if keys.just_pressed(KeyCode::KeyM) {
+            app_state.toggle_overlay(ActiveOverlay::Map);
+        }
         if keys.just_pressed(KeyCode::F1) || keys.just_pressed(KeyCode::Slash) {
             app_state.toggle_overlay(ActiveOverlay::Help);
         }
@@ -369,8 +517,11 @@ async fn list_game_saves(dir: &str) -> Result<Vec<GameSaveInfo>, String> {
     while let Ok(Some(entry)) = entries.next_entry().await {
         let path = entry.path();
 
-        // Skip directories and non-json files
-        if path.is_dir() || path.extension().map(|e| e != "json").unwrap_or(true) {
+        // Skip directories, non-json files, and metadata sidecars
+        if path.is_dir()
+            || path.extension().map(|e| e != "json").unwrap_or(true)
+            || chronicler_core::persist::is_metadata_sidecar(&path)
+        {
             continue;
         }
 
@@ -383,13 +534,19 @@ async fn list_game_saves(dir: &str) -> Result<Vec<GameSaveInfo>, String> {
             continue;
         }
 
-        // Try to peek at the save metadata
-        if let Ok(metadata) = chronicler_core::persist::SavedCampaign::peek_metadata(&path).await {
+        // Prefer the metadata sidecar, falling back to peeking at the save itself
+        let metadata = match chronicler_core::persist::SaveMetadata::read_sidecar(&path).await {
+            Ok(metadata) => Ok(metadata),
+            Err(_) => chronicler_core::persist::SavedCampaign::peek_metadata(&path).await,
+        };
+        if let Ok(metadata) = metadata {
             saves.push(GameSaveInfo {
                 path: path.to_string_lossy().to_string(),
                 campaign_name: metadata.campaign_name,
                 character_name: metadata.character_name,
                 character_level: metadata.level,
+                location: metadata.location,
+                game_date: metadata.game_date,
                 saved_at: metadata.saved_at,
             });
         }
@@ -400,3 +557,102 @@ async fn list_game_saves(dir: &str) -> Result<Vec<GameSaveInfo>, String> {
 
     Ok(saves)
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn style_with(ui_scale: f32, high_contrast: bool, dyslexia_font: bool) -> egui::Style {
+        build_style(
+            &egui::Style::default(),
+            &AccessibilitySettings::new(ui_scale, high_contrast, dyslexia_font),
+        )
+    }
+
+    fn body_font(style: &egui::Style) -> &egui::FontId {
+        &style.text_styles[&egui::TextStyle::Body]
+    }
+
+    #[test]
+    fn test_default_style_uses_parchment_palette() {
+        let style = style_with(1.0, false, false);
+        assert_eq!(body_font(&style).size, 16.0);
+        assert_eq!(body_font(&style).family, egui::FontFamily::Proportional);
+        assert_eq!(
+            style.visuals.override_text_color,
+            Some(egui::Color32::from_rgb(230, 220, 200))
+        );
+        assert_eq!(
+            style.visuals.panel_fill,
+            egui::Color32::from_rgb(40, 35, 30)
+        );
+    }
+
+    #[test]
+    fn test_ui_scale_scales_every_text_style() {
+        let normal = style_with(1.0, false, false);
+        let large = style_with(1.5, false, false);
+        for (text_style, font) in &normal.text_styles {
+            assert_eq!(large.text_styles[text_style].size, font.size * 1.5);
+        }
+        // Scaling leaves the palette alone
+        assert_eq!(large.visuals.panel_fill, normal.visuals.panel_fill);
+    }
+
+    #[test]
+    fn test_ui_scale_is_clamped() {
+        assert_eq!(body_font(&style_with(10.0, false, false)).size, 32.0);
+        assert_eq!(body_font(&style_with(0.1, false, false)).size, 12.0);
+    }
+
+    #[test]
+    fn test_high_contrast_swaps_palette() {
+        let style = style_with(1.0, true, false);
+        assert_eq!(
+            style.visuals.override_text_color,
+            Some(egui::Color32::WHITE)
+        );
+        assert_eq!(style.visuals.panel_fill, egui::Color32::BLACK);
+        assert_eq!(style.visuals.window_fill, egui::Color32::BLACK);
+        // Text sizes are unaffected
+        assert_eq!(body_font(&style).size, 16.0);
+    }
+
+    #[test]
+    fn test_dyslexia_font_uses_even_letterforms_and_spacing() {
+        let normal = style_with(1.0, false, false);
+        let style = style_with(1.0, false, true);
+        assert_eq!(body_font(&style).family, egui::FontFamily::Monospace);
+        assert_eq!(body_font(&style).size, 16.0);
+        assert!(style.spacing.item_spacing.y > normal.spacing.item_spacing.y);
+        assert_eq!(style.visuals.panel_fill, normal.visuals.panel_fill);
+    }
+
+    #[test]
+    fn test_rebuilding_style_is_stable() {
+        for settings in [
+            AccessibilitySettings::new(1.0, false, true),
+            AccessibilitySettings::new(1.5, true, true),
+        ] {
+            let once = build_style(&egui::Style::default(), &settings);
+            let twice = build_style(&once, &settings);
+            assert_eq!(twice, once);
+        }
+
+        // Turning the dyslexia font off restores the normal spacing
+        let spaced = style_with(1.0, false, true);
+        let plain = build_style(&spaced, &AccessibilitySettings::new(1.0, false, false));
+        assert_eq!(
+            plain.spacing.item_spacing,
+            egui::Style::default().spacing.item_spacing
+        );
+    }
+
+    #[test]
+    fn test_all_settings_combine() {
+        let style = style_with(2.0, true, true);
+        assert_eq!(body_font(&style).size, 32.0);
+        assert_eq!(body_font(&style).family, egui::FontFamily::Monospace);
+        assert_eq!(style.visuals.panel_fill, egui::Color32::BLACK);
+    }
+}
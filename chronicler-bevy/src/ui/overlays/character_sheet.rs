@@ -1,12 +1,12 @@
 //! Character sheet overlay.
 
 use bevy_egui::egui;
-use chronicler_core::world::Ability;
+use chronicler_core::world::{Ability, ReputationTier};
 
-use crate::state::AppState;
+use crate::state::{AppState, WorkerRequest};
 
 /// Render the character sheet overlay.
-pub fn render_character_sheet(ctx: &egui::Context, app_state: &mut AppState) {
+pub fn render_character_sheet(ctx: &egui::Context, app_state: &mut AppState, saves_path: &str) {
     // Use responsive sizing based on available screen
     let screen = ctx.screen_rect();
     let width = (screen.width() * 0.85).clamp(320.0, 500.0);
@@ -244,6 +244,49 @@ pub fn render_character_sheet(ctx: &egui::Context, app_state: &mut AppState) {
                 ui.separator();
             }
 
+            // Faction reputation
+            if !app_state.world.factions.is_empty() {
+                ui.heading("Reputation");
+                egui::Grid::new("reputation_grid")
+                    .num_columns(3)
+                    .spacing([20.0, 4.0])
+                    .show(ui, |ui| {
+                        for (faction, score) in &app_state.world.factions {
+                            let tier = ReputationTier::from_score(*score);
+                            let color = match tier {
+                                ReputationTier::Hostile | ReputationTier::Unfriendly => {
+                                    egui::Color32::from_rgb(220, 100, 100)
+                                }
+                                ReputationTier::Neutral => egui::Color32::GRAY,
+                                ReputationTier::Friendly
+                                | ReputationTier::Honored
+                                | ReputationTier::Allied => egui::Color32::from_rgb(100, 200, 100),
+                            };
+                            ui.label(faction);
+                            ui.label(egui::RichText::new(tier.name()).color(color));
+                            ui.label(format!("{score:+}"));
+                            ui.end_row();
+                        }
+                    });
+                ui.separator();
+            }
+
+            let export_enabled = !app_state.is_processing && app_state.has_session();
+            if ui
+                .add_enabled(export_enabled, egui::Button::new("Export Character"))
+                .on_hover_text("Write a shareable JSON and Markdown character sheet")
+                .clicked()
+            {
+                app_state.play_click();
+                if let Some(tx) = &app_state.request_tx {
+                    let path = chronicler_core::persist::character_export_path(
+                        saves_path,
+                        &app_state.world.player_name,
+                    );
+                    let _ = tx.try_send(WorkerRequest::ExportCharacter(path));
+                }
+            }
+
             ui.label(
                 egui::RichText::new("Press C or Escape to close")
                     .small()
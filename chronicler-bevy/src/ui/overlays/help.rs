@@ -48,6 +48,14 @@ pub fn render_help(ctx: &egui::Context) {
                     ui.label(egui::RichText::new("Ctrl+S / Cmd+S").strong());
                     ui.label("- Quick Save");
                 });
+                ui.horizontal(|ui| {
+                    ui.label(egui::RichText::new("Ctrl+Z / Ctrl+Shift+Z").strong());
+                    ui.label("- Undo / redo the last applied action");
+                });
+                ui.horizontal(|ui| {
+                    ui.label(egui::RichText::new("Ctrl+F / Cmd+F").strong());
+                    ui.label("- Search the adventure log (n / N for next / previous)");
+                });
                 ui.horizontal(|ui| {
                     ui.label(egui::RichText::new("Escape").strong());
                     ui.label("- Close overlay / Cancel");
@@ -67,6 +75,10 @@ pub fn render_help(ctx: &egui::Context) {
                     ui.label(egui::RichText::new("Up / Down").strong());
                     ui.label("- Browse command history");
                 });
+                ui.horizontal(|ui| {
+                    ui.label(egui::RichText::new("PageUp / PageDown").strong());
+                    ui.label("- Scroll the adventure log");
+                });
 
                 ui.add_space(8.0);
                 ui.label(
@@ -86,11 +98,30 @@ pub fn render_help(ctx: &egui::Context) {
                     ui.label(egui::RichText::new("Shift+Q").strong());
                     ui.label("- Quest Log");
                 });
+                ui.horizontal(|ui| {
+                    ui.label(egui::RichText::new("M").strong());
+                    ui.label("- Map");
+                });
                 ui.horizontal(|ui| {
                     ui.label(egui::RichText::new("F1 / ?").strong());
                     ui.label("- Help (this screen)");
                 });
 
+                ui.add_space(8.0);
+                ui.label(
+                    egui::RichText::new("Combat (when not typing):")
+                        .strong()
+                        .color(egui::Color32::from_rgb(218, 165, 32)),
+                );
+                ui.horizontal(|ui| {
+                    ui.label(egui::RichText::new("T then 1-9").strong());
+                    ui.label("- Pick the target of your next action from the initiative list");
+                });
+                ui.horizontal(|ui| {
+                    ui.label(egui::RichText::new("Escape").strong());
+                    ui.label("- Cancel targeting");
+                });
+
                 ui.add_space(10.0);
                 ui.heading("Tips");
                 ui.label("- Be descriptive - the DM understands natural language");
@@ -1,6 +1,7 @@
 //! Inventory overlay.
 
 use bevy_egui::egui;
+use chronicler_core::world::{Encumbrance, MAX_ATTUNED_ITEMS};
 
 use crate::state::AppState;
 
@@ -33,6 +34,31 @@ pub fn render_inventory(ctx: &egui::Context, app_state: &AppState) {
                 );
             });
 
+            // Carried weight
+            let world = &app_state.world;
+            ui.horizontal(|ui| {
+                ui.label(format!(
+                    "Weight: {:.1} / {:.0} lb",
+                    world.carried_weight, world.carrying_capacity
+                ));
+                if world.encumbrance != Encumbrance::Unencumbered {
+                    let color = if world.encumbrance >= Encumbrance::HeavilyEncumbered {
+                        egui::Color32::from_rgb(220, 100, 100)
+                    } else {
+                        egui::Color32::YELLOW
+                    };
+                    let effect = match world.encumbrance {
+                        Encumbrance::Encumbered => "Speed -10 ft",
+                        Encumbrance::HeavilyEncumbered => {
+                            "Speed -20 ft; disadvantage on STR, DEX, and CON checks and saves"
+                        }
+                        _ => "Speed 5 ft; disadvantage on STR, DEX, and CON checks and saves",
+                    };
+                    ui.label(egui::RichText::new(world.encumbrance.name()).color(color))
+                        .on_hover_text(effect);
+                }
+            });
+
             ui.separator();
 
             // Equipped items
@@ -48,6 +74,15 @@ pub fn render_inventory(ctx: &egui::Context, app_state: &AppState) {
                 } else {
                     ui.label("Armor: (none)");
                 }
+                ui.label(format!(
+                    "Attuned ({}/{MAX_ATTUNED_ITEMS}): {}",
+                    app_state.world.attuned_items.len(),
+                    if app_state.world.attuned_items.is_empty() {
+                        "none".to_string()
+                    } else {
+                        app_state.world.attuned_items.join(", ")
+                    }
+                ));
             });
 
             ui.separator();
@@ -77,6 +112,23 @@ pub fn render_inventory(ctx: &egui::Context, app_state: &AppState) {
 
                                 ui.label(egui::RichText::new(name).color(color));
 
+                                if item.requires_attunement {
+                                    let attuned = app_state
+                                        .world
+                                        .attuned_items
+                                        .iter()
+                                        .any(|n| n.eq_ignore_ascii_case(&item.name));
+                                    ui.label(
+                                        egui::RichText::new(if attuned {
+                                            "(attuned)"
+                                        } else {
+                                            "(requires attunement)"
+                                        })
+                                        .color(egui::Color32::GRAY)
+                                        .small(),
+                                    );
+                                }
+
                                 if item.weight > 0.0 {
                                     ui.label(
                                         egui::RichText::new(format!("({:.1} lb)", item.weight))
@@ -63,13 +63,20 @@ pub fn render_load_game(
                         for (i, save) in save_list.saves.iter().enumerate() {
                             let is_selected = save_list.selected == Some(i);
 
-                            let text = format!(
-                                "{} - {} (Level {})\nSaved: {}",
-                                save.campaign_name,
-                                save.character_name,
-                                save.character_level,
-                                save.saved_at
+                            let mut text = format!(
+                                "{} - {} (Level {})",
+                                save.campaign_name, save.character_name, save.character_level,
                             );
+                            if !save.location.is_empty() {
+                                text.push_str(&format!("\n{}", save.location));
+                            }
+                            if !save.game_date.is_empty() {
+                                text.push_str(&format!("\n{}", save.game_date));
+                            }
+                            text.push_str(&format!(
+                                "\nSaved {}",
+                                describe_saved_at(&save.saved_at)
+                            ));
 
                             if ui.selectable_label(is_selected, text).clicked() {
                                 save_list.selected = Some(i);
@@ -109,3 +116,22 @@ pub fn render_load_game(
 
     selected_path
 }
+
+/// Describe a save's unix timestamp relative to now (e.g. "5 minutes ago").
+fn describe_saved_at(saved_at: &str) -> String {
+    let Ok(saved) = saved_at.parse::<u64>() else {
+        return saved_at.to_string();
+    };
+    let now = std::time::SystemTime::now()
+        .duration_since(std::time::UNIX_EPOCH)
+        .map(|d| d.as_secs())
+        .unwrap_or(saved);
+    let ago =
+        |count: u64, unit: &str| format!("{count} {unit}{} ago", if count == 1 { "" } else { "s" });
+    match now.saturating_sub(saved) {
+        secs if secs < 60 => "just now".to_string(),
+        secs if secs < 3600 => ago(secs / 60, "minute"),
+        secs if secs < 86_400 => ago(secs / 3600, "hour"),
+        secs => ago(secs / 86_400, "day"),
+    }
+}
@@ -0,0 +1,118 @@
+//! Location map overlay.
+
+use bevy_egui::egui;
+
+use crate::state::AppState;
+
+/// Grid cell size for one location.
+const CELL: egui::Vec2 = egui::vec2(170.0, 100.0);
+/// Size of a location box within its cell.
+const NODE: egui::Vec2 = egui::vec2(140.0, 36.0);
+
+/// Render the location map overlay.
+pub fn render_map(ctx: &egui::Context, app_state: &AppState) {
+    let screen = ctx.screen_rect();
+    let width = (screen.width() * 0.8).clamp(300.0, 700.0);
+    let height = (screen.height() * 0.7).clamp(300.0, 550.0);
+
+    egui::Window::new("Map")
+        .collapsible(false)
+        .resizable(true)
+        .anchor(egui::Align2::CENTER_CENTER, [0.0, 0.0])
+        .default_size([width, height])
+        .show(ctx, |ui| {
+            let map = &app_state.world.location_map;
+
+            if map.nodes.len() < 2 && map.edges.is_empty() {
+                ui.vertical_centered(|ui| {
+                    ui.add_space(50.0);
+                    ui.label(
+                        egui::RichText::new("No paths discovered yet.")
+                            .italics()
+                            .color(egui::Color32::GRAY),
+                    );
+                    ui.add_space(10.0);
+                    ui.label("Explore to fill in the map...");
+                });
+                return;
+            }
+
+            egui::ScrollArea::both()
+                .max_height(height - 40.0)
+                .show(ui, |ui| {
+                    let size = egui::vec2(CELL.x * map.columns as f32, CELL.y * map.rows as f32);
+                    let (response, painter) = ui.allocate_painter(size, egui::Sense::hover());
+                    let origin = response.rect.min;
+                    let center_of = |column: usize, row: usize| {
+                        origin
+                            + egui::vec2(
+                                CELL.x * (column as f32 + 0.5),
+                                CELL.y * (row as f32 + 0.5),
+                            )
+                    };
+
+                    let edge_stroke = egui::Stroke::new(1.5, egui::Color32::from_gray(150));
+                    for edge in &map.edges {
+                        let (Some(from), Some(to)) = (map.node(edge.from), map.node(edge.to))
+                        else {
+                            continue;
+                        };
+                        let start = center_of(from.column, from.row);
+                        let end = center_of(to.column, to.row);
+                        let dir = (end - start).normalized();
+                        if edge.bidirectional {
+                            painter.line_segment([start, end], edge_stroke);
+                        } else {
+                            // Stop the arrow at the edge of the destination box
+                            let tip = end - dir * (NODE.y * 0.5 + 6.0);
+                            painter.arrow(start, tip - start, edge_stroke);
+                        }
+                        if !edge.label.is_empty() {
+                            painter.text(
+                                start + (end - start) * 0.5 + egui::vec2(0.0, -8.0),
+                                egui::Align2::CENTER_CENTER,
+                                &edge.label,
+                                egui::FontId::proportional(11.0),
+                                egui::Color32::LIGHT_GRAY,
+                            );
+                        }
+                    }
+
+                    for node in &map.nodes {
+                        let rect =
+                            egui::Rect::from_center_size(center_of(node.column, node.row), NODE);
+                        let (fill, stroke) = if node.is_current {
+                            (
+                                egui::Color32::from_rgb(70, 60, 20),
+                                egui::Stroke::new(2.0, egui::Color32::YELLOW),
+                            )
+                        } else {
+                            (
+                                egui::Color32::from_gray(40),
+                                egui::Stroke::new(1.0, egui::Color32::GRAY),
+                            )
+                        };
+                        painter.rect_filled(rect, 4.0, fill);
+                        painter.rect_stroke(rect, 4.0, stroke);
+                        painter.text(
+                            rect.center(),
+                            egui::Align2::CENTER_CENTER,
+                            &node.name,
+                            egui::FontId::proportional(13.0),
+                            if node.is_current {
+                                egui::Color32::YELLOW
+                            } else {
+                                egui::Color32::WHITE
+                            },
+                        );
+                    }
+                });
+
+            ui.separator();
+            ui.label(
+                egui::RichText::new("Highlighted: current location · → one-way path")
+                    .small()
+                    .color(egui::Color32::GRAY),
+            );
+        });
+}
@@ -5,6 +5,7 @@ mod help;
 mod inventory;
 mod load_character;
 mod load_game;
+mod map;
 mod onboarding;
 mod quest_log;
 mod settings;
@@ -15,6 +16,7 @@ pub use help::render_help;
 pub use inventory::render_inventory;
 pub use load_character::render_load_character;
 pub use load_game::render_load_game;
+pub use map::render_map;
 pub use onboarding::render_onboarding;
 pub use quest_log::render_quest_log;
 pub use settings::render_settings;
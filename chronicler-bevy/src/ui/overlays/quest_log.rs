@@ -1,7 +1,7 @@
 //! Quest log overlay.
 
 use bevy_egui::egui;
-use chronicler_core::world::QuestStatus;
+use chronicler_core::world::{format_quest_log, ObjectiveState, QuestStatus};
 
 use crate::state::AppState;
 
@@ -18,7 +18,9 @@ pub fn render_quest_log(ctx: &egui::Context, app_state: &AppState) {
         .default_size([width, height])
         .max_size([550.0, 600.0])
         .show(ctx, |ui| {
-            if app_state.world.quests.is_empty() {
+            let log = format_quest_log(&app_state.world.quests);
+
+            if log.is_empty() {
                 ui.vertical_centered(|ui| {
                     ui.add_space(50.0);
                     ui.label(
@@ -30,90 +32,78 @@ pub fn render_quest_log(ctx: &egui::Context, app_state: &AppState) {
                     ui.label("Your adventure awaits...");
                 });
             } else {
-                // Active quests
-                let active_quests: Vec<_> = app_state
-                    .world
-                    .quests
-                    .iter()
-                    .filter(|q| q.status == QuestStatus::Active)
-                    .collect();
+                egui::ScrollArea::vertical()
+                    .max_height(height - 60.0)
+                    .show(ui, |ui| {
+                        for (i, section) in log.sections.iter().enumerate() {
+                            if i > 0 {
+                                ui.add_space(10.0);
+                            }
+                            let heading_color = match section.status {
+                                QuestStatus::Active => egui::Color32::YELLOW,
+                                QuestStatus::Completed => egui::Color32::GREEN,
+                                QuestStatus::Failed => egui::Color32::RED,
+                                QuestStatus::Abandoned => egui::Color32::GRAY,
+                            };
+                            ui.heading(egui::RichText::new(section.title).color(heading_color));
+                            ui.separator();
 
-                if !active_quests.is_empty() {
-                    ui.heading(egui::RichText::new("Active Quests").color(egui::Color32::YELLOW));
-                    ui.separator();
+                            for quest in &section.quests {
+                                ui.group(|ui| {
+                                    ui.label(egui::RichText::new(&quest.name).strong());
+                                    if let Some(giver) = &quest.giver {
+                                        ui.label(
+                                            egui::RichText::new(format!("From {giver}"))
+                                                .small()
+                                                .color(egui::Color32::GRAY),
+                                        );
+                                    }
+                                    ui.label(&quest.description);
 
-                    for quest in active_quests {
-                        ui.group(|ui| {
-                            ui.label(egui::RichText::new(&quest.name).strong());
-                            ui.label(&quest.description);
+                                    // Objectives
+                                    if !quest.objectives.is_empty() {
+                                        ui.add_space(4.0);
+                                        for obj in &quest.objectives {
+                                            let text = match obj.state {
+                                                ObjectiveState::Completed => egui::RichText::new(
+                                                    format!("[X] {}", obj.description),
+                                                )
+                                                .color(egui::Color32::GREEN)
+                                                .strikethrough(),
+                                                ObjectiveState::Active => egui::RichText::new(
+                                                    format!("[ ] {}", obj.description),
+                                                )
+                                                .color(egui::Color32::WHITE),
+                                                ObjectiveState::Optional => egui::RichText::new(
+                                                    format!("[ ] {} (optional)", obj.description),
+                                                )
+                                                .color(egui::Color32::LIGHT_GRAY)
+                                                .italics(),
+                                                ObjectiveState::Failed => egui::RichText::new(
+                                                    format!("[-] {}", obj.description),
+                                                )
+                                                .color(egui::Color32::from_rgb(180, 100, 100)),
+                                            };
+                                            ui.label(text);
+                                        }
+                                    }
 
-                            // Objectives
-                            if !quest.objectives.is_empty() {
+                                    // Rewards
+                                    if !quest.rewards.is_empty() {
+                                        ui.add_space(4.0);
+                                        ui.label(
+                                            egui::RichText::new(format!(
+                                                "Rewards: {}",
+                                                quest.rewards.join(", ")
+                                            ))
+                                            .color(egui::Color32::from_rgb(218, 165, 32)),
+                                        );
+                                    }
+                                });
                                 ui.add_space(4.0);
-                                for obj in &quest.objectives {
-                                    let marker = if obj.completed { "[X]" } else { "[ ]" };
-                                    let color = if obj.completed {
-                                        egui::Color32::GREEN
-                                    } else {
-                                        egui::Color32::WHITE
-                                    };
-                                    ui.label(
-                                        egui::RichText::new(format!(
-                                            "{} {}",
-                                            marker, obj.description
-                                        ))
-                                        .color(color),
-                                    );
-                                }
                             }
-                        });
-                        ui.add_space(4.0);
-                    }
-                }
-
-                // Completed quests
-                let completed_quests: Vec<_> = app_state
-                    .world
-                    .quests
-                    .iter()
-                    .filter(|q| q.status == QuestStatus::Completed)
-                    .collect();
-
-                if !completed_quests.is_empty() {
-                    ui.add_space(10.0);
-                    ui.heading(egui::RichText::new("Completed Quests").color(egui::Color32::GREEN));
-                    ui.separator();
-
-                    for quest in completed_quests {
-                        ui.label(
-                            egui::RichText::new(format!("[Done] {}", quest.name))
-                                .color(egui::Color32::from_rgb(100, 180, 100)),
-                        );
-                    }
-                }
-
-                // Failed quests
-                let failed_quests: Vec<_> = app_state
-                    .world
-                    .quests
-                    .iter()
-                    .filter(|q| {
-                        q.status == QuestStatus::Failed || q.status == QuestStatus::Abandoned
-                    })
-                    .collect();
-
-                if !failed_quests.is_empty() {
-                    ui.add_space(10.0);
-                    ui.heading(egui::RichText::new("Failed Quests").color(egui::Color32::RED));
-                    ui.separator();
-
-                    for quest in failed_quests {
-                        ui.label(
-                            egui::RichText::new(format!("[Failed] {}", quest.name))
-                                .color(egui::Color32::from_rgb(180, 100, 100)),
-                        );
-                    }
-                }
+                        }
+                    });
             }
 
             ui.separator();
@@ -2,6 +2,8 @@
 
 use bevy_egui::egui;
 
+use crate::accessibility::{AccessibilitySettings, UI_SCALE_RANGE};
+use crate::autosave::AutoSaveSettings;
 use crate::state::{ActiveOverlay, AppState};
 use crate::window::WindowSettings;
 
@@ -11,6 +13,8 @@ pub fn render_settings(
     app_state: &mut AppState,
     sound_settings: Option<&mut crate::sound::SoundSettings>,
     window_settings: Option<&mut WindowSettings>,
+    autosave_settings: Option<&mut AutoSaveSettings>,
+    accessibility_settings: Option<&mut AccessibilitySettings>,
     saves_path: &str,
 ) -> bool {
     let mut return_to_menu = false;
@@ -99,6 +103,43 @@ pub fn render_settings(
 
             ui.add_space(8.0);
 
+            // Accessibility section
+            ui.collapsing(egui::RichText::new("Accessibility").strong(), |ui| {
+                if let Some(accessibility) = accessibility_settings {
+                    ui.horizontal(|ui| {
+                        ui.label("Text size:");
+                        let slider_response = ui.add(
+                            egui::Slider::new(&mut accessibility.ui_scale, UI_SCALE_RANGE)
+                                .show_value(false)
+                                .clamping(egui::SliderClamping::Always),
+                        );
+                        if slider_response.changed() {
+                            accessibility.mark_changed();
+                        }
+                        ui.label(format!(
+                            "{}%",
+                            (accessibility.ui_scale * 100.0).round() as i32
+                        ));
+                    });
+
+                    ui.horizontal(|ui| {
+                        ui.label("High contrast:");
+                        if ui.checkbox(&mut accessibility.high_contrast, "").changed() {
+                            accessibility.mark_changed();
+                        }
+                    });
+
+                    ui.horizontal(|ui| {
+                        ui.label("Dyslexia-friendly font:");
+                        if ui.checkbox(&mut accessibility.dyslexia_font, "").changed() {
+                            accessibility.mark_changed();
+                        }
+                    });
+                }
+            });
+
+            ui.add_space(8.0);
+
             // Audio section
             ui.collapsing(egui::RichText::new("Audio").strong(), |ui| {
                 if let Some(sound) = sound_settings {
@@ -141,6 +182,34 @@ pub fn render_settings(
 
                 ui.add_space(4.0);
 
+                if let Some(autosave) = autosave_settings {
+                    ui.horizontal(|ui| {
+                        ui.label("Auto-save:");
+                        let label = |secs: u32| match secs {
+                            0 => "Off".to_string(),
+                            secs => format!("Every {} min", secs / 60),
+                        };
+                        egui::ComboBox::from_id_salt("auto_save_interval")
+                            .selected_text(label(autosave.interval_secs))
+                            .show_ui(ui, |ui| {
+                                for secs in [0, 60, 300, 600, 900, 1800] {
+                                    if ui
+                                        .selectable_label(
+                                            autosave.interval_secs == secs,
+                                            label(secs),
+                                        )
+                                        .clicked()
+                                    {
+                                        autosave.interval_secs = secs;
+                                        autosave.mark_changed();
+                                    }
+                                }
+                            });
+                    });
+
+                    ui.add_space(4.0);
+                }
+
                 if ui.button("Open saves folder").clicked() {
                     app_state.play_click();
                     #[cfg(target_os = "macos")]
@@ -172,6 +241,7 @@ pub fn render_settings(
                 ui.label("C - Character sheet");
                 ui.label("Shift+Q - Quest log");
                 ui.label("F1 / ? - Help");
+                ui.label("T then 1-9 - Pick a combat target");
                 ui.label("Escape - Close overlay");
             });
 
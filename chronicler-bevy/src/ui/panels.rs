@@ -2,7 +2,7 @@
 
 use bevy::prelude::*;
 use bevy_egui::egui;
-use chronicler_core::world::NarrativeType;
+use chronicler_core::world::{HealthStatus, NarrativeType};
 
 use crate::state::{ActiveOverlay, AppState, GamePhase, WorkerRequest};
 
@@ -110,11 +110,14 @@ pub fn render_top_bar(ctx: &egui::Context, app_state: &mut AppState, saves_path:
 
             ui.separator();
 
-            // Game time
+            // Game time and weather
             let time = &app_state.world.game_time;
             ui.label(format!(
-                "Day {}, {}:{:02}",
-                time.day, time.hour, time.minute
+                "Day {}, {}:{:02} · {}",
+                time.day,
+                time.hour,
+                time.minute,
+                app_state.world.weather.name()
             ));
 
             ui.with_layout(egui::Layout::right_to_left(egui::Align::Center), |ui| {
@@ -147,6 +150,12 @@ pub fn render_top_bar(ctx: &egui::Context, app_state: &mut AppState, saves_path:
                     app_state.toggle_overlay(ActiveOverlay::QuestLog);
                 }
 
+                // Map button
+                if ui.button("Map").on_hover_text("Location map (M)").clicked() {
+                    app_state.play_click();
+                    app_state.toggle_overlay(ActiveOverlay::Map);
+                }
+
                 // Inventory button
                 if ui
                     .button("Inventory")
@@ -315,75 +324,191 @@ pub fn render_location_panel(ctx: &egui::Context, app_state: &mut AppState) {
         });
 }
 
+/// How far PageUp/PageDown moves the narrative log: negative pages back
+/// towards earlier entries.
+fn narrative_page(ui: &egui::Ui) -> f32 {
+    let page = ui.clip_rect().height() * 0.9;
+    ui.input(|i| {
+        if i.key_pressed(egui::Key::PageUp) {
+            -page
+        } else if i.key_pressed(egui::Key::PageDown) {
+            page
+        } else {
+            0.0
+        }
+    })
+}
+
+/// Scroll offset after moving `page` from `offset`, kept between the first
+/// line (0) and the last (`max_offset`).
+fn paged_offset(offset: f32, page: f32, max_offset: f32) -> f32 {
+    (offset + page).clamp(0.0, max_offset.max(0.0))
+}
+
+/// Page the narrative log once its content is laid out.
+fn page_narrative(ui: &mut egui::Ui) {
+    let page = narrative_page(ui);
+    if page == 0.0 {
+        return;
+    }
+    let content = ui.min_rect();
+    let view = ui.clip_rect();
+    let offset = view.top() - content.top();
+    let target = paged_offset(offset, page, content.height() - view.height());
+    // Positive deltas scroll back towards the start
+    ui.scroll_with_delta(egui::vec2(0.0, offset - target));
+}
+
+/// Render the search bar above the narrative log while a search is open.
+fn render_search_bar(ui: &mut egui::Ui, app_state: &mut AppState) {
+    let Some(search) = app_state.narrative_search.as_mut() else {
+        return;
+    };
+    let mut close = false;
+
+    ui.horizontal(|ui| {
+        ui.label("Find:");
+        let response = ui.add(
+            egui::TextEdit::singleline(&mut search.query)
+                .hint_text("Search the adventure log")
+                .desired_width(240.0),
+        );
+        if std::mem::take(&mut search.focus_pending) {
+            response.request_focus();
+        }
+        search.refresh(&app_state.narrative);
+
+        // Enter jumps to the next match, Shift+Enter to the previous one
+        if response.lost_focus() && ui.input(|i| i.key_pressed(egui::Key::Enter)) {
+            if ui.input(|i| i.modifiers.shift) {
+                search.previous();
+            } else {
+                search.next();
+            }
+            response.request_focus();
+        }
+
+        if search.query.trim().is_empty() {
+            // Nothing searched yet
+        } else if search.matches.is_empty() {
+            ui.colored_label(egui::Color32::GRAY, "No matches");
+        } else {
+            ui.label(format!(
+                "{} of {}",
+                search.current + 1,
+                search.matches.len()
+            ));
+        }
+        if ui.small_button("Prev").clicked() {
+            search.previous();
+        }
+        if ui.small_button("Next").clicked() {
+            search.next();
+        }
+        if ui.small_button("Close").clicked() {
+            close = true;
+        }
+    });
+
+    if close {
+        app_state.narrative_search = None;
+    }
+}
+
 /// Render the narrative panel (main story area).
-pub fn render_narrative_panel(ctx: &egui::Context, app_state: &AppState, _current_time: f64) {
+pub fn render_narrative_panel(ctx: &egui::Context, app_state: &mut AppState, _current_time: f64) {
     egui::CentralPanel::default().show(ctx, |ui| {
         ui.heading("Adventure Log");
+        render_search_bar(ui, app_state);
         ui.separator();
 
-        // Scrollable narrative area
+        // Which entries to highlight, and whether to bring the focused one into view
+        let (matches, focused, scroll_to_focused) = match app_state.narrative_search.as_mut() {
+            Some(search) => {
+                let scroll = std::mem::take(&mut search.scroll_pending);
+                (search.matches.clone(), search.current_match(), scroll)
+            }
+            None => (Vec::new(), None, false),
+        };
+
+        // Scrollable narrative area. Sticking to the bottom resumes once the
+        // player scrolls back down to the newest entry.
         egui::ScrollArea::vertical()
             .auto_shrink([false, false])
             .stick_to_bottom(true)
             .show(ui, |ui| {
-                for entry in &app_state.narrative {
-                    let color = match entry.entry_type {
-                        NarrativeType::DmNarration => egui::Color32::from_rgb(230, 220, 200), // Parchment
-                        NarrativeType::PlayerAction => egui::Color32::from_rgb(100, 180, 255), // Blue
-                        NarrativeType::NpcDialogue => egui::Color32::from_rgb(200, 200, 150), // Tan
-                        NarrativeType::Combat => egui::Color32::from_rgb(255, 100, 100),      // Red
-                        NarrativeType::System => egui::Color32::from_rgb(180, 180, 180), // Gray
-                    };
-
-                    let prefix = match entry.entry_type {
-                        NarrativeType::DmNarration => "",
-                        NarrativeType::PlayerAction => "> ",
-                        NarrativeType::NpcDialogue => "\"",
-                        NarrativeType::Combat => "[Combat] ",
-                        NarrativeType::System => "[System] ",
+                for (index, entry) in app_state.narrative.iter().enumerate() {
+                    let highlight = if focused == Some(index) {
+                        egui::Color32::from_rgb(110, 80, 20) // Focused match
+                    } else if matches.contains(&index) {
+                        egui::Color32::from_rgb(70, 55, 30) // Other matches
+                    } else {
+                        egui::Color32::TRANSPARENT
                     };
+                    let frame = egui::Frame::none().fill(highlight).show(ui, |ui| {
+                        let color = match entry.entry_type {
+                            NarrativeType::DmNarration => egui::Color32::from_rgb(230, 220, 200), // Parchment
+                            NarrativeType::PlayerAction => egui::Color32::from_rgb(100, 180, 255), // Blue
+                            NarrativeType::NpcDialogue => egui::Color32::from_rgb(200, 200, 150), // Tan
+                            NarrativeType::Combat => egui::Color32::from_rgb(255, 100, 100), // Red
+                            NarrativeType::System => egui::Color32::from_rgb(180, 180, 180), // Gray
+                        };
 
-                    // Split by paragraph breaks (double newlines first, then single)
-                    // and render each paragraph with proper visual spacing
-                    let text_with_prefix = format!("{}{}", prefix, entry.text);
+                        let prefix = match entry.entry_type {
+                            NarrativeType::DmNarration => "",
+                            NarrativeType::PlayerAction => "> ",
+                            NarrativeType::NpcDialogue => "\"",
+                            NarrativeType::Combat => "[Combat] ",
+                            NarrativeType::System => "[System] ",
+                        };
 
-                    // Check if there are double newlines (proper paragraphs)
-                    if text_with_prefix.contains("\n\n") {
-                        let paragraphs: Vec<&str> = text_with_prefix.split("\n\n").collect();
-                        for (i, paragraph) in paragraphs.iter().enumerate() {
-                            let text = paragraph.trim();
-                            if !text.is_empty() {
-                                ui.add(
-                                    egui::Label::new(egui::RichText::new(text).color(color)).wrap(),
-                                );
-                                // Add space between paragraphs
-                                if i < paragraphs.len() - 1 {
-                                    ui.add_space(8.0);
+                        // Split by paragraph breaks (double newlines first, then single)
+                        // and render each paragraph with proper visual spacing
+                        let text_with_prefix = format!("{}{}", prefix, entry.text);
+
+                        // Check if there are double newlines (proper paragraphs)
+                        if text_with_prefix.contains("\n\n") {
+                            let paragraphs: Vec<&str> = text_with_prefix.split("\n\n").collect();
+                            for (i, paragraph) in paragraphs.iter().enumerate() {
+                                let text = paragraph.trim();
+                                if !text.is_empty() {
+                                    ui.add(
+                                        egui::Label::new(egui::RichText::new(text).color(color))
+                                            .wrap(),
+                                    );
+                                    // Add space between paragraphs
+                                    if i < paragraphs.len() - 1 {
+                                        ui.add_space(8.0);
+                                    }
                                 }
                             }
-                        }
-                    } else if text_with_prefix.contains('\n') {
-                        // Single newlines - render each line with smaller spacing
-                        let lines: Vec<&str> = text_with_prefix.split('\n').collect();
-                        for (i, line) in lines.iter().enumerate() {
-                            let text = line.trim();
-                            if !text.is_empty() {
-                                ui.add(
-                                    egui::Label::new(egui::RichText::new(text).color(color)).wrap(),
-                                );
-                                if i < lines.len() - 1 {
-                                    ui.add_space(4.0);
+                        } else if text_with_prefix.contains('\n') {
+                            // Single newlines - render each line with smaller spacing
+                            let lines: Vec<&str> = text_with_prefix.split('\n').collect();
+                            for (i, line) in lines.iter().enumerate() {
+                                let text = line.trim();
+                                if !text.is_empty() {
+                                    ui.add(
+                                        egui::Label::new(egui::RichText::new(text).color(color))
+                                            .wrap(),
+                                    );
+                                    if i < lines.len() - 1 {
+                                        ui.add_space(4.0);
+                                    }
                                 }
                             }
+                        } else {
+                            // No newlines - render as a single block
+                            ui.add(
+                                egui::Label::new(
+                                    egui::RichText::new(text_with_prefix.trim()).color(color),
+                                )
+                                .wrap(),
+                            );
                         }
-                    } else {
-                        // No newlines - render as a single block
-                        ui.add(
-                            egui::Label::new(
-                                egui::RichText::new(text_with_prefix.trim()).color(color),
-                            )
-                            .wrap(),
-                        );
+                    });
+                    if scroll_to_focused && focused == Some(index) {
+                        frame.response.scroll_to_me(Some(egui::Align::Center));
                     }
                     ui.add_space(12.0);
                 }
@@ -442,6 +567,8 @@ pub fn render_narrative_panel(ctx: &egui::Context, app_state: &AppState, _curren
                             .italics(),
                     );
                 }
+
+                page_narrative(ui);
             });
     });
 }
@@ -729,43 +856,109 @@ pub fn render_combat_panel(ctx: &egui::Context, app_state: &AppState) {
                 ui.label(format!("Round {}", combat.round));
                 ui.separator();
 
-                // Initiative order
-                for (i, combatant) in combat.combatants.iter().enumerate() {
-                    let is_current = i == combat.turn_index;
-                    let prefix = if is_current { "> " } else { "  " };
-
-                    let hp_text = format!("{}/{}", combatant.current_hp, combatant.max_hp);
-                    let hp_color = if combatant.current_hp as f32 / combatant.max_hp as f32 > 0.5 {
-                        egui::Color32::GREEN
-                    } else if combatant.current_hp > 0 {
-                        egui::Color32::YELLOW
+                // Initiative order, highest first, with the active combatant highlighted
+                for (position, (combatant, is_current)) in
+                    combat.turn_order().into_iter().enumerate()
+                {
+                    let is_target = app_state.selected_target == Some(combatant.id);
+                    let status = combatant.health_status();
+                    let hp_color = match status {
+                        HealthStatus::Healthy => egui::Color32::GREEN,
+                        HealthStatus::Bloodied => egui::Color32::YELLOW,
+                        HealthStatus::Critical => egui::Color32::from_rgb(255, 140, 0), // Orange
+                        HealthStatus::Down => egui::Color32::RED,
+                    };
+                    let fill = if is_current {
+                        egui::Color32::from_rgb(80, 65, 30)
                     } else {
-                        egui::Color32::RED
+                        egui::Color32::TRANSPARENT
                     };
 
-                    ui.horizontal(|ui| {
-                        if is_current {
-                            ui.label(
-                                egui::RichText::new(prefix)
-                                    .color(egui::Color32::YELLOW)
-                                    .strong(),
-                            );
-                        } else {
-                            ui.label(prefix);
-                        }
+                    egui::Frame::none()
+                        .fill(fill)
+                        .inner_margin(2.0)
+                        .show(ui, |ui| {
+                            ui.horizontal(|ui| {
+                                let prefix = if is_current { "> " } else { "  " };
+                                ui.label(
+                                    egui::RichText::new(prefix)
+                                        .color(egui::Color32::YELLOW)
+                                        .strong(),
+                                );
+                                // Number keys pick targets while targeting
+                                if app_state.targeting && position < 9 {
+                                    ui.label(
+                                        egui::RichText::new(format!("{}.", position + 1))
+                                            .color(egui::Color32::YELLOW),
+                                    );
+                                }
 
-                        let name_color = if combatant.is_player {
-                            egui::Color32::from_rgb(100, 180, 255)
-                        } else if combatant.is_ally {
-                            egui::Color32::GREEN
-                        } else {
-                            egui::Color32::RED
-                        };
+                                let name_color = if combatant.is_player {
+                                    egui::Color32::from_rgb(100, 180, 255)
+                                } else if combatant.is_ally {
+                                    egui::Color32::GREEN
+                                } else {
+                                    egui::Color32::RED
+                                };
+                                let mut name =
+                                    egui::RichText::new(&combatant.name).color(name_color);
+                                if is_current {
+                                    name = name.strong();
+                                }
+                                // Monsters at 0 HP are dead; the player is only dying
+                                if status == HealthStatus::Down && !combatant.is_player {
+                                    name = name.strikethrough();
+                                }
+                                ui.label(name);
+                                ui.label(format!("({})", combatant.initiative));
+                                if is_target {
+                                    ui.label(
+                                        egui::RichText::new("[target]")
+                                            .color(egui::Color32::from_rgb(218, 165, 32)),
+                                    );
+                                }
+                            });
 
-                        ui.label(egui::RichText::new(&combatant.name).color(name_color));
-                        ui.label(format!("({})", combatant.initiative));
-                        ui.label(egui::RichText::new(hp_text).color(hp_color));
-                    });
+                            let fraction = if combatant.max_hp > 0 {
+                                (combatant.current_hp.max(0) as f32 / combatant.max_hp as f32)
+                                    .clamp(0.0, 1.0)
+                            } else {
+                                0.0
+                            };
+                            ui.add(
+                                egui::ProgressBar::new(fraction)
+                                    .fill(hp_color)
+                                    .desired_height(10.0)
+                                    .text(
+                                        egui::RichText::new(format!(
+                                            "{}/{}",
+                                            combatant.current_hp.max(0),
+                                            combatant.max_hp
+                                        ))
+                                        .small(),
+                                    ),
+                            );
+                        });
+                }
+
+                ui.separator();
+                if app_state.targeting {
+                    ui.label(
+                        egui::RichText::new("Press 1-9 to pick a target (Esc cancels)")
+                            .small()
+                            .color(egui::Color32::YELLOW),
+                    );
+                } else if let Some(name) = app_state.target_name() {
+                    ui.label(
+                        egui::RichText::new(format!("Next action targets {name} (Esc clears)"))
+                            .small(),
+                    );
+                } else {
+                    ui.label(
+                        egui::RichText::new("Press T to pick a target")
+                            .small()
+                            .color(egui::Color32::GRAY),
+                    );
                 }
 
                 if app_state.is_player_turn {
@@ -809,3 +1002,22 @@ pub fn render_game_over(
         });
     });
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_paging_stays_within_the_log() {
+        // A page in either direction from the middle
+        assert_eq!(paged_offset(500.0, 300.0, 1000.0), 800.0);
+        assert_eq!(paged_offset(500.0, -300.0, 1000.0), 200.0);
+
+        // Can't page above the first line or below the last
+        assert_eq!(paged_offset(100.0, -300.0, 1000.0), 0.0);
+        assert_eq!(paged_offset(900.0, 300.0, 1000.0), 1000.0);
+
+        // A log shorter than the view doesn't scroll at all
+        assert_eq!(paged_offset(0.0, 300.0, -50.0), 0.0);
+    }
+}
@@ -203,10 +203,10 @@ async fn run_scenario(
     }
 
     // Footer
-    transcript.push_str(&format!(
+    transcript.push_str(
         "*This transcript was generated by [chronicler](https://github.com/SamuelSchlesinger/chronicler), \
-        an AI Dungeon Master powered by Claude.*\n"
-    ));
+        an AI Dungeon Master powered by Claude.*\n",
+    );
 
     // Write the file
     let output_path = output_dir.join(scenario.filename);
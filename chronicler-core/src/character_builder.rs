@@ -5,8 +5,8 @@
 
 use crate::world::{
     Ability, AbilityScores, Background, Character, CharacterClass, ClassLevel, ClassResources,
-    HitDice, HitPoints, ProficiencyLevel, Race, RaceType, Skill, SlotInfo, Speed, SpellSlots,
-    SpellcastingData,
+    HitDice, HitPoints, PactMagic, ProficiencyLevel, Race, RaceType, Skill, SlotInfo, Speed,
+    SpellSlots, SpellcastingData,
 };
 use std::collections::{HashMap, HashSet};
 
@@ -324,13 +324,12 @@ impl CharacterBuilder {
         if class.is_spellcaster() {
             if let Some(ability) = class.spellcasting_ability() {
                 let mut spell_slots = SpellSlots::new();
+                // Pact Magic: 1 first-level slot at level 1, tracked apart from regular slots
+                let pact_slots =
+                    PactMagic::for_warlock_level(1).filter(|_| class == CharacterClass::Warlock);
 
                 // Set up level 1 spell slots based on class
                 match class {
-                    CharacterClass::Warlock => {
-                        // Pact Magic: 1 first-level slot at level 1
-                        spell_slots.slots[0] = SlotInfo { total: 1, used: 0 };
-                    }
                     CharacterClass::Bard
                     | CharacterClass::Cleric
                     | CharacterClass::Druid
@@ -419,6 +418,7 @@ impl CharacterBuilder {
                     spells_prepared,
                     cantrips_known,
                     spell_slots,
+                    pact_slots,
                 });
             }
         }
@@ -743,7 +743,11 @@ mod tests {
         assert_eq!(spellcasting.spells_known.len(), 2);
 
         // Warlock gets only 1 first-level slot (Pact Magic)
-        assert_eq!(spellcasting.spell_slots.slots[0].total, 1);
+        let pact = spellcasting
+            .pact_slots
+            .expect("warlock should have pact slots");
+        assert_eq!((pact.slot_level, pact.total), (1, 1));
+        assert_eq!(spellcasting.spell_slots.slots[0].total, 0);
     }
 
     #[test]
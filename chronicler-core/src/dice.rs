@@ -1,10 +1,17 @@
 //! D&D dice rolling system.
 //!
 //! Supports standard dice notation: XdY+Z, advantage/disadvantage,
-//! keep highest/lowest, and more.
+//! keep highest/lowest (`4d6kh3`), exploding dice (`1d10!`), and
+//! rerolls (`2d6ro2` rerolls once, `1d8rr1` rerolls until above 1),
+//! percentile dice (`d%`), and Fudge/Fate dice (`4dF`).
+//!
+//! Rolls draw from thread-local randomness unless a seeded [`DiceRng`] is
+//! installed with [`with_rng`], which makes a sequence of rolls repeatable.
 
-use rand::Rng;
+use rand::rngs::StdRng;
+use rand::{Rng, RngCore, SeedableRng};
 use serde::{Deserialize, Serialize};
+use std::cell::RefCell;
 use std::fmt;
 use std::str::FromStr;
 use thiserror::Error;
@@ -58,6 +65,8 @@ pub enum DieType {
     D12,
     D20,
     D100,
+    /// Fudge/Fate die with faces -1, 0, and +1, rolled as 1-3
+    Fudge,
 }
 
 impl DieType {
@@ -70,6 +79,25 @@ impl DieType {
             DieType::D12 => 12,
             DieType::D20 => 20,
             DieType::D100 => 100,
+            DieType::Fudge => 3,
+        }
+    }
+
+    /// The value a rolled face adds to the total.
+    pub fn face_value(&self, face: u32) -> i32 {
+        match self {
+            DieType::Fudge => face as i32 - 2,
+            _ => face as i32,
+        }
+    }
+
+    /// How a rolled face is shown, e.g. `+`, `0`, and `-` for Fudge dice.
+    pub fn face_label(&self, face: u32) -> String {
+        match (self, self.face_value(face)) {
+            (DieType::Fudge, 1) => "+".to_string(),
+            (DieType::Fudge, 0) => "0".to_string(),
+            (DieType::Fudge, _) => "-".to_string(),
+            (_, value) => value.to_string(),
         }
     }
 
@@ -89,21 +117,41 @@ impl DieType {
 
 impl fmt::Display for DieType {
     fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
-        write!(f, "d{}", self.sides())
+        match self {
+            DieType::Fudge => write!(f, "dF"),
+            _ => write!(f, "d{}", self.sides()),
+        }
     }
 }
 
+/// Rerolling low results, e.g. `ro2` for Great Weapon Fighting.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+pub struct Reroll {
+    /// Results at or below this value are rerolled
+    pub threshold: u32,
+    /// Reroll only once (`ro`) rather than until the result is above the threshold (`rr`)
+    pub once: bool,
+}
+
 /// A single die component of a dice expression.
-#[derive(Debug, Clone, Serialize, Deserialize)]
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
 pub struct DiceComponent {
     pub count: u32,
     pub die_type: DieType,
     pub keep_highest: Option<u32>,
     pub keep_lowest: Option<u32>,
+    /// Roll an extra die whenever a die shows its maximum
+    #[serde(default)]
+    pub exploding: bool,
+    #[serde(default)]
+    pub reroll: Option<Reroll>,
 }
 
+/// Limit on chained explosions from a single die, so a long streak can't run away.
+const MAX_EXPLOSIONS: u32 = 100;
+
 /// A complete dice expression (e.g., 2d6+3).
-#[derive(Debug, Clone, Serialize, Deserialize)]
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
 pub struct DiceExpression {
     pub components: Vec<DiceComponent>,
     pub modifier: i32,
@@ -170,30 +218,65 @@ impl DiceExpression {
                     .map_err(|_| DiceError::InvalidNotation(s.to_string()))?
             };
 
-            let (sides_str, keep_highest, keep_lowest) = if let Some(kh_pos) = rest.find("kh") {
-                let sides = &rest[..kh_pos];
-                let keep: u32 = rest[kh_pos + 2..]
-                    .parse()
-                    .map_err(|_| DiceError::InvalidNotation(s.to_string()))?;
-                (sides, Some(keep), None)
-            } else if let Some(kl_pos) = rest.find("kl") {
-                let sides = &rest[..kl_pos];
-                let keep: u32 = rest[kl_pos + 2..]
+            // The die size is the leading digits (or `%`/`f`); modifiers follow in any order
+            let (die_type, sides_end) = if rest.starts_with('%') {
+                (DieType::D100, 1)
+            } else if rest.starts_with('f') {
+                (DieType::Fudge, 1)
+            } else {
+                let sides_end = rest
+                    .find(|c: char| !c.is_ascii_digit())
+                    .unwrap_or(rest.len());
+                let sides: u32 = rest[..sides_end]
                     .parse()
                     .map_err(|_| DiceError::InvalidNotation(s.to_string()))?;
-                (sides, None, Some(keep))
-            } else {
-                (rest, None, None)
+                let die_type =
+                    DieType::from_sides(sides).ok_or(DiceError::InvalidDieSize(sides))?;
+                (die_type, sides_end)
             };
+            let sides = die_type.sides();
 
-            let sides: u32 = sides_str
-                .parse()
-                .map_err(|_| DiceError::InvalidNotation(s.to_string()))?;
-
-            let die_type = DieType::from_sides(sides).ok_or(DiceError::InvalidDieSize(sides))?;
+            let mut component = DiceComponent {
+                count,
+                die_type,
+                keep_highest: None,
+                keep_lowest: None,
+                exploding: false,
+                reroll: None,
+            };
+            let mut suffix = &rest[sides_end..];
+            while !suffix.is_empty() {
+                if let Some(after) = suffix.strip_prefix('!') {
+                    component.exploding = true;
+                    suffix = after;
+                    continue;
+                }
+                let invalid = || DiceError::InvalidNotation(s.to_string());
+                let (tag, after) = suffix.split_at_checked(2).ok_or_else(invalid)?;
+                let value_end = after
+                    .find(|c: char| !c.is_ascii_digit())
+                    .unwrap_or(after.len());
+                let value: u32 = after[..value_end].parse().map_err(|_| invalid())?;
+                match tag {
+                    "kh" => component.keep_highest = Some(value),
+                    "kl" => component.keep_lowest = Some(value),
+                    "ro" | "rr" => {
+                        // Rerolling every face would never stop
+                        if value >= sides {
+                            return Err(invalid());
+                        }
+                        component.reroll = Some(Reroll {
+                            threshold: value,
+                            once: tag == "ro",
+                        });
+                    }
+                    _ => return Err(invalid()),
+                }
+                suffix = &after[value_end..];
+            }
 
             // Validate keep count doesn't exceed dice count
-            if let Some(keep) = keep_highest.or(keep_lowest) {
+            if let Some(keep) = component.keep_highest.or(component.keep_lowest) {
                 if keep > count {
                     return Err(DiceError::InvalidKeepCount {
                         keep,
@@ -203,17 +286,12 @@ impl DiceExpression {
                 }
             }
 
-            components.push(DiceComponent {
-                count,
-                die_type,
-                keep_highest,
-                keep_lowest,
-            });
+            components.push(component);
         } else {
             let value: i32 = s
                 .parse()
                 .map_err(|_| DiceError::InvalidNotation(s.to_string()))?;
-            *modifier += sign * value;
+            *modifier = modifier.saturating_add(sign * value);
         }
 
         Ok(())
@@ -221,7 +299,7 @@ impl DiceExpression {
 
     /// Roll the dice expression and return the result.
     pub fn roll(&self) -> RollResult {
-        self.roll_with_rng(&mut rand::thread_rng())
+        with_current_rng(|mut rng| self.roll_with_rng(&mut rng))
     }
 
     /// Roll with a specific RNG (useful for testing).
@@ -230,23 +308,50 @@ impl DiceExpression {
         let mut component_results = Vec::new();
 
         for component in &self.components {
-            let mut rolls: Vec<u32> = (0..component.count)
-                .map(|_| rng.gen_range(1..=component.die_type.sides()))
-                .collect();
+            let sides = component.die_type.sides();
+            // Every die rolled, including results that were rerolled away
+            let mut rolls = Vec::new();
+            // The results that count toward the total before keeping
+            let mut results = Vec::new();
+            for _ in 0..component.count {
+                let mut value = rng.gen_range(1..=sides);
+                if let Some(reroll) = component.reroll {
+                    while value <= reroll.threshold {
+                        rolls.push(value);
+                        value = rng.gen_range(1..=sides);
+                        if reroll.once {
+                            break;
+                        }
+                    }
+                }
+                rolls.push(value);
+                results.push(value);
+
+                let mut explosions = 0;
+                while component.exploding && value == sides && explosions < MAX_EXPLOSIONS {
+                    value = rng.gen_range(1..=sides);
+                    rolls.push(value);
+                    results.push(value);
+                    explosions += 1;
+                }
+            }
 
             let kept_rolls = if let Some(keep) = component.keep_highest {
-                rolls.sort_by(|a, b| b.cmp(a));
-                rolls.truncate(keep as usize);
-                rolls.clone()
+                results.sort_by(|a, b| b.cmp(a));
+                results.truncate(keep as usize);
+                results
             } else if let Some(keep) = component.keep_lowest {
-                rolls.sort();
-                rolls.truncate(keep as usize);
-                rolls.clone()
+                results.sort();
+                results.truncate(keep as usize);
+                results
             } else {
-                rolls.clone()
+                results
             };
 
-            let subtotal: u32 = kept_rolls.iter().sum();
+            let subtotal: i32 = kept_rolls
+                .iter()
+                .map(|&face| component.die_type.face_value(face))
+                .sum();
             component_results.push(ComponentResult {
                 die_type: component.die_type,
                 rolls: rolls.clone(),
@@ -256,7 +361,7 @@ impl DiceExpression {
             all_rolls.extend(rolls);
         }
 
-        let dice_total: i32 = component_results.iter().map(|c| c.subtotal as i32).sum();
+        let dice_total: i32 = component_results.iter().map(|c| c.subtotal).sum();
         let total = dice_total + self.modifier;
 
         // Find the d20 result for natural 20/1 detection
@@ -278,7 +383,7 @@ impl DiceExpression {
 
     /// Roll with advantage/disadvantage (only applies to single d20 rolls).
     pub fn roll_with_advantage(&self, advantage: Advantage) -> RollResult {
-        self.roll_with_advantage_rng(advantage, &mut rand::thread_rng())
+        with_current_rng(|mut rng| self.roll_with_advantage_rng(advantage, &mut rng))
     }
 
     pub fn roll_with_advantage_rng<R: Rng>(&self, advantage: Advantage, rng: &mut R) -> RollResult {
@@ -318,7 +423,7 @@ impl DiceExpression {
                         die_type: DieType::D20,
                         rolls: vec![roll1, roll2],
                         kept: vec![chosen],
-                        subtotal: chosen,
+                        subtotal: chosen as i32,
                     }],
                     modifier: self.modifier,
                     total,
@@ -351,16 +456,17 @@ impl fmt::Display for DiceExpression {
 }
 
 /// Result of rolling a single dice component.
-#[derive(Debug, Clone, Serialize, Deserialize)]
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
 pub struct ComponentResult {
     pub die_type: DieType,
+    /// Faces rolled; see [`DieType::face_value`] for what each adds
     pub rolls: Vec<u32>,
     pub kept: Vec<u32>,
-    pub subtotal: u32,
+    pub subtotal: i32,
 }
 
 /// Complete result of a dice roll.
-#[derive(Debug, Clone, Serialize, Deserialize)]
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
 pub struct RollResult {
     pub expression: DiceExpression,
     pub component_results: Vec<ComponentResult>,
@@ -391,10 +497,11 @@ impl RollResult {
                             }
                         });
 
+                        let label = c.die_type.face_label(roll);
                         if is_kept {
-                            shown.push(format!("{roll}"));
+                            shown.push(label);
                         } else {
-                            shown.push(format!("({roll})"));
+                            shown.push(format!("({label})"));
                         }
                     }
                     format!("[{}]", shown.join(", "))
@@ -403,7 +510,7 @@ impl RollResult {
                         "[{}]",
                         c.rolls
                             .iter()
-                            .map(|r| r.to_string())
+                            .map(|&r| c.die_type.face_label(r))
                             .collect::<Vec<_>>()
                             .join(", ")
                     )
@@ -445,12 +552,89 @@ impl fmt::Display for RollResult {
     }
 }
 
+/// A seedable source of randomness for dice rolls.
+#[derive(Debug, Clone)]
+pub struct DiceRng(StdRng);
+
+impl DiceRng {
+    /// An RNG whose rolls are the same every time for a given seed.
+    pub fn seeded(seed: u64) -> Self {
+        Self(StdRng::seed_from_u64(seed))
+    }
+
+    /// An RNG seeded from system entropy.
+    pub fn from_entropy() -> Self {
+        Self(StdRng::from_entropy())
+    }
+}
+
+impl RngCore for DiceRng {
+    fn next_u32(&mut self) -> u32 {
+        self.0.next_u32()
+    }
+
+    fn next_u64(&mut self) -> u64 {
+        self.0.next_u64()
+    }
+
+    fn fill_bytes(&mut self, dest: &mut [u8]) {
+        self.0.fill_bytes(dest)
+    }
+
+    fn try_fill_bytes(&mut self, dest: &mut [u8]) -> Result<(), rand::Error> {
+        self.0.try_fill_bytes(dest)
+    }
+}
+
+thread_local! {
+    /// The RNG installed by [`with_rng`], if any.
+    static SCOPED_RNG: RefCell<Option<DiceRng>> = const { RefCell::new(None) };
+}
+
+/// Run `f` with every roll on this thread drawn from `rng`.
+///
+/// The RNG's state advances with each roll, so calling this again with the
+/// same `rng` continues the sequence.
+pub fn with_rng<T>(rng: &mut DiceRng, f: impl FnOnce() -> T) -> T {
+    /// Restores the previous RNG and hands the advanced one back, even on panic.
+    struct Restore<'a> {
+        rng: &'a mut DiceRng,
+        previous: Option<DiceRng>,
+    }
+
+    impl Drop for Restore<'_> {
+        fn drop(&mut self) {
+            let used = SCOPED_RNG.with(|slot| slot.replace(self.previous.take()));
+            if let Some(used) = used {
+                *self.rng = used;
+            }
+        }
+    }
+
+    let previous = SCOPED_RNG.with(|slot| slot.replace(Some(rng.clone())));
+    let _restore = Restore { rng, previous };
+    f()
+}
+
+/// Call `f` with the installed RNG, or thread-local randomness if none is.
+pub(crate) fn with_current_rng<T>(f: impl FnOnce(&mut dyn RngCore) -> T) -> T {
+    SCOPED_RNG.with(|slot| match slot.borrow_mut().as_mut() {
+        Some(rng) => f(rng),
+        None => f(&mut rand::thread_rng()),
+    })
+}
+
 /// Convenience function to roll dice from a notation string.
 pub fn roll(notation: &str) -> Result<RollResult, DiceError> {
     let expr = DiceExpression::parse(notation)?;
     Ok(expr.roll())
 }
 
+/// Roll percentile dice, returning 1-100.
+pub fn roll_percentile() -> u32 {
+    with_current_rng(|rng| rng.gen_range(1..=DieType::D100.sides()))
+}
+
 /// Roll with advantage/disadvantage.
 pub fn roll_with_advantage(notation: &str, advantage: Advantage) -> Result<RollResult, DiceError> {
     let expr = DiceExpression::parse(notation)?;
@@ -516,6 +700,158 @@ mod tests {
         assert!(result.is_ok());
     }
 
+    #[test]
+    fn test_keep_highest_for_ability_scores() {
+        let expr = DiceExpression::parse("4d6kh3").unwrap();
+        for _ in 0..100 {
+            let result = expr.roll();
+            let component = &result.component_results[0];
+            assert_eq!(component.rolls.len(), 4);
+            assert_eq!(component.kept.len(), 3);
+
+            let mut sorted = component.rolls.clone();
+            sorted.sort_by(|a, b| b.cmp(a));
+            assert_eq!(component.kept, sorted[..3]);
+            assert_eq!(result.total, component.kept.iter().sum::<u32>() as i32);
+        }
+    }
+
+    #[test]
+    fn test_exploding_dice() {
+        let expr = DiceExpression::parse("1d10!").unwrap();
+        assert!(expr.components[0].exploding);
+        assert_eq!(expr.components[0].die_type, DieType::D10);
+
+        for _ in 0..200 {
+            let result = expr.roll();
+            let component = &result.component_results[0];
+            // Every die but the last exploded, so it must have rolled a 10
+            let (last, exploded) = component.rolls.split_last().unwrap();
+            assert!(exploded.iter().all(|&r| r == 10));
+            assert!(*last >= 1 && *last <= 10);
+            assert_eq!(component.kept, component.rolls);
+            assert_eq!(result.total, component.rolls.iter().sum::<u32>() as i32);
+        }
+    }
+
+    #[test]
+    fn test_reroll_once() {
+        let expr = DiceExpression::parse("2d6ro2").unwrap();
+        assert_eq!(
+            expr.components[0].reroll,
+            Some(Reroll {
+                threshold: 2,
+                once: true
+            })
+        );
+
+        for _ in 0..200 {
+            let result = expr.roll();
+            let component = &result.component_results[0];
+            // Each die is rerolled at most once, and only the second result counts
+            assert_eq!(component.kept.len(), 2);
+            assert!(component.rolls.len() >= 2 && component.rolls.len() <= 4);
+            assert_eq!(result.total, component.kept.iter().sum::<u32>() as i32);
+        }
+    }
+
+    #[test]
+    fn test_reroll_always() {
+        let expr = DiceExpression::parse("3d8rr1").unwrap();
+        for _ in 0..100 {
+            let result = expr.roll();
+            assert!(result.component_results[0].kept.iter().all(|&r| r > 1));
+        }
+
+        // Rerolling every face would never finish
+        assert!(DiceExpression::parse("1d6rr6").is_err());
+        assert!(DiceExpression::parse("1d6ro").is_err());
+        assert!(DiceExpression::parse("1d6xx2").is_err());
+    }
+
+    #[test]
+    fn test_parse_never_panics() {
+        use rand::{rngs::StdRng, SeedableRng};
+
+        let alphabet: Vec<char> = "0123456789dkhlro!+- ".chars().collect();
+        let mut rng = StdRng::seed_from_u64(26);
+        for _ in 0..20_000 {
+            let len = rng.gen_range(0..16);
+            let notation: String = (0..len)
+                .map(|_| alphabet[rng.gen_range(0..alphabet.len())])
+                .collect();
+            if let Ok(expr) = DiceExpression::parse(&notation) {
+                // Only roll expressions of a sensible size
+                if expr.components.iter().map(|c| c.count as u64).sum::<u64>() <= 1000 {
+                    let _ = expr.roll_with_rng(&mut rng).dice_display();
+                }
+            }
+        }
+    }
+
+    #[test]
+    fn test_seeded_rng_repeats_rolls() {
+        let mut first = DiceRng::seeded(42);
+        let mut second = DiceRng::seeded(42);
+        let a: Vec<_> = with_rng(&mut first, || {
+            (0..5).map(|_| roll("2d6+3").unwrap()).collect()
+        });
+        let b: Vec<_> = with_rng(&mut second, || {
+            (0..5).map(|_| roll("2d6+3").unwrap()).collect()
+        });
+        assert_eq!(a, b);
+
+        // The RNG keeps its place between scopes
+        let next_a = with_rng(&mut first, roll_percentile);
+        let next_b = with_rng(&mut second, roll_percentile);
+        assert_eq!(next_a, next_b);
+    }
+
+    #[test]
+    fn test_percentile_is_uniform() {
+        use rand::{rngs::StdRng, SeedableRng};
+
+        let expr = DiceExpression::parse("d%").unwrap();
+        assert_eq!(expr.components[0].die_type, DieType::D100);
+        assert_eq!(
+            DiceExpression::parse("1d100").unwrap().components[0].die_type,
+            DieType::D100
+        );
+
+        let mut rng = StdRng::seed_from_u64(100);
+        let mut counts = [0u32; 100];
+        for _ in 0..100_000 {
+            let total = expr.roll_with_rng(&mut rng).total;
+            assert!((1..=100).contains(&total));
+            counts[total as usize - 1] += 1;
+        }
+        // Each face expects 1000 hits; a fair die stays well inside this band
+        assert!(counts.iter().all(|&n| (850..=1150).contains(&n)));
+
+        for _ in 0..100 {
+            assert!((1..=100).contains(&roll_percentile()));
+        }
+    }
+
+    #[test]
+    fn test_fudge_dice() {
+        let expr = DiceExpression::parse("4dF").unwrap();
+        assert_eq!(expr.components[0].die_type, DieType::Fudge);
+        assert_eq!(expr.components[0].count, 4);
+
+        for _ in 0..200 {
+            let result = expr.roll();
+            assert!((-4..=4).contains(&result.total));
+            let display = result.dice_display();
+            assert!(display
+                .chars()
+                .all(|c| matches!(c, '[' | ']' | ',' | ' ' | '+' | '0' | '-')));
+        }
+
+        let result = roll("4dF+2").unwrap();
+        assert!((-2..=6).contains(&result.total));
+    }
+
     #[test]
     fn test_roll_range() {
         for _ in 0..100 {
@@ -5,14 +5,24 @@
 //! and tool calls that are resolved by the RulesEngine.
 
 use super::memory::{DmMemory, FactCategory};
-use super::relevance::{InferredStateChange, RelevanceChecker, RelevanceResult, StateInferrer};
+use super::recap;
+use super::relevance::{
+    InferredStateChange, MemoryConsolidator, RelevanceChecker, RelevanceResult, StateInferrer,
+};
 use super::story_memory::{
-    ConsequenceSeverity, EntityType, FactCategory as StoryFactCategory, FactSource, StoryMemory,
+    ConsequenceSeverity, EntityType, EventTrigger, EventVisibility,
+    FactCategory as StoryFactCategory, FactSource, KnowledgeSource, StoryFact, StoryMemory,
+    TriggerContext, VerificationStatus,
+};
+use super::tools::{
+    execute_info_tool_with_memory, name_lookup_error, parse_tool_call, parse_tool_call_with_target,
+    DmTools,
 };
-use super::tools::{execute_info_tool_with_memory, parse_tool_call, DmTools};
 use crate::rules::{apply_effects, Effect, Intent, Resolution, RulesEngine, StateType};
-use crate::world::{GameMode, GameWorld, NarrativeType};
-use claude::{Claude, ContentBlock, Message, Request, StopReason, StreamEvent, ToolResult};
+use crate::world::{CharacterId, GameMode, GameWorld, NarrativeType, WorldHistory};
+use claude::{
+    CharEstimate, Claude, ContentBlock, Message, Request, StopReason, StreamEvent, ToolResult,
+};
 use futures::StreamExt;
 use thiserror::Error;
 
@@ -111,6 +121,7 @@ pub struct DungeonMaster {
     memory: DmMemory,
     story_memory: StoryMemory,
     rules: RulesEngine,
+    history: WorldHistory,
 }
 
 impl DungeonMaster {
@@ -122,6 +133,7 @@ impl DungeonMaster {
             memory: DmMemory::new(),
             story_memory: StoryMemory::new(),
             rules: RulesEngine::new(),
+            history: WorldHistory::default(),
         }
     }
 
@@ -134,6 +146,7 @@ impl DungeonMaster {
             memory: DmMemory::new(),
             story_memory: StoryMemory::new(),
             rules: RulesEngine::new(),
+            history: WorldHistory::default(),
         })
     }
 
@@ -147,6 +160,16 @@ impl DungeonMaster {
         &mut self.story_memory
     }
 
+    /// Get the undo/redo history of applied effects.
+    pub fn history(&self) -> &WorldHistory {
+        &self.history
+    }
+
+    /// Get a mutable reference to the undo/redo history.
+    pub fn history_mut(&mut self) -> &mut WorldHistory {
+        &mut self.history
+    }
+
     /// Configure the DungeonMaster.
     pub fn with_config(mut self, config: DmConfig) -> Self {
         self.config = config;
@@ -199,8 +222,9 @@ impl DungeonMaster {
         let mut all_resolutions = Vec::new();
         let mut narrative = String::new();
 
-        // Build initial messages
-        let mut messages = self.memory.get_messages();
+        // Build initial messages, dropping the oldest if the context would
+        // exceed the memory's token budget
+        let mut messages = self.messages_within_budget(&system_prompt);
 
         // Tool use loop
         loop {
@@ -253,20 +277,27 @@ impl DungeonMaster {
             // Execute tools and collect results
             let mut tool_results = Vec::new();
             for (id, name, input) in tool_uses {
-                // First check if it's an informational tool
-                let result = if let Some(info_result) =
+                // Reject input that doesn't match the tool's schema
+                let result = if let Err(err) = DmTools::validate(&name, &input) {
+                    ToolResult::error(format!("Invalid input for {name}: {err}"))
+                } else if let Some(info_result) =
                     execute_info_tool_with_memory(&name, &input, world, &self.story_memory)
                 {
                     // Info tools just return data without changing state
                     ToolResult::success(&info_result)
                 } else if let Some(intent) = parse_tool_call(&name, &input, world) {
                     // Resolve the intent
-                    let resolution = self.rules.resolve(world, intent.clone());
+                    let mut resolution = self.rules.resolve(world, intent.clone());
 
                     // Apply effects to world
-                    apply_effects(world, &resolution.effects);
+                    self.apply_recorded(world, &resolution.effects);
+                    self.resolve_queued_intents(world, &mut resolution);
+                    self.store_scheduled_event(&intent, world);
+                    self.fire_scheduled_events(world, &mut resolution);
+                    self.fire_consequence_triggers(&mut resolution);
 
                     // Handle FactRemembered and ConsequenceRegistered effects specially - store in story memory
+                    let mut conflicts = Vec::new();
                     for effect in &resolution.effects {
                         match effect {
                             Effect::FactRemembered {
@@ -276,15 +307,17 @@ impl DungeonMaster {
                                 category,
                                 related_entities,
                                 importance,
+                                supersedes,
                             } => {
-                                self.store_fact(
+                                conflicts.extend(self.store_fact(
                                     subject_name,
                                     subject_type,
                                     fact,
                                     category,
                                     related_entities,
                                     *importance,
-                                );
+                                    *supersedes,
+                                ));
                             }
                             Effect::ConsequenceRegistered {
                                 trigger_description,
@@ -298,9 +331,27 @@ impl DungeonMaster {
                                     severity,
                                 );
                             }
+                            Effect::KnowledgeShared {
+                                knowing_entity,
+                                content,
+                                source,
+                                verification,
+                                context,
+                            } => {
+                                self.store_knowledge(
+                                    knowing_entity,
+                                    content,
+                                    source,
+                                    verification,
+                                    context.clone(),
+                                );
+                            }
                             _ => {}
                         }
                     }
+                    for conflict in conflicts {
+                        resolution.narrative.push_str(&format!("\n{conflict}"));
+                    }
 
                     // Store for response
                     all_intents.push(intent);
@@ -310,7 +361,7 @@ impl DungeonMaster {
                     // Return narrative as tool result
                     ToolResult::success(&resolution.narrative)
                 } else {
-                    ToolResult::error(format!("Unknown tool: {name}"))
+                    tool_call_error(&name, &input, world)
                 };
 
                 tool_results.push(ContentBlock::ToolResult {
@@ -333,6 +384,9 @@ impl DungeonMaster {
         // Add to game world narrative
         world.add_narrative(narrative.clone(), NarrativeType::DmNarration);
 
+        // Remember the exchange for any NPCs the player addressed
+        world.record_npc_dialogue(player_input, &narrative);
+
         // Run post-narrative state inference if enabled
         let inferred_state_changes = if self.config.enable_state_inference {
             self.infer_and_apply_state_changes(&narrative, world)
@@ -364,7 +418,7 @@ impl DungeonMaster {
         F: FnMut(&str) + Send,
     {
         // Delegate to the version with effects callback, using a no-op for effects
-        self.process_input_streaming_with_effects(player_input, world, on_text, |_| {})
+        self.process_input_streaming_with_effects(player_input, world, None, on_text, |_| {})
             .await
     }
 
@@ -372,11 +426,13 @@ impl DungeonMaster {
     ///
     /// The `on_text` callback is invoked with each text chunk as it arrives.
     /// The `on_effect` callback is invoked immediately when effects are generated,
-    /// allowing real-time sound and animation triggering.
+    /// allowing real-time sound and animation triggering. `selected_target` is
+    /// the combatant the player picked for this action, if any.
     pub async fn process_input_streaming_with_effects<F, E>(
         &mut self,
         player_input: &str,
         world: &mut GameWorld,
+        selected_target: Option<CharacterId>,
         mut on_text: F,
         mut on_effect: E,
     ) -> Result<DmResponse, DmError>
@@ -414,8 +470,9 @@ impl DungeonMaster {
         let mut all_resolutions = Vec::new();
         let mut narrative = String::new();
 
-        // Build initial messages
-        let mut messages = self.memory.get_messages();
+        // Build initial messages, dropping the oldest if the context would
+        // exceed the memory's token budget
+        let mut messages = self.messages_within_budget(&system_prompt);
 
         // Tool use loop
         let mut iteration = 0;
@@ -464,16 +521,14 @@ impl DungeonMaster {
                         content_type,
                         tool_use_id,
                         tool_name,
-                    } => {
-                        if content_type == "tool_use" {
-                            // Start accumulating a new tool use
-                            current_tool_index = Some(index);
-                            tool_uses.push(PartialToolUse {
-                                id: tool_use_id.unwrap_or_default(),
-                                name: tool_name.unwrap_or_default(),
-                                json_buffer: String::new(),
-                            });
-                        }
+                    } if content_type == "tool_use" => {
+                        // Start accumulating a new tool use
+                        current_tool_index = Some(index);
+                        tool_uses.push(PartialToolUse {
+                            id: tool_use_id.unwrap_or_default(),
+                            name: tool_name.unwrap_or_default(),
+                            json_buffer: String::new(),
+                        });
                     }
                     StreamEvent::InputJsonDelta {
                         index,
@@ -488,11 +543,11 @@ impl DungeonMaster {
                             }
                         }
                     }
-                    StreamEvent::ContentBlockStop { index } => {
+                    StreamEvent::ContentBlockStop { index }
+                        if Some(index) == current_tool_index =>
+                    {
                         // Reset current tool index if this was a tool block
-                        if Some(index) == current_tool_index {
-                            current_tool_index = None;
-                        }
+                        current_tool_index = None;
                     }
                     StreamEvent::MessageDelta {
                         stop_reason: Some(sr),
@@ -550,22 +605,31 @@ impl DungeonMaster {
                 let input: serde_json::Value = serde_json::from_str(&tool.json_buffer)
                     .unwrap_or_else(|_| serde_json::json!({}));
 
-                // First check if it's an informational tool
-                let result = if let Some(info_result) =
+                // Reject input that doesn't match the tool's schema
+                let result = if let Err(err) = DmTools::validate(&tool.name, &input) {
+                    ToolResult::error(format!("Invalid input for {}: {err}", tool.name))
+                } else if let Some(info_result) =
                     execute_info_tool_with_memory(&tool.name, &input, world, &self.story_memory)
                 {
                     // Info tools just return data without changing state
                     ToolResult::success(&info_result)
-                } else if let Some(intent) = parse_tool_call(&tool.name, &input, world) {
+                } else if let Some(intent) =
+                    parse_tool_call_with_target(&tool.name, &input, world, selected_target)
+                {
                     // Resolve the intent
-                    let resolution = self.rules.resolve(world, intent.clone());
+                    let mut resolution = self.rules.resolve(world, intent.clone());
 
                     // Apply effects to world (unless deferred)
                     if !self.config.deferred_effects {
-                        apply_effects(world, &resolution.effects);
+                        self.apply_recorded(world, &resolution.effects);
+                        self.resolve_queued_intents(world, &mut resolution);
+                        self.fire_scheduled_events(world, &mut resolution);
+                        self.fire_consequence_triggers(&mut resolution);
                     }
+                    self.store_scheduled_event(&intent, world);
 
                     // Handle FactRemembered and ConsequenceRegistered effects specially - store in story memory
+                    let mut conflicts = Vec::new();
                     for effect in &resolution.effects {
                         match effect {
                             Effect::FactRemembered {
@@ -575,15 +639,17 @@ impl DungeonMaster {
                                 category,
                                 related_entities,
                                 importance,
+                                supersedes,
                             } => {
-                                self.store_fact(
+                                conflicts.extend(self.store_fact(
                                     subject_name,
                                     subject_type,
                                     fact,
                                     category,
                                     related_entities,
                                     *importance,
-                                );
+                                    *supersedes,
+                                ));
                             }
                             Effect::ConsequenceRegistered {
                                 trigger_description,
@@ -597,9 +663,27 @@ impl DungeonMaster {
                                     severity,
                                 );
                             }
+                            Effect::KnowledgeShared {
+                                knowing_entity,
+                                content,
+                                source,
+                                verification,
+                                context,
+                            } => {
+                                self.store_knowledge(
+                                    knowing_entity,
+                                    content,
+                                    source,
+                                    verification,
+                                    context.clone(),
+                                );
+                            }
                             _ => {}
                         }
                     }
+                    for conflict in conflicts {
+                        resolution.narrative.push_str(&format!("\n{conflict}"));
+                    }
 
                     // Stream effects in real-time for immediate sound/animation (unless deferred)
                     if !self.config.deferred_effects {
@@ -616,7 +700,7 @@ impl DungeonMaster {
                     // Return narrative as tool result
                     ToolResult::success(&resolution.narrative)
                 } else {
-                    ToolResult::error(format!("Unknown tool: {}", tool.name))
+                    tool_call_error(&tool.name, &input, world)
                 };
 
                 tool_results.push(ContentBlock::ToolResult {
@@ -637,7 +721,13 @@ impl DungeonMaster {
 
         // If effects were deferred, apply them all atomically now that streaming succeeded
         if self.config.deferred_effects && !all_effects.is_empty() {
-            apply_effects(world, &all_effects);
+            self.apply_recorded(world, &all_effects);
+            let mut deferred = Resolution::new(String::new());
+            deferred.effects = all_effects.clone();
+            self.resolve_queued_intents(world, &mut deferred);
+            self.fire_scheduled_events(world, &mut deferred);
+            self.fire_consequence_triggers(&mut deferred);
+            all_effects = deferred.effects;
             // Stream all effects at once at the end
             for effect in &all_effects {
                 on_effect(effect);
@@ -650,6 +740,9 @@ impl DungeonMaster {
         // Add to game world narrative
         world.add_narrative(narrative.clone(), NarrativeType::DmNarration);
 
+        // Remember the exchange for any NPCs the player addressed
+        world.record_npc_dialogue(player_input, &narrative);
+
         // Run post-narrative state inference if enabled
         let inferred_state_changes = if self.config.enable_state_inference {
             self.infer_and_apply_state_changes(&narrative, world)
@@ -755,6 +848,9 @@ impl DungeonMaster {
             pc.hit_points.current, pc.hit_points.maximum
         ));
         prompt.push_str(&format!("**AC:** {}\n", pc.current_ac()));
+        if pc.has_inspiration {
+            prompt.push_str("**Inspiration:** Yes (can spend for advantage)\n");
+        }
 
         // Add backstory if present
         if let Some(ref backstory) = pc.backstory {
@@ -799,6 +895,14 @@ impl DungeonMaster {
             if !slots.is_empty() {
                 prompt.push_str(&format!("- Spell Slots: {}\n", slots.join(", ")));
             }
+            if let Some(pact) = spellcasting.pact_slots {
+                prompt.push_str(&format!(
+                    "- Pact Slots: {}/{} (level {}, recover on short rest)\n",
+                    pact.available(),
+                    pact.total,
+                    pact.slot_level
+                ));
+            }
         }
 
         // Add ability scores
@@ -890,6 +994,13 @@ impl DungeonMaster {
             }
         }
 
+        if let Some(ref concentration) = pc.concentrating_on {
+            prompt.push_str(&format!(
+                "\nConcentrating on: {} (call concentration_check when damaged)\n",
+                concentration.spell_name
+            ));
+        }
+
         // Add memory context
         let memory_context = self.memory.build_context();
         if !memory_context.is_empty() {
@@ -897,6 +1008,14 @@ impl DungeonMaster {
             prompt.push_str(&memory_context);
         }
 
+        // Recap earlier conversations with NPCs mentioned in player input
+        for id in world.npcs_mentioned_in(player_input) {
+            if let Some(recap) = world.npcs.get(&id).and_then(|npc| npc.dialogue_recap()) {
+                prompt.push('\n');
+                prompt.push_str(&recap);
+            }
+        }
+
         // Add story memory context for entities mentioned in player input
         let story_context = self.story_memory.build_context_for_input(player_input);
         if !story_context.is_empty() {
@@ -990,7 +1109,7 @@ impl DungeonMaster {
                 };
 
                 let resolution = self.rules.resolve(world, intent);
-                apply_effects(world, &resolution.effects);
+                self.apply_recorded(world, &resolution.effects);
 
                 // Also record as a fact in story memory
                 self.store_fact(
@@ -1003,6 +1122,7 @@ impl DungeonMaster {
                     &change.state_type,
                     &[],
                     0.7,
+                    true,
                 );
             }
         }
@@ -1011,6 +1131,10 @@ impl DungeonMaster {
     }
 
     /// Store a fact in story memory.
+    ///
+    /// Returns a note for the DM when the fact was refused because it likely
+    /// contradicts an earlier one and `supersedes` wasn't set.
+    #[allow(clippy::too_many_arguments)]
     fn store_fact(
         &mut self,
         subject_name: &str,
@@ -1019,7 +1143,8 @@ impl DungeonMaster {
         category: &str,
         related_entities: &[String],
         importance: f32,
-    ) {
+        supersedes: bool,
+    ) -> Option<String> {
         // Parse entity type
         let entity_type = match subject_type.to_lowercase().as_str() {
             "npc" => EntityType::Npc,
@@ -1064,14 +1189,37 @@ impl DungeonMaster {
         }
 
         // Record the fact with the specified importance
-        self.story_memory.record_fact_full(
+        let mut story_fact = StoryFact::new(
             subject_id,
             fact,
             fact_category,
             FactSource::DmNarration,
-            &mentioned_ids,
-            importance,
-        );
+            self.story_memory.current_turn(),
+        )
+        .with_importance(importance);
+        for id in mentioned_ids {
+            story_fact = story_fact.with_mentioned(id);
+        }
+
+        loop {
+            let conflict = match self.story_memory.add_fact_checked(story_fact) {
+                Ok(_) => return None,
+                Err(conflict) => conflict,
+            };
+            if !supersedes {
+                let existing = self
+                    .story_memory
+                    .get_fact(conflict.existing)
+                    .map(|f| f.content.clone())
+                    .unwrap_or_default();
+                return Some(format!(
+                    "Not recorded: this may contradict an earlier fact about {subject_name}: \"{existing}\". If the situation changed, call remember_fact again with supersedes=true; otherwise reconcile the two in your narration."
+                ));
+            }
+            // Retire each contradicted fact until the new one fits
+            self.story_memory.supersede_fact(conflict.existing);
+            story_fact = *conflict.incoming;
+        }
     }
 
     /// Store a consequence in story memory.
@@ -1096,6 +1244,227 @@ impl DungeonMaster {
         );
     }
 
+    /// Store knowledge shared with an entity in story memory.
+    fn store_knowledge(
+        &mut self,
+        knowing_entity: &str,
+        content: &str,
+        source: &str,
+        verification: &str,
+        context: Option<String>,
+    ) {
+        let knower_id = self
+            .story_memory
+            .get_or_create_entity(EntityType::Npc, knowing_entity);
+        let source_id = self.story_memory.find_entity_id(source);
+
+        self.story_memory.share_knowledge(
+            knower_id,
+            content,
+            VerificationStatus::parse(verification),
+            KnowledgeSource::from_str(source, source_id),
+            context,
+        );
+    }
+
+    /// Record an event scheduled (or cancelled) with the schedule tools in story memory.
+    fn store_scheduled_event(&mut self, intent: &Intent, world: &GameWorld) {
+        match intent {
+            Intent::ScheduleEvent {
+                description,
+                minutes,
+                hours,
+                day,
+                month,
+                year,
+                hour,
+                daily_hour,
+                daily_minute,
+                location,
+                involved_entities,
+                visibility,
+                repeating,
+            } => {
+                // Measure relative triggers from the current game time
+                self.story_memory.sync_time(&world.game_time);
+                let id = if let Some(daily_hour) = *daily_hour {
+                    let daily_minute = daily_minute.unwrap_or(0);
+                    if *repeating {
+                        self.story_memory
+                            .schedule_daily(description, daily_hour, daily_minute)
+                    } else {
+                        self.story_memory.schedule_event(
+                            description,
+                            EventTrigger::TimeOfDay {
+                                hour: daily_hour,
+                                minute: daily_minute,
+                            },
+                        )
+                    }
+                } else if let (Some(day), Some(month), Some(year)) = (*day, *month, *year) {
+                    self.story_memory
+                        .schedule_at_time(description, year, month, day, *hour)
+                } else if minutes.is_some() || hours.is_some() {
+                    let total = minutes.unwrap_or(0) + hours.unwrap_or(0) * 60;
+                    self.story_memory
+                        .schedule_after_duration(description, total)
+                } else {
+                    // Untimed events are left for the DM to trigger in the narrative
+                    return;
+                };
+                self.story_memory.configure_event(
+                    id,
+                    location.clone(),
+                    Some(involved_entities.clone()),
+                    EventVisibility::parse(visibility),
+                    None,
+                );
+            }
+            Intent::CancelEvent {
+                event_description, ..
+            } => {
+                let pending = self
+                    .story_memory
+                    .pending_events()
+                    .into_iter()
+                    .find(|e| e.description.eq_ignore_ascii_case(event_description))
+                    .map(|e| e.id);
+                if let Some(id) = pending {
+                    self.story_memory.cancel_event(id);
+                }
+            }
+            _ => {}
+        }
+    }
+
+    /// After a resolution advances game time, fire the scheduled events that came
+    /// due and tell the DM about them.
+    fn fire_scheduled_events(&mut self, world: &GameWorld, resolution: &mut Resolution) {
+        let advanced = resolution
+            .effects
+            .iter()
+            .any(|e| matches!(e, Effect::TimeAdvanced { .. }));
+        if !advanced {
+            return;
+        }
+
+        for event in self.story_memory.tick(&world.game_time) {
+            let at = event
+                .location
+                .as_ref()
+                .map(|l| format!(" at {l}"))
+                .unwrap_or_default();
+            resolution.narrative.push_str(&format!(
+                "\nScheduled event triggered{at}: {}",
+                event.description
+            ));
+            resolution.effects.push(Effect::EventTriggered {
+                description: event.description,
+                location: event.location,
+            });
+        }
+    }
+
+    /// Resolve the intents queued while applying a resolution's effects, such as
+    /// automatic concentration checks, and fold their outcomes into it.
+    fn resolve_queued_intents(&self, world: &mut GameWorld, resolution: &mut Resolution) {
+        for intent in world.take_queued_intents() {
+            let queued = self.rules.resolve(world, intent);
+            apply_effects(world, &queued.effects);
+            resolution
+                .narrative
+                .push_str(&format!("\n{}", queued.narrative));
+            resolution.effects.extend(queued.effects);
+        }
+    }
+
+    /// Apply effects to the world, recording an undo snapshot first.
+    fn apply_recorded(&mut self, world: &mut GameWorld, effects: &[Effect]) {
+        if !effects.is_empty() {
+            self.history.record(world);
+        }
+        apply_effects(world, effects);
+    }
+
+    /// Fire pending consequences whose triggers match the locations entered
+    /// and NPCs dealt with in a resolution, and tell the DM about them.
+    fn fire_consequence_triggers(&mut self, resolution: &mut Resolution) {
+        let mut context = TriggerContext::new();
+        for effect in &resolution.effects {
+            match effect {
+                Effect::LocationChanged { new_location, .. } => {
+                    context.entered_location = Some(new_location.clone());
+                }
+                Effect::NpcUpdated { npc_name, .. } => context.npcs.push(npc_name.clone()),
+                _ => {}
+            }
+        }
+
+        for id in self.story_memory.check_triggers(&context) {
+            let Some(consequence) = self.story_memory.get_consequence(id) else {
+                continue;
+            };
+            resolution.narrative.push_str(&format!(
+                "\nConsequence triggered: {}",
+                consequence.consequence_description
+            ));
+            resolution.effects.push(Effect::ConsequenceTriggered {
+                consequence_id: id.to_string(),
+                consequence_description: consequence.consequence_description.clone(),
+            });
+        }
+    }
+
+    /// Roll old event facts in story memory up into summaries using a fast model.
+    ///
+    /// Returns the number of summary facts created.
+    pub async fn consolidate_memory(&mut self) -> Result<usize, DmError> {
+        let batches: Vec<Vec<StoryFact>> = self
+            .story_memory
+            .consolidation_batches()
+            .into_iter()
+            .map(|batch| batch.into_iter().cloned().collect())
+            .collect();
+        if batches.is_empty() {
+            return Ok(0);
+        }
+
+        let consolidator = MemoryConsolidator::new(self.client.clone());
+        let mut created = 0;
+        for batch in batches {
+            let facts: Vec<&StoryFact> = batch.iter().collect();
+            let summary = consolidator
+                .summarize(&facts)
+                .await
+                .map_err(|e| DmError::ToolError(format!("Memory consolidation failed: {e}")))?;
+            let ids: Vec<_> = batch.iter().map(|f| f.id).collect();
+            if self.story_memory.consolidate(&ids, summary).is_some() {
+                created += 1;
+            }
+        }
+        Ok(created)
+    }
+
+    /// Write a short "previously on..." recap of the campaign for a returning player.
+    pub async fn generate_recap(
+        &self,
+        world: &GameWorld,
+        n_recent_facts: usize,
+    ) -> Result<String, DmError> {
+        Ok(recap::generate_recap(&self.client, world, &self.story_memory, n_recent_facts).await?)
+    }
+
+    /// Conversation history that fits in the token budget left over after the
+    /// system prompt and tool definitions.
+    fn messages_within_budget(&self, system_prompt: &str) -> Vec<Message> {
+        let fixed = Request::new(Vec::new())
+            .with_system(system_prompt)
+            .with_tools(DmTools::all())
+            .estimated_tokens(&CharEstimate);
+        let budget = self.memory.token_budget.saturating_sub(fixed);
+        self.memory.get_messages_within(budget, &CharEstimate)
+    }
+
     /// Check relevance of stored context against player input using a fast model.
     ///
     /// Returns triggered consequences and relevant entities that should be
@@ -1167,6 +1536,21 @@ struct PartialToolUse {
     json_buffer: String,
 }
 
+/// The error returned to the DM when a tool call can't be turned into an intent:
+/// the tool doesn't exist, a creature it names can't be found or told apart,
+/// or its input was missing something it needs.
+fn tool_call_error(name: &str, input: &serde_json::Value, world: &GameWorld) -> ToolResult {
+    if let Some(err) = name_lookup_error(name, input, world) {
+        ToolResult::error(format!("Invalid input for {name}: {err}"))
+    } else if DmTools::get(name).is_some() {
+        ToolResult::error(format!(
+            "Invalid input for {name}: check the required fields in its schema"
+        ))
+    } else {
+        ToolResult::error(format!("Unknown tool: {name}"))
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -1295,4 +1679,111 @@ mod tests {
         assert_eq!(partial.name, "roll_dice");
         assert!(partial.json_buffer.contains("1d20"));
     }
+
+    #[test]
+    fn test_scheduled_event_fires_when_time_advances() {
+        let mut dm = DungeonMaster::new("test-key");
+        let mut world = create_test_world();
+
+        let intent = Intent::ScheduleEvent {
+            description: "The spy arrives at the docks".to_string(),
+            minutes: None,
+            hours: Some(2),
+            day: None,
+            month: None,
+            year: None,
+            hour: None,
+            daily_hour: None,
+            daily_minute: None,
+            location: Some("The Docks".to_string()),
+            involved_entities: vec![],
+            visibility: "public".to_string(),
+            repeating: false,
+        };
+        dm.store_scheduled_event(&intent, &world);
+        assert_eq!(dm.story_memory().pending_events().len(), 1);
+
+        let mut resolution =
+            Resolution::new("Time passes.").with_effect(Effect::TimeAdvanced { minutes: 180 });
+        apply_effects(&mut world, &resolution.effects);
+        dm.fire_scheduled_events(&world, &mut resolution);
+
+        assert!(resolution.effects.iter().any(|e| matches!(
+            e,
+            Effect::EventTriggered { description, .. } if description == "The spy arrives at the docks"
+        )));
+        assert!(resolution
+            .narrative
+            .contains("Scheduled event triggered at The Docks"));
+        assert!(dm.story_memory().pending_events().is_empty());
+    }
+
+    #[test]
+    fn test_consequence_fires_when_player_enters_location() {
+        let mut dm = DungeonMaster::new("test-key");
+        dm.story_memory_mut().create_consequence(
+            "The player enters Riverside",
+            "Guards recognize the player",
+            ConsequenceSeverity::Major,
+        );
+
+        let mut resolution = Resolution::new("You travel.").with_effect(Effect::LocationChanged {
+            previous_location: "The Road".to_string(),
+            new_location: "Riverside".to_string(),
+        });
+        dm.fire_consequence_triggers(&mut resolution);
+
+        assert!(resolution.effects.iter().any(|e| matches!(
+            e,
+            Effect::ConsequenceTriggered { consequence_description, .. }
+                if consequence_description == "Guards recognize the player"
+        )));
+        assert!(resolution
+            .narrative
+            .contains("Consequence triggered: Guards recognize the player"));
+        assert_eq!(dm.story_memory().pending_consequence_count(), 0);
+    }
+
+    #[test]
+    fn test_tool_call_error_distinguishes_unknown_tools() {
+        let world = create_test_world();
+        let unknown = tool_call_error("summon_dragon", &serde_json::json!({}), &world);
+        assert!(unknown.is_error);
+        assert_eq!(unknown.content, "Unknown tool: summon_dragon");
+
+        let invalid = tool_call_error("apply_damage", &serde_json::json!({}), &world);
+        assert!(invalid.is_error);
+        assert!(invalid
+            .content
+            .starts_with("Invalid input for apply_damage"));
+
+        // A target that can't be found is named in the error
+        let input = serde_json::json!({"amount": 3, "damage_type": "fire", "target": "Mira"});
+        let missing = tool_call_error("apply_damage", &input, &world);
+        assert!(missing.is_error);
+        assert!(missing.content.contains("No one named 'Mira' was found."));
+    }
+
+    #[test]
+    fn test_contradictory_fact_is_refused_until_superseded() {
+        let mut dm = DungeonMaster::new("test-key");
+        let remember = |dm: &mut DungeonMaster, fact: &str, supersedes: bool| {
+            dm.store_fact("Mira", "npc", fact, "status", &[], 0.7, supersedes)
+        };
+
+        assert!(remember(&mut dm, "Mira is alive", false).is_none());
+        let note = remember(&mut dm, "Mira was slain by the cultists", false).unwrap();
+        assert!(note.contains("\"Mira is alive\""));
+        assert_eq!(dm.story_memory().fact_count(), 1);
+
+        assert!(remember(&mut dm, "Mira was slain by the cultists", true).is_none());
+        let mira = dm.story_memory().find_entity_id("Mira").unwrap();
+        let current: Vec<_> = dm
+            .story_memory()
+            .facts_about(mira)
+            .iter()
+            .map(|f| f.content.clone())
+            .collect();
+        assert_eq!(current, vec!["Mira was slain by the cultists"]);
+    }
 }
@@ -4,7 +4,7 @@
 //! Implements a hybrid approach: shared campaign facts + sliding window
 //! of recent conversation.
 
-use claude::Message;
+use claude::{Message, TokenCounter};
 use serde::{Deserialize, Serialize};
 
 /// Maximum number of recent messages to keep in full detail.
@@ -83,6 +83,31 @@ impl DmMemory {
             .collect()
     }
 
+    /// Get the most recent messages that fit within a token budget.
+    ///
+    /// Older messages are dropped first, and the result always starts with a
+    /// player message. The newest message is always included so the current
+    /// turn is never lost, even if it alone exceeds the budget.
+    pub fn get_messages_within(&self, budget: usize, counter: &dyn TokenCounter) -> Vec<Message> {
+        let mut used = 0;
+        let mut start = self.recent_messages.len();
+        for (i, message) in self.recent_messages.iter().enumerate().rev() {
+            used += counter.count(&message.content);
+            if used > budget && start < self.recent_messages.len() {
+                break;
+            }
+            start = i;
+        }
+        // The API requires the conversation to open with a player message
+        while start + 1 < self.recent_messages.len()
+            && matches!(self.recent_messages[start].role, MessageRole::Assistant)
+        {
+            start += 1;
+        }
+
+        self.get_messages().split_off(start)
+    }
+
     /// Build context string with campaign facts.
     pub fn build_context(&self) -> String {
         let mut context = String::new();
@@ -409,4 +434,32 @@ mod tests {
         assert_eq!(memory.token_budget, 100_000);
         assert_eq!(memory.message_count(), 0);
     }
+
+    #[test]
+    fn test_get_messages_within_keeps_most_recent() {
+        use claude::CharEstimate;
+
+        let mut memory = DmMemory::new();
+        memory.add_player_message("I open the door"); // 4 tokens
+        memory.add_dm_message("A long corridor stretches ahead"); // 8 tokens
+        memory.add_player_message("I walk on"); // 3 tokens
+        memory.add_dm_message("You hear dripping"); // 5 tokens
+
+        let all = memory.get_messages_within(100, &CharEstimate);
+        assert_eq!(all.len(), 4);
+
+        let recent = memory.get_messages_within(12, &CharEstimate);
+        assert_eq!(recent.len(), 2);
+        assert_eq!(recent[0].content[0].as_text(), Some("I walk on"));
+
+        // A DM message left at the start is dropped so a player message leads.
+        let recent = memory.get_messages_within(16, &CharEstimate);
+        assert_eq!(recent.len(), 2);
+        assert!(matches!(recent[0].role, claude::Role::User));
+
+        // The newest message survives even an impossible budget.
+        let newest = memory.get_messages_within(0, &CharEstimate);
+        assert_eq!(newest.len(), 1);
+        assert_eq!(newest[0].content[0].as_text(), Some("You hear dripping"));
+    }
 }
@@ -5,14 +5,17 @@
 
 mod agent;
 pub mod memory;
+pub mod recap;
 pub mod relevance;
 pub mod story_memory;
 mod tools;
 
 pub use agent::{DmConfig, DmError, DmResponse, DungeonMaster};
 pub use memory::{CampaignFact, DmMemory, FactCategory};
+pub use recap::{generate_recap, RecapModel};
 pub use relevance::{
-    InferredStateChange, RelevanceChecker, RelevanceError, RelevanceResult, StateInferrer,
+    InferredStateChange, MemoryConsolidator, RelevanceChecker, RelevanceError, RelevanceResult,
+    StateInferrer,
 };
 pub use story_memory::{
     Consequence, ConsequenceId, ConsequenceSeverity, ConsequenceStatus, Entity, EntityId,
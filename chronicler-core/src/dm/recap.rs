@@ -0,0 +1,197 @@
+//! "Previously on..." recaps for players returning to a saved campaign.
+//!
+//! The recap is written by a language model from the campaign's open quests,
+//! unresolved consequences and the most important recent story facts.
+
+use super::story_memory::StoryMemory;
+use crate::world::{GameWorld, QuestStatus};
+use claude::{Claude, Message, Request};
+use std::future::Future;
+
+/// Maximum tokens for the recap response.
+const RECAP_MAX_TOKENS: usize = 400;
+
+/// A language model that turns a prompt into text.
+///
+/// The Claude client is the real implementation; tests substitute a stub.
+pub trait RecapModel {
+    /// Complete a single user prompt and return the response text.
+    fn complete_text(
+        &self,
+        prompt: String,
+    ) -> impl Future<Output = Result<String, claude::Error>> + Send;
+}
+
+impl RecapModel for Claude {
+    async fn complete_text(&self, prompt: String) -> Result<String, claude::Error> {
+        let request = Request::new(vec![Message::user(prompt)])
+            .with_max_tokens(RECAP_MAX_TOKENS)
+            .with_temperature(0.7);
+        Ok(self.complete(request).await?.text())
+    }
+}
+
+/// Build the prompt asking for a recap of the campaign so far.
+///
+/// Includes active quests, pending consequences that haven't expired, and the
+/// `n_recent_facts` current facts ranked highest by recency and importance.
+pub fn recap_prompt(
+    world: &GameWorld,
+    story_memory: &StoryMemory,
+    n_recent_facts: usize,
+) -> String {
+    let now = story_memory.current_turn();
+    let mut prompt = format!(
+        "Write a short \"previously on...\" recap for a player returning to their D&D campaign, \
+         \"{}\". Address the player as \"you\", keep it to 3-5 sentences, and end on what they \
+         were about to do. Use only the notes below; don't invent new events.\n\n\
+         ## Character\n{} is at {}.\n",
+        world.campaign_name, world.player_character.name, world.current_location.name
+    );
+
+    let quests: Vec<String> = world
+        .quests
+        .iter()
+        .filter(|q| q.status == QuestStatus::Active)
+        .map(
+            |q| match q.objectives.iter().find(|o| !o.completed && !o.optional) {
+                Some(next) => format!(
+                    "- {}: {} (next: {})",
+                    q.name, q.description, next.description
+                ),
+                None => format!("- {}: {}", q.name, q.description),
+            },
+        )
+        .collect();
+    if !quests.is_empty() {
+        prompt.push_str("\n## Open Quests\n");
+        prompt.push_str(&quests.join("\n"));
+        prompt.push('\n');
+    }
+
+    let threads: Vec<String> = story_memory
+        .pending_consequences_by_importance()
+        .into_iter()
+        .filter(|c| c.expires_turn.is_none_or(|turn| now < turn))
+        .map(|c| {
+            format!(
+                "- {} (if {})",
+                c.consequence_description, c.trigger_description
+            )
+        })
+        .collect();
+    if !threads.is_empty() {
+        prompt.push_str("\n## Unresolved Threads\n");
+        prompt.push_str(&threads.join("\n"));
+        prompt.push('\n');
+    }
+
+    let mut facts = story_memory.retrieve("", now, n_recent_facts);
+    if !facts.is_empty() {
+        // Tell the story in the order it happened
+        facts.sort_by_key(|f| f.established.turn);
+        prompt.push_str("\n## Key Facts\n");
+        for fact in facts {
+            prompt.push_str(&format!("- {}\n", fact.content));
+        }
+    }
+
+    prompt
+}
+
+/// Ask `llm` for a short recap of the campaign so far.
+pub async fn generate_recap(
+    llm: &impl RecapModel,
+    world: &GameWorld,
+    story_memory: &StoryMemory,
+    n_recent_facts: usize,
+) -> Result<String, claude::Error> {
+    let prompt = recap_prompt(world, story_memory, n_recent_facts);
+    Ok(llm.complete_text(prompt).await?.trim().to_string())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::dm::story_memory::{
+        ConsequenceSeverity, EntityType, FactCategory, FactSource, StoryFact,
+    };
+    use crate::world::{create_sample_fighter, Quest};
+    use std::sync::Mutex;
+
+    /// Records the prompt it was given and answers with canned text.
+    struct StubModel {
+        prompt: Mutex<Option<String>>,
+    }
+
+    impl RecapModel for StubModel {
+        fn complete_text(
+            &self,
+            prompt: String,
+        ) -> impl Future<Output = Result<String, claude::Error>> + Send {
+            *self.prompt.lock().unwrap() = Some(prompt);
+            async { Ok("  Previously, you fought a goblin.\n".to_string()) }
+        }
+    }
+
+    #[tokio::test]
+    async fn test_recap_prompt_covers_open_threads_only() {
+        let mut world = GameWorld::new("Test", create_sample_fighter("Roland"));
+        world
+            .quests
+            .push(Quest::new("Find the Amulet", "Recover the stolen amulet"));
+        let mut finished = Quest::new("Clear the Cellar", "Rats in the tavern cellar");
+        finished.status = QuestStatus::Completed;
+        world.quests.push(finished);
+
+        let mut memory = StoryMemory::new();
+        let mira = memory.create_entity(EntityType::Npc, "Mira");
+        memory.add_fact(
+            StoryFact::new(
+                mira,
+                "Mira revealed she is the thieves' guild leader",
+                FactCategory::Event,
+                FactSource::DmNarration,
+                0,
+            )
+            .with_importance(0.9),
+        );
+        memory.add_fact(
+            StoryFact::new(
+                mira,
+                "Mira ordered a cup of tea",
+                FactCategory::Event,
+                FactSource::DmNarration,
+                0,
+            )
+            .with_importance(0.1),
+        );
+        memory.create_consequence(
+            "the player returns to the docks",
+            "The guild ambushes the player",
+            ConsequenceSeverity::Major,
+        );
+        memory.create_consequence_with_expiry(
+            "the player visits the market",
+            "A pickpocket strikes",
+            ConsequenceSeverity::Minor,
+            2,
+        );
+        memory.advance_turn();
+        memory.advance_turn();
+
+        let stub = StubModel {
+            prompt: Mutex::new(None),
+        };
+        let recap = generate_recap(&stub, &world, &memory, 1).await.unwrap();
+        assert_eq!(recap, "Previously, you fought a goblin.");
+
+        let prompt = stub.prompt.lock().unwrap().clone().unwrap();
+        assert!(prompt.contains("Find the Amulet"));
+        assert!(!prompt.contains("Clear the Cellar"));
+        assert!(prompt.contains("thieves' guild leader"));
+        assert!(!prompt.contains("cup of tea"));
+        assert!(prompt.contains("The guild ambushes the player"));
+        assert!(!prompt.contains("pickpocket"));
+    }
+}
@@ -4,7 +4,7 @@
 //! and facts are relevant to the current player input, enabling semantic
 //! matching instead of just keyword matching.
 
-use super::story_memory::{ConsequenceId, EntityId, FactId, StoryMemory};
+use super::story_memory::{ConsequenceId, EntityId, FactId, StoryFact, StoryMemory};
 use claude::{Claude, Message, Request};
 use serde::Deserialize;
 use thiserror::Error;
@@ -363,6 +363,63 @@ If no state changes are implied, return: {{"inferred_changes": []}}"#
     }
 }
 
+// =============================================================================
+// Memory Consolidation
+// =============================================================================
+
+/// Summarizes batches of old story facts into a single fact.
+///
+/// Uses a fast model (Haiku) so long campaigns can roll up episodic detail
+/// ("sold potions on day 3", "sold potions on day 4") into one line.
+pub struct MemoryConsolidator {
+    client: Claude,
+    model: String,
+}
+
+impl MemoryConsolidator {
+    /// Create a new consolidator with the given API client.
+    pub fn new(client: Claude) -> Self {
+        Self {
+            client,
+            model: RELEVANCE_MODEL.to_string(),
+        }
+    }
+
+    /// Set a custom model for consolidation.
+    pub fn with_model(mut self, model: impl Into<String>) -> Self {
+        self.model = model.into();
+        self
+    }
+
+    /// Summarize a batch of facts into one sentence.
+    pub async fn summarize(&self, facts: &[&StoryFact]) -> Result<String, RelevanceError> {
+        let facts_text: String = facts
+            .iter()
+            .map(|f| format!("- (turn {}) {}\n", f.established.turn, f.content))
+            .collect();
+
+        let prompt = format!(
+            r#"Summarize these related events from a D&D campaign into ONE concise sentence that keeps every name, place, and outcome a Dungeon Master would need later.
+
+## Events
+{facts_text}
+Respond with ONLY the summary sentence."#
+        );
+
+        let request = Request::new(vec![Message::user(&prompt)])
+            .with_model(&self.model)
+            .with_max_tokens(200)
+            .with_temperature(0.0);
+
+        let response = self.client.complete(request).await?;
+        let summary = response.text().trim().to_string();
+        if summary.is_empty() {
+            return Err(RelevanceError::ParseError("Empty summary".to_string()));
+        }
+        Ok(summary)
+    }
+}
+
 /// Extract JSON from a response that might have markdown code blocks or trailing text.
 fn extract_json(text: &str) -> &str {
     let text = text.trim();
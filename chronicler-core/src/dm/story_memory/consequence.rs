@@ -222,6 +222,54 @@ impl Consequence {
     }
 }
 
+/// What happened recently, used to decide which pending consequences fire.
+///
+/// Built by the DM agent from the effects of a resolution and handed to
+/// [`StoryMemory::check_triggers`](super::StoryMemory::check_triggers).
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct TriggerContext {
+    /// The location the player just entered, if they moved.
+    pub entered_location: Option<String>,
+    /// Names of NPCs the player interacted with.
+    pub npcs: Vec<String>,
+    /// How many story turns have passed since the last check.
+    pub elapsed_turns: u32,
+}
+
+impl TriggerContext {
+    /// Create an empty context.
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Record that the player entered a location.
+    pub fn with_location(mut self, location: impl Into<String>) -> Self {
+        self.entered_location = Some(location.into());
+        self
+    }
+
+    /// Record an interaction with an NPC.
+    pub fn with_npc(mut self, name: impl Into<String>) -> Self {
+        self.npcs.push(name.into());
+        self
+    }
+
+    /// Record elapsed story turns.
+    pub fn with_elapsed_turns(mut self, turns: u32) -> Self {
+        self.elapsed_turns = turns;
+        self
+    }
+
+    /// Names from this context that a trigger description may mention.
+    pub fn names(&self) -> impl Iterator<Item = &str> {
+        self.entered_location
+            .iter()
+            .chain(self.npcs.iter())
+            .map(String::as_str)
+            .filter(|name| !name.trim().is_empty())
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -2,8 +2,44 @@
 
 use super::entity::{EntityId, StoryMoment};
 use serde::{Deserialize, Serialize};
+use std::collections::{HashMap, HashSet};
 use uuid::Uuid;
 
+/// Words for opposing states; a fact using one side contradicts a fact using the other.
+const OPPOSING_STATES: &[(&[&str], &[&str])] = &[
+    (
+        &["alive", "living"],
+        &["dead", "deceased", "killed", "slain"],
+    ),
+    (
+        &["intact", "standing"],
+        &["collapsed", "destroyed", "broken", "ruined"],
+    ),
+    (&["open"], &["closed", "shut", "sealed"]),
+    (&["locked"], &["unlocked"]),
+    (&["friendly", "ally", "allied"], &["hostile", "enemy"]),
+    (&["free", "escaped"], &["captured", "imprisoned", "captive"]),
+    (&["present", "here"], &["missing", "gone", "absent"]),
+    (&["awake", "conscious"], &["asleep", "unconscious"]),
+    (&["healthy"], &["sick", "ill", "wounded", "injured"]),
+    (
+        &["loyal", "trustworthy"],
+        &["traitor", "treacherous", "betrayed"],
+    ),
+];
+
+/// Words that negate a statement.
+const NEGATIONS: &[&str] = &[
+    "not", "no", "never", "isn't", "wasn't", "aren't", "weren't", "doesn't", "didn't", "don't",
+    "cannot", "can't", "won't", "nobody", "nothing",
+];
+
+/// Filler words ignored when comparing statements.
+const STOPWORDS: &[&str] = &[
+    "the", "a", "an", "is", "was", "are", "were", "be", "been", "has", "have", "had", "does",
+    "did", "do", "will", "can", "of", "to", "in", "on", "at", "and", "his", "her", "their", "its",
+];
+
 /// Unique identifier for a story fact.
 #[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Serialize, Deserialize)]
 pub struct FactId(Uuid);
@@ -93,6 +129,114 @@ pub enum FactSource {
     WorldBuilding,
 }
 
+impl FactSource {
+    /// How quickly facts from this source fade, relative to the decay curve.
+    ///
+    /// World lore and mechanical outcomes hold up; passing dialogue fades fastest.
+    pub fn decay_multiplier(&self) -> f32 {
+        match self {
+            FactSource::WorldBuilding => 0.5,
+            FactSource::Mechanics => 0.75,
+            FactSource::DmNarration | FactSource::PlayerAction => 1.0,
+            FactSource::NpcDialogue => 1.5,
+        }
+    }
+}
+
+/// How a fact's importance fades with age when pruning story memory.
+#[derive(Debug, Clone, Copy, PartialEq, Serialize, Deserialize)]
+pub struct FactDecay {
+    /// Turns for a fact's effective importance to halve, before the
+    /// source multiplier is applied.
+    pub half_life_turns: f32,
+    /// Facts at or above this importance are never pruned.
+    pub keep_threshold: f32,
+}
+
+impl Default for FactDecay {
+    fn default() -> Self {
+        Self {
+            half_life_turns: 50.0,
+            keep_threshold: 0.9,
+        }
+    }
+}
+
+impl FactDecay {
+    /// The importance of a fact after decaying it by age and source.
+    pub fn effective_importance(&self, fact: &StoryFact, now: u32) -> f32 {
+        let age = now.saturating_sub(fact.established.turn) as f32;
+        let half_lives = age * fact.source.decay_multiplier() / self.half_life_turns.max(1.0);
+        fact.importance * 0.5f32.powf(half_lives)
+    }
+}
+
+/// When and how story memory rolls old event facts up into summaries.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+pub struct ConsolidationConfig {
+    /// How many facts are summarized together.
+    pub batch_size: usize,
+    /// How many current event facts a subject needs before its oldest are
+    /// consolidated.
+    pub threshold: usize,
+    /// Whether consolidated facts are removed rather than superseded.
+    pub prune_sources: bool,
+}
+
+impl Default for ConsolidationConfig {
+    fn default() -> Self {
+        Self {
+            batch_size: 5,
+            threshold: 10,
+            prune_sources: false,
+        }
+    }
+}
+
+/// Weights for ranking facts by recency, importance and relevance to a query.
+#[derive(Debug, Clone, Copy, PartialEq, Serialize, Deserialize)]
+pub struct RetrievalWeights {
+    /// Weight of how recently the fact was established.
+    pub recency: f32,
+    /// Weight of the fact's stored importance.
+    pub importance: f32,
+    /// Weight of the fact's similarity to the query.
+    pub relevance: f32,
+    /// Fraction of recency kept per turn of age (exponential decay).
+    pub recency_decay: f32,
+}
+
+impl Default for RetrievalWeights {
+    fn default() -> Self {
+        Self {
+            recency: 1.0,
+            importance: 1.0,
+            relevance: 1.0,
+            recency_decay: 0.95,
+        }
+    }
+}
+
+impl RetrievalWeights {
+    /// Score a fact for retrieval against a query at turn `now`.
+    pub fn score(&self, fact: &StoryFact, query: &str, now: u32) -> f32 {
+        let age = now.saturating_sub(fact.established.turn);
+        let recency = self.recency_decay.clamp(0.0, 1.0).powi(age as i32);
+        self.recency * recency
+            + self.importance * fact.importance
+            + self.relevance * fact.similarity(query)
+    }
+}
+
+/// A new fact that likely contradicts one already in story memory.
+#[derive(Debug, Clone)]
+pub struct FactConflict {
+    /// The current fact the new one contradicts.
+    pub existing: FactId,
+    /// The fact that was not recorded.
+    pub incoming: Box<StoryFact>,
+}
+
 /// A story fact - a piece of information about an entity.
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct StoryFact {
@@ -114,6 +258,9 @@ pub struct StoryFact {
     pub importance: f32,
     /// Where this fact came from.
     pub source: FactSource,
+    /// The facts this one summarizes, if it was produced by consolidation.
+    #[serde(default)]
+    pub consolidated_from: Vec<FactId>,
 }
 
 impl StoryFact {
@@ -135,6 +282,7 @@ impl StoryFact {
             is_current: true,
             importance: 1.0,
             source,
+            consolidated_from: Vec::new(),
         }
     }
 
@@ -166,6 +314,86 @@ impl StoryFact {
     pub fn involves(&self, entity_id: EntityId) -> bool {
         self.subject == entity_id || self.mentioned_entities.contains(&entity_id)
     }
+
+    /// Check if this fact likely contradicts another about the same subject and category.
+    ///
+    /// This is a heuristic: it looks for opposing state words ("alive"/"dead")
+    /// or the same statement with and without a negation.
+    pub fn contradicts(&self, other: &StoryFact) -> bool {
+        self.subject == other.subject
+            && self.category == other.category
+            && contents_contradict(&self.content, &other.content)
+    }
+
+    /// How similar this fact's content is to a query, from 0.0 to 1.0.
+    ///
+    /// This is the cosine similarity of the two texts' word-count vectors,
+    /// ignoring stopwords.
+    pub fn similarity(&self, query: &str) -> f32 {
+        cosine_similarity(&term_vector(&self.content), &term_vector(query))
+    }
+}
+
+fn words(content: &str) -> Vec<String> {
+    content
+        .to_lowercase()
+        .split(|c: char| !c.is_alphanumeric() && c != '\'')
+        .filter(|w| !w.is_empty())
+        .map(str::to_string)
+        .collect()
+}
+
+fn term_vector(content: &str) -> HashMap<String, f32> {
+    let mut terms = HashMap::new();
+    for word in words(content) {
+        if !STOPWORDS.contains(&word.as_str()) {
+            *terms.entry(word).or_insert(0.0) += 1.0;
+        }
+    }
+    terms
+}
+
+fn cosine_similarity(a: &HashMap<String, f32>, b: &HashMap<String, f32>) -> f32 {
+    let dot: f32 = a
+        .iter()
+        .filter_map(|(term, x)| b.get(term).map(|y| x * y))
+        .sum();
+    let norm = |v: &HashMap<String, f32>| v.values().map(|x| x * x).sum::<f32>().sqrt();
+    let denominator = norm(a) * norm(b);
+    if denominator == 0.0 {
+        0.0
+    } else {
+        dot / denominator
+    }
+}
+
+fn contents_contradict(a: &str, b: &str) -> bool {
+    let (a, b) = (words(a), words(b));
+    let has_any =
+        |ws: &[String], options: &[&str]| ws.iter().any(|w| options.contains(&w.as_str()));
+    let negated = |ws: &[String]| has_any(ws, NEGATIONS);
+
+    let opposed = OPPOSING_STATES.iter().any(|(one, other)| {
+        (has_any(&a, one) && has_any(&b, other)) || (has_any(&a, other) && has_any(&b, one))
+    });
+    if opposed {
+        // "Not dead" agrees with "alive"
+        return negated(&a) == negated(&b);
+    }
+    if negated(&a) == negated(&b) {
+        return false;
+    }
+
+    // The same statement with and without a negation
+    let content = |ws: &[String]| -> HashSet<String> {
+        ws.iter()
+            .filter(|w| !NEGATIONS.contains(&w.as_str()) && !STOPWORDS.contains(&w.as_str()))
+            .cloned()
+            .collect()
+    };
+    let (a, b) = (content(&a), content(&b));
+    let longest = a.len().max(b.len());
+    longest > 0 && a.intersection(&b).count() * 3 >= longest * 2
 }
 
 #[cfg(test)]
@@ -208,4 +436,34 @@ mod tests {
         assert!(fact.involves(entity2));
         assert!(!fact.involves(entity3));
     }
+
+    #[test]
+    fn test_contradicting_facts() {
+        let mira = EntityId::new();
+        let status = |content: &str| {
+            StoryFact::new(
+                mira,
+                content,
+                FactCategory::Status,
+                FactSource::DmNarration,
+                0,
+            )
+        };
+
+        assert!(status("Mira is alive").contradicts(&status("Mira was slain by bandits")));
+        assert!(status("The bridge is intact").contradicts(&status("The bridge collapsed")));
+        assert!(
+            status("Mira trusts the player").contradicts(&status("Mira does not trust the player"))
+        );
+        assert!(!status("Mira is not dead").contradicts(&status("Mira is alive")));
+        assert!(!status("Mira is alive").contradicts(&status("Mira runs the tavern")));
+
+        // Different categories or subjects never conflict
+        let mut appearance = status("Mira is dead");
+        appearance.category = FactCategory::Appearance;
+        assert!(!status("Mira is alive").contradicts(&appearance));
+        let mut other = status("Mira is dead");
+        other.subject = EntityId::new();
+        assert!(!status("Mira is alive").contradicts(&other));
+    }
 }
@@ -68,6 +68,20 @@ impl VerificationStatus {
             Self::Outdated => "outdated",
         }
     }
+
+    /// How much weight this knowledge carries when ranking who knows what.
+    ///
+    /// Confirmed knowledge ranks above partial truths, which rank above
+    /// rumors; outdated knowledge and lies rank lowest.
+    pub fn rank(&self) -> u8 {
+        match self {
+            Self::True => 4,
+            Self::PartiallyTrue => 3,
+            Self::Unknown => 2,
+            Self::Outdated => 1,
+            Self::False => 0,
+        }
+    }
 }
 
 /// A record of an entity knowing a piece of information.
@@ -37,9 +37,14 @@ mod relationship;
 mod scheduled_event;
 mod store;
 
-pub use consequence::{Consequence, ConsequenceId, ConsequenceSeverity, ConsequenceStatus};
+pub use consequence::{
+    Consequence, ConsequenceId, ConsequenceSeverity, ConsequenceStatus, TriggerContext,
+};
 pub use entity::{Entity, EntityId, EntityType, StoryMoment};
-pub use fact::{FactCategory, FactId, FactSource, StoryFact};
+pub use fact::{
+    ConsolidationConfig, FactCategory, FactConflict, FactDecay, FactId, FactSource,
+    RetrievalWeights, StoryFact,
+};
 pub use knowledge::{KnowledgeEntry, KnowledgeId, KnowledgeSource, VerificationStatus};
 pub use relationship::{Relationship, RelationshipType};
 pub use scheduled_event::{
@@ -133,6 +133,11 @@ impl RelationshipType {
             _ => None,
         }
     }
+
+    /// Check if this relationship reads the same in both directions.
+    pub fn is_symmetric(&self) -> bool {
+        self.inverse() == Some(*self)
+    }
 }
 
 /// A relationship between two entities.
@@ -152,6 +157,10 @@ pub struct Relationship {
     pub established: StoryMoment,
     /// Whether this relationship is still active.
     pub is_active: bool,
+    /// Whether the relationship also holds from `to_entity` to `from_entity`.
+    /// Defaults to true for symmetric relationship types.
+    #[serde(default)]
+    pub bidirectional: bool,
 }
 
 impl Relationship {
@@ -178,6 +187,7 @@ impl Relationship {
             strength: default_strength,
             established: StoryMoment::new(current_turn),
             is_active: true,
+            bidirectional: relationship_type.is_symmetric(),
         }
     }
 
@@ -193,6 +203,12 @@ impl Relationship {
         self
     }
 
+    /// Make the relationship hold in one direction only.
+    pub fn one_way(mut self) -> Self {
+        self.bidirectional = false;
+        self
+    }
+
     /// Adjust the strength.
     pub fn adjust_strength(&mut self, delta: f32) {
         self.strength = (self.strength + delta).clamp(-1.0, 1.0);
@@ -218,6 +234,19 @@ impl Relationship {
             None
         }
     }
+
+    /// Get the entity this relationship leads to when followed from `entity_id`.
+    ///
+    /// One-way relationships can only be followed from their source.
+    pub fn follow_from(&self, entity_id: EntityId) -> Option<EntityId> {
+        if self.from_entity == entity_id {
+            Some(self.to_entity)
+        } else if self.bidirectional && self.to_entity == entity_id {
+            Some(self.from_entity)
+        } else {
+            None
+        }
+    }
 }
 
 #[cfg(test)]
@@ -250,4 +279,22 @@ mod tests {
         );
         assert_eq!(RelationshipType::Owns.inverse(), None);
     }
+
+    #[test]
+    fn test_symmetric_relationships_are_bidirectional() {
+        let a = EntityId::new();
+        let b = EntityId::new();
+
+        let siblings = Relationship::new(a, b, RelationshipType::Family, 0);
+        assert!(siblings.bidirectional);
+        assert_eq!(siblings.follow_from(b), Some(a));
+
+        let mentor = Relationship::new(a, b, RelationshipType::Mentor, 0);
+        assert!(!mentor.bidirectional);
+        assert_eq!(mentor.follow_from(a), Some(b));
+        assert_eq!(mentor.follow_from(b), None);
+
+        let one_way = Relationship::new(a, b, RelationshipType::Friend, 0).one_way();
+        assert_eq!(one_way.follow_from(b), None);
+    }
 }
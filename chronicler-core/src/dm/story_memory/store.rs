@@ -2,18 +2,26 @@
 
 #[cfg(test)]
 use super::consequence::ConsequenceStatus;
-use super::consequence::{Consequence, ConsequenceId, ConsequenceSeverity};
+use super::consequence::{Consequence, ConsequenceId, ConsequenceSeverity, TriggerContext};
 use super::entity::{Entity, EntityId, EntityType};
-use super::fact::{FactCategory, FactSource, StoryFact};
+use super::fact::{
+    ConsolidationConfig, FactCategory, FactConflict, FactDecay, FactId, FactSource,
+    RetrievalWeights, StoryFact,
+};
 use super::knowledge::{KnowledgeEntry, KnowledgeId, KnowledgeSource, VerificationStatus};
 use super::relationship::{Relationship, RelationshipType};
 use super::scheduled_event::{
     EventStatus, EventTrigger, EventVisibility, ScheduledEvent, ScheduledEventId,
 };
+use crate::persist::{write_atomic, PersistError};
 use crate::world::GameTime;
 use serde::{Deserialize, Serialize};
 use std::cmp::Ordering;
+use std::collections::hash_map::DefaultHasher;
 use std::collections::HashMap;
+use std::hash::{Hash, Hasher};
+use std::path::{Path, PathBuf};
+use tokio::fs;
 
 /// Maximum facts to include in context.
 const MAX_CONTEXT_FACTS: usize = 30;
@@ -27,6 +35,19 @@ const IMPORTANCE_DECAY_PER_TURN: f32 = 0.02;
 /// Consequence decay rate per turn (slower than facts).
 const CONSEQUENCE_DECAY_PER_TURN: f32 = 0.01;
 
+/// Minutes in a game day, for daily events.
+const MINUTES_PER_DAY: u64 = 24 * 60;
+
+/// Total minutes elapsed since year 0 for a game time.
+fn minute_of(game_time: &GameTime) -> u64 {
+    // Days since year 0, then hours, then minutes
+    let days = (game_time.year as u64 * 12 * 30)  // 12 months, 30 days each
+        + (game_time.month as u64 - 1) * 30
+        + (game_time.day as u64 - 1);
+    let hours = days * 24 + game_time.hour as u64;
+    hours * 60 + game_time.minute as u64
+}
+
 /// The main story memory store.
 #[derive(Debug, Clone, Default, Serialize, Deserialize)]
 pub struct StoryMemory {
@@ -56,6 +77,28 @@ pub struct StoryMemory {
     current_minute: u64,
     /// Current turn number.
     current_turn: u32,
+    /// How fact importance fades with age when pruning.
+    #[serde(default)]
+    pub fact_decay: FactDecay,
+    /// How facts are ranked by [`retrieve`](Self::retrieve).
+    #[serde(default)]
+    pub retrieval_weights: RetrievalWeights,
+    /// When old event facts are rolled up into summaries.
+    #[serde(default)]
+    pub consolidation: ConsolidationConfig,
+    /// Where and what was last saved, so unchanged saves can be skipped.
+    #[serde(skip)]
+    last_save: Option<(PathBuf, u64)>,
+}
+
+/// Current story memory save file version.
+const STORY_MEMORY_VERSION: u32 = 1;
+
+/// A story memory file: the memory under a version header.
+#[derive(Serialize)]
+struct SavedStoryMemory<'a> {
+    version: u32,
+    story_memory: &'a StoryMemory,
 }
 
 impl StoryMemory {
@@ -198,6 +241,79 @@ impl StoryMemory {
         entities
     }
 
+    /// Merge a duplicate entity into another.
+    ///
+    /// Every fact, relationship, knowledge entry and consequence that refers
+    /// to `merge` is re-pointed at `keep`, and the merged entity's name and
+    /// aliases become aliases of the kept entity so lookups by either name
+    /// resolve to it. Relationships between the two entities are dropped.
+    /// Returns false if either entity is missing or they are the same.
+    pub fn merge_entities(&mut self, keep: EntityId, merge: EntityId) -> bool {
+        if keep == merge || !self.entities.contains_key(&keep) {
+            return false;
+        }
+        let Some(merged) = self.entities.remove(&merge) else {
+            return false;
+        };
+
+        if let Some(kept) = self.entities.get_mut(&keep) {
+            for name in std::iter::once(&merged.name).chain(&merged.aliases) {
+                if !kept.matches_name(name) {
+                    kept.aliases.push(name.clone());
+                }
+            }
+            if kept.description.is_none() {
+                kept.description = merged.description;
+            }
+            kept.first_seen = kept.first_seen.min(merged.first_seen);
+            kept.last_seen = kept.last_seen.max(merged.last_seen);
+            kept.importance = kept.importance.max(merged.importance);
+        }
+        for id in self.name_index.values_mut() {
+            if *id == merge {
+                *id = keep;
+            }
+        }
+
+        let repoint = |id: &mut EntityId| {
+            if *id == merge {
+                *id = keep;
+            }
+        };
+        let repoint_all = |ids: &mut Vec<EntityId>| {
+            ids.iter_mut().for_each(repoint);
+            let mut seen = Vec::new();
+            ids.retain(|id| {
+                let first = !seen.contains(id);
+                seen.push(*id);
+                first
+            });
+        };
+
+        for fact in &mut self.facts {
+            repoint(&mut fact.subject);
+            repoint_all(&mut fact.mentioned_entities);
+        }
+        for rel in &mut self.relationships {
+            repoint(&mut rel.from_entity);
+            repoint(&mut rel.to_entity);
+        }
+        self.relationships.retain(|r| r.from_entity != r.to_entity);
+        for entry in &mut self.knowledge {
+            repoint(&mut entry.knowing_entity);
+            if let Some(KnowledgeSource::Entity(source)) = &mut entry.learned_from {
+                repoint(source);
+            }
+        }
+        for consequence in &mut self.consequences {
+            if let Some(subject) = &mut consequence.subject_entity {
+                repoint(subject);
+            }
+            repoint_all(&mut consequence.related_entities);
+        }
+        true
+    }
+
     // =========================================================================
     // Fact Management
     // =========================================================================
@@ -215,6 +331,40 @@ impl StoryMemory {
         self.facts.push(fact);
     }
 
+    /// Add a story fact unless it likely contradicts a current fact about the
+    /// same subject in the same category, in which case nothing is stored.
+    pub fn add_fact_checked(&mut self, fact: StoryFact) -> Result<FactId, FactConflict> {
+        if let Some(existing) = self
+            .facts
+            .iter()
+            .find(|f| f.is_current && f.contradicts(&fact))
+        {
+            return Err(FactConflict {
+                existing: existing.id,
+                incoming: Box::new(fact),
+            });
+        }
+
+        let id = fact.id;
+        self.add_fact(fact);
+        Ok(id)
+    }
+
+    /// Get a fact by ID.
+    pub fn get_fact(&self, id: FactId) -> Option<&StoryFact> {
+        self.facts.iter().find(|f| f.id == id)
+    }
+
+    /// Mark a fact as no longer current. Returns false if there is no such fact.
+    pub fn supersede_fact(&mut self, id: FactId) -> bool {
+        if let Some(fact) = self.facts.iter_mut().find(|f| f.id == id) {
+            fact.supersede();
+            true
+        } else {
+            false
+        }
+    }
+
     /// Create and add a fact about an entity.
     pub fn record_fact(
         &mut self,
@@ -286,6 +436,152 @@ impl StoryMemory {
             .collect()
     }
 
+    /// Find the `k` current facts most similar to a query, best match first.
+    ///
+    /// Facts that share no words with the query are never returned.
+    pub fn search_facts(&self, query: &str, k: usize) -> Vec<&StoryFact> {
+        let mut scored: Vec<(f32, &StoryFact)> = self
+            .facts
+            .iter()
+            .filter(|f| f.is_current)
+            .map(|f| (f.similarity(query), f))
+            .filter(|(score, _)| *score > 0.0)
+            .collect();
+        scored.sort_by(|a, b| b.0.partial_cmp(&a.0).unwrap_or(Ordering::Equal));
+        scored.into_iter().take(k).map(|(_, f)| f).collect()
+    }
+
+    /// Retrieve the `k` current facts that best fit a query at turn `now`.
+    ///
+    /// Facts are scored by a weighted sum of exponentially decayed recency,
+    /// stored importance and similarity to the query, using
+    /// [`retrieval_weights`](Self::retrieval_weights).
+    pub fn retrieve(&self, query: &str, now: u32, k: usize) -> Vec<&StoryFact> {
+        let weights = self.retrieval_weights;
+        let mut scored: Vec<(f32, &StoryFact)> = self
+            .facts
+            .iter()
+            .filter(|f| f.is_current)
+            .map(|f| (weights.score(f, query, now), f))
+            .collect();
+        scored.sort_by(|a, b| b.0.partial_cmp(&a.0).unwrap_or(Ordering::Equal));
+        scored.into_iter().take(k).map(|(_, f)| f).collect()
+    }
+
+    /// Batches of old event facts that are ready to be summarized.
+    ///
+    /// For each subject with at least the configured threshold of current
+    /// event facts, the oldest facts are grouped into full batches; the most
+    /// recent facts that don't fill a batch are left alone.
+    pub fn consolidation_batches(&self) -> Vec<Vec<&StoryFact>> {
+        let config = self.consolidation;
+        let batch_size = config.batch_size.max(2);
+        let mut by_subject: HashMap<EntityId, Vec<&StoryFact>> = HashMap::new();
+        for fact in self
+            .facts
+            .iter()
+            .filter(|f| f.is_current && f.category == FactCategory::Event)
+        {
+            by_subject.entry(fact.subject).or_default().push(fact);
+        }
+
+        let mut batches = Vec::new();
+        for mut facts in by_subject.into_values() {
+            if facts.len() < config.threshold {
+                continue;
+            }
+            facts.sort_by_key(|f| f.established.turn);
+            batches.extend(facts.chunks_exact(batch_size).map(<[&StoryFact]>::to_vec));
+        }
+        batches.sort_by_key(|batch| batch[0].established.turn);
+        batches
+    }
+
+    /// Replace a batch of facts with a single summary fact.
+    ///
+    /// The summary is filed under the first fact's subject, mentions every
+    /// entity the sources did, keeps their highest importance, and records
+    /// the source fact IDs. The sources are superseded, or removed if the
+    /// consolidation config says to prune them. Returns the summary's ID, or
+    /// `None` if none of the facts exist.
+    pub fn consolidate(
+        &mut self,
+        fact_ids: &[FactId],
+        summary: impl Into<String>,
+    ) -> Option<FactId> {
+        let sources: Vec<&StoryFact> = fact_ids
+            .iter()
+            .filter_map(|id| self.get_fact(*id))
+            .collect();
+        let first = sources.first()?;
+
+        let mut fact = StoryFact::new(
+            first.subject,
+            summary,
+            FactCategory::Event,
+            FactSource::DmNarration,
+            sources
+                .iter()
+                .map(|f| f.established.turn)
+                .max()
+                .unwrap_or(self.current_turn),
+        )
+        .with_importance(sources.iter().map(|f| f.importance).fold(0.0, f32::max));
+        for source in &sources {
+            for entity in
+                std::iter::once(source.subject).chain(source.mentioned_entities.iter().copied())
+            {
+                fact = fact.with_mentioned(entity);
+            }
+        }
+        fact.consolidated_from = sources.iter().map(|f| f.id).collect();
+
+        let consolidated = fact.consolidated_from.clone();
+        if self.consolidation.prune_sources {
+            self.facts.retain(|f| !consolidated.contains(&f.id));
+        } else {
+            for id in &consolidated {
+                self.supersede_fact(*id);
+            }
+        }
+
+        let id = fact.id;
+        self.facts.push(fact);
+        Some(id)
+    }
+
+    /// Drop the least important facts until at most `max_facts` remain.
+    ///
+    /// Facts are ranked by importance decayed by age and source according to
+    /// [`fact_decay`](Self::fact_decay). Facts at or above its keep threshold
+    /// always survive, even if that leaves more than `max_facts`. Returns the
+    /// number of facts removed.
+    pub fn prune(&mut self, max_facts: usize, now: u32) -> usize {
+        if self.facts.len() <= max_facts {
+            return 0;
+        }
+
+        let decay = self.fact_decay;
+        let (mut keep, mut rest): (Vec<StoryFact>, Vec<StoryFact>) =
+            std::mem::take(&mut self.facts)
+                .into_iter()
+                .partition(|f| f.importance >= decay.keep_threshold);
+        rest.sort_by(|a, b| {
+            decay
+                .effective_importance(b, now)
+                .partial_cmp(&decay.effective_importance(a, now))
+                .unwrap_or(Ordering::Equal)
+        });
+
+        let slots = max_facts.saturating_sub(keep.len());
+        let removed = rest.len().saturating_sub(slots);
+        rest.truncate(slots);
+        keep.extend(rest);
+        keep.sort_by_key(|f| f.established.turn);
+        self.facts = keep;
+        removed
+    }
+
     // =========================================================================
     // Relationship Management
     // =========================================================================
@@ -314,6 +610,47 @@ impl StoryMemory {
             .collect()
     }
 
+    /// Get the entities an entity is related to by a given relationship type.
+    ///
+    /// Bidirectional relationships are followed from either end.
+    pub fn related_by(
+        &self,
+        entity_id: EntityId,
+        relationship_type: RelationshipType,
+    ) -> Vec<EntityId> {
+        let mut related = Vec::new();
+        for rel in self
+            .relationships
+            .iter()
+            .filter(|r| r.is_active && r.relationship_type == relationship_type)
+        {
+            if let Some(other) = rel.follow_from(entity_id) {
+                if !related.contains(&other) {
+                    related.push(other);
+                }
+            }
+        }
+        related
+    }
+
+    /// Get the allies of an entity's allies, excluding the entity itself and
+    /// its direct allies.
+    pub fn allies_of_allies(&self, entity_id: EntityId) -> Vec<EntityId> {
+        let allies = self.related_by(entity_id, RelationshipType::Ally);
+        let mut second_hop = Vec::new();
+        for ally in &allies {
+            for candidate in self.related_by(*ally, RelationshipType::Ally) {
+                if candidate != entity_id
+                    && !allies.contains(&candidate)
+                    && !second_hop.contains(&candidate)
+                {
+                    second_hop.push(candidate);
+                }
+            }
+        }
+        second_hop
+    }
+
     /// Find a specific relationship between two entities.
     pub fn find_relationship(&self, from_id: EntityId, to_id: EntityId) -> Option<&Relationship> {
         self.relationships
@@ -434,6 +771,50 @@ impl StoryMemory {
         }
     }
 
+    /// Fire pending consequences whose trigger mentions what just happened.
+    ///
+    /// Elapsed turns are applied first, so a consequence that runs out of time
+    /// in the same step is marked expired rather than fired. A consequence
+    /// fires when its trigger description names the entered location or an
+    /// NPC from the context, or when its subject or a related entity has one
+    /// of those names. Returns the IDs of the consequences that fired.
+    pub fn check_triggers(&mut self, context: &TriggerContext) -> Vec<ConsequenceId> {
+        for _ in 0..context.elapsed_turns {
+            self.advance_turn();
+        }
+        for consequence in &mut self.consequences {
+            consequence.check_expiry(self.current_turn);
+        }
+
+        let names: Vec<String> = context.names().map(str::to_lowercase).collect();
+        if names.is_empty() {
+            return Vec::new();
+        }
+
+        let fired: Vec<ConsequenceId> = self
+            .consequences
+            .iter()
+            .filter(|c| c.status.is_active())
+            .filter(|c| {
+                let trigger = c.trigger_description.to_lowercase();
+                let entity_named = |id: &EntityId| {
+                    self.entities
+                        .get(id)
+                        .is_some_and(|e| names.contains(&e.name.to_lowercase()))
+                };
+                names.iter().any(|name| trigger.contains(name.as_str()))
+                    || c.subject_entity.iter().any(entity_named)
+                    || c.related_entities.iter().any(entity_named)
+            })
+            .map(|c| c.id)
+            .collect();
+
+        for id in &fired {
+            self.trigger_consequence(*id);
+        }
+        fired
+    }
+
     /// Get the total number of consequences (all statuses).
     pub fn consequence_count(&self) -> usize {
         self.consequences.len()
@@ -542,6 +923,41 @@ impl StoryMemory {
         !self.query_entity_knowledge(entity_id, topic).is_empty()
     }
 
+    /// Find what a named entity knows about something, if anything.
+    ///
+    /// Matches `content_substring` case-insensitively against the entity's
+    /// current knowledge and returns the best-verified entry, so confirmed
+    /// knowledge wins over rumors.
+    pub fn knows(&self, entity: &str, content_substring: &str) -> Option<&KnowledgeEntry> {
+        let entity_id = self.find_entity_id(entity)?;
+        self.query_entity_knowledge(entity_id, content_substring)
+            .into_iter()
+            .max_by_key(|k| k.verification_status.rank())
+    }
+
+    /// Names of every entity that knows something matching `content_substring`.
+    ///
+    /// Entities holding confirmed knowledge come first and rumor-holders last.
+    pub fn who_knows(&self, content_substring: &str) -> Vec<&str> {
+        let needle = content_substring.to_lowercase();
+        let mut best: HashMap<EntityId, u8> = HashMap::new();
+        for entry in self
+            .knowledge
+            .iter()
+            .filter(|k| k.is_current && k.content.to_lowercase().contains(&needle))
+        {
+            let rank = best.entry(entry.knowing_entity).or_default();
+            *rank = (*rank).max(entry.verification_status.rank());
+        }
+
+        let mut knowers: Vec<(&str, u8)> = best
+            .into_iter()
+            .filter_map(|(id, rank)| self.entities.get(&id).map(|e| (e.name.as_str(), rank)))
+            .collect();
+        knowers.sort_by(|a, b| b.1.cmp(&a.1).then_with(|| a.0.cmp(b.0)));
+        knowers.into_iter().map(|(name, _)| name).collect()
+    }
+
     /// Get knowledge by ID.
     pub fn get_knowledge(&self, id: KnowledgeId) -> Option<&KnowledgeEntry> {
         self.knowledge.iter().find(|k| k.id == id)
@@ -619,13 +1035,7 @@ impl StoryMemory {
     /// Update the current minute count from game time.
     /// Call this when time advances in the game.
     pub fn sync_time(&mut self, game_time: &GameTime) {
-        // Calculate total minutes from game time
-        // Days since year 0, then hours, then minutes
-        let days = (game_time.year as u64 * 12 * 30)  // 12 months, 30 days each
-            + (game_time.month as u64 - 1) * 30
-            + (game_time.day as u64 - 1);
-        let hours = days * 24 + game_time.hour as u64;
-        self.current_minute = hours * 60 + game_time.minute as u64;
+        self.current_minute = minute_of(game_time);
     }
 
     /// Advance time by a number of minutes.
@@ -743,60 +1153,54 @@ impl StoryMemory {
     /// Check for events that should trigger at the current time.
     /// Returns triggered events and marks them as triggered.
     pub fn check_triggered_events(&mut self, game_time: &GameTime) -> Vec<ScheduledEvent> {
-        self.sync_time(game_time);
+        self.tick(game_time)
+    }
 
-        let mut triggered = Vec::new();
-        let mut to_reschedule = Vec::new();
+    /// Advance the clock to `current_time` and fire every event that came due
+    /// since the last tick. One-off events are marked triggered; repeating
+    /// events are rescheduled.
+    pub fn tick(&mut self, current_time: &GameTime) -> Vec<ScheduledEvent> {
+        let previous_minute = self.current_minute;
+        self.sync_time(current_time);
+        let now = self.current_minute;
 
+        let mut triggered = Vec::new();
         for event in &mut self.scheduled_events {
             if event.status != EventStatus::Scheduled {
                 continue;
             }
 
-            let should_trigger = match &event.trigger {
+            let due = match event.trigger {
                 EventTrigger::AfterDuration {
                     trigger_at_minute, ..
-                } => self.current_minute >= *trigger_at_minute,
+                } => now >= trigger_at_minute,
                 EventTrigger::AtTime {
                     year,
                     month,
                     day,
                     hour,
-                } => {
-                    game_time.year >= *year
-                        && (game_time.year > *year || game_time.month >= *month)
-                        && (game_time.year > *year
-                            || game_time.month > *month
-                            || game_time.day >= *day)
-                        && hour.is_none_or(|h| {
-                            game_time.year > *year
-                                || game_time.month > *month
-                                || game_time.day > *day
-                                || game_time.hour >= h
-                        })
-                }
+                } => now >= minute_of(&GameTime::new(year, month, day, hour.unwrap_or(0), 0)),
                 EventTrigger::TimeOfDay { hour, minute } => {
-                    game_time.hour == *hour && game_time.minute >= *minute
-                }
-            };
-
-            if should_trigger {
-                triggered.push(event.clone());
-                event.trigger();
-
-                // Mark for rescheduling if repeating
-                if event.repeating {
-                    if let Some(interval) = event.repeat_interval_minutes {
-                        to_reschedule.push((event.id, self.current_minute + interval as u64));
+                    // The first time the clock reads hour:minute after the last tick
+                    let since = previous_minute.max(event.scheduled_at_minute);
+                    let time_of_day = hour as u64 * 60 + minute as u64;
+                    let mut next = since - since % MINUTES_PER_DAY + time_of_day;
+                    if next <= since {
+                        next += MINUTES_PER_DAY;
                     }
+                    now >= next
                 }
+            };
+            if !due {
+                continue;
             }
-        }
 
-        // Reschedule repeating events
-        for (id, new_trigger_minute) in to_reschedule {
-            if let Some(event) = self.get_scheduled_event_mut(id) {
-                event.reschedule(new_trigger_minute);
+            triggered.push(event.clone());
+            event.trigger();
+            if event.repeating {
+                if let Some(interval) = event.repeat_interval_minutes {
+                    event.reschedule(now + interval as u64);
+                }
             }
         }
 
@@ -1164,6 +1568,53 @@ impl StoryMemory {
         summary
     }
 
+    // =========================================================================
+    // Persistence
+    // =========================================================================
+
+    /// Save story memory to a JSON file.
+    ///
+    /// The file is written atomically under a version header. Saving is
+    /// skipped when nothing has changed since the last save to the same path;
+    /// returns whether the file was written.
+    pub async fn save_to(&mut self, path: impl AsRef<Path>) -> Result<bool, PersistError> {
+        let path = path.as_ref();
+        let content = serde_json::to_string_pretty(&SavedStoryMemory {
+            version: STORY_MEMORY_VERSION,
+            story_memory: &*self,
+        })?;
+
+        let mut hasher = DefaultHasher::new();
+        content.hash(&mut hasher);
+        let save = (path.to_path_buf(), hasher.finish());
+        if self.last_save.as_ref() == Some(&save) && fs::try_exists(path).await? {
+            return Ok(false);
+        }
+
+        write_atomic(path, &content).await?;
+        self.last_save = Some(save);
+        Ok(true)
+    }
+
+    /// Load story memory from a JSON file.
+    pub async fn load_from(path: impl AsRef<Path>) -> Result<Self, PersistError> {
+        #[derive(Deserialize)]
+        struct Saved {
+            version: u32,
+            story_memory: StoryMemory,
+        }
+
+        let content = fs::read_to_string(path).await?;
+        let saved: Saved = serde_json::from_str(&content)?;
+        if saved.version != STORY_MEMORY_VERSION {
+            return Err(PersistError::VersionMismatch {
+                expected: STORY_MEMORY_VERSION,
+                found: saved.version,
+            });
+        }
+        Ok(saved.story_memory)
+    }
+
     // =========================================================================
     // Statistics and Debug
     // =========================================================================
@@ -1467,4 +1918,559 @@ mod tests {
         let involving = store.consequences_involving(npc_id);
         assert_eq!(involving.len(), 1);
     }
+
+    #[test]
+    fn test_check_triggers_fires_on_entering_location() {
+        let mut store = StoryMemory::new();
+        let arrest = store.create_consequence(
+            "The player enters Riverside village",
+            "Guards attempt an arrest",
+            ConsequenceSeverity::Major,
+        );
+        let unrelated = store.create_consequence(
+            "The player returns to the Sunken Temple",
+            "The cultists are waiting",
+            ConsequenceSeverity::Critical,
+        );
+
+        let fired = store.check_triggers(&TriggerContext::new().with_location("Old Mill"));
+        assert!(fired.is_empty());
+
+        let fired = store.check_triggers(&TriggerContext::new().with_location("Riverside"));
+        assert_eq!(fired, vec![arrest]);
+        assert_eq!(
+            store.get_consequence(arrest).unwrap().status,
+            ConsequenceStatus::Triggered
+        );
+        assert!(store.get_consequence(unrelated).unwrap().status.is_active());
+
+        // A consequence only fires once.
+        let fired = store.check_triggers(&TriggerContext::new().with_location("Riverside"));
+        assert!(fired.is_empty());
+    }
+
+    #[test]
+    fn test_check_triggers_matches_npc_by_subject() {
+        let mut store = StoryMemory::new();
+        let baron = store.create_entity(EntityType::Npc, "Baron Aldric");
+        let grudge = store.add_consequence(
+            Consequence::new(
+                "The player meets the wronged noble again",
+                "The baron demands repayment",
+                ConsequenceSeverity::Moderate,
+                store.current_turn(),
+            )
+            .with_subject(baron),
+        );
+
+        let fired = store.check_triggers(&TriggerContext::new().with_npc("baron aldric"));
+        assert_eq!(fired, vec![grudge]);
+    }
+
+    #[test]
+    fn test_check_triggers_skips_expired() {
+        let mut store = StoryMemory::new();
+        let id = store.create_consequence_with_expiry(
+            "The player enters Riverside",
+            "Guards attempt an arrest",
+            ConsequenceSeverity::Major,
+            2,
+        );
+
+        let context = TriggerContext::new()
+            .with_location("Riverside")
+            .with_elapsed_turns(2);
+        assert!(store.check_triggers(&context).is_empty());
+        assert_eq!(
+            store.get_consequence(id).unwrap().status,
+            ConsequenceStatus::Expired
+        );
+    }
+
+    #[test]
+    fn test_knows_secret_only_when_told() {
+        let mut store = StoryMemory::new();
+        let mira = store.create_entity(EntityType::Npc, "Mira");
+        let tomas = store.create_entity(EntityType::Npc, "Tomas");
+        store.create_entity(EntityType::Npc, "Garrick");
+
+        store.share_knowledge(
+            mira,
+            "The mayor is a vampire",
+            VerificationStatus::True,
+            KnowledgeSource::Player,
+            None,
+        );
+        store.share_knowledge(
+            tomas,
+            "Some say the mayor is a vampire",
+            VerificationStatus::Unknown,
+            KnowledgeSource::Unknown,
+            None,
+        );
+
+        let entry = store.knows("mira", "vampire").unwrap();
+        assert_eq!(entry.verification_status, VerificationStatus::True);
+        assert!(store.knows("Garrick", "vampire").is_none());
+        assert!(store.knows("Nobody", "vampire").is_none());
+
+        // The confirmed knower ranks above the one who only heard a rumor.
+        assert_eq!(store.who_knows("VAMPIRE"), vec!["Mira", "Tomas"]);
+        assert!(store.who_knows("dragon").is_empty());
+    }
+
+    #[test]
+    fn test_relationship_graph_queries() {
+        let mut store = StoryMemory::new();
+        let a = store.create_entity(EntityType::Npc, "Aldric");
+        let b = store.create_entity(EntityType::Npc, "Brenna");
+        let c = store.create_entity(EntityType::Npc, "Corvin");
+        let d = store.create_entity(EntityType::Npc, "Dara");
+
+        store.create_relationship(a, b, RelationshipType::Ally);
+        store.create_relationship(b, c, RelationshipType::Rival);
+        store.create_relationship(d, b, RelationshipType::Ally);
+        store.create_relationship(b, a, RelationshipType::Mentor);
+
+        assert_eq!(store.relationships_of(b).len(), 4);
+        assert_eq!(store.related_by(a, RelationshipType::Ally), vec![b]);
+        assert_eq!(store.related_by(b, RelationshipType::Ally), vec![a, d]);
+
+        // Rivalry is symmetric, so it can be followed from either end.
+        assert_eq!(store.related_by(b, RelationshipType::Rival), vec![c]);
+        assert_eq!(store.related_by(c, RelationshipType::Rival), vec![b]);
+
+        // Mentorship is one-way.
+        assert_eq!(store.related_by(b, RelationshipType::Mentor), vec![a]);
+        assert!(store.related_by(a, RelationshipType::Mentor).is_empty());
+
+        assert_eq!(store.allies_of_allies(a), vec![d]);
+        assert!(store.allies_of_allies(c).is_empty());
+    }
+
+    #[test]
+    fn test_merge_entities_repoints_facts_to_canonical_name() {
+        let mut store = StoryMemory::new();
+        let aldric = store.create_entity(EntityType::Npc, "Baron Aldric");
+        let baron = store.create_entity(EntityType::Npc, "the Baron");
+        let mira = store.create_entity(EntityType::Npc, "Mira");
+
+        store.record_fact(
+            baron,
+            "Collects taxes twice a year",
+            FactCategory::Event,
+            FactSource::NpcDialogue,
+        );
+        store.create_relationship(mira, baron, RelationshipType::Enemy);
+        store.create_relationship(aldric, baron, RelationshipType::Family);
+
+        assert!(store.merge_entities(aldric, baron));
+        assert!(!store.merge_entities(aldric, baron));
+        assert_eq!(store.entity_count(), 2);
+
+        // The alias now resolves to the canonical entity.
+        assert_eq!(store.find_entity_id("The Baron"), Some(aldric));
+
+        let canonical = store.find_entity_id("Baron Aldric").unwrap();
+        let facts = store.facts_about(canonical);
+        assert_eq!(facts.len(), 1);
+        assert_eq!(facts[0].content, "Collects taxes twice a year");
+
+        assert_eq!(
+            store.related_by(mira, RelationshipType::Enemy),
+            vec![aldric]
+        );
+        assert!(store
+            .related_by(aldric, RelationshipType::Family)
+            .is_empty());
+    }
+
+    #[test]
+    fn test_prune_drops_old_unimportant_facts_first() {
+        let mut store = StoryMemory::new();
+        let town = store.create_entity(EntityType::Location, "Millbrook");
+        let mut add = |content: &str, turn: u32, importance: f32, source: FactSource| {
+            store.add_fact(
+                StoryFact::new(town, content, FactCategory::Event, source, turn)
+                    .with_importance(importance),
+            );
+        };
+        add(
+            "The founding charter was signed",
+            0,
+            0.95,
+            FactSource::WorldBuilding,
+        );
+        add("A cart lost a wheel", 0, 0.3, FactSource::DmNarration);
+        add(
+            "The baker gossiped about rain",
+            5,
+            0.3,
+            FactSource::NpcDialogue,
+        );
+        add("The mill burned down", 90, 0.6, FactSource::DmNarration);
+        add(
+            "A stranger asked for directions",
+            95,
+            0.3,
+            FactSource::NpcDialogue,
+        );
+
+        assert_eq!(store.prune(3, 100), 2);
+        let remaining: Vec<&str> = store.facts.iter().map(|f| f.content.as_str()).collect();
+        assert_eq!(
+            remaining,
+            vec![
+                "The founding charter was signed",
+                "The mill burned down",
+                "A stranger asked for directions",
+            ]
+        );
+
+        // Nothing to do when already under the limit.
+        assert_eq!(store.prune(3, 100), 0);
+    }
+
+    #[test]
+    fn test_prune_keeps_important_facts_regardless_of_age() {
+        let mut store = StoryMemory::new();
+        let king = store.create_entity(EntityType::Npc, "King Orrin");
+        for turn in 0..5 {
+            store.add_fact(
+                StoryFact::new(
+                    king,
+                    format!("Decree {turn}"),
+                    FactCategory::Event,
+                    FactSource::DmNarration,
+                    turn,
+                )
+                .with_importance(0.95),
+            );
+        }
+        store.add_fact(
+            StoryFact::new(
+                king,
+                "Wore a blue cloak",
+                FactCategory::Appearance,
+                FactSource::DmNarration,
+                999,
+            )
+            .with_importance(0.5),
+        );
+
+        assert_eq!(store.prune(2, 1000), 1);
+        assert_eq!(store.fact_count(), 5);
+        assert!(store.facts.iter().all(|f| f.importance >= 0.9));
+
+        // The keep threshold is tunable through the public field.
+        store.fact_decay.keep_threshold = 1.0;
+        assert_eq!(store.prune(2, 1000), 3);
+    }
+
+    #[test]
+    fn test_search_facts_orders_by_similarity() {
+        let mut store = StoryMemory::new();
+        let inn = store.create_entity(EntityType::Location, "The Gilded Goose");
+        for content in [
+            "The innkeeper hides a silver dagger under the bar",
+            "The cellar floods every spring",
+            "A silver dagger was stolen from the temple",
+        ] {
+            store.record_fact(inn, content, FactCategory::Event, FactSource::DmNarration);
+        }
+
+        let results = store.search_facts("who stole the silver dagger from the temple?", 2);
+        let contents: Vec<&str> = results.iter().map(|f| f.content.as_str()).collect();
+        assert_eq!(
+            contents,
+            vec![
+                "A silver dagger was stolen from the temple",
+                "The innkeeper hides a silver dagger under the bar",
+            ]
+        );
+        assert!(store.search_facts("dragons", 5).is_empty());
+    }
+
+    #[test]
+    fn test_retrieve_balances_recency_and_importance() {
+        let mut store = StoryMemory::new();
+        let baron = store.create_entity(EntityType::Npc, "Baron Aldric");
+        store.add_fact(
+            StoryFact::new(
+                baron,
+                "Swore an oath to the crown",
+                FactCategory::Backstory,
+                FactSource::WorldBuilding,
+                0,
+            )
+            .with_importance(0.9),
+        );
+        store.add_fact(
+            StoryFact::new(
+                baron,
+                "Ordered a second helping of stew",
+                FactCategory::Event,
+                FactSource::DmNarration,
+                40,
+            )
+            .with_importance(0.2),
+        );
+
+        // With the default weights the fresh trivia outranks the old oath.
+        let top = store.retrieve("", 40, 1);
+        assert_eq!(top[0].content, "Ordered a second helping of stew");
+
+        // Weighting importance over recency flips the order.
+        store.retrieval_weights.recency = 0.2;
+        store.retrieval_weights.importance = 2.0;
+        let top = store.retrieve("", 40, 1);
+        assert_eq!(top[0].content, "Swore an oath to the crown");
+
+        // Relevance to the query can outweigh both.
+        store.retrieval_weights.relevance = 5.0;
+        let top = store.retrieve("what did he eat, stew?", 40, 1);
+        assert_eq!(top[0].content, "Ordered a second helping of stew");
+    }
+
+    #[test]
+    fn test_consolidate_rolls_old_events_into_summary() {
+        let mut store = StoryMemory::new();
+        store.consolidation = ConsolidationConfig {
+            batch_size: 3,
+            threshold: 4,
+            prune_sources: false,
+        };
+        let mira = store.create_entity(EntityType::Npc, "Mira");
+        let tomas = store.create_entity(EntityType::Npc, "Tomas");
+        for turn in 0..5 {
+            store.add_fact(
+                StoryFact::new(
+                    mira,
+                    format!("Mira sold potions on day {turn}"),
+                    FactCategory::Event,
+                    FactSource::DmNarration,
+                    turn,
+                )
+                .with_importance(0.4)
+                .with_mentioned(tomas),
+            );
+        }
+        store.record_fact(
+            tomas,
+            "Tomas fixed a wagon",
+            FactCategory::Event,
+            FactSource::DmNarration,
+        );
+
+        let batches = store.consolidation_batches();
+        assert_eq!(batches.len(), 1);
+        let source_ids: Vec<FactId> = batches[0].iter().map(|f| f.id).collect();
+        assert_eq!(batches[0][0].content, "Mira sold potions on day 0");
+
+        // A stub summarizer stands in for the LLM.
+        let summary_id = store
+            .consolidate(&source_ids, "Mira sold potions daily")
+            .unwrap();
+
+        let summary = store.get_fact(summary_id).unwrap();
+        assert_eq!(summary.consolidated_from, source_ids);
+        assert_eq!(summary.subject, mira);
+        assert!(summary.involves(tomas));
+        assert!(source_ids
+            .iter()
+            .all(|id| !store.get_fact(*id).unwrap().is_current));
+        assert!(store.consolidation_batches().is_empty());
+
+        store.consolidation.prune_sources = true;
+        assert!(store.consolidate(&source_ids, "Again").is_some());
+        assert!(source_ids.iter().all(|id| store.get_fact(*id).is_none()));
+    }
+
+    #[tokio::test]
+    async fn test_save_and_load_round_trip() {
+        use tempfile::TempDir;
+
+        let mut store = StoryMemory::new();
+        let baron = store.create_entity(EntityType::Npc, "Baron Aldric");
+        let castle = store.create_entity(EntityType::Location, "Castle Dunmore");
+        store.record_fact_with_mentions(
+            baron,
+            "Rules from Castle Dunmore",
+            FactCategory::Relationship,
+            FactSource::DmNarration,
+            &[castle],
+        );
+        store.create_relationship(baron, castle, RelationshipType::Owns);
+        store.create_consequence_with_expiry(
+            "Player returns to the castle",
+            "The guards arrest the player",
+            ConsequenceSeverity::Major,
+            10,
+        );
+        store.share_knowledge(
+            baron,
+            "The player stole the signet ring",
+            VerificationStatus::True,
+            KnowledgeSource::Observation,
+            None,
+        );
+        store.schedule_event(
+            "The baron's tax collectors arrive",
+            EventTrigger::TimeOfDay { hour: 9, minute: 0 },
+        );
+        store.advance_turn();
+
+        let temp_dir = TempDir::new().expect("Failed to create temp dir");
+        let path = temp_dir.path().join("story_memory.json");
+        assert!(store.save_to(&path).await.expect("Save should succeed"));
+        assert!(!store.save_to(&path).await.expect("Save should succeed"));
+        assert!(!path.with_file_name("story_memory.json.tmp").exists());
+        let loaded = StoryMemory::load_from(&path)
+            .await
+            .expect("Load should succeed");
+
+        assert_eq!(
+            serde_json::to_value(&loaded).unwrap(),
+            serde_json::to_value(&store).unwrap()
+        );
+        assert_eq!(loaded.current_turn(), 1);
+        assert_eq!(loaded.fact_count(), 1);
+        assert_eq!(loaded.consequence_count(), 1);
+        assert_eq!(loaded.knowledge_count(), 1);
+        assert_eq!(loaded.scheduled_event_count(), 1);
+        assert!(loaded.find_entity_by_name("baron aldric").is_some());
+    }
+
+    #[tokio::test]
+    async fn test_save_writes_again_after_changes() {
+        use tempfile::TempDir;
+
+        let temp_dir = TempDir::new().expect("Failed to create temp dir");
+        let path = temp_dir.path().join("story_memory.json");
+        let mut store = StoryMemory::new();
+        assert!(store.save_to(&path).await.unwrap());
+
+        store.create_entity(EntityType::Npc, "Mira");
+        assert!(store.save_to(&path).await.unwrap());
+        assert!(!store.save_to(&path).await.unwrap());
+
+        let mut loaded = StoryMemory::load_from(&path).await.unwrap();
+        assert_eq!(loaded.entity_count(), 1);
+        // A freshly loaded memory has not been saved yet.
+        assert!(loaded.save_to(&path).await.unwrap());
+    }
+
+    #[tokio::test]
+    async fn test_load_rejects_unknown_version() {
+        use tempfile::TempDir;
+
+        let temp_dir = TempDir::new().expect("Failed to create temp dir");
+        let path = temp_dir.path().join("story_memory.json");
+        let content = serde_json::json!({
+            "version": 99,
+            "story_memory": StoryMemory::new(),
+        });
+        tokio::fs::write(&path, content.to_string()).await.unwrap();
+
+        assert!(matches!(
+            StoryMemory::load_from(&path).await,
+            Err(PersistError::VersionMismatch { found: 99, .. })
+        ));
+    }
+
+    #[test]
+    fn test_tick_fires_relative_event_once() {
+        let mut store = StoryMemory::new();
+        let mut time = GameTime::new(1492, 3, 1, 10, 0);
+        store.sync_time(&time);
+        let id = store.schedule_after_duration("The guard patrol arrives", 30);
+
+        time.advance_minutes(20);
+        assert!(store.tick(&time).is_empty());
+
+        time.advance_minutes(15);
+        let fired = store.tick(&time);
+        assert_eq!(fired.len(), 1);
+        assert_eq!(fired[0].description, "The guard patrol arrives");
+        assert_eq!(
+            store.get_scheduled_event(id).unwrap().status,
+            EventStatus::Triggered
+        );
+
+        time.advance_minutes(60);
+        assert!(store.tick(&time).is_empty());
+    }
+
+    #[test]
+    fn test_tick_fires_absolute_event_when_time_passes() {
+        let mut store = StoryMemory::new();
+        let mut time = GameTime::new(1492, 3, 1, 10, 0);
+        store.sync_time(&time);
+        store.schedule_at_time("The Harvest Festival begins", 1492, 3, 3, Some(12));
+
+        time.advance_days(1);
+        assert!(store.tick(&time).is_empty());
+
+        // Skipping well past the moment still fires it
+        time.advance_days(2);
+        assert_eq!(store.tick(&time).len(), 1);
+        assert!(store.tick(&time).is_empty());
+    }
+
+    #[test]
+    fn test_tick_repeats_daily_event() {
+        let mut store = StoryMemory::new();
+        let mut time = GameTime::new(1492, 3, 1, 6, 0);
+        store.sync_time(&time);
+        let id = store.schedule_daily("The morning market opens", 8, 0);
+
+        time.advance_hours(1);
+        assert!(store.tick(&time).is_empty());
+
+        time.advance_hours(2);
+        assert_eq!(store.tick(&time).len(), 1);
+        // Later the same day it doesn't fire again
+        time.advance_hours(4);
+        assert!(store.tick(&time).is_empty());
+        assert_eq!(
+            store.get_scheduled_event(id).unwrap().status,
+            EventStatus::Scheduled
+        );
+
+        // ...but it does the next morning, even across a long rest
+        time.advance_hours(20);
+        assert_eq!(store.tick(&time).len(), 1);
+    }
+
+    #[test]
+    fn test_add_fact_checked_flags_contradiction() {
+        let mut store = StoryMemory::new();
+        let mira = store.create_entity(EntityType::Npc, "Mira");
+        let fact = |content: &str| {
+            StoryFact::new(
+                mira,
+                content,
+                FactCategory::Status,
+                FactSource::DmNarration,
+                0,
+            )
+        };
+
+        let alive = store.add_fact_checked(fact("Mira is alive")).unwrap();
+        store
+            .add_fact_checked(fact("Mira is hiding in the cellar"))
+            .unwrap();
+
+        let conflict = store
+            .add_fact_checked(fact("Mira was killed in the fire"))
+            .unwrap_err();
+        assert_eq!(conflict.existing, alive);
+        assert_eq!(conflict.incoming.content, "Mira was killed in the fire");
+        assert_eq!(store.fact_count(), 2);
+
+        // Superseding the old fact lets the new one in
+        assert!(store.supersede_fact(conflict.existing));
+        assert!(store.add_fact_checked(*conflict.incoming).is_ok());
+        assert!(!store.get_fact(alive).unwrap().is_current);
+    }
 }
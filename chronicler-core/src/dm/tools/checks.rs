@@ -7,14 +7,13 @@ use serde_json::json;
 pub fn roll_dice() -> Tool {
     Tool {
         name: "roll_dice".to_string(),
-        description: "Roll dice using standard D&D notation (e.g., '2d6+3', '1d20', '4d6kh3')."
-            .to_string(),
+        description: "Roll dice using standard D&D notation (e.g., '2d6+3', '1d20', '4d6kh3'). Supports exploding dice ('1d10!'), percentile ('d%'), Fudge dice ('4dF'), and rerolls ('2d6ro2' rerolls 1s and 2s once, '1d8rr1' rerolls 1s until higher).".to_string(),
         input_schema: json!({
             "type": "object",
             "properties": {
                 "notation": {
                     "type": "string",
-                    "description": "Dice notation (e.g., '2d6+3', '1d20+5', '4d6kh3')"
+                    "description": "Dice notation (e.g., '2d6+3', '1d20+5', '4d6kh3', '1d10!', '2d6ro2')"
                 },
                 "purpose": {
                     "type": "string",
@@ -28,8 +28,15 @@ pub fn apply_damage() -> Tool {
                 },
                 "target": {
                     "type": "string",
-                    "enum": ["player", "npc"],
-                    "description": "Who receives the damage"
+                    "description": "Name of the combatant who receives the damage (omit for the player)"
+                },
+                "critical": {
+                    "type": "boolean",
+                    "description": "Whether the damage came from a critical hit (costs a creature at 0 HP two death save failures)"
+                },
+                "melee_within_5ft": {
+                    "type": "boolean",
+                    "description": "Whether the damage came from a melee attack within 5 feet (always a critical hit against a creature at 0 HP)"
                 }
             },
             "required": ["amount", "damage_type", "source"]
@@ -52,6 +59,32 @@ pub fn apply_healing() -> Tool {
                 "source": {
                     "type": "string",
                     "description": "Source of the healing"
+                },
+                "target": {
+                    "type": "string",
+                    "description": "Name of the combatant who is healed (omit for the player)"
+                }
+            },
+            "required": ["amount", "source"]
+        }),
+    }
+}
+
+/// Grant temporary hit points to a character.
+pub fn grant_temp_hp() -> Tool {
+    Tool {
+        name: "grant_temp_hp".to_string(),
+        description: "Grant temporary hit points to the player character. Temporary HP absorbs damage before real HP, does not stack (the higher value is kept), and is lost on a long rest.".to_string(),
+        input_schema: json!({
+            "type": "object",
+            "properties": {
+                "amount": {
+                    "type": "integer",
+                    "description": "Number of temporary hit points to grant"
+                },
+                "source": {
+                    "type": "string",
+                    "description": "Source of the temporary hit points (e.g., 'False Life', 'Inspiring Leader')"
                 }
             },
             "required": ["amount", "source"]
@@ -81,6 +114,10 @@ pub fn apply_condition() -> Tool {
                 "duration_rounds": {
                     "type": "integer",
                     "description": "How many rounds the condition lasts (omit for indefinite)"
+                },
+                "target": {
+                    "type": "string",
+                    "description": "Name of the combatant who gains the condition (omit for the player)"
                 }
             },
             "required": ["condition", "source"]
@@ -88,6 +125,51 @@ pub fn apply_condition() -> Tool {
     }
 }
 
+/// Attach a damage-over-time, healing-over-time, or lingering modifier effect.
+pub fn apply_ongoing_effect() -> Tool {
+    Tool {
+        name: "apply_ongoing_effect".to_string(),
+        description: "Attach an effect that triggers at the start of each of the target's turns for a number of rounds, such as burning, bleeding, or regeneration. Give exactly one of damage, healing, or modifier.".to_string(),
+        input_schema: json!({
+            "type": "object",
+            "properties": {
+                "name": {
+                    "type": "string",
+                    "description": "Name of the effect (e.g., 'Burning', 'Bleeding')"
+                },
+                "rounds": {
+                    "type": "integer",
+                    "description": "How many of the target's turns the effect lasts"
+                },
+                "damage": {
+                    "type": "string",
+                    "description": "Damage dice rolled each turn (e.g., '1d4')"
+                },
+                "damage_type": {
+                    "type": "string",
+                    "enum": ["slashing", "piercing", "bludgeoning", "fire", "cold",
+                            "lightning", "thunder", "acid", "poison", "necrotic",
+                            "radiant", "force", "psychic"],
+                    "description": "Type of the damage (required with damage)"
+                },
+                "healing": {
+                    "type": "string",
+                    "description": "Healing dice rolled each turn (e.g., '1d6')"
+                },
+                "modifier": {
+                    "type": "string",
+                    "description": "A bonus or penalty to remind about each turn (e.g., '-2 to attack rolls')"
+                },
+                "target": {
+                    "type": "string",
+                    "description": "Name of the affected combatant (omit for the player)"
+                }
+            },
+            "required": ["name", "rounds"]
+        }),
+    }
+}
+
 /// Remove a condition from a character.
 pub fn remove_condition() -> Tool {
     Tool {
@@ -102,6 +184,10 @@ pub fn remove_condition() -> Tool {
                             "incapacitated", "invisible", "paralyzed", "petrified", "poisoned",
                             "prone", "restrained", "stunned", "unconscious"],
                     "description": "The condition to remove"
+                },
+                "target": {
+                    "type": "string",
+                    "description": "Name of the combatant who loses the condition (omit for the player)"
                 }
             },
             "required": ["condition"]
@@ -109,6 +195,24 @@ pub fn remove_condition() -> Tool {
     }
 }
 
+/// Raise or lower the player's exhaustion level.
+pub fn modify_exhaustion() -> Tool {
+    Tool {
+        name: "modify_exhaustion".to_string(),
+        description: "Raise or lower the player's exhaustion level (0-6). Use after forced marches, starvation, extreme environments, or effects that impose exhaustion. Level 1: disadvantage on ability checks; 2: speed halved; 3: disadvantage on attacks and saves; 4: HP maximum halved; 5: speed 0; 6: death. A long rest removes one level.".to_string(),
+        input_schema: json!({
+            "type": "object",
+            "properties": {
+                "delta": {
+                    "type": "integer",
+                    "description": "Levels to add (positive) or remove (negative)"
+                }
+            },
+            "required": ["delta"]
+        }),
+    }
+}
+
 /// Start a combat encounter.
 pub fn start_combat() -> Tool {
     Tool {
@@ -138,11 +242,74 @@ pub fn start_combat() -> Tool {
                             "initiative_modifier": {
                                 "type": "integer",
                                 "description": "Initiative modifier based on DEX (e.g., Goblin: +2, Orc: +1, Wolf: +2)"
+                            },
+                            "resistances": {
+                                "type": "array",
+                                "items": { "type": "string" },
+                                "description": "Damage types this enemy takes half damage from (e.g., Fire Elemental: bludgeoning, piercing, slashing)"
+                            },
+                            "vulnerabilities": {
+                                "type": "array",
+                                "items": { "type": "string" },
+                                "description": "Damage types this enemy takes double damage from (e.g., Skeleton: bludgeoning)"
+                            },
+                            "immunities": {
+                                "type": "array",
+                                "items": { "type": "string" },
+                                "description": "Damage types this enemy takes no damage from (e.g., Fire Elemental: fire, poison)"
+                            },
+                            "legendary_actions": {
+                                "type": "integer",
+                                "minimum": 0,
+                                "description": "Legendary actions per round for boss monsters (e.g., Adult Red Dragon: 3). Omit for ordinary creatures."
+                            },
+                            "surprised": {
+                                "type": "boolean",
+                                "description": "The party ambushed this enemy: it loses its first turn"
+                            },
+                            "position": {
+                                "type": "array",
+                                "items": { "type": "integer" },
+                                "minItems": 2,
+                                "maxItems": 2,
+                                "description": "Where the enemy stands as [x, y] in feet. Give positions to everyone (with player_position) so area spells find their targets."
                             }
                         },
                         "required": ["name"]
                     },
                     "description": "List of enemy combatants with their stats"
+                },
+                "lair_actions": {
+                    "type": "array",
+                    "items": {
+                        "type": "object",
+                        "properties": {
+                            "name": { "type": "string" },
+                            "description": { "type": "string" },
+                            "damage": {
+                                "type": "string",
+                                "description": "Damage dealt to each target in dice notation (e.g., '2d6')"
+                            },
+                            "damage_type": { "type": "string" },
+                            "condition": {
+                                "type": "string",
+                                "description": "Condition applied to each target (e.g., 'restrained')"
+                            }
+                        },
+                        "required": ["name"]
+                    },
+                    "description": "Actions the boss's lair can take on initiative count 20, once per round. Omit outside a lair."
+                },
+                "player_surprised": {
+                    "type": "boolean",
+                    "description": "The enemies ambushed the player, who loses their first turn"
+                },
+                "player_position": {
+                    "type": "array",
+                    "items": { "type": "integer" },
+                    "minItems": 2,
+                    "maxItems": 2,
+                    "description": "Where the player stands as [x, y] in feet"
                 }
             },
             "required": ["enemies"]
@@ -176,6 +343,19 @@ pub fn next_turn() -> Tool {
     }
 }
 
+/// Recap the current fight.
+pub fn combat_summary() -> Tool {
+    Tool {
+        name: "combat_summary".to_string(),
+        description: "Get a compact recap of the current fight: the round, whose turn it is, and every combatant's HP and conditions. Use this to get your bearings in a long fight instead of rereading earlier turns.".to_string(),
+        input_schema: json!({
+            "type": "object",
+            "properties": {},
+            "required": []
+        }),
+    }
+}
+
 /// Make a death saving throw.
 pub fn death_save() -> Tool {
     Tool {
@@ -189,11 +369,37 @@ pub fn death_save() -> Tool {
     }
 }
 
+/// Stabilize a dying creature.
+pub fn stabilize() -> Tool {
+    Tool {
+        name: "stabilize".to_string(),
+        description: "Attempt to stabilize a creature at 0 HP with a DC 10 Wisdom (Medicine) check. On success the creature stops making death saves but stays at 0 HP and unconscious.".to_string(),
+        input_schema: json!({
+            "type": "object",
+            "properties": {
+                "medic": {
+                    "type": "string",
+                    "description": "Name of the creature tending the wounds (omit for the player)"
+                },
+                "target": {
+                    "type": "string",
+                    "description": "Name of the dying creature (omit for the player)"
+                },
+                "npc_modifier": {
+                    "type": "integer",
+                    "description": "Medicine modifier of the medic when it isn't the player (default 0)"
+                }
+            },
+            "required": []
+        }),
+    }
+}
+
 /// Make a concentration check.
 pub fn concentration_check() -> Tool {
     Tool {
         name: "concentration_check".to_string(),
-        description: "Make a concentration check when a concentrating spellcaster takes damage. DC = max(10, damage/2). CON save to maintain concentration.".to_string(),
+        description: "Make a concentration check when a concentrating spellcaster takes damage. DC = max(10, damage/2). CON save to maintain concentration. Damage applied through the other tools triggers this check automatically.".to_string(),
         input_schema: json!({
             "type": "object",
             "properties": {
@@ -219,6 +425,10 @@ pub fn attack() -> Tool {
         input_schema: json!({
             "type": "object",
             "properties": {
+                "attacker": {
+                    "type": "string",
+                    "description": "Name of the party member making the attack (omit for the player)"
+                },
                 "weapon": {
                     "type": "string",
                     "description": "Name of the weapon to attack with (e.g., 'longsword', 'shortbow', 'dagger'). Use 'unarmed' for unarmed strikes."
@@ -231,9 +441,259 @@ pub fn attack() -> Tool {
                     "type": "string",
                     "enum": ["normal", "advantage", "disadvantage"],
                     "description": "Advantage state for the attack roll. Use 'advantage' when attacker has advantage (e.g., target is prone, attacker is hidden, ally used Help). Use 'disadvantage' when attacker has disadvantage (e.g., target is obscured, attacker is restrained)."
+                },
+                "offhand": {
+                    "type": "boolean",
+                    "description": "True for the bonus-action off-hand attack when two-weapon fighting. The weapon must be light, and the ability modifier is not added to damage unless the player has the Two-Weapon Fighting style."
+                },
+                "cover": {
+                    "type": "string",
+                    "enum": ["none", "half", "three_quarters", "total"],
+                    "description": "Cover the target has from the attacker: half (+2 AC), three-quarters (+5 AC), or total (the attack automatically misses)."
+                },
+                "adjacent_enemy": {
+                    "type": "boolean",
+                    "description": "Whether a hostile creature is within 5 feet of the player. Ranged attacks made in melee have disadvantage. If omitted, combatant positions decide, and without positions no enemy is assumed to be close; set true when an enemy is next to the player."
+                },
+                "attack_mode": {
+                    "type": "string",
+                    "enum": ["melee", "thrown", "improvised"],
+                    "description": "How the weapon is used. 'thrown' hurls a weapon with the Thrown property (dagger, handaxe, javelin). 'improvised' is for objects that aren't weapons, like a chair or bottle (name the object as the weapon): 1d4 damage, no proficiency. Defaults to 'melee', which also covers firing ranged weapons."
+                }
+            },
+            "required": ["weapon", "target"]
+        }),
+    }
+}
+
+/// Take the Attack action with Extra Attack.
+pub fn multiattack() -> Tool {
+    Tool {
+        name: "multiattack".to_string(),
+        description: "Take the Attack action with every attack the player gets from Extra Attack (two at Fighter/Barbarian/Monk/Paladin/Ranger level 5, three at Fighter 11, four at Fighter 20). Each attack rolls separately against its target.".to_string(),
+        input_schema: json!({
+            "type": "object",
+            "properties": {
+                "attacker": {
+                    "type": "string",
+                    "description": "Name of the party member taking the Attack action (omit for the player)"
+                },
+                "weapon": {
+                    "type": "string",
+                    "description": "Name of the weapon to attack with"
+                },
+                "targets": {
+                    "type": "array",
+                    "items": { "type": "string" },
+                    "description": "Target of each attack in order. Names may repeat; any remaining attacks go at the last target listed."
+                }
+            },
+            "required": ["weapon", "targets"]
+        }),
+    }
+}
+
+/// Grapple a creature.
+pub fn grapple() -> Tool {
+    Tool {
+        name: "grapple".to_string(),
+        description: "Attempt to grapple a creature. The grappler's Athletics is contested by the target's Athletics or Acrobatics; on a win the target is Grappled and its speed becomes 0.".to_string(),
+        input_schema: json!({
+            "type": "object",
+            "properties": {
+                "grappler": {
+                    "type": "string",
+                    "description": "Name of the creature attempting the grapple (omit for the player)"
+                },
+                "target": {
+                    "type": "string",
+                    "description": "Name of the creature being grappled (omit for the player)"
+                },
+                "npc_modifier": {
+                    "type": "integer",
+                    "description": "Athletics/Acrobatics modifier of the non-player creature in the contest (default 0)"
+                }
+            },
+            "required": []
+        }),
+    }
+}
+
+/// Shove a creature prone or away.
+pub fn shove() -> Tool {
+    Tool {
+        name: "shove".to_string(),
+        description: "Attempt to shove a creature, either knocking it prone or pushing it 5 feet away. The shover's Athletics is contested by the target's Athletics or Acrobatics.".to_string(),
+        input_schema: json!({
+            "type": "object",
+            "properties": {
+                "shover": {
+                    "type": "string",
+                    "description": "Name of the creature doing the shoving (omit for the player)"
+                },
+                "target": {
+                    "type": "string",
+                    "description": "Name of the creature being shoved (omit for the player)"
+                },
+                "mode": {
+                    "type": "string",
+                    "enum": ["prone", "push"],
+                    "description": "Knock the target prone or push it 5 feet away"
+                },
+                "npc_modifier": {
+                    "type": "integer",
+                    "description": "Athletics/Acrobatics modifier of the non-player creature in the contest (default 0)"
+                }
+            },
+            "required": ["mode"]
+        }),
+    }
+}
+
+/// Make an opportunity attack as a reaction.
+pub fn opportunity_attack() -> Tool {
+    Tool {
+        name: "opportunity_attack".to_string(),
+        description: "Make an opportunity attack when a hostile creature a party member can see moves out of their reach. Uses that character's reaction, which is regained at the start of their next turn.".to_string(),
+        input_schema: json!({
+            "type": "object",
+            "properties": {
+                "attacker": {
+                    "type": "string",
+                    "description": "Name of the party member reacting (omit for the player). Monsters' opportunity attacks are rolled by the DM, not with this tool."
+                },
+                "weapon": {
+                    "type": "string",
+                    "description": "Name of the melee weapon to attack with"
+                },
+                "target": {
+                    "type": "string",
+                    "description": "Name of the creature leaving the attacker's reach"
                 }
             },
             "required": ["weapon", "target"]
         }),
     }
 }
+
+/// Cast Counterspell as a reaction.
+pub fn counterspell() -> Tool {
+    Tool {
+        name: "counterspell".to_string(),
+        description: "Cast Counterspell as the player's reaction to interrupt a creature casting a spell. A slot of at least the target spell's level counters it automatically; a lower slot needs a spellcasting ability check against DC 10 + the spell's level. The slot is spent either way.".to_string(),
+        input_schema: json!({
+            "type": "object",
+            "properties": {
+                "target_spell_level": {
+                    "type": "integer",
+                    "minimum": 1,
+                    "maximum": 9,
+                    "description": "Level of the spell being countered"
+                },
+                "slot_level": {
+                    "type": "integer",
+                    "minimum": 3,
+                    "maximum": 9,
+                    "description": "Spell slot level to cast Counterspell with (default 3)"
+                }
+            },
+            "required": ["target_spell_level"]
+        }),
+    }
+}
+
+/// Trigger a lair action on initiative count 20.
+pub fn trigger_lair_action() -> Tool {
+    Tool {
+        name: "trigger_lair_action".to_string(),
+        description: "On initiative count 20 (losing ties), the lair takes one of the lair actions given to start_combat. Only once per round. Damage and conditions apply to each listed target; decide saving throws before choosing targets.".to_string(),
+        input_schema: json!({
+            "type": "object",
+            "properties": {
+                "index": {
+                    "type": "integer",
+                    "minimum": 0,
+                    "description": "Which lair action to use (0-based, in the order given to start_combat)"
+                },
+                "targets": {
+                    "type": "array",
+                    "items": { "type": "string" },
+                    "description": "Names of the creatures affected (defaults to the player)"
+                }
+            },
+            "required": ["index"]
+        }),
+    }
+}
+
+/// Spend a boss monster's legendary actions.
+pub fn use_legendary_action() -> Tool {
+    Tool {
+        name: "use_legendary_action".to_string(),
+        description: "Spend legendary actions for a boss monster at the end of another creature's turn. The pool refills at the start of the boss's own turn. Resolve the action itself (attack, movement, etc.) with the matching tool.".to_string(),
+        input_schema: json!({
+            "type": "object",
+            "properties": {
+                "combatant": {
+                    "type": "string",
+                    "description": "Name of the creature taking the legendary action"
+                },
+                "action": {
+                    "type": "string",
+                    "description": "Name of the legendary action (e.g., 'Tail Attack', 'Wing Attack')"
+                },
+                "cost": {
+                    "type": "integer",
+                    "minimum": 1,
+                    "maximum": 3,
+                    "description": "How many legendary actions it costs (default 1)"
+                }
+            },
+            "required": ["combatant", "action"]
+        }),
+    }
+}
+
+/// Take the Dodge action.
+pub fn dodge() -> Tool {
+    Tool {
+        name: "dodge".to_string(),
+        description: "Take the Dodge action. Until the start of the dodger's next turn, attack rolls against them have disadvantage.".to_string(),
+        input_schema: json!({
+            "type": "object",
+            "properties": {
+                "combatant": {
+                    "type": "string",
+                    "description": "Name of the combatant taking the Dodge action (omit for the player)"
+                }
+            },
+            "required": []
+        }),
+    }
+}
+
+/// Take the Help action.
+pub fn help() -> Tool {
+    Tool {
+        name: "help".to_string(),
+        description: "Take the Help action. The helped creature gains advantage on its next ability check or attack roll, as long as it happens before the helper's next turn.".to_string(),
+        input_schema: json!({
+            "type": "object",
+            "properties": {
+                "helper": {
+                    "type": "string",
+                    "description": "Name of the combatant giving help"
+                },
+                "target": {
+                    "type": "string",
+                    "description": "Name of the combatant being helped (omit for the player)"
+                },
+                "task": {
+                    "type": "string",
+                    "description": "What the helper is assisting with (e.g., 'distracting the ogre', 'picking the lock')"
+                }
+            },
+            "required": ["helper", "task"]
+        }),
+    }
+}
@@ -4,8 +4,9 @@
 //! into their corresponding D&D enum types.
 
 use crate::dice::Advantage;
-use crate::rules::DamageType;
+use crate::rules::{AttackMode, Cover, DamageType};
 use crate::world::{Ability, Condition, Skill};
+use serde_json::Value;
 
 /// Parse a skill name string into a Skill enum.
 pub fn parse_skill(s: &str) -> Option<Skill> {
@@ -54,6 +55,33 @@ pub fn parse_advantage(s: Option<&str>) -> Advantage {
     }
 }
 
+/// Parse an attack mode string into an AttackMode enum.
+pub fn parse_attack_mode(s: Option<&str>) -> AttackMode {
+    match s {
+        Some("thrown") => AttackMode::Thrown,
+        Some("improvised") => AttackMode::Improvised,
+        _ => AttackMode::Melee,
+    }
+}
+
+/// Parse a cover string into a Cover enum.
+pub fn parse_cover(s: Option<&str>) -> Cover {
+    match s {
+        Some("half") => Cover::Half,
+        Some("three_quarters") => Cover::ThreeQuarters,
+        Some("total") => Cover::Total,
+        _ => Cover::None,
+    }
+}
+
+/// Parse an `[x, y]` position in feet.
+pub fn parse_position(value: &Value) -> Option<(i32, i32)> {
+    match value.as_array()?.as_slice() {
+        [x, y] => Some((x.as_i64()? as i32, y.as_i64()? as i32)),
+        _ => None,
+    }
+}
+
 /// Parse a damage type string into a DamageType enum.
 pub fn parse_damage_type(s: &str) -> Option<DamageType> {
     match s.to_lowercase().as_str() {
@@ -91,6 +119,7 @@ pub fn parse_condition(s: &str) -> Option<Condition> {
         "restrained" => Some(Condition::Restrained),
         "stunned" => Some(Condition::Stunned),
         "unconscious" => Some(Condition::Unconscious),
+        "surprised" => Some(Condition::Surprised),
         _ => None,
     }
 }
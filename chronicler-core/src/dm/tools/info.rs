@@ -1,8 +1,52 @@
 //! Informational tools that return data without creating Intents.
 
 use crate::dm::story_memory::StoryMemory;
-use crate::world::GameWorld;
-use serde_json::Value;
+use crate::rules_reference;
+use crate::world::{find_by_name, ActiveCondition, GameWorld, NameLookupError, OngoingEffect};
+use claude::Tool;
+use serde_json::{json, Map, Value};
+
+/// Look up the rules text for a condition, damage type, or class feature.
+pub fn rules_lookup() -> Tool {
+    Tool {
+        name: "rules_lookup".to_string(),
+        description: "Look up the mechanical rules for a condition (e.g., 'Grappled'), damage type (e.g., 'necrotic'), or class feature (e.g., 'Sneak Attack'). Use this instead of recalling rules from memory when adjudicating.".to_string(),
+        input_schema: json!({
+            "type": "object",
+            "properties": {
+                "term": {
+                    "type": "string",
+                    "description": "Name of the condition, damage type, or class feature"
+                }
+            },
+            "required": ["term"]
+        }),
+    }
+}
+
+/// Recall the most recent exchanges between the player and an NPC.
+pub fn recall_npc_dialogue() -> Tool {
+    Tool {
+        name: "recall_npc_dialogue".to_string(),
+        description: "Recall what the player and an NPC said to each other in recent conversations. Use this to keep an NPC consistent with promises, threats, or details from earlier exchanges.".to_string(),
+        input_schema: json!({
+            "type": "object",
+            "properties": {
+                "npc_name": {
+                    "type": "string",
+                    "description": "Name of the NPC"
+                },
+                "turns": {
+                    "type": "integer",
+                    "minimum": 1,
+                    "default": 3,
+                    "description": "How many of the most recent exchanges to return"
+                }
+            },
+            "required": ["npc_name"]
+        }),
+    }
+}
 
 /// Execute an informational tool that may need StoryMemory access.
 /// This is called when story_memory is available (from DungeonMaster).
@@ -14,13 +58,54 @@ pub fn execute_info_tool_with_memory(
 ) -> Option<String> {
     match name {
         "show_inventory" => Some(format_inventory(world)),
-        "query_state" => Some(query_entity_state(input, world)),
-        "query_knowledge" => Some(query_entity_knowledge(input, world)),
+        "combat_summary" => Some(
+            world
+                .combat_summary()
+                .unwrap_or_else(|| "No combat in progress.".to_string()),
+        ),
+        "query_state" => Some(query_entity_state(input, world, story_memory)),
+        "query_knowledge" => Some(query_entity_knowledge(input, world, story_memory)),
         "check_schedule" => Some(check_schedule(input, world, story_memory)),
+        "rules_lookup" => Some(lookup_rule(input)),
+        "recall_npc_dialogue" => Some(recall_dialogue(input, world)),
         _ => None,
     }
 }
 
+/// List an NPC's most recent exchanges with the player.
+fn recall_dialogue(input: &Value, world: &GameWorld) -> String {
+    let Some(name) = input["npc_name"].as_str() else {
+        return "Error: npc_name is required".to_string();
+    };
+    let turns = input["turns"].as_u64().unwrap_or(3).max(1) as usize;
+    let npc = match world.find_npc_fuzzy(name) {
+        Ok(npc) => npc,
+        Err(err) => return err.to_string(),
+    };
+
+    let recent = npc.recent_dialogue(turns);
+    if recent.is_empty() {
+        return format!("The player has not spoken with {} yet.", npc.name);
+    }
+    let mut output = format!("Last {} exchange(s) with {}:\n", recent.len(), npc.name);
+    for turn in recent {
+        output.push_str(&format!("- {}\n", turn.summary()));
+    }
+    output
+}
+
+/// Answer a rules question from the structured reference data.
+fn lookup_rule(input: &Value) -> String {
+    let term = input["term"].as_str().unwrap_or("");
+    match rules_reference::lookup(term) {
+        Some(entry) => entry.format(),
+        None => format!(
+            "No rules entry found for '{}'. Try the exact name of a condition, damage type, or class feature.",
+            term
+        ),
+    }
+}
+
 /// Check the schedule for upcoming events.
 fn check_schedule(input: &Value, world: &GameWorld, story_memory: &StoryMemory) -> String {
     let location_filter = input["location"].as_str();
@@ -86,139 +171,247 @@ fn check_schedule(input: &Value, world: &GameWorld, story_memory: &StoryMemory)
     result
 }
 
-/// Query the state of an entity.
-fn query_entity_state(input: &Value, world: &GameWorld) -> String {
-    let entity_name = match input["entity_name"].as_str() {
-        Some(name) => name,
-        None => return "Error: entity_name is required".to_string(),
+/// Query the state of an entity as structured JSON.
+///
+/// The entity may be the player (or "player"), another party member, a
+/// combatant, or an NPC, matched by a possibly partial name. Combat stats
+/// come from the current fight; disposition, location and knowledge from the
+/// NPC record; relationships from story memory. `state_type` narrows the
+/// answer to one group of fields.
+fn query_entity_state(input: &Value, world: &GameWorld, story_memory: &StoryMemory) -> String {
+    let Some(entity_name) = input["entity_name"].as_str() else {
+        return json!({ "error": "entity_name is required" }).to_string();
     };
-
     let state_type = input["state_type"].as_str().unwrap_or("all");
 
-    // Find the NPC
-    let npc = world
-        .npcs
-        .values()
-        .find(|npc| npc.name.eq_ignore_ascii_case(entity_name));
-
-    match npc {
-        Some(npc) => {
-            let mut result = format!("=== State of {} ===\n\n", npc.name);
+    let character = if entity_name.eq_ignore_ascii_case("player") {
+        Ok(&world.player_character)
+    } else {
+        find_by_name(
+            entity_name,
+            world.party_members().map(|c| (c.name.as_str(), c)),
+        )
+    };
+    let combatant = world.find_combatant_fuzzy(entity_name);
+    let npc = world.find_npc_fuzzy(entity_name);
+
+    let mut state = Map::new();
+    let mut npc_record = None;
+    if let Ok(character) = character {
+        let kind = if character.id == world.player_character.id {
+            "player"
+        } else {
+            "party_member"
+        };
+        state.insert("name".into(), json!(character.name));
+        state.insert("kind".into(), json!(kind));
+        state.insert(
+            "hp".into(),
+            json!({
+                "current": character.hit_points.current,
+                "max": character.hit_points.maximum,
+                "temporary": character.hit_points.temporary,
+            }),
+        );
+        state.insert("life_state".into(), json!(character.life_state.name()));
+        insert_effects(
+            &mut state,
+            &character.conditions,
+            &character.ongoing_effects,
+        );
+        state.insert("location".into(), json!(world.current_location.name));
+    } else if let Some(combatant) = combatant
+        .as_ref()
+        .ok()
+        .and_then(|id| world.combat.as_ref()?.combatant(*id))
+    {
+        state.insert("name".into(), json!(combatant.name));
+        state.insert("kind".into(), json!("combatant"));
+        state.insert("is_ally".into(), json!(combatant.is_ally));
+        state.insert(
+            "hp".into(),
+            json!({ "current": combatant.current_hp, "max": combatant.max_hp }),
+        );
+        insert_effects(
+            &mut state,
+            &combatant.conditions,
+            &combatant.ongoing_effects,
+        );
+        // A named NPC who is fighting also has social state
+        npc_record = npc.as_ref().ok().filter(|npc| npc.name == combatant.name);
+    } else if let Ok(npc) = &npc {
+        state.insert("name".into(), json!(npc.name));
+        state.insert("kind".into(), json!("npc"));
+        npc_record = Some(npc);
+    } else {
+        // Prefer telling the DM a name was ambiguous over that it wasn't found
+        let error = [character.err(), combatant.err(), npc.err()]
+            .into_iter()
+            .flatten()
+            .find(|e| matches!(e, NameLookupError::Ambiguous { .. }))
+            .map(|e| e.to_string())
+            .unwrap_or_else(|| {
+                format!(
+                    "No entity found with name '{}'. Check spelling or create the NPC first.",
+                    entity_name
+                )
+            });
+        return json!({ "error": error }).to_string();
+    }
 
-            let disposition_str = match npc.disposition {
-                crate::world::Disposition::Hostile => "hostile",
-                crate::world::Disposition::Unfriendly => "unfriendly",
-                crate::world::Disposition::Neutral => "neutral",
-                crate::world::Disposition::Friendly => "friendly",
-                crate::world::Disposition::Helpful => "helpful",
-            };
+    if let Some(npc) = npc_record {
+        let disposition = match npc.disposition {
+            crate::world::Disposition::Hostile => "hostile",
+            crate::world::Disposition::Unfriendly => "unfriendly",
+            crate::world::Disposition::Neutral => "neutral",
+            crate::world::Disposition::Friendly => "friendly",
+            crate::world::Disposition::Helpful => "helpful",
+        };
+        let location = npc
+            .location_id
+            .and_then(|id| world.known_locations.get(&id))
+            .map(|loc| loc.name.as_str());
+        state.insert("disposition".into(), json!(disposition));
+        state.insert("location".into(), json!(location));
+        state.insert("occupation".into(), json!(npc.occupation));
+        state.insert("knowledge".into(), json!(npc.known_information));
+    }
 
-            match state_type {
-                "disposition" => {
-                    result.push_str(&format!("Disposition: {}\n", disposition_str));
-                }
-                "location" => {
-                    let location_name = npc
-                        .location_id
-                        .and_then(|id| world.known_locations.get(&id))
-                        .map(|loc| loc.name.as_str())
-                        .unwrap_or("Unknown");
-                    result.push_str(&format!("Location: {}\n", location_name));
-                }
-                "knowledge" => {
-                    if npc.known_information.is_empty() {
-                        result.push_str("Knowledge: (none recorded)\n");
-                    } else {
-                        result.push_str("Knowledge:\n");
-                        for info in &npc.known_information {
-                            result.push_str(&format!("  - {}\n", info));
-                        }
-                    }
-                }
-                _ => {
-                    result.push_str(&format!("Disposition: {}\n", disposition_str));
-                    let location_name = npc
-                        .location_id
-                        .and_then(|id| world.known_locations.get(&id))
-                        .map(|loc| loc.name.as_str())
-                        .unwrap_or("Unknown");
-                    result.push_str(&format!("Location: {}\n", location_name));
-                    result.push_str(&format!(
-                        "Occupation: {}\n",
-                        npc.occupation.as_deref().unwrap_or("Unknown")
-                    ));
-                    if !npc.known_information.is_empty() {
-                        result.push_str("Knowledge:\n");
-                        for info in &npc.known_information {
-                            result.push_str(&format!("  - {}\n", info));
-                        }
-                    }
-                }
-            }
+    let name = state["name"].as_str().unwrap_or(entity_name).to_string();
+    if let Some(entity) = story_memory.find_entity_by_name(&name) {
+        let relationships: Vec<Value> = story_memory
+            .relationships_of(entity.id)
+            .into_iter()
+            .filter_map(|r| {
+                let other = story_memory.get_entity(r.other(entity.id)?)?;
+                Some(json!({
+                    "type": r.relationship_type.name(),
+                    "with": other.name,
+                }))
+            })
+            .collect();
+        state.insert("relationships".into(), json!(relationships));
+    }
 
-            result
-        }
-        None => format!(
-            "No entity found with name '{}'. Check spelling or create the NPC first.",
-            entity_name
-        ),
+    let keep: &[&str] = match state_type {
+        "disposition" => &["disposition"],
+        "location" => &["location"],
+        "status" => &[
+            "hp",
+            "life_state",
+            "conditions",
+            "ongoing_effects",
+            "is_ally",
+        ],
+        "knowledge" => &["knowledge"],
+        "relationship" => &["relationships"],
+        _ => &[],
+    };
+    if !keep.is_empty() {
+        state.retain(|key, _| key == "name" || key == "kind" || keep.contains(&key.as_str()));
     }
+
+    Value::Object(state).to_string()
+}
+
+/// Add condition and ongoing effect names to a queried state.
+fn insert_effects(
+    state: &mut Map<String, Value>,
+    conditions: &[ActiveCondition],
+    ongoing: &[OngoingEffect],
+) {
+    let conditions: Vec<&str> = conditions.iter().map(|c| c.condition.name()).collect();
+    let ongoing: Vec<&str> = ongoing.iter().map(|e| e.name.as_str()).collect();
+    state.insert("conditions".into(), json!(conditions));
+    state.insert("ongoing_effects".into(), json!(ongoing));
 }
 
 /// Query what an entity knows.
-fn query_entity_knowledge(input: &Value, world: &GameWorld) -> String {
+///
+/// Knowledge tracked in story memory is listed first, best-verified first,
+/// followed by anything only recorded on the NPC itself. When a topic is
+/// given, the other entities who know about it are listed too.
+fn query_entity_knowledge(input: &Value, world: &GameWorld, story_memory: &StoryMemory) -> String {
     let entity_name = match input["entity_name"].as_str() {
         Some(name) => name,
         None => return "Error: entity_name is required".to_string(),
     };
 
     let topic = input["topic"].as_str();
+    let topic_lower = topic.map(str::to_lowercase);
+    let matches_topic = |info: &str| {
+        topic_lower
+            .as_ref()
+            .is_none_or(|t| info.to_lowercase().contains(t.as_str()))
+    };
 
-    // Find the NPC
+    // Find the NPC in the world and the entity in story memory
     let npc = world
         .npcs
         .values()
         .find(|npc| npc.name.eq_ignore_ascii_case(entity_name));
+    let entity = story_memory.find_entity_by_name(entity_name);
+
+    let display_name = match (npc, entity) {
+        (Some(npc), _) => npc.name.as_str(),
+        (None, Some(entity)) => entity.name.as_str(),
+        (None, None) => {
+            return format!(
+                "No entity found with name '{}'. Check spelling or create the NPC first.",
+                entity_name
+            )
+        }
+    };
 
-    match npc {
-        Some(npc) => {
-            let mut result = format!("=== Knowledge of {} ===\n\n", npc.name);
-
-            if npc.known_information.is_empty() {
-                result.push_str("No recorded knowledge.\n");
-            } else {
-                // Filter by topic if provided
-                let knowledge: Vec<&String> = if let Some(topic_str) = topic {
-                    let topic_lower = topic_str.to_lowercase();
-                    npc.known_information
-                        .iter()
-                        .filter(|info| info.to_lowercase().contains(&topic_lower))
-                        .collect()
-                } else {
-                    npc.known_information.iter().collect()
-                };
+    let mut tracked = entity
+        .map(|e| story_memory.query_entity_knowledge(e.id, topic.unwrap_or("")))
+        .unwrap_or_default();
+    tracked.sort_by_key(|k| std::cmp::Reverse(k.verification_status.rank()));
+
+    let mut lines: Vec<String> = tracked
+        .iter()
+        .map(|k| format!("{} [{}]", k.content, k.verification_status.name()))
+        .collect();
+    if let Some(npc) = npc {
+        lines.extend(
+            npc.known_information
+                .iter()
+                .filter(|info| matches_topic(info))
+                .filter(|info| !tracked.iter().any(|k| &k.content == *info))
+                .cloned(),
+        );
+    }
 
-                if knowledge.is_empty() {
-                    if let Some(topic_str) = topic {
-                        result.push_str(&format!("No knowledge about '{}'.\n", topic_str));
-                    } else {
-                        result.push_str("No recorded knowledge.\n");
-                    }
-                } else {
-                    result.push_str(&format!("{} knows:\n", npc.name));
-                    for info in knowledge {
-                        result.push_str(&format!("  - {}\n", info));
-                    }
-                }
-            }
+    let mut result = format!("=== Knowledge of {} ===\n\n", display_name);
+    if lines.is_empty() {
+        if let Some(topic_str) = topic {
+            result.push_str(&format!("No knowledge about '{}'.\n", topic_str));
+        } else {
+            result.push_str("No recorded knowledge.\n");
+        }
+    } else {
+        result.push_str(&format!("{} knows:\n", display_name));
+        for line in lines {
+            result.push_str(&format!("  - {}\n", line));
+        }
+    }
 
-            result
+    if let Some(topic_str) = topic {
+        let others: Vec<&str> = story_memory
+            .who_knows(topic_str)
+            .into_iter()
+            .filter(|name| !name.eq_ignore_ascii_case(display_name))
+            .collect();
+        if !others.is_empty() {
+            result.push_str(&format!(
+                "\nOthers who know about '{}': {}\n",
+                topic_str,
+                others.join(", ")
+            ));
         }
-        None => format!(
-            "No entity found with name '{}'. Check spelling or create the NPC first.",
-            entity_name
-        ),
     }
+
+    result
 }
 
 /// Format the player's inventory for display.
@@ -345,6 +538,152 @@ mod tests {
         assert!(inventory.contains("sp"));
     }
 
+    #[test]
+    fn test_info_tool_rules_lookup_condition() {
+        let world = create_test_world();
+        let story_memory = create_test_story_memory();
+        let input = json!({"term": "Grappled"});
+
+        let result =
+            execute_info_tool_with_memory("rules_lookup", &input, &world, &story_memory).unwrap();
+        assert!(result.starts_with("Grappled (condition)"));
+        assert!(result.contains("Speed becomes 0"));
+    }
+
+    #[test]
+    fn test_info_tool_rules_lookup_unknown_term() {
+        let world = create_test_world();
+        let story_memory = create_test_story_memory();
+        let input = json!({"term": "Gelatinous Hug"});
+
+        let result =
+            execute_info_tool_with_memory("rules_lookup", &input, &world, &story_memory).unwrap();
+        assert!(result.contains("No rules entry found for 'Gelatinous Hug'"));
+    }
+
+    #[test]
+    fn test_info_tool_recall_npc_dialogue() {
+        let mut world = create_test_world();
+        let mut npc = crate::world::NPC::new("Mira the Innkeeper");
+        for i in 1..=4 {
+            npc.record_dialogue(format!("question {i}"), format!("answer {i}"));
+        }
+        world.npcs.insert(npc.id, npc);
+        let story_memory = create_test_story_memory();
+
+        let input = json!({"npc_name": "mira", "turns": 2});
+        let result =
+            execute_info_tool_with_memory("recall_npc_dialogue", &input, &world, &story_memory)
+                .unwrap();
+        assert!(result.starts_with("Last 2 exchange(s) with Mira the Innkeeper"));
+        assert!(result.contains("question 3") && result.contains("answer 4"));
+        assert!(!result.contains("question 2"));
+    }
+
+    #[test]
+    fn test_query_state_reports_poisoned_combatant() {
+        let mut world = create_test_world();
+        let goblin_id = crate::world::CharacterId::new();
+        let mut goblin = crate::world::Combatant {
+            id: goblin_id,
+            name: "Goblin Archer".to_string(),
+            initiative: 12,
+            dex_modifier: 2,
+            is_player: false,
+            is_ally: false,
+            current_hp: 4,
+            max_hp: 7,
+            armor_class: 13,
+            conditions: Vec::new(),
+            ongoing_effects: Vec::new(),
+            resistances: std::collections::HashMap::new(),
+            reaction_used: false,
+            dodging: false,
+            helped_by: None,
+            legendary_actions: 0,
+            legendary_max: 0,
+            position: None,
+        };
+        goblin.add_condition_with_duration(crate::world::Condition::Poisoned, "dart", None);
+        world.start_combat().add_combatant(goblin);
+        let story_memory = create_test_story_memory();
+
+        let query = |input: Value| {
+            let result =
+                execute_info_tool_with_memory("query_state", &input, &world, &story_memory);
+            serde_json::from_str::<Value>(&result.unwrap()).unwrap()
+        };
+
+        let state = query(json!({"entity_name": "goblin"}));
+        assert_eq!(state["name"], "Goblin Archer");
+        assert_eq!(state["kind"], "combatant");
+        assert_eq!(state["hp"], json!({"current": 4, "max": 7}));
+        assert_eq!(state["conditions"], json!(["Poisoned"]));
+
+        let status = query(json!({"entity_name": "goblin", "state_type": "disposition"}));
+        assert!(status.get("hp").is_none());
+
+        let missing = query(json!({"entity_name": "dragon"}));
+        assert!(missing["error"]
+            .as_str()
+            .unwrap()
+            .contains("No entity found"));
+    }
+
+    #[test]
+    fn test_query_state_reports_npc_and_player() {
+        let mut world = create_test_world();
+        let mut npc = crate::world::NPC::new("Mira");
+        npc.disposition = crate::world::Disposition::Friendly;
+        world.npcs.insert(npc.id, npc);
+        let story_memory = create_test_story_memory();
+
+        let query = |name: &str| {
+            let input = json!({"entity_name": name});
+            let result =
+                execute_info_tool_with_memory("query_state", &input, &world, &story_memory);
+            serde_json::from_str::<Value>(&result.unwrap()).unwrap()
+        };
+
+        let mira = query("mira");
+        assert_eq!(mira["kind"], "npc");
+        assert_eq!(mira["disposition"], "friendly");
+
+        let player = query("player");
+        assert_eq!(player["kind"], "player");
+        assert_eq!(player["name"], "Test Hero");
+        assert_eq!(player["life_state"], "alive");
+    }
+
+    #[test]
+    fn test_query_knowledge_distinguishes_informed_npcs() {
+        use crate::dm::story_memory::{EntityType, KnowledgeSource, VerificationStatus};
+
+        let world = create_test_world();
+        let mut story_memory = create_test_story_memory();
+        let mira = story_memory.create_entity(EntityType::Npc, "Mira");
+        story_memory.create_entity(EntityType::Npc, "Garrick");
+        story_memory.share_knowledge(
+            mira,
+            "The mayor is a vampire",
+            VerificationStatus::True,
+            KnowledgeSource::Player,
+            None,
+        );
+
+        let query = |name: &str| {
+            let input = json!({"entity_name": name, "topic": "vampire"});
+            execute_info_tool_with_memory("query_knowledge", &input, &world, &story_memory).unwrap()
+        };
+
+        let informed = query("Mira");
+        assert!(informed.contains("The mayor is a vampire [verified]"));
+
+        let uninformed = query("Garrick");
+        assert!(uninformed.contains("No knowledge about 'vampire'"));
+        assert!(uninformed.contains("Others who know about 'vampire': Mira"));
+    }
+
     #[test]
     fn test_info_tool_unknown() {
         let world = create_test_world();
@@ -132,6 +132,43 @@ pub fn unequip_item() -> Tool {
     }
 }
 
+/// Attune to a magic item.
+pub fn attune_item() -> Tool {
+    Tool {
+        name: "attune_item".to_string(),
+        description: "Attune the player to a magic item they carry, spending a short rest. Items that require attunement can't be used or equipped until attuned, and a character can be attuned to at most three items at once.".to_string(),
+        input_schema: json!({
+            "type": "object",
+            "properties": {
+                "item_name": {
+                    "type": "string",
+                    "description": "Name of the magic item to attune to"
+                }
+            },
+            "required": ["item_name"]
+        }),
+    }
+}
+
+/// End attunement to a magic item.
+pub fn unattune_item() -> Tool {
+    Tool {
+        name: "unattune_item".to_string(),
+        description: "End the player's attunement to a magic item, freeing an attunement slot."
+            .to_string(),
+        input_schema: json!({
+            "type": "object",
+            "properties": {
+                "item_name": {
+                    "type": "string",
+                    "description": "Name of the attuned item"
+                }
+            },
+            "required": ["item_name"]
+        }),
+    }
+}
+
 /// Add or remove gold pieces.
 pub fn adjust_gold() -> Tool {
     Tool {
@@ -176,6 +213,29 @@ pub fn adjust_silver() -> Tool {
     }
 }
 
+/// Roll loot on the treasure table.
+pub fn generate_loot() -> Tool {
+    Tool {
+        name: "generate_loot".to_string(),
+        description: "Roll treasure for defeated creatures or a discovered hoard. Gold and items scale with challenge rating and are added to the player's inventory. Use this instead of inventing loot.".to_string(),
+        input_schema: json!({
+            "type": "object",
+            "properties": {
+                "challenge_rating": {
+                    "type": "number",
+                    "minimum": 0,
+                    "description": "Challenge rating of the creature, or of the strongest guardian for a hoard"
+                },
+                "hoard": {
+                    "type": "boolean",
+                    "description": "True for a treasure hoard (lair, vault, chest), false for what a single creature carries (default false)"
+                }
+            },
+            "required": ["challenge_rating"]
+        }),
+    }
+}
+
 /// Display the player's current inventory.
 pub fn show_inventory() -> Tool {
     Tool {
@@ -66,7 +66,7 @@ Examples:
 pub fn query_knowledge() -> Tool {
     Tool {
         name: "query_knowledge".to_string(),
-        description: "Query what an entity knows. Returns all current knowledge the entity has, or filters by topic if specified.".to_string(),
+        description: "Query what an entity knows. Returns all current knowledge the entity has (verified knowledge before rumors), or filters by topic if specified and lists who else knows about it.".to_string(),
         input_schema: json!({
             "type": "object",
             "properties": {
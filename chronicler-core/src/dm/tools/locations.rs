@@ -80,6 +80,31 @@ pub fn connect_locations() -> Tool {
     }
 }
 
+/// Roll for a random encounter.
+pub fn roll_encounter() -> Tool {
+    Tool {
+        name: "roll_encounter".to_string(),
+        description: "Roll on the random encounter table for a type of location. Use this when the party travels, camps, or lingers somewhere dangerous. On an encounter it suggests monsters and their challenge ratings; use start_combat to run a hostile encounter.".to_string(),
+        input_schema: json!({
+            "type": "object",
+            "properties": {
+                "location_type": {
+                    "type": "string",
+                    "enum": ["wilderness", "town", "city", "dungeon", "building", "room", "road", "cave", "other"],
+                    "description": "Type of area to roll for (defaults to the current location's type)"
+                },
+                "party_level": {
+                    "type": "integer",
+                    "minimum": 1,
+                    "maximum": 20,
+                    "description": "Level used to pick suitable encounters (defaults to the player's level)"
+                }
+            },
+            "required": []
+        }),
+    }
+}
+
 /// Update an existing location's state.
 pub fn update_location() -> Tool {
     Tool {
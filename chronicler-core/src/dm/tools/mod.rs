@@ -13,6 +13,7 @@
 //! - `npc` - NPC creation, updates, movement, and removal
 //! - `quests` - Quest creation, objectives, and completion tracking
 //! - `state` - Declarative state assertions (disposition, location, status, relationships)
+//! - `info` - Read-only lookups (inventory, state queries, rules reference)
 
 mod checks;
 mod class_features;
@@ -27,10 +28,12 @@ mod parsing;
 mod quests;
 mod schedule;
 mod state;
+mod validation;
 mod world;
 
 pub use info::execute_info_tool_with_memory;
-pub use parsing::parse_tool_call;
+pub use parsing::{name_lookup_error, parse_tool_call, parse_tool_call_with_target};
+pub use validation::validate_input;
 
 use claude::Tool;
 
@@ -48,14 +51,28 @@ impl DmTools {
             checks::saving_throw(),
             // Combat
             combat::attack(),
+            combat::multiattack(),
+            combat::grapple(),
+            combat::shove(),
+            combat::opportunity_attack(),
+            combat::counterspell(),
+            combat::use_legendary_action(),
+            combat::trigger_lair_action(),
+            combat::dodge(),
+            combat::help(),
             combat::apply_damage(),
             combat::apply_healing(),
+            combat::grant_temp_hp(),
             combat::apply_condition(),
             combat::remove_condition(),
+            combat::apply_ongoing_effect(),
+            combat::modify_exhaustion(),
             combat::start_combat(),
             combat::end_combat(),
             combat::next_turn(),
+            combat::combat_summary(),
             combat::death_save(),
+            combat::stabilize(),
             combat::concentration_check(),
             // Inventory
             inventory::give_item(),
@@ -63,8 +80,11 @@ impl DmTools {
             inventory::use_item(),
             inventory::equip_item(),
             inventory::unequip_item(),
+            inventory::attune_item(),
+            inventory::unattune_item(),
             inventory::adjust_gold(),
             inventory::adjust_silver(),
+            inventory::generate_loot(),
             inventory::show_inventory(),
             // Class features
             class_features::use_rage(),
@@ -82,23 +102,32 @@ impl DmTools {
             // World
             world::short_rest(),
             world::long_rest(),
+            world::spend_hit_dice(),
             world::change_location(),
             world::remember_fact(),
             world::register_consequence(),
             world::cast_spell(),
+            world::cast_spell_aoe(),
             world::award_experience(),
+            world::grant_inspiration(),
+            world::spend_inspiration(),
             world::modify_ability_score(),
             world::advance_time(),
+            world::set_weather(),
+            world::downtime(),
             world::restore_spell_slot(),
+            world::prepare_spells(),
             // Locations
             locations::create_location(),
             locations::connect_locations(),
+            locations::roll_encounter(),
             locations::update_location(),
             // NPCs
             npc::create_npc(),
             npc::update_npc(),
             npc::move_npc(),
             npc::remove_npc(),
+            npc::adjust_reputation(),
             // Quests
             quests::create_quest(),
             quests::add_quest_objective(),
@@ -116,8 +145,27 @@ impl DmTools {
             schedule::schedule_event(),
             schedule::check_schedule(),
             schedule::cancel_event(),
+            // Rules reference
+            info::rules_lookup(),
+            // Conversation memory
+            info::recall_npc_dialogue(),
         ]
     }
+
+    /// Look up a tool definition by name.
+    pub fn get(name: &str) -> Option<Tool> {
+        Self::all().into_iter().find(|tool| tool.name == name)
+    }
+
+    /// Check a tool call's input against the tool's schema.
+    ///
+    /// Unknown tools pass, so the caller can report them separately.
+    pub fn validate(name: &str, input: &serde_json::Value) -> Result<(), String> {
+        match Self::get(name) {
+            Some(tool) => validate_input(&tool.input_schema, input),
+            None => Ok(()),
+        }
+    }
 }
 
 #[cfg(test)]
@@ -144,6 +192,13 @@ mod tests {
         }
     }
 
+    #[test]
+    fn test_get_tool_by_name() {
+        let tool = DmTools::get("roll_dice").expect("roll_dice should be registered");
+        assert_eq!(tool.name, "roll_dice");
+        assert!(DmTools::get("summon_dragon").is_none());
+    }
+
     #[test]
     fn test_tool_count() {
         let tools = DmTools::all();
@@ -110,6 +110,32 @@ pub fn move_npc() -> Tool {
     }
 }
 
+/// Adjust the party's standing with a faction.
+pub fn adjust_reputation() -> Tool {
+    Tool {
+        name: "adjust_reputation".to_string(),
+        description: "Raise or lower the party's reputation with a faction, guild, city, or other organization. Reputation runs from -100 to 100 and maps to a tier: Hostile, Unfriendly, Neutral, Friendly, Honored, or Allied. Use update_npc for individual NPCs instead.".to_string(),
+        input_schema: json!({
+            "type": "object",
+            "properties": {
+                "faction": {
+                    "type": "string",
+                    "description": "Name of the faction (e.g., 'The Harpers', 'Thieves Guild', 'City Watch of Waterdeep')"
+                },
+                "delta": {
+                    "type": "integer",
+                    "description": "How much to change reputation by: about 5 for a small favor or slight, 15-25 for a major deed or betrayal"
+                },
+                "reason": {
+                    "type": "string",
+                    "description": "Why standing changed (e.g., 'Recovered the stolen ledger', 'Killed a guild member')"
+                }
+            },
+            "required": ["faction", "delta", "reason"]
+        }),
+    }
+}
+
 /// Remove an NPC from the game.
 pub fn remove_npc() -> Tool {
     Tool {
@@ -1,12 +1,27 @@
 //! Parsing for combat-related tools.
 
-use super::super::converters::{parse_advantage, parse_condition, parse_damage_type};
-use crate::rules::{CombatantInit, Intent};
-use crate::world::{CharacterId, GameWorld};
+use super::super::converters::{
+    parse_advantage, parse_attack_mode, parse_condition, parse_cover, parse_damage_type,
+    parse_position,
+};
+use crate::rules::{CombatantInit, DamageType, Intent, ShoveMode};
+use crate::world::{
+    find_by_name, Ability, CharacterId, GameWorld, LairAction, NameLookupError, OngoingEffect,
+    OngoingKind, ResistanceLevel,
+};
 use serde_json::Value;
+use std::collections::HashMap;
 
 /// Parse combat-related tool calls.
-pub fn parse_combat_tool(name: &str, input: &Value, world: &GameWorld) -> Option<Intent> {
+///
+/// `selected` is the combatant the player picked as their target; a target
+/// named after it resolves to it even when another combatant shares the name.
+pub fn parse_combat_tool(
+    name: &str,
+    input: &Value,
+    world: &GameWorld,
+    selected: Option<CharacterId>,
+) -> Option<Intent> {
     match name {
         "apply_damage" => {
             let amount = input["amount"].as_i64()? as i32;
@@ -16,11 +31,19 @@ pub fn parse_combat_tool(name: &str, input: &Value, world: &GameWorld) -> Option
             }
             let damage_type = parse_damage_type(input["damage_type"].as_str()?)?;
             let source = input["source"].as_str().unwrap_or("unknown").to_string();
+            let target_id = target_id_from_input(input, world, selected)?;
+            // A melee hit from within 5 feet against an unconscious creature is always a crit
+            let target_down = world
+                .character(target_id)
+                .is_some_and(|c| c.hit_points.current <= 0);
+            let is_critical = input["critical"].as_bool().unwrap_or(false)
+                || (target_down && input["melee_within_5ft"].as_bool().unwrap_or(false));
             Some(Intent::Damage {
-                target_id: world.player_character.id,
+                target_id,
                 amount,
                 damage_type,
                 source,
+                is_critical,
             })
         }
         "apply_healing" => {
@@ -31,6 +54,18 @@ pub fn parse_combat_tool(name: &str, input: &Value, world: &GameWorld) -> Option
             }
             let source = input["source"].as_str().unwrap_or("healing").to_string();
             Some(Intent::Heal {
+                target_id: target_id_from_input(input, world, selected)?,
+                amount,
+                source,
+            })
+        }
+        "grant_temp_hp" => {
+            let amount = input["amount"].as_i64()? as i32;
+            if amount <= 0 {
+                return None;
+            }
+            let source = input["source"].as_str().unwrap_or("unknown").to_string();
+            Some(Intent::GrantTempHp {
                 target_id: world.player_character.id,
                 amount,
                 source,
@@ -41,7 +76,7 @@ pub fn parse_combat_tool(name: &str, input: &Value, world: &GameWorld) -> Option
             let source = input["source"].as_str().unwrap_or("unknown").to_string();
             let duration_rounds = input["duration_rounds"].as_i64().map(|d| d as u32);
             Some(Intent::ApplyCondition {
-                target_id: world.player_character.id,
+                target_id: target_id_from_input(input, world, selected)?,
                 condition,
                 source,
                 duration_rounds,
@@ -50,10 +85,44 @@ pub fn parse_combat_tool(name: &str, input: &Value, world: &GameWorld) -> Option
         "remove_condition" => {
             let condition = parse_condition(input["condition"].as_str()?)?;
             Some(Intent::RemoveCondition {
-                target_id: world.player_character.id,
+                target_id: target_id_from_input(input, world, selected)?,
                 condition,
             })
         }
+        "apply_ongoing_effect" => {
+            let kind = if let Some(dice) = input["damage"].as_str() {
+                OngoingKind::Damage {
+                    dice: dice.to_string(),
+                    damage_type: parse_damage_type(input["damage_type"].as_str()?)?,
+                }
+            } else if let Some(dice) = input["healing"].as_str() {
+                OngoingKind::Healing {
+                    dice: dice.to_string(),
+                }
+            } else {
+                OngoingKind::Modifier {
+                    description: input["modifier"].as_str()?.to_string(),
+                }
+            };
+            Some(Intent::ApplyOngoingEffect {
+                target_id: target_id_from_input(input, world, selected)?,
+                effect: OngoingEffect {
+                    name: input["name"].as_str()?.to_string(),
+                    kind,
+                    remaining_rounds: input["rounds"].as_u64()? as u32,
+                },
+            })
+        }
+        "modify_exhaustion" => {
+            let delta = input["delta"].as_i64()?.clamp(-6, 6) as i8;
+            if delta == 0 {
+                return None;
+            }
+            Some(Intent::ModifyExhaustion {
+                target_id: world.player_character.id,
+                delta,
+            })
+        }
         "start_combat" => {
             let enemies = input["enemies"].as_array()?;
             let player_hp = &world.player_character.hit_points;
@@ -66,16 +135,32 @@ pub fn parse_combat_tool(name: &str, input: &Value, world: &GameWorld) -> Option
                 max_hp: player_hp.maximum,
                 armor_class: world.player_character.current_ac(),
                 initiative_modifier: world.player_character.initiative_modifier(),
+                dex_modifier: world
+                    .player_character
+                    .ability_scores
+                    .modifier(Ability::Dexterity),
+                resistances: HashMap::new(),
+                legendary_actions: 0,
+                position: parse_position(&input["player_position"]),
             }];
 
+            let mut surprised_ids = Vec::new();
+            if input["player_surprised"].as_bool().unwrap_or(false) {
+                surprised_ids.push(world.player_character.id);
+            }
+
             for enemy in enemies {
+                let id = CharacterId::new();
+                if enemy["surprised"].as_bool().unwrap_or(false) {
+                    surprised_ids.push(id);
+                }
                 let name = enemy["name"].as_str().unwrap_or("Enemy").to_string();
                 let max_hp = enemy["max_hp"].as_i64().unwrap_or(10) as i32;
                 let current_hp = enemy["current_hp"].as_i64().unwrap_or(max_hp as i64) as i32;
                 let armor_class = enemy["armor_class"].as_u64().unwrap_or(10) as u8;
                 let initiative_modifier = enemy["initiative_modifier"].as_i64().unwrap_or(0) as i8;
                 combatants.push(CombatantInit {
-                    id: CharacterId::new(),
+                    id,
                     name,
                     is_player: false,
                     is_ally: false,
@@ -83,13 +168,26 @@ pub fn parse_combat_tool(name: &str, input: &Value, world: &GameWorld) -> Option
                     max_hp,
                     armor_class,
                     initiative_modifier,
+                    dex_modifier: initiative_modifier,
+                    resistances: resistances_from_input(enemy),
+                    legendary_actions: enemy["legendary_actions"].as_u64().unwrap_or(0) as u8,
+                    position: parse_position(&enemy["position"]),
                 });
             }
 
-            Some(Intent::StartCombat { combatants })
+            Some(Intent::StartCombat {
+                combatants,
+                lair_actions: lair_actions_from_input(input),
+                surprised_ids,
+            })
         }
         "end_combat" => Some(Intent::EndCombat),
         "next_turn" => Some(Intent::NextTurn),
+        "stabilize" => Some(Intent::Stabilize {
+            medic_id: named_or_player(input, "medic", world)?,
+            target_id: target_id_from_input(input, world, selected)?,
+            npc_modifier: input["npc_modifier"].as_i64().unwrap_or(0) as i8,
+        }),
         "death_save" => Some(Intent::DeathSave {
             character_id: world.player_character.id,
         }),
@@ -106,28 +204,230 @@ pub fn parse_combat_tool(name: &str, input: &Value, world: &GameWorld) -> Option
             let weapon_name = input["weapon"].as_str()?.to_string();
             let target_name = input["target"].as_str()?;
             let advantage = parse_advantage(input["advantage"].as_str());
-
-            // Find the target in combat state by name
-            let target_id = if let Some(ref combat) = world.combat {
-                combat
-                    .combatants
-                    .iter()
-                    .find(|c| c.name.eq_ignore_ascii_case(target_name))
-                    .map(|c| c.id)
-            } else {
-                None
-            };
+            let is_offhand = input["offhand"].as_bool().unwrap_or(false);
+            let cover = parse_cover(input["cover"].as_str());
+            let adjacent_enemy = input["adjacent_enemy"].as_bool();
+            let attack_mode = parse_attack_mode(input["attack_mode"].as_str());
 
             // If no combat or target not found, we can't attack
-            let target_id = target_id?;
+            let target_id = combatant_target(world, target_name, selected)?;
 
             Some(Intent::Attack {
-                attacker_id: world.player_character.id,
+                attacker_id: named_or_player(input, "attacker", world)?,
                 target_id,
                 weapon_name,
                 advantage,
+                is_offhand,
+                cover,
+                adjacent_enemy,
+                attack_mode,
+            })
+        }
+        "multiattack" => {
+            let weapon_name = input["weapon"].as_str()?.to_string();
+            let target_ids = input["targets"]
+                .as_array()?
+                .iter()
+                .map(|name| combatant_target(world, name.as_str()?, selected))
+                .collect::<Option<Vec<_>>>()?;
+            Some(Intent::MultiAttack {
+                attacker_id: named_or_player(input, "attacker", world)?,
+                target_ids,
+                weapon_name,
+            })
+        }
+        "grapple" => Some(Intent::Grapple {
+            grappler_id: named_or_player(input, "grappler", world)?,
+            target_id: target_id_from_input(input, world, selected)?,
+            npc_modifier: input["npc_modifier"].as_i64().unwrap_or(0) as i8,
+        }),
+        "shove" => {
+            let mode = match input["mode"].as_str()? {
+                "prone" => ShoveMode::Prone,
+                "push" => ShoveMode::Push,
+                _ => return None,
+            };
+            Some(Intent::Shove {
+                shover_id: named_or_player(input, "shover", world)?,
+                target_id: target_id_from_input(input, world, selected)?,
+                mode,
+                npc_modifier: input["npc_modifier"].as_i64().unwrap_or(0) as i8,
+            })
+        }
+        "opportunity_attack" => {
+            let weapon_name = input["weapon"].as_str()?.to_string();
+            let target_id = combatant_target(world, input["target"].as_str()?, selected)?;
+            Some(Intent::OpportunityAttack {
+                attacker_id: named_or_player(input, "attacker", world)?,
+                target_id,
+                weapon_name,
+            })
+        }
+        "counterspell" => Some(Intent::Counterspell {
+            caster_id: world.player_character.id,
+            target_spell_level: input["target_spell_level"].as_u64()? as u8,
+            slot_level: input["slot_level"].as_u64().unwrap_or(3) as u8,
+        }),
+        "trigger_lair_action" => {
+            let target_ids = match input["targets"].as_array() {
+                Some(names) => names
+                    .iter()
+                    .map(|n| combatant_id_by_name(world, n.as_str()?).ok())
+                    .collect::<Option<Vec<_>>>()?,
+                None => vec![world.player_character.id],
+            };
+            Some(Intent::TriggerLairAction {
+                index: input["index"].as_u64().unwrap_or(0) as usize,
+                target_ids,
+            })
+        }
+        "use_legendary_action" => Some(Intent::UseLegendaryAction {
+            combatant_id: combatant_id_by_name(world, input["combatant"].as_str()?).ok()?,
+            action_name: input["action"].as_str()?.to_string(),
+            cost: input["cost"].as_u64().unwrap_or(1) as u8,
+        }),
+        "dodge" => Some(Intent::Dodge {
+            character_id: named_or_player(input, "combatant", world)?,
+        }),
+        "help" => {
+            let helper_id = combatant_id_by_name(world, input["helper"].as_str()?).ok()?;
+            let task = input["task"].as_str()?.to_string();
+            Some(Intent::Help {
+                helper_id,
+                target_id: target_id_from_input(input, world, selected)?,
+                task,
             })
         }
         _ => None,
     }
 }
+
+/// Resolve the optional `target` name to a character ID, defaulting to the player.
+fn target_id_from_input(
+    input: &Value,
+    world: &GameWorld,
+    selected: Option<CharacterId>,
+) -> Option<CharacterId> {
+    match input["target"].as_str() {
+        Some(name) => selected_by_name(world, name, selected)
+            .or_else(|| character_id_by_name(world, name).ok()),
+        None => Some(world.player_character.id),
+    }
+}
+
+/// Resolve a target that must be in the fight, preferring the selected one.
+fn combatant_target(
+    world: &GameWorld,
+    name: &str,
+    selected: Option<CharacterId>,
+) -> Option<CharacterId> {
+    selected_by_name(world, name, selected).or_else(|| combatant_id_by_name(world, name).ok())
+}
+
+/// The selected combatant, if `name` is its name.
+fn selected_by_name(
+    world: &GameWorld,
+    name: &str,
+    selected: Option<CharacterId>,
+) -> Option<CharacterId> {
+    let combatant = world.combat.as_ref()?.combatant(selected?)?;
+    combatant
+        .name
+        .eq_ignore_ascii_case(name.trim())
+        .then_some(combatant.id)
+}
+
+/// Resolve an optional name under `key`, defaulting to the player only when
+/// the key is absent. A name that doesn't resolve gives `None`.
+fn named_or_player(input: &Value, key: &str, world: &GameWorld) -> Option<CharacterId> {
+    match input[key].as_str() {
+        Some(name) => character_id_by_name(world, name).ok(),
+        None => Some(world.player_character.id),
+    }
+}
+
+/// Find a combatant in the current combat by a possibly partial name.
+fn combatant_id_by_name(world: &GameWorld, name: &str) -> Result<CharacterId, NameLookupError> {
+    world.find_combatant_fuzzy(name)
+}
+
+/// Find a combatant by name, or a party member when there's no fight.
+fn character_id_by_name(world: &GameWorld, name: &str) -> Result<CharacterId, NameLookupError> {
+    if world.combat.is_some() {
+        world.find_combatant_fuzzy(name)
+    } else {
+        find_by_name(name, world.party_members().map(|c| (c.name.as_str(), c.id)))
+    }
+}
+
+/// Why a combat tool's creature names don't resolve, if that's what kept it
+/// from parsing.
+pub fn combat_name_error(name: &str, input: &Value, world: &GameWorld) -> Option<NameLookupError> {
+    // Attackers, legendary creatures and helpers must be in the fight
+    let combatant_only = |key: &str| {
+        matches!(
+            (name, key),
+            (
+                "attack" | "multiattack" | "opportunity_attack" | "trigger_lair_action",
+                "target" | "targets"
+            ) | ("use_legendary_action", "combatant")
+                | ("help", "helper")
+        )
+    };
+    let lookup = |key: &str, query: &str| {
+        if combatant_only(key) {
+            combatant_id_by_name(world, query)
+        } else {
+            character_id_by_name(world, query)
+        }
+    };
+    for key in [
+        "target",
+        "attacker",
+        "medic",
+        "grappler",
+        "shover",
+        "combatant",
+        "helper",
+    ] {
+        if let Some(Err(err)) = input[key].as_str().map(|query| lookup(key, query)) {
+            return Some(err);
+        }
+    }
+    let targets = input["targets"].as_array().into_iter().flatten();
+    targets
+        .filter_map(Value::as_str)
+        .find_map(|query| lookup("targets", query).err())
+}
+
+/// Read the optional `lair_actions` list of a start_combat call.
+fn lair_actions_from_input(input: &Value) -> Vec<LairAction> {
+    let actions = input["lair_actions"].as_array().into_iter().flatten();
+    actions
+        .filter_map(|action| {
+            Some(LairAction {
+                name: action["name"].as_str()?.to_string(),
+                description: action["description"].as_str().unwrap_or("").to_string(),
+                damage: action["damage"].as_str().map(str::to_string),
+                damage_type: action["damage_type"].as_str().and_then(parse_damage_type),
+                condition: action["condition"].as_str().and_then(parse_condition),
+            })
+        })
+        .collect()
+}
+
+/// Collect the damage resistances, vulnerabilities, and immunities listed for an enemy.
+fn resistances_from_input(enemy: &Value) -> HashMap<DamageType, ResistanceLevel> {
+    let mut resistances = HashMap::new();
+    for (key, level) in [
+        ("resistances", ResistanceLevel::Resistant),
+        ("vulnerabilities", ResistanceLevel::Vulnerable),
+        ("immunities", ResistanceLevel::Immune),
+    ] {
+        let types = enemy[key].as_array().into_iter().flatten();
+        for damage_type in types.filter_map(|t| t.as_str().and_then(parse_damage_type)) {
+            resistances.insert(damage_type, level);
+        }
+    }
+    resistances
+}
@@ -1,8 +1,9 @@
 //! Gameplay tool parsing - converts gameplay polish tool calls into game Intents.
 
-use super::super::converters::parse_ability;
+use super::super::converters::{parse_ability, parse_skill};
+use crate::downtime::DowntimeActivity;
 use crate::rules::Intent;
-use crate::world::GameWorld;
+use crate::world::{GameWorld, Weather};
 use serde_json::Value;
 
 /// Parse gameplay-related tool calls into Intents.
@@ -31,6 +32,30 @@ pub fn parse_gameplay_tool(name: &str, input: &Value, _world: &GameWorld) -> Opt
             Some(Intent::AdvanceTime { minutes })
         }
 
+        "set_weather" => {
+            let weather = Weather::from_name(input.get("weather")?.as_str()?)?;
+            Some(Intent::SetWeather { weather })
+        }
+
+        "downtime" => {
+            let days = input.get("days")?.as_u64()? as u32;
+            let activity = match input.get("activity")?.as_str()? {
+                "crafting" => DowntimeActivity::Crafting {
+                    item_name: input.get("item_name")?.as_str()?.to_string(),
+                    value_gp: input
+                        .get("value_gp")
+                        .and_then(|v| v.as_u64())
+                        .map(|v| v as u32),
+                },
+                "training" => DowntimeActivity::Training {
+                    skill: parse_skill(input.get("skill")?.as_str()?)?,
+                },
+                "carousing" => DowntimeActivity::Carousing,
+                _ => return None,
+            };
+            Some(Intent::Downtime { activity, days })
+        }
+
         "restore_spell_slot" => {
             let slot_level = input.get("slot_level")?.as_u64()? as u8;
             let source = input.get("source")?.as_str()?.to_string();
@@ -43,6 +68,17 @@ pub fn parse_gameplay_tool(name: &str, input: &Value, _world: &GameWorld) -> Opt
             Some(Intent::RestoreSpellSlot { slot_level, source })
         }
 
+        "prepare_spells" => {
+            let spells = input
+                .get("spells")?
+                .as_array()?
+                .iter()
+                .filter_map(|v| v.as_str())
+                .map(String::from)
+                .collect();
+            Some(Intent::PrepareSpells { spells })
+        }
+
         _ => None,
     }
 }
@@ -50,7 +86,7 @@ pub fn parse_gameplay_tool(name: &str, input: &Value, _world: &GameWorld) -> Opt
 #[cfg(test)]
 mod tests {
     use super::*;
-    use crate::world::{Ability, Character, CharacterClass, ClassLevel, GameWorld};
+    use crate::world::{Ability, Character, CharacterClass, ClassLevel, GameWorld, Skill};
     use serde_json::json;
 
     fn create_test_world() -> GameWorld {
@@ -109,6 +145,49 @@ mod tests {
         }
     }
 
+    #[test]
+    fn test_set_weather() {
+        let world = create_test_world();
+        let intent = parse_gameplay_tool("set_weather", &json!({"weather": "heavy_rain"}), &world);
+        assert!(matches!(
+            intent,
+            Some(Intent::SetWeather {
+                weather: Weather::HeavyRain
+            })
+        ));
+
+        let intent = parse_gameplay_tool("set_weather", &json!({"weather": "sleet"}), &world);
+        assert!(intent.is_none());
+    }
+
+    #[test]
+    fn test_downtime() {
+        let world = create_test_world();
+        let input = json!({"activity": "crafting", "days": 20, "item_name": "Longsword"});
+        assert!(matches!(
+            parse_gameplay_tool("downtime", &input, &world),
+            Some(Intent::Downtime {
+                activity: DowntimeActivity::Crafting { value_gp: None, .. },
+                days: 20
+            })
+        ));
+
+        let input = json!({"activity": "training", "days": 70, "skill": "stealth"});
+        assert!(matches!(
+            parse_gameplay_tool("downtime", &input, &world),
+            Some(Intent::Downtime {
+                activity: DowntimeActivity::Training {
+                    skill: Skill::Stealth
+                },
+                ..
+            })
+        ));
+
+        // Training needs a skill
+        let input = json!({"activity": "training", "days": 70});
+        assert!(parse_gameplay_tool("downtime", &input, &world).is_none());
+    }
+
     #[test]
     fn test_restore_spell_slot() {
         let world = create_test_world();
@@ -128,6 +207,20 @@ mod tests {
         }
     }
 
+    #[test]
+    fn test_prepare_spells() {
+        let world = create_test_world();
+        let input = json!({"spells": ["Bless", "Cure Wounds"]});
+
+        let intent = parse_gameplay_tool("prepare_spells", &input, &world);
+        assert!(matches!(
+            intent,
+            Some(Intent::PrepareSpells { ref spells }) if spells.len() == 2
+        ));
+
+        assert!(parse_gameplay_tool("prepare_spells", &json!({}), &world).is_none());
+    }
+
     #[test]
     fn test_restore_spell_slot_invalid_level() {
         let world = create_test_world();
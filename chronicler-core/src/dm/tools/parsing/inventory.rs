@@ -51,6 +51,14 @@ pub fn parse_inventory_tool(name: &str, input: &Value) -> Option<Intent> {
             let slot = input["slot"].as_str()?.to_string();
             Some(Intent::UnequipItem { slot })
         }
+        "attune_item" => {
+            let item_name = input["item_name"].as_str()?.to_string();
+            Some(Intent::Attune { item_name })
+        }
+        "unattune_item" => {
+            let item_name = input["item_name"].as_str()?.to_string();
+            Some(Intent::Unattune { item_name })
+        }
         "adjust_gold" => {
             let amount = input["amount"].as_i64()? as i32;
             let reason = input["reason"]
@@ -67,6 +75,15 @@ pub fn parse_inventory_tool(name: &str, input: &Value) -> Option<Intent> {
                 .to_string();
             Some(Intent::AdjustSilver { amount, reason })
         }
+        "generate_loot" => {
+            // Fractional challenge ratings (1/8, 1/4, 1/2) share the CR 0 tier
+            let challenge_rating = input["challenge_rating"].as_f64()?.clamp(0.0, 30.0) as u8;
+            let hoard = input["hoard"].as_bool().unwrap_or(false);
+            Some(Intent::GenerateLoot {
+                challenge_rating,
+                hoard,
+            })
+        }
         // show_inventory is handled specially via execute_info_tool
         _ => None,
     }
@@ -239,6 +256,22 @@ mod tests {
         }
     }
 
+    #[test]
+    fn test_parse_attune_and_unattune_item() {
+        let input = json!({
+            "item_name": "Ring of Protection"
+        });
+
+        assert!(matches!(
+            parse_inventory_tool("attune_item", &input),
+            Some(Intent::Attune { item_name }) if item_name == "Ring of Protection"
+        ));
+        assert!(matches!(
+            parse_inventory_tool("unattune_item", &input),
+            Some(Intent::Unattune { item_name }) if item_name == "Ring of Protection"
+        ));
+    }
+
     #[test]
     fn test_parse_adjust_gold() {
         let input = json!({
@@ -290,6 +323,26 @@ mod tests {
         }
     }
 
+    #[test]
+    fn test_parse_generate_loot() {
+        let input = json!({
+            "challenge_rating": 0.25
+        });
+
+        let intent = parse_inventory_tool("generate_loot", &input);
+
+        if let Some(Intent::GenerateLoot {
+            challenge_rating,
+            hoard,
+        }) = intent
+        {
+            assert_eq!(challenge_rating, 0);
+            assert!(!hoard);
+        } else {
+            panic!("Expected GenerateLoot intent");
+        }
+    }
+
     #[test]
     fn test_parse_unknown_inventory_tool() {
         let input = json!({});
@@ -1,6 +1,7 @@
 //! Location tool parsing - converts location tool calls into game Intents.
 
 use crate::rules::Intent;
+use crate::world::LocationType;
 use serde_json::Value;
 
 /// Parse location-related tool calls into Intents.
@@ -68,6 +69,22 @@ pub fn parse_locations_tool(name: &str, input: &Value) -> Option<Intent> {
             })
         }
 
+        "roll_encounter" => {
+            let location_type = input
+                .get("location_type")
+                .and_then(|v| v.as_str())
+                .map(LocationType::from_name);
+            let party_level = input
+                .get("party_level")
+                .and_then(|v| v.as_u64())
+                .map(|l| l.clamp(1, 20) as u8);
+
+            Some(Intent::RollEncounter {
+                location_type,
+                party_level,
+            })
+        }
+
         "update_location" => {
             let location_name = input.get("location_name")?.as_str()?.to_string();
             let new_description = input
@@ -130,6 +147,26 @@ mod tests {
     use super::*;
     use serde_json::json;
 
+    #[test]
+    fn test_roll_encounter() {
+        let input = json!({ "location_type": "Wilderness", "party_level": 4 });
+        assert!(matches!(
+            parse_locations_tool("roll_encounter", &input),
+            Some(Intent::RollEncounter {
+                location_type: Some(LocationType::Wilderness),
+                party_level: Some(4),
+            })
+        ));
+
+        assert!(matches!(
+            parse_locations_tool("roll_encounter", &json!({})),
+            Some(Intent::RollEncounter {
+                location_type: None,
+                party_level: None,
+            })
+        ));
+    }
+
     #[test]
     fn test_create_location() {
         let input = json!({
@@ -29,7 +29,7 @@ mod world;
 
 pub use checks::parse_checks_tool;
 pub use class_features::parse_class_features_tool;
-pub use combat::parse_combat_tool;
+pub use combat::{combat_name_error, parse_combat_tool};
 pub use gameplay::parse_gameplay_tool;
 pub use inventory::parse_inventory_tool;
 pub use knowledge::parse_knowledge_tool;
@@ -41,7 +41,7 @@ pub use state::parse_state_tool;
 pub use world::parse_world_tool;
 
 use crate::rules::Intent;
-use crate::world::GameWorld;
+use crate::world::{CharacterId, GameWorld, NameLookupError};
 use serde_json::Value;
 use std::sync::LazyLock;
 
@@ -76,14 +76,27 @@ static TOOL_DOMAINS: LazyLock<std::collections::HashMap<&'static str, ToolDomain
         // Combat domain
         m.insert("apply_damage", ToolDomain::Combat);
         m.insert("apply_healing", ToolDomain::Combat);
+        m.insert("grant_temp_hp", ToolDomain::Combat);
         m.insert("apply_condition", ToolDomain::Combat);
         m.insert("remove_condition", ToolDomain::Combat);
+        m.insert("apply_ongoing_effect", ToolDomain::Combat);
+        m.insert("modify_exhaustion", ToolDomain::Combat);
         m.insert("start_combat", ToolDomain::Combat);
         m.insert("end_combat", ToolDomain::Combat);
         m.insert("next_turn", ToolDomain::Combat);
         m.insert("death_save", ToolDomain::Combat);
+        m.insert("stabilize", ToolDomain::Combat);
         m.insert("concentration_check", ToolDomain::Combat);
         m.insert("attack", ToolDomain::Combat);
+        m.insert("multiattack", ToolDomain::Combat);
+        m.insert("grapple", ToolDomain::Combat);
+        m.insert("shove", ToolDomain::Combat);
+        m.insert("opportunity_attack", ToolDomain::Combat);
+        m.insert("counterspell", ToolDomain::Combat);
+        m.insert("use_legendary_action", ToolDomain::Combat);
+        m.insert("trigger_lair_action", ToolDomain::Combat);
+        m.insert("dodge", ToolDomain::Combat);
+        m.insert("help", ToolDomain::Combat);
 
         // Inventory domain
         m.insert("give_item", ToolDomain::Inventory);
@@ -91,8 +104,11 @@ static TOOL_DOMAINS: LazyLock<std::collections::HashMap<&'static str, ToolDomain
         m.insert("use_item", ToolDomain::Inventory);
         m.insert("equip_item", ToolDomain::Inventory);
         m.insert("unequip_item", ToolDomain::Inventory);
+        m.insert("attune_item", ToolDomain::Inventory);
+        m.insert("unattune_item", ToolDomain::Inventory);
         m.insert("adjust_gold", ToolDomain::Inventory);
         m.insert("adjust_silver", ToolDomain::Inventory);
+        m.insert("generate_loot", ToolDomain::Inventory);
 
         // Class features domain
         m.insert("use_rage", ToolDomain::ClassFeatures);
@@ -111,11 +127,15 @@ static TOOL_DOMAINS: LazyLock<std::collections::HashMap<&'static str, ToolDomain
         // World domain
         m.insert("short_rest", ToolDomain::World);
         m.insert("long_rest", ToolDomain::World);
+        m.insert("spend_hit_dice", ToolDomain::World);
         m.insert("change_location", ToolDomain::World);
         m.insert("remember_fact", ToolDomain::World);
         m.insert("register_consequence", ToolDomain::World);
         m.insert("cast_spell", ToolDomain::World);
+        m.insert("cast_spell_aoe", ToolDomain::World);
         m.insert("award_experience", ToolDomain::World);
+        m.insert("grant_inspiration", ToolDomain::World);
+        m.insert("spend_inspiration", ToolDomain::World);
 
         // Quests domain
         m.insert("create_quest", ToolDomain::Quests);
@@ -130,16 +150,21 @@ static TOOL_DOMAINS: LazyLock<std::collections::HashMap<&'static str, ToolDomain
         m.insert("update_npc", ToolDomain::Npc);
         m.insert("move_npc", ToolDomain::Npc);
         m.insert("remove_npc", ToolDomain::Npc);
+        m.insert("adjust_reputation", ToolDomain::Npc);
 
         // Locations domain
         m.insert("create_location", ToolDomain::Locations);
         m.insert("connect_locations", ToolDomain::Locations);
+        m.insert("roll_encounter", ToolDomain::Locations);
         m.insert("update_location", ToolDomain::Locations);
 
         // Gameplay domain
         m.insert("modify_ability_score", ToolDomain::Gameplay);
         m.insert("advance_time", ToolDomain::Gameplay);
+        m.insert("set_weather", ToolDomain::Gameplay);
+        m.insert("downtime", ToolDomain::Gameplay);
         m.insert("restore_spell_slot", ToolDomain::Gameplay);
+        m.insert("prepare_spells", ToolDomain::Gameplay);
 
         // State domain
         m.insert("assert_state", ToolDomain::State);
@@ -158,13 +183,24 @@ static TOOL_DOMAINS: LazyLock<std::collections::HashMap<&'static str, ToolDomain
 ///
 /// Uses O(1) HashMap lookup to find the appropriate domain parser.
 pub fn parse_tool_call(name: &str, input: &Value, world: &GameWorld) -> Option<Intent> {
+    parse_tool_call_with_target(name, input, world, None)
+}
+
+/// Parse a tool call made while the player has a combatant selected as their
+/// target, so a name it shares with another combatant resolves to it.
+pub fn parse_tool_call_with_target(
+    name: &str,
+    input: &Value,
+    world: &GameWorld,
+    selected_target: Option<CharacterId>,
+) -> Option<Intent> {
     // O(1) lookup for tool domain
     let domain = TOOL_DOMAINS.get(name)?;
 
     // Dispatch to the appropriate domain parser
     match domain {
         ToolDomain::Checks => parse_checks_tool(name, input, world),
-        ToolDomain::Combat => parse_combat_tool(name, input, world),
+        ToolDomain::Combat => parse_combat_tool(name, input, world, selected_target),
         ToolDomain::Inventory => parse_inventory_tool(name, input),
         ToolDomain::ClassFeatures => parse_class_features_tool(name, input, world),
         ToolDomain::World => parse_world_tool(name, input, world),
@@ -178,11 +214,22 @@ pub fn parse_tool_call(name: &str, input: &Value, world: &GameWorld) -> Option<I
     }
 }
 
+/// Why a tool call names a creature that can't be found, or can't be told
+/// apart from another, if that's why it failed to parse.
+pub fn name_lookup_error(name: &str, input: &Value, world: &GameWorld) -> Option<NameLookupError> {
+    match TOOL_DOMAINS.get(name)? {
+        ToolDomain::Combat => combat_name_error(name, input, world),
+        _ => None,
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
     use crate::rules::{DamageType, Intent};
-    use crate::world::{Character, CharacterClass, ClassLevel, Condition, GameWorld, Skill};
+    use crate::world::{
+        Character, CharacterClass, ClassLevel, Condition, GameWorld, OngoingKind, Skill,
+    };
     use serde_json::json;
 
     fn create_test_world() -> GameWorld {
@@ -214,6 +261,30 @@ mod tests {
         }
     }
 
+    #[test]
+    fn test_parse_tool_call_spend_hit_dice() {
+        let mut world = create_test_world();
+        let companion = Character::new("Lyra Swiftwind");
+        let companion_id = companion.id;
+        world.add_party_member(companion);
+
+        let intent = parse_tool_call("spend_hit_dice", &json!({"count": 2}), &world);
+        assert!(matches!(
+            intent,
+            Some(Intent::SpendHitDice { character_id, count: 2 })
+                if character_id == world.player_character.id
+        ));
+
+        let input = json!({"count": 1, "character": "lyra"});
+        let intent = parse_tool_call("spend_hit_dice", &input, &world);
+        assert!(matches!(
+            intent,
+            Some(Intent::SpendHitDice { character_id, count: 1 }) if character_id == companion_id
+        ));
+
+        assert!(parse_tool_call("spend_hit_dice", &json!({}), &world).is_none());
+    }
+
     #[test]
     fn test_parse_tool_call_skill_check() {
         let world = create_test_world();
@@ -268,6 +339,395 @@ mod tests {
         }
     }
 
+    #[test]
+    fn test_parse_apply_damage_melee_crit_only_against_downed_target() {
+        let mut world = create_test_world();
+        let input = json!({
+            "amount": 4,
+            "damage_type": "piercing",
+            "source": "dagger",
+            "melee_within_5ft": true
+        });
+        let is_critical = |world: &GameWorld| match parse_tool_call("apply_damage", &input, world) {
+            Some(Intent::Damage { is_critical, .. }) => is_critical,
+            other => panic!("Expected Damage intent, got {other:?}"),
+        };
+
+        assert!(!is_critical(&world));
+        world.player_character.hit_points.current = 0;
+        assert!(is_critical(&world));
+    }
+
+    #[test]
+    fn test_parse_tool_call_apply_damage_to_named_combatant() {
+        let mut world = create_test_world();
+        let goblin_id = crate::world::CharacterId::new();
+        let combat = world.start_combat();
+        combat.add_combatant(crate::world::Combatant {
+            id: goblin_id,
+            name: "Goblin".to_string(),
+            initiative: 12,
+            dex_modifier: 0,
+            is_player: false,
+            is_ally: false,
+            current_hp: 7,
+            max_hp: 7,
+            armor_class: 15,
+            conditions: Vec::new(),
+            ongoing_effects: Vec::new(),
+            resistances: std::collections::HashMap::new(),
+            reaction_used: false,
+            dodging: false,
+            helped_by: None,
+            legendary_actions: 0,
+            legendary_max: 0,
+            position: None,
+        });
+
+        let input = json!({
+            "amount": 4,
+            "damage_type": "piercing",
+            "source": "arrow",
+            "target": "goblin"
+        });
+
+        match parse_tool_call("apply_damage", &input, &world) {
+            Some(Intent::Damage { target_id, .. }) => assert_eq!(target_id, goblin_id),
+            other => panic!("Expected Damage intent, got {other:?}"),
+        }
+    }
+
+    #[test]
+    fn test_parse_tool_call_opportunity_attack() {
+        let mut world = create_test_world();
+        let goblin_id = crate::world::CharacterId::new();
+        world.start_combat().add_combatant(crate::world::Combatant {
+            id: goblin_id,
+            name: "Goblin".to_string(),
+            initiative: 12,
+            dex_modifier: 0,
+            is_player: false,
+            is_ally: false,
+            current_hp: 7,
+            max_hp: 7,
+            armor_class: 15,
+            conditions: Vec::new(),
+            ongoing_effects: Vec::new(),
+            resistances: std::collections::HashMap::new(),
+            reaction_used: false,
+            dodging: false,
+            helped_by: None,
+            legendary_actions: 0,
+            legendary_max: 0,
+            position: None,
+        });
+
+        let input = json!({ "weapon": "longsword", "target": "Goblin" });
+        match parse_tool_call("opportunity_attack", &input, &world) {
+            Some(Intent::OpportunityAttack {
+                attacker_id,
+                target_id,
+                weapon_name,
+            }) => {
+                assert_eq!(attacker_id, world.player_character.id);
+                assert_eq!(target_id, goblin_id);
+                assert_eq!(weapon_name, "longsword");
+            }
+            other => panic!("Expected OpportunityAttack intent, got {other:?}"),
+        }
+
+        // Unknown targets can't be attacked
+        let input = json!({ "weapon": "longsword", "target": "Dragon" });
+        assert!(parse_tool_call("opportunity_attack", &input, &world).is_none());
+
+        // A named attacker reacts instead of the player
+        let input = json!({ "attacker": "Goblin", "weapon": "scimitar", "target": "Goblin" });
+        match parse_tool_call("opportunity_attack", &input, &world) {
+            Some(Intent::OpportunityAttack { attacker_id, .. }) => {
+                assert_eq!(attacker_id, goblin_id)
+            }
+            other => panic!("Expected OpportunityAttack intent, got {other:?}"),
+        }
+        let input = json!({ "attacker": "Dragon", "weapon": "claw", "target": "Goblin" });
+        assert!(parse_tool_call("opportunity_attack", &input, &world).is_none());
+        assert!(name_lookup_error("opportunity_attack", &input, &world).is_some());
+    }
+
+    #[test]
+    fn test_parse_tool_call_multiattack_requires_known_targets() {
+        let mut world = create_test_world();
+        let goblin_id = crate::world::CharacterId::new();
+        let combat = world.start_combat();
+        combat.add_combatant(crate::world::Combatant {
+            id: goblin_id,
+            name: "Goblin".to_string(),
+            initiative: 12,
+            dex_modifier: 0,
+            is_player: false,
+            is_ally: false,
+            current_hp: 7,
+            max_hp: 7,
+            armor_class: 15,
+            conditions: Vec::new(),
+            ongoing_effects: Vec::new(),
+            resistances: std::collections::HashMap::new(),
+            reaction_used: false,
+            dodging: false,
+            helped_by: None,
+            legendary_actions: 0,
+            legendary_max: 0,
+            position: None,
+        });
+
+        let input = json!({"weapon": "longsword", "targets": ["Goblin", "goblin"]});
+        match parse_tool_call("multiattack", &input, &world) {
+            Some(Intent::MultiAttack { target_ids, .. }) => {
+                assert_eq!(target_ids, vec![goblin_id, goblin_id])
+            }
+            other => panic!("Expected MultiAttack intent, got {other:?}"),
+        }
+
+        let unknown = json!({"weapon": "longsword", "targets": ["Goblin", "Dragon"]});
+        assert!(parse_tool_call("multiattack", &unknown, &world).is_none());
+    }
+
+    #[test]
+    fn test_parse_tool_call_unresolved_names_never_fall_back_to_player() {
+        let mut world = create_test_world();
+        let combat = world.start_combat();
+        for _ in 0..2 {
+            combat.add_combatant(crate::world::Combatant {
+                id: crate::world::CharacterId::new(),
+                name: "Goblin".to_string(),
+                initiative: 12,
+                dex_modifier: 0,
+                is_player: false,
+                is_ally: false,
+                current_hp: 7,
+                max_hp: 7,
+                armor_class: 15,
+                conditions: Vec::new(),
+                ongoing_effects: Vec::new(),
+                resistances: std::collections::HashMap::new(),
+                reaction_used: false,
+                dodging: false,
+                helped_by: None,
+                legendary_actions: 0,
+                legendary_max: 0,
+                position: None,
+            });
+        }
+
+        // Two goblins share the name, so neither is picked
+        let input = json!({"amount": 4, "damage_type": "piercing", "target": "Goblin"});
+        assert!(parse_tool_call("apply_damage", &input, &world).is_none());
+        assert!(matches!(
+            name_lookup_error("apply_damage", &input, &world),
+            Some(NameLookupError::Ambiguous { .. })
+        ));
+
+        // A misspelled name is an error rather than the player
+        let input = json!({"condition": "prone", "target": "Gobiln"});
+        assert!(parse_tool_call("apply_condition", &input, &world).is_none());
+        assert!(matches!(
+            name_lookup_error("apply_condition", &input, &world),
+            Some(NameLookupError::NotFound { .. })
+        ));
+
+        let input = json!({"targets": ["Dragon"]});
+        assert!(parse_tool_call("trigger_lair_action", &input, &world).is_none());
+        assert!(name_lookup_error("trigger_lair_action", &input, &world).is_some());
+
+        // Leaving the name out still means the player
+        let input = json!({"condition": "prone"});
+        match parse_tool_call("apply_condition", &input, &world) {
+            Some(Intent::ApplyCondition { target_id, .. }) => {
+                assert_eq!(target_id, world.player_character.id)
+            }
+            other => panic!("Expected ApplyCondition intent, got {other:?}"),
+        }
+        assert!(name_lookup_error("apply_condition", &input, &world).is_none());
+    }
+
+    #[test]
+    fn test_parse_tool_call_resolves_shared_name_to_selected_target() {
+        let mut world = create_test_world();
+        let combat = world.start_combat();
+        let mut goblin_ids = Vec::new();
+        for _ in 0..2 {
+            let id = crate::world::CharacterId::new();
+            goblin_ids.push(id);
+            combat.add_combatant(crate::world::Combatant {
+                id,
+                name: "Goblin".to_string(),
+                initiative: 12,
+                dex_modifier: 0,
+                is_player: false,
+                is_ally: false,
+                current_hp: 7,
+                max_hp: 7,
+                armor_class: 15,
+                conditions: Vec::new(),
+                ongoing_effects: Vec::new(),
+                resistances: std::collections::HashMap::new(),
+                reaction_used: false,
+                dodging: false,
+                helped_by: None,
+                legendary_actions: 0,
+                legendary_max: 0,
+                position: None,
+            });
+        }
+
+        let input = json!({"weapon": "Longsword", "target": "goblin"});
+        assert!(parse_tool_call("attack", &input, &world).is_none());
+
+        // The goblin the player clicked on is the one attacked
+        let selected = Some(goblin_ids[1]);
+        match parse_tool_call_with_target("attack", &input, &world, selected) {
+            Some(Intent::Attack { target_id, .. }) => assert_eq!(target_id, goblin_ids[1]),
+            _ => panic!("Expected Attack intent"),
+        }
+
+        // A different name is still looked up normally
+        let input = json!({"weapon": "Longsword", "target": "Orc"});
+        assert!(parse_tool_call_with_target("attack", &input, &world, selected).is_none());
+    }
+
+    #[test]
+    fn test_parse_tool_call_shove() {
+        let world = create_test_world();
+        let input = json!({"mode": "push", "npc_modifier": 3});
+        match parse_tool_call("shove", &input, &world) {
+            Some(Intent::Shove {
+                shover_id,
+                target_id,
+                mode,
+                npc_modifier,
+            }) => {
+                assert_eq!(shover_id, world.player_character.id);
+                assert_eq!(target_id, world.player_character.id);
+                assert_eq!(mode, crate::rules::ShoveMode::Push);
+                assert_eq!(npc_modifier, 3);
+            }
+            other => panic!("Expected Shove intent, got {other:?}"),
+        }
+
+        let bad_mode = json!({"mode": "trip"});
+        assert!(parse_tool_call("shove", &bad_mode, &world).is_none());
+    }
+
+    #[test]
+    fn test_parse_tool_call_dodge_defaults_to_player() {
+        let world = create_test_world();
+        match parse_tool_call("dodge", &json!({}), &world) {
+            Some(Intent::Dodge { character_id }) => {
+                assert_eq!(character_id, world.player_character.id)
+            }
+            other => panic!("Expected Dodge intent, got {other:?}"),
+        }
+    }
+
+    #[test]
+    fn test_parse_tool_call_help() {
+        let mut world = create_test_world();
+        let ally_id = crate::world::CharacterId::new();
+        let combat = world.start_combat();
+        combat.add_combatant(crate::world::Combatant {
+            id: ally_id,
+            name: "Brother Aldric".to_string(),
+            initiative: 10,
+            dex_modifier: 0,
+            is_player: false,
+            is_ally: true,
+            current_hp: 10,
+            max_hp: 10,
+            armor_class: 12,
+            conditions: Vec::new(),
+            ongoing_effects: Vec::new(),
+            resistances: std::collections::HashMap::new(),
+            reaction_used: false,
+            dodging: false,
+            helped_by: None,
+            legendary_actions: 0,
+            legendary_max: 0,
+            position: None,
+        });
+
+        let input = json!({"helper": "Brother Aldric", "task": "flanking the orc"});
+        match parse_tool_call("help", &input, &world) {
+            Some(Intent::Help {
+                helper_id,
+                target_id,
+                task,
+            }) => {
+                assert_eq!(helper_id, ally_id);
+                assert_eq!(target_id, world.player_character.id);
+                assert_eq!(task, "flanking the orc");
+            }
+            other => panic!("Expected Help intent, got {other:?}"),
+        }
+
+        let unknown = json!({"helper": "Nobody", "task": "anything"});
+        assert!(parse_tool_call("help", &unknown, &world).is_none());
+    }
+
+    #[test]
+    fn test_parse_tool_call_start_combat_with_resistances() {
+        let world = create_test_world();
+        let input = json!({
+            "enemies": [{
+                "name": "Fire Elemental",
+                "max_hp": 102,
+                "resistances": ["bludgeoning", "piercing", "slashing"],
+                "immunities": ["fire", "poison"],
+                "vulnerabilities": ["cold"]
+            }]
+        });
+
+        match parse_tool_call("start_combat", &input, &world) {
+            Some(Intent::StartCombat { combatants, .. }) => {
+                let elemental = &combatants[1];
+                assert_eq!(
+                    elemental.resistances.get(&DamageType::Slashing),
+                    Some(&crate::world::ResistanceLevel::Resistant)
+                );
+                assert_eq!(
+                    elemental.resistances.get(&DamageType::Fire),
+                    Some(&crate::world::ResistanceLevel::Immune)
+                );
+                assert_eq!(
+                    elemental.resistances.get(&DamageType::Cold),
+                    Some(&crate::world::ResistanceLevel::Vulnerable)
+                );
+                assert!(combatants[0].resistances.is_empty());
+            }
+            other => panic!("Expected StartCombat intent, got {other:?}"),
+        }
+    }
+
+    #[test]
+    fn test_parse_tool_call_start_combat_with_surprise() {
+        let world = create_test_world();
+        let input = json!({
+            "enemies": [
+                { "name": "Bandit", "surprised": true },
+                { "name": "Bandit Captain" }
+            ]
+        });
+
+        match parse_tool_call("start_combat", &input, &world) {
+            Some(Intent::StartCombat {
+                combatants,
+                surprised_ids,
+                ..
+            }) => {
+                assert_eq!(surprised_ids, vec![combatants[1].id]);
+            }
+            other => panic!("Expected StartCombat intent, got {other:?}"),
+        }
+    }
+
     #[test]
     fn test_parse_tool_call_invalid_damage_amount() {
         let world = create_test_world();
@@ -309,6 +769,67 @@ mod tests {
         }
     }
 
+    #[test]
+    fn test_parse_tool_call_grant_temp_hp() {
+        let world = create_test_world();
+        let input = json!({
+            "amount": 7,
+            "source": "False Life"
+        });
+
+        match parse_tool_call("grant_temp_hp", &input, &world) {
+            Some(Intent::GrantTempHp {
+                target_id,
+                amount,
+                source,
+            }) => {
+                assert_eq!(target_id, world.player_character.id);
+                assert_eq!(amount, 7);
+                assert_eq!(source, "False Life");
+            }
+            other => panic!("Expected GrantTempHp intent, got {other:?}"),
+        }
+
+        let input = json!({ "amount": 0, "source": "False Life" });
+        assert!(parse_tool_call("grant_temp_hp", &input, &world).is_none());
+    }
+
+    #[test]
+    fn test_parse_tool_call_modify_exhaustion() {
+        let world = create_test_world();
+        let input = json!({ "delta": -1 });
+
+        match parse_tool_call("modify_exhaustion", &input, &world) {
+            Some(Intent::ModifyExhaustion { target_id, delta }) => {
+                assert_eq!(target_id, world.player_character.id);
+                assert_eq!(delta, -1);
+            }
+            other => panic!("Expected ModifyExhaustion intent, got {other:?}"),
+        }
+
+        let input = json!({ "delta": 0 });
+        assert!(parse_tool_call("modify_exhaustion", &input, &world).is_none());
+    }
+
+    #[test]
+    fn test_parse_tool_call_counterspell_defaults_to_third_level_slot() {
+        let world = create_test_world();
+        let input = json!({ "target_spell_level": 5 });
+
+        match parse_tool_call("counterspell", &input, &world) {
+            Some(Intent::Counterspell {
+                caster_id,
+                target_spell_level,
+                slot_level,
+            }) => {
+                assert_eq!(caster_id, world.player_character.id);
+                assert_eq!(target_spell_level, 5);
+                assert_eq!(slot_level, 3);
+            }
+            other => panic!("Expected Counterspell intent, got {other:?}"),
+        }
+    }
+
     #[test]
     fn test_parse_tool_call_invalid_healing_amount() {
         let world = create_test_world();
@@ -344,6 +865,37 @@ mod tests {
         }
     }
 
+    #[test]
+    fn test_parse_tool_call_apply_ongoing_effect() {
+        let world = create_test_world();
+        let input = json!({
+            "name": "Burning",
+            "rounds": 3,
+            "damage": "1d4",
+            "damage_type": "fire"
+        });
+
+        match parse_tool_call("apply_ongoing_effect", &input, &world) {
+            Some(Intent::ApplyOngoingEffect { target_id, effect }) => {
+                assert_eq!(target_id, world.player_character.id);
+                assert_eq!(effect.name, "Burning");
+                assert_eq!(effect.remaining_rounds, 3);
+                assert_eq!(
+                    effect.kind,
+                    OngoingKind::Damage {
+                        dice: "1d4".to_string(),
+                        damage_type: DamageType::Fire,
+                    }
+                );
+            }
+            other => panic!("Expected ApplyOngoingEffect intent, got {other:?}"),
+        }
+
+        // Damage without a type is rejected
+        let input = json!({ "name": "Burning", "rounds": 3, "damage": "1d4" });
+        assert!(parse_tool_call("apply_ongoing_effect", &input, &world).is_none());
+    }
+
     #[test]
     fn test_parse_tool_call_unknown_tool() {
         let world = create_test_world();
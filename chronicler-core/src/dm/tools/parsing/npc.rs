@@ -107,6 +107,18 @@ pub fn parse_npc_tool(name: &str, input: &Value) -> Option<Intent> {
             })
         }
 
+        "adjust_reputation" => {
+            let faction = input.get("faction")?.as_str()?.to_string();
+            let delta = input.get("delta")?.as_i64()? as i32;
+            let reason = input.get("reason")?.as_str()?.to_string();
+
+            Some(Intent::AdjustReputation {
+                faction,
+                delta,
+                reason,
+            })
+        }
+
         _ => None,
     }
 }
@@ -227,4 +239,27 @@ mod tests {
             panic!("Expected RemoveNpc intent");
         }
     }
+
+    #[test]
+    fn test_adjust_reputation() {
+        let input = json!({
+            "faction": "Thieves Guild",
+            "delta": -15,
+            "reason": "Turned a member over to the watch"
+        });
+
+        let intent = parse_npc_tool("adjust_reputation", &input);
+        if let Some(Intent::AdjustReputation {
+            faction,
+            delta,
+            reason,
+        }) = intent
+        {
+            assert_eq!(faction, "Thieves Guild");
+            assert_eq!(delta, -15);
+            assert!(reason.contains("watch"));
+        } else {
+            panic!("Expected AdjustReputation intent");
+        }
+    }
 }
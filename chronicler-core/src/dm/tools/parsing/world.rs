@@ -1,7 +1,8 @@
 //! Parsing for world/session-related tools.
 
-use crate::rules::Intent;
-use crate::world::GameWorld;
+use super::super::converters::{parse_cover, parse_position};
+use crate::rules::{AreaShape, Intent};
+use crate::world::{find_by_name, GameWorld};
 use serde_json::Value;
 
 /// Parse world/session-related tool calls.
@@ -9,6 +10,20 @@ pub fn parse_world_tool(name: &str, input: &Value, world: &GameWorld) -> Option<
     match name {
         "short_rest" => Some(Intent::ShortRest),
         "long_rest" => Some(Intent::LongRest),
+        "spend_hit_dice" => {
+            let count = input["count"].as_u64()?.min(u8::MAX as u64) as u8;
+            let character_id = match input["character"].as_str() {
+                Some(name) => {
+                    find_by_name(name, world.party_members().map(|c| (c.name.as_str(), c.id)))
+                        .ok()?
+                }
+                None => world.player_character.id,
+            };
+            Some(Intent::SpendHitDice {
+                character_id,
+                count,
+            })
+        }
         "change_location" => {
             let new_location = input["new_location"].as_str()?.to_string();
             let location_type = input["location_type"].as_str().map(|s| s.to_string());
@@ -41,6 +56,7 @@ pub fn parse_world_tool(name: &str, input: &Value, world: &GameWorld) -> Option<
                 category,
                 related_entities,
                 importance,
+                supersedes: input["supersedes"].as_bool().unwrap_or(false),
             })
         }
         "register_consequence" => {
@@ -92,12 +108,65 @@ pub fn parse_world_tool(name: &str, input: &Value, world: &GameWorld) -> Option<
                 targets: vec![],
                 spell_level: slot_level,
                 target_names: targets,
+                cover: parse_cover(input["cover"].as_str()),
+                ritual: input["ritual"].as_bool().unwrap_or(false),
+            })
+        }
+        "cast_spell_aoe" => {
+            let spell_name = input["spell_name"].as_str()?.to_string();
+            let slot_level = input["slot_level"].as_u64().unwrap_or(0) as u8;
+            let size = input["size"].as_u64()? as u32;
+            // Cones and lines start at the caster unless told otherwise
+            let caster_position = world.combat.as_ref().and_then(|combat| {
+                combat
+                    .combatant(world.player_character.id)
+                    .and_then(|c| c.position)
+            });
+            let origin = parse_position(&input["origin"])
+                .or(caster_position)
+                .unwrap_or((0, 0));
+            let toward = parse_position(&input["toward"]).unwrap_or(origin);
+            let shape = match input["shape"].as_str()? {
+                "sphere" => AreaShape::Sphere { radius: size },
+                "cone" => AreaShape::Cone {
+                    length: size,
+                    toward,
+                },
+                "line" => AreaShape::Line {
+                    length: size,
+                    width: input["width"].as_u64().unwrap_or(5) as u32,
+                    toward,
+                },
+                _ => return None,
+            };
+            let target_names = input["targets"]
+                .as_array()
+                .map(|arr| {
+                    arr.iter()
+                        .filter_map(|v| v.as_str().map(|s| s.to_string()))
+                        .collect()
+                })
+                .unwrap_or_default();
+
+            Some(Intent::CastSpellAoe {
+                caster_id: world.player_character.id,
+                spell_name,
+                slot_level,
+                origin,
+                shape,
+                target_names,
             })
         }
         "award_experience" => {
             let amount = input["amount"].as_u64()? as u32;
             Some(Intent::GainExperience { amount })
         }
+        "grant_inspiration" => Some(Intent::GrantInspiration {
+            character_id: world.player_character.id,
+        }),
+        "spend_inspiration" => Some(Intent::SpendInspiration {
+            character_id: world.player_character.id,
+        }),
         _ => None,
     }
 }
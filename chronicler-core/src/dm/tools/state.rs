@@ -63,13 +63,13 @@ If the player befriends someone → assert_state(entity_name="Mira", state_type=
 pub fn query_state() -> Tool {
     Tool {
         name: "query_state".to_string(),
-        description: "Query the current state of an entity. Use this to check an NPC's disposition, location, status, or relationships before making decisions.".to_string(),
+        description: "Query the current state of an entity and get it back as JSON. Works for the player (or 'player'), party members, combatants, and NPCs. Use this to check HP, conditions, disposition, location, or relationships before making decisions.".to_string(),
         input_schema: json!({
             "type": "object",
             "properties": {
                 "entity_name": {
                     "type": "string",
-                    "description": "Name of the entity to query"
+                    "description": "Name of the entity to query (partial names work)"
                 },
                 "state_type": {
                     "type": "string",
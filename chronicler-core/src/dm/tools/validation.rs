@@ -0,0 +1,174 @@
+//! Checking tool inputs against their JSON schemas.
+//!
+//! Supports the subset of JSON Schema the DM tools use: `type`, `properties`,
+//! `required`, `enum` and array `items`. Enum values are compared without
+//! regard to case, matching how the parsers read them.
+
+use serde_json::Value;
+
+/// Check a tool input against the tool's input schema.
+///
+/// Returns a message naming the offending field (e.g. `combatants[1].name`)
+/// for the first problem found.
+pub fn validate_input(schema: &Value, input: &Value) -> Result<(), String> {
+    validate_at(schema, input, "")
+}
+
+fn validate_at(schema: &Value, value: &Value, path: &str) -> Result<(), String> {
+    let field = if path.is_empty() { "input" } else { path };
+
+    if let Some(expected) = schema.get("type").and_then(Value::as_str) {
+        if !has_type(value, expected) {
+            return Err(format!(
+                "{field} should be {} but was {}",
+                article(expected),
+                type_name(value)
+            ));
+        }
+    }
+
+    if let Some(options) = schema.get("enum").and_then(Value::as_array) {
+        let allowed = options.iter().any(|option| match (option, value) {
+            (Value::String(a), Value::String(b)) => a.eq_ignore_ascii_case(b),
+            (a, b) => a == b,
+        });
+        if !allowed {
+            let names: Vec<String> = options.iter().map(Value::to_string).collect();
+            return Err(format!("{field} must be one of {}", names.join(", ")));
+        }
+    }
+
+    if let Some(object) = value.as_object() {
+        for required in schema
+            .get("required")
+            .and_then(Value::as_array)
+            .into_iter()
+            .flatten()
+            .filter_map(Value::as_str)
+        {
+            if object.get(required).is_none_or(Value::is_null) {
+                return Err(format!("missing required field {}", join(path, required)));
+            }
+        }
+        if let Some(properties) = schema.get("properties").and_then(Value::as_object) {
+            for (name, property) in properties {
+                match object.get(name) {
+                    Some(Value::Null) | None => {}
+                    Some(v) => validate_at(property, v, &join(path, name))?,
+                }
+            }
+        }
+    }
+
+    if let (Some(items), Some(array)) = (schema.get("items"), value.as_array()) {
+        for (i, item) in array.iter().enumerate() {
+            validate_at(items, item, &format!("{path}[{i}]"))?;
+        }
+    }
+
+    Ok(())
+}
+
+fn has_type(value: &Value, expected: &str) -> bool {
+    match expected {
+        "object" => value.is_object(),
+        "array" => value.is_array(),
+        "string" => value.is_string(),
+        "boolean" => value.is_boolean(),
+        "number" => value.is_number(),
+        "integer" => value.is_i64() || value.is_u64(),
+        "null" => value.is_null(),
+        _ => true,
+    }
+}
+
+fn type_name(value: &Value) -> &'static str {
+    match value {
+        Value::Null => "null",
+        Value::Bool(_) => "a boolean",
+        Value::Number(n) if n.is_f64() => "a number",
+        Value::Number(_) => "an integer",
+        Value::String(_) => "a string",
+        Value::Array(_) => "an array",
+        Value::Object(_) => "an object",
+    }
+}
+
+fn article(type_name: &str) -> String {
+    match type_name {
+        "array" | "integer" | "object" => format!("an {type_name}"),
+        _ => format!("a {type_name}"),
+    }
+}
+
+fn join(path: &str, name: &str) -> String {
+    if path.is_empty() {
+        name.to_string()
+    } else {
+        format!("{path}.{name}")
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::dm::tools::DmTools;
+    use serde_json::json;
+
+    fn schema(name: &str) -> Value {
+        DmTools::get(name).unwrap().input_schema
+    }
+
+    #[test]
+    fn test_valid_input_passes() {
+        let input = json!({"notation": "2d6+3", "purpose": "damage"});
+        assert_eq!(validate_input(&schema("roll_dice"), &input), Ok(()));
+    }
+
+    #[test]
+    fn test_missing_required_field_is_rejected() {
+        let input = json!({"purpose": "damage"});
+        assert_eq!(
+            validate_input(&schema("roll_dice"), &input),
+            Err("missing required field notation".to_string())
+        );
+    }
+
+    #[test]
+    fn test_wrong_type_is_rejected_with_path() {
+        let input = json!({"notation": 20, "purpose": "damage"});
+        assert_eq!(
+            validate_input(&schema("roll_dice"), &input),
+            Err("notation should be a string but was an integer".to_string())
+        );
+
+        let schema = json!({
+            "type": "object",
+            "properties": {
+                "combatants": {
+                    "type": "array",
+                    "items": {
+                        "type": "object",
+                        "properties": {"hp": {"type": "integer"}},
+                        "required": ["hp"]
+                    }
+                }
+            }
+        });
+        let input = json!({"combatants": [{"hp": 7}, {"hp": "lots"}]});
+        assert_eq!(
+            validate_input(&schema, &input),
+            Err("combatants[1].hp should be an integer but was a string".to_string())
+        );
+    }
+
+    #[test]
+    fn test_enum_values_ignore_case() {
+        let schema = json!({"type": "string", "enum": ["fire", "cold"]});
+        assert!(validate_input(&schema, &json!("Fire")).is_ok());
+        assert_eq!(
+            validate_input(&schema, &json!("acid")),
+            Err("input must be one of \"fire\", \"cold\"".to_string())
+        );
+    }
+}
@@ -29,6 +29,29 @@ pub fn long_rest() -> Tool {
     }
 }
 
+/// Spend hit dice to heal.
+pub fn spend_hit_dice() -> Tool {
+    Tool {
+        name: "spend_hit_dice".to_string(),
+        description: "Spend hit dice to regain hit points, usually during a short rest. Each die heals its roll plus the character's Constitution modifier. Spent dice return on a long rest (up to half the total).".to_string(),
+        input_schema: json!({
+            "type": "object",
+            "properties": {
+                "count": {
+                    "type": "integer",
+                    "minimum": 1,
+                    "description": "Number of hit dice to spend"
+                },
+                "character": {
+                    "type": "string",
+                    "description": "Name of the party member spending the dice (omit for the player)"
+                }
+            },
+            "required": ["count"]
+        }),
+    }
+}
+
 /// Change the current location.
 pub fn change_location() -> Tool {
     Tool {
@@ -92,6 +115,10 @@ pub fn remember_fact() -> Tool {
                     "minimum": 0.1,
                     "maximum": 1.0,
                     "description": "How important this fact is (0.1-1.0, default 0.7)"
+                },
+                "supersedes": {
+                    "type": "boolean",
+                    "description": "Set to true when the situation has changed and this fact replaces an earlier one it contradicts (e.g., an NPC who was alive has died). Facts that contradict earlier ones are otherwise refused."
                 }
             },
             "required": ["subject_name", "subject_type", "fact", "category"]
@@ -163,7 +190,16 @@ pub fn cast_spell() -> Tool {
                 "targets": {
                     "type": "array",
                     "items": { "type": "string" },
-                    "description": "Names of targets for the spell (for targeted spells)"
+                    "description": "Names of targets for the spell (for targeted spells). List every creature caught in an area spell; each rolls its own saving throw"
+                },
+                "cover": {
+                    "type": "string",
+                    "enum": ["none", "half", "three_quarters", "total"],
+                    "description": "Cover the targets have from the caster. Half (+2) and three-quarters (+5) apply to spell attack AC and Dexterity saves; total cover means the spell can't reach them."
+                },
+                "ritual": {
+                    "type": "boolean",
+                    "description": "Cast as a ritual (only for spells with the ritual tag): no spell slot is used, but casting takes 10 extra minutes. Default false."
                 }
             },
             "required": ["spell_name"]
@@ -171,6 +207,64 @@ pub fn cast_spell() -> Tool {
     }
 }
 
+/// Cast an area spell at everyone inside its area.
+pub fn cast_spell_aoe() -> Tool {
+    Tool {
+        name: "cast_spell_aoe".to_string(),
+        description: "Cast an area-of-effect spell (Fireball, Burning Hands, Lightning Bolt...) at a point. Every combatant standing inside the area is caught automatically, using the positions given in start_combat. If combatants have no positions, the listed targets are used instead.".to_string(),
+        input_schema: json!({
+            "type": "object",
+            "properties": {
+                "spell_name": {
+                    "type": "string",
+                    "description": "Name of the spell to cast (e.g., 'Fireball')"
+                },
+                "slot_level": {
+                    "type": "integer",
+                    "minimum": 0,
+                    "maximum": 9,
+                    "description": "Spell slot level to use (0 for the spell's base level)"
+                },
+                "shape": {
+                    "type": "string",
+                    "enum": ["sphere", "cone", "line"],
+                    "description": "Shape of the area"
+                },
+                "size": {
+                    "type": "integer",
+                    "minimum": 5,
+                    "description": "Radius of a sphere, or length of a cone or line, in feet (e.g., Fireball: 20, Burning Hands: 15, Lightning Bolt: 100)"
+                },
+                "width": {
+                    "type": "integer",
+                    "minimum": 5,
+                    "description": "Width of a line in feet (default 5)"
+                },
+                "origin": {
+                    "type": "array",
+                    "items": { "type": "integer" },
+                    "minItems": 2,
+                    "maxItems": 2,
+                    "description": "Point of origin as [x, y] in feet. Omit for cones and lines that start at the caster."
+                },
+                "toward": {
+                    "type": "array",
+                    "items": { "type": "integer" },
+                    "minItems": 2,
+                    "maxItems": 2,
+                    "description": "A point the cone or line is aimed at, as [x, y] in feet"
+                },
+                "targets": {
+                    "type": "array",
+                    "items": { "type": "string" },
+                    "description": "Names of the creatures caught, used only when combatants have no positions"
+                }
+            },
+            "required": ["spell_name", "shape", "size"]
+        }),
+    }
+}
+
 /// Award experience points.
 pub fn award_experience() -> Tool {
     Tool {
@@ -193,6 +287,36 @@ pub fn award_experience() -> Tool {
     }
 }
 
+/// Award Heroic Inspiration.
+pub fn grant_inspiration() -> Tool {
+    Tool {
+        name: "grant_inspiration".to_string(),
+        description: "Award the player Heroic Inspiration for great roleplaying, clever ideas, or acting true to their character. The player can hold only one Inspiration at a time.".to_string(),
+        input_schema: json!({
+            "type": "object",
+            "properties": {
+                "reason": {
+                    "type": "string",
+                    "description": "Why inspiration is being awarded"
+                }
+            },
+            "required": ["reason"]
+        }),
+    }
+}
+
+/// Spend Heroic Inspiration.
+pub fn spend_inspiration() -> Tool {
+    Tool {
+        name: "spend_inspiration".to_string(),
+        description: "Spend the player's Heroic Inspiration when they ask to use it. Their next attack roll, skill check, or saving throw is made with advantage. Call this before the roll it applies to.".to_string(),
+        input_schema: json!({
+            "type": "object",
+            "properties": {}
+        }),
+    }
+}
+
 /// Temporarily modify an ability score.
 pub fn modify_ability_score() -> Tool {
     Tool {
@@ -250,6 +374,80 @@ pub fn advance_time() -> Tool {
     }
 }
 
+/// Change the weather.
+pub fn set_weather() -> Tool {
+    Tool {
+        name: "set_weather".to_string(),
+        description: "Change the current weather. Fog, heavy rain, snow, and storms impose disadvantage on Perception checks, as does dim light or darkness for characters without darkvision. Weather eases on its own as time passes.".to_string(),
+        input_schema: json!({
+            "type": "object",
+            "properties": {
+                "weather": {
+                    "type": "string",
+                    "enum": ["clear", "overcast", "rain", "heavy_rain", "fog", "snow", "storm"],
+                    "description": "The new weather"
+                }
+            },
+            "required": ["weather"]
+        }),
+    }
+}
+
+/// Spend days of downtime on an activity.
+pub fn downtime() -> Tool {
+    Tool {
+        name: "downtime".to_string(),
+        description: "Spend days or weeks of downtime between adventures. Time advances by the given number of days and gold is spent. Crafting makes 5 gp of progress per day and costs half the item's value; training in a skill takes about ten workweeks at 25 gp per week; carousing costs 2 gp per day and ends with a Persuasion roll that may bring new contacts or a complication.".to_string(),
+        input_schema: json!({
+            "type": "object",
+            "properties": {
+                "activity": {
+                    "type": "string",
+                    "enum": ["crafting", "training", "carousing"],
+                    "description": "The downtime activity"
+                },
+                "days": {
+                    "type": "integer",
+                    "minimum": 1,
+                    "description": "Number of days spent"
+                },
+                "item_name": {
+                    "type": "string",
+                    "description": "For crafting: the item to craft"
+                },
+                "value_gp": {
+                    "type": "integer",
+                    "description": "For crafting: the item's market value in gold. Optional for standard items."
+                },
+                "skill": {
+                    "type": "string",
+                    "description": "For training: the skill to become proficient in"
+                }
+            },
+            "required": ["activity", "days"]
+        }),
+    }
+}
+
+/// Prepare spells after a long rest.
+pub fn prepare_spells() -> Tool {
+    Tool {
+        name: "prepare_spells".to_string(),
+        description: "Replace the player's prepared spells. Only for prepared casters (Cleric, Druid, Paladin, Wizard) and only at the end of a long rest. The list may hold at most spellcasting modifier + class level spells (half level for Paladins); wizards prepare from their spellbook.".to_string(),
+        input_schema: json!({
+            "type": "object",
+            "properties": {
+                "spells": {
+                    "type": "array",
+                    "items": { "type": "string" },
+                    "description": "The complete new list of prepared spells (not cantrips)"
+                }
+            },
+            "required": ["spells"]
+        }),
+    }
+}
+
 /// Restore a specific spell slot.
 pub fn restore_spell_slot() -> Tool {
     Tool {
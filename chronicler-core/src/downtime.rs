@@ -0,0 +1,335 @@
+//! Downtime activities for long stretches between adventures.
+//!
+//! Costs and durations follow the PHB and Xanathar's Guide, simplified:
+//! - Crafting makes 5 gp of progress per day and costs half the item's value
+//! - Training a skill takes ten workweeks, less one per point of Intelligence
+//!   modifier, at 25 gp per workweek
+//! - Carousing costs 2 gp per day and ends with a Persuasion check; a low
+//!   result brings a complication
+//!
+//! Progress isn't carried between downtime periods, so an activity given too
+//! few days passes the time without producing anything.
+
+use crate::dice::{Advantage, DiceExpression};
+use crate::rules::{Effect, Resolution};
+use crate::world::{GameWorld, ProficiencyLevel, Skill};
+use serde::{Deserialize, Serialize};
+
+/// Gold pieces of crafting progress made per day.
+pub const CRAFTING_GP_PER_DAY: u32 = 5;
+/// Gold pieces spent per workweek of training.
+pub const TRAINING_GP_PER_WEEK: i32 = 25;
+/// Gold pieces spent per day of carousing.
+pub const CAROUSING_GP_PER_DAY: i32 = 2;
+/// Carousing check totals at or below this bring a complication.
+pub const CAROUSING_COMPLICATION_MAX: i32 = 5;
+
+const CAROUSING_COMPLICATIONS: &[&str] = &[
+    "A brawl breaks out and you are blamed for the damages",
+    "You wake up in a cell after a night you can't remember",
+    "You insult a local noble, who won't soon forget it",
+    "You lose a cherished keepsake in a game of chance",
+    "You accidentally become engaged",
+    "A pickpocket relieves you of your coin purse's lining",
+];
+
+/// What a character does with their downtime.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub enum DowntimeActivity {
+    /// Craft an item. Without a value, it's looked up in the item database.
+    Crafting {
+        item_name: String,
+        value_gp: Option<u32>,
+    },
+    /// Train to gain proficiency in a skill.
+    Training { skill: Skill },
+    /// Carouse to make contacts, at the risk of complications.
+    Carousing,
+}
+
+impl DowntimeActivity {
+    pub fn name(&self) -> &'static str {
+        match self {
+            DowntimeActivity::Crafting { .. } => "crafting",
+            DowntimeActivity::Training { .. } => "training",
+            DowntimeActivity::Carousing => "carousing",
+        }
+    }
+}
+
+/// Spend `days` of downtime on an activity.
+pub fn resolve_downtime(activity: &DowntimeActivity, days: u32, world: &GameWorld) -> Resolution {
+    if days == 0 {
+        return Resolution::new("Downtime must last at least one day.");
+    }
+    if world.combat.is_some() {
+        return Resolution::new("Cannot spend downtime while in combat!");
+    }
+
+    // Activities that can't start return an error and spend no time
+    let outcome = match activity {
+        DowntimeActivity::Crafting {
+            item_name,
+            value_gp,
+        } => resolve_crafting(world, item_name, *value_gp, days),
+        DowntimeActivity::Training { skill } => resolve_training(world, *skill, days),
+        DowntimeActivity::Carousing => resolve_carousing(world, days),
+    };
+    match outcome {
+        Ok(resolution) => resolution.with_effect(Effect::TimeAdvanced {
+            minutes: days.saturating_mul(24 * 60),
+        }),
+        Err(reason) => Resolution::new(reason),
+    }
+}
+
+fn resolve_crafting(
+    world: &GameWorld,
+    item_name: &str,
+    value_gp: Option<u32>,
+    days: u32,
+) -> Result<Resolution, String> {
+    let character = &world.player_character;
+    let standard_item = crate::items::find_item(item_name).map(|i| i.as_item());
+    let Some(value) = value_gp.or(standard_item.as_ref().map(|i| i.value_gp.round() as u32)) else {
+        return Err(format!(
+            "{item_name} has no known value; give one to craft it."
+        ));
+    };
+    let item_name = standard_item.map_or(item_name.to_string(), |i| i.name);
+
+    let days_needed = value.div_ceil(CRAFTING_GP_PER_DAY).max(1);
+    if days < days_needed {
+        return Ok(Resolution::new(format!(
+            "{} works on {item_name} for {days} days, but it needs {days_needed} days of work and is left unfinished.",
+            character.name
+        )));
+    }
+
+    let cost = (value / 2) as i32;
+    if character.inventory.gold < cost {
+        return Err(format!(
+            "{} can't afford the {cost} gp of materials to craft {item_name}.",
+            character.name
+        ));
+    }
+
+    let existing_qty = character
+        .inventory
+        .find_item(&item_name)
+        .map(|i| i.quantity)
+        .unwrap_or(0);
+    Ok(Resolution::new(format!(
+        "{} spends {days_needed} days and {cost} gp on materials crafting {item_name}.",
+        character.name
+    ))
+    .with_effect(Effect::GoldChanged {
+        amount: -cost,
+        new_total: character.inventory.gold - cost,
+        reason: format!("materials for {item_name}"),
+    })
+    .with_effect(Effect::ItemAdded {
+        item_name,
+        quantity: 1,
+        new_total: existing_qty + 1,
+    }))
+}
+
+fn resolve_training(world: &GameWorld, skill: Skill, days: u32) -> Result<Resolution, String> {
+    let character = &world.player_character;
+    let current = character
+        .skill_proficiencies
+        .get(&skill)
+        .copied()
+        .unwrap_or_default();
+    if matches!(
+        current,
+        ProficiencyLevel::Proficient | ProficiencyLevel::Expertise
+    ) {
+        return Err(format!(
+            "{} is already proficient in {}.",
+            character.name,
+            skill.name()
+        ));
+    }
+
+    let int_mod = character
+        .ability_scores
+        .modifier(crate::world::Ability::Intelligence);
+    let weeks_needed = (10 - i32::from(int_mod)).max(1);
+    let days_needed = weeks_needed as u32 * 7;
+    if days < days_needed {
+        return Ok(Resolution::new(format!(
+            "{} trains in {} for {days} days, but needs {days_needed} days to become proficient.",
+            character.name,
+            skill.name()
+        )));
+    }
+
+    let cost = weeks_needed * TRAINING_GP_PER_WEEK;
+    if character.inventory.gold < cost {
+        return Err(format!(
+            "{} can't afford the {cost} gp for a tutor in {}.",
+            character.name,
+            skill.name()
+        ));
+    }
+
+    Ok(Resolution::new(format!(
+        "After {days_needed} days of training and {cost} gp in fees, {} becomes proficient in {}.",
+        character.name,
+        skill.name()
+    ))
+    .with_effect(Effect::GoldChanged {
+        amount: -cost,
+        new_total: character.inventory.gold - cost,
+        reason: format!("{} training", skill.name()),
+    })
+    .with_effect(Effect::SkillProficiencyGained {
+        character_id: character.id,
+        skill,
+    }))
+}
+
+fn resolve_carousing(world: &GameWorld, days: u32) -> Result<Resolution, String> {
+    let character = &world.player_character;
+    let cost = (days as i32).saturating_mul(CAROUSING_GP_PER_DAY);
+    if character.inventory.gold < cost {
+        return Err(format!(
+            "{} can't afford {cost} gp for {days} days of carousing.",
+            character.name
+        ));
+    }
+
+    let modifier = character.skill_modifier(Skill::Persuasion);
+    let roll = DiceExpression::parse(&format!("1d20+{modifier}"))
+        .unwrap()
+        .roll_with_advantage(Advantage::Normal);
+
+    let outcome = if roll.total <= CAROUSING_COMPLICATION_MAX {
+        let index = crate::dice::with_current_rng(|rng| {
+            rand::Rng::gen_range(rng, 0..CAROUSING_COMPLICATIONS.len())
+        });
+        format!("Complication: {}.", CAROUSING_COMPLICATIONS[index])
+    } else {
+        match roll.total {
+            ..=10 => "No lasting contacts are made.".to_string(),
+            11..=15 => "You make one friendly contact.".to_string(),
+            16..=20 => "You make two friendly contacts.".to_string(),
+            _ => "You make three friendly contacts.".to_string(),
+        }
+    };
+
+    Ok(Resolution::new(format!(
+        "{} spends {days} days and {cost} gp carousing (Persuasion: {}). {outcome}",
+        character.name, roll.total
+    ))
+    .with_effect(Effect::DiceRolled {
+        roll,
+        purpose: "Carousing".to_string(),
+    })
+    .with_effect(Effect::GoldChanged {
+        amount: -cost,
+        new_total: character.inventory.gold - cost,
+        reason: "carousing".to_string(),
+    }))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::rules::{apply_effects, RulesEngine};
+    use crate::world::create_sample_fighter;
+
+    fn downtime_world() -> GameWorld {
+        let mut world = GameWorld::new("Test", create_sample_fighter("Roland"));
+        world.player_character.inventory.gold = 500;
+        world
+    }
+
+    #[test]
+    fn test_crafting_adds_item_after_enough_days() {
+        let mut world = downtime_world();
+        let activity = DowntimeActivity::Crafting {
+            item_name: "Silvered Dagger".to_string(),
+            value_gp: Some(100),
+        };
+
+        let resolution = resolve_downtime(&activity, 10, &world);
+        assert!(resolution.narrative.contains("unfinished"));
+        assert!(!resolution
+            .effects
+            .iter()
+            .any(|e| matches!(e, Effect::ItemAdded { .. })));
+
+        let resolution = resolve_downtime(&activity, 20, &world);
+        assert!(resolution.effects.iter().any(|e| matches!(
+            e,
+            Effect::ItemAdded { item_name, quantity: 1, .. } if item_name == "Silvered Dagger"
+        )));
+        let day = world.game_time.day;
+        apply_effects(&mut world, &resolution.effects);
+        assert_eq!(world.player_character.inventory.gold, 450);
+        assert!(world
+            .player_character
+            .inventory
+            .find_item("Silvered Dagger")
+            .is_some());
+        assert_ne!(world.game_time.day, day);
+    }
+
+    #[test]
+    fn test_training_grants_proficiency() {
+        let mut world = downtime_world();
+        let activity = DowntimeActivity::Training {
+            skill: Skill::Arcana,
+        };
+
+        let resolution = resolve_downtime(&activity, 100, &world);
+        apply_effects(&mut world, &resolution.effects);
+        assert_eq!(
+            world
+                .player_character
+                .skill_proficiencies
+                .get(&Skill::Arcana),
+            Some(&ProficiencyLevel::Proficient)
+        );
+        assert!(world.player_character.inventory.gold < 500);
+    }
+
+    #[test]
+    fn test_carousing_complication_on_low_roll() {
+        let world = downtime_world();
+        let mut complications = 0;
+        for seed in 0..100 {
+            let resolution = RulesEngine::with_seed(seed).resolve(
+                &world,
+                crate::rules::Intent::Downtime {
+                    activity: DowntimeActivity::Carousing,
+                    days: 7,
+                },
+            );
+            let total = resolution
+                .effects
+                .iter()
+                .find_map(|e| match e {
+                    Effect::DiceRolled { roll, .. } => Some(roll.total),
+                    _ => None,
+                })
+                .unwrap();
+            let complicated = resolution.narrative.contains("Complication:");
+            assert_eq!(complicated, total <= CAROUSING_COMPLICATION_MAX);
+            complications += usize::from(complicated);
+        }
+        assert!(complications > 0);
+    }
+
+    #[test]
+    fn test_downtime_needs_gold() {
+        let mut world = downtime_world();
+        world.player_character.inventory.gold = 0;
+        let resolution = resolve_downtime(&DowntimeActivity::Carousing, 7, &world);
+        assert!(resolution.narrative.contains("can't afford"));
+        assert!(resolution.effects.is_empty());
+    }
+}
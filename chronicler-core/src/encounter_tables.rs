@@ -0,0 +1,443 @@
+//! Random encounter tables keyed by location type.
+//!
+//! Each table rolls a d20: results at or above the table's `encounter_on`
+//! value trigger an encounter, picked by weight from the entries suited to
+//! the party's level. Tables are plain serializable data, so homebrew
+//! campaigns can replace them or add entries.
+
+use crate::dice::{DiceExpression, RollResult};
+use crate::world::LocationType;
+use rand::Rng;
+use serde::{Deserialize, Serialize};
+
+/// The d20 result needed for an encounter on a newly created table.
+pub const DEFAULT_ENCOUNTER_ON: u32 = 18;
+
+/// A kind of monster in an encounter, with how many appear.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct EncounterMonster {
+    pub name: String,
+    /// Dice notation for how many appear (e.g. "1d4+1").
+    pub count: String,
+    pub challenge_rating: f32,
+}
+
+impl EncounterMonster {
+    pub fn new(name: impl Into<String>, count: impl Into<String>, challenge_rating: f32) -> Self {
+        Self {
+            name: name.into(),
+            count: count.into(),
+            challenge_rating,
+        }
+    }
+}
+
+/// One row of an encounter table.
+///
+/// Entries without monsters are social or atmospheric encounters.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct EncounterEntry {
+    pub description: String,
+    pub monsters: Vec<EncounterMonster>,
+    /// Relative chance of this entry among those suited to the party.
+    pub weight: u32,
+    pub min_level: u8,
+    pub max_level: u8,
+}
+
+impl EncounterEntry {
+    pub fn new(description: impl Into<String>, levels: (u8, u8), weight: u32) -> Self {
+        Self {
+            description: description.into(),
+            monsters: Vec::new(),
+            weight,
+            min_level: levels.0,
+            max_level: levels.1,
+        }
+    }
+
+    pub fn with_monster(mut self, name: &str, count: &str, challenge_rating: f32) -> Self {
+        self.monsters
+            .push(EncounterMonster::new(name, count, challenge_rating));
+        self
+    }
+
+    fn suits(&self, party_level: u8) -> bool {
+        (self.min_level..=self.max_level).contains(&party_level)
+    }
+}
+
+/// The encounter table for one type of location.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct EncounterTable {
+    pub location_type: LocationType,
+    /// Lowest d20 result that triggers an encounter.
+    pub encounter_on: u32,
+    pub entries: Vec<EncounterEntry>,
+}
+
+/// A monster group with its number rolled.
+#[derive(Debug, Clone, PartialEq)]
+pub struct RolledMonster {
+    pub name: String,
+    pub count: u32,
+    pub challenge_rating: f32,
+}
+
+/// A suggested encounter for the DM to run.
+#[derive(Debug, Clone, PartialEq)]
+pub struct Encounter {
+    pub description: String,
+    pub monsters: Vec<RolledMonster>,
+}
+
+impl Encounter {
+    /// Whether the encounter involves monsters to fight.
+    pub fn is_combat(&self) -> bool {
+        !self.monsters.is_empty()
+    }
+
+    /// A one-line list of the monsters, e.g. "3 x Wolf (CR 1/4)".
+    pub fn monster_summary(&self) -> String {
+        self.monsters
+            .iter()
+            .map(|m| {
+                format!(
+                    "{} x {} (CR {})",
+                    m.count,
+                    m.name,
+                    format_challenge_rating(m.challenge_rating)
+                )
+            })
+            .collect::<Vec<_>>()
+            .join(", ")
+    }
+}
+
+/// The outcome of rolling on an encounter table.
+#[derive(Debug, Clone, PartialEq)]
+pub struct EncounterRoll {
+    pub roll: RollResult,
+    pub encounter_on: u32,
+    /// `None` when the roll was too low for an encounter.
+    pub encounter: Option<Encounter>,
+}
+
+/// Encounter tables for every location type that has one.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct EncounterTables {
+    pub tables: Vec<EncounterTable>,
+}
+
+impl Default for EncounterTables {
+    fn default() -> Self {
+        let table = |location_type, encounter_on, entries| EncounterTable {
+            location_type,
+            encounter_on,
+            entries,
+        };
+        Self {
+            tables: vec![
+                table(
+                    LocationType::Wilderness,
+                    15,
+                    vec![
+                        EncounterEntry::new("A hungry wolf pack circles the party", (1, 4), 4)
+                            .with_monster("Wolf", "1d4+1", 0.25),
+                        EncounterEntry::new("Goblins spring an ambush from the brush", (1, 4), 4)
+                            .with_monster("Goblin", "1d6+1", 0.25),
+                        EncounterEntry::new(
+                            "A wandering druid shares news of the land",
+                            (1, 20),
+                            2,
+                        ),
+                        EncounterEntry::new("An owlbear crashes out of the trees", (3, 8), 3)
+                            .with_monster("Owlbear", "1", 3.0),
+                        EncounterEntry::new("Ogres hunting for their next meal", (4, 10), 3)
+                            .with_monster("Ogre", "1d2", 2.0),
+                        EncounterEntry::new("A troll stalks the party's trail", (7, 14), 2)
+                            .with_monster("Troll", "1", 5.0),
+                        EncounterEntry::new("Hill giants raid a nearby farmstead", (11, 20), 2)
+                            .with_monster("Hill Giant", "1d3", 5.0),
+                        EncounterEntry::new("A young green dragon claims the forest", (10, 20), 1)
+                            .with_monster("Young Green Dragon", "1", 8.0),
+                    ],
+                ),
+                table(
+                    LocationType::Road,
+                    17,
+                    vec![
+                        EncounterEntry::new("Bandits demand a toll", (1, 6), 4)
+                            .with_monster("Bandit", "1d6+2", 0.125)
+                            .with_monster("Bandit Captain", "1", 2.0),
+                        EncounterEntry::new("A merchant caravan offers to trade", (1, 20), 3),
+                        EncounterEntry::new("Gnolls raid travellers on the road", (3, 10), 3)
+                            .with_monster("Gnoll", "1d4+2", 0.5),
+                        EncounterEntry::new("A knight errant challenges the party", (5, 14), 1)
+                            .with_monster("Knight", "1", 3.0),
+                    ],
+                ),
+                table(
+                    LocationType::Town,
+                    19,
+                    vec![
+                        EncounterEntry::new("A pickpocket tries their luck", (1, 20), 3)
+                            .with_monster("Bandit", "1", 0.125),
+                        EncounterEntry::new("A tavern brawl spills into the street", (1, 8), 3)
+                            .with_monster("Thug", "1d3", 0.5),
+                        EncounterEntry::new("The town watch questions the party", (1, 20), 2),
+                        EncounterEntry::new("Cultists gather in a back alley", (3, 10), 2)
+                            .with_monster("Cultist", "1d4+2", 0.125)
+                            .with_monster("Cult Fanatic", "1", 2.0),
+                    ],
+                ),
+                table(
+                    LocationType::City,
+                    18,
+                    vec![
+                        EncounterEntry::new(
+                            "Thieves' guild enforcers corner the party",
+                            (1, 10),
+                            3,
+                        )
+                        .with_monster("Thug", "1d4+1", 0.5),
+                        EncounterEntry::new(
+                            "A noble's herald seeks capable adventurers",
+                            (1, 20),
+                            2,
+                        ),
+                        EncounterEntry::new("A spy tails the party through the crowds", (3, 12), 2)
+                            .with_monster("Spy", "1", 1.0),
+                        EncounterEntry::new("A veteran duellist picks a fight", (5, 16), 1)
+                            .with_monster("Veteran", "1d2", 3.0),
+                    ],
+                ),
+                table(
+                    LocationType::Dungeon,
+                    12,
+                    vec![
+                        EncounterEntry::new("Giant rats swarm from the walls", (1, 4), 4)
+                            .with_monster("Giant Rat", "2d4", 0.125),
+                        EncounterEntry::new("Skeletons rise from the dust", (1, 6), 4)
+                            .with_monster("Skeleton", "1d6+1", 0.25),
+                        EncounterEntry::new(
+                            "A gelatinous cube glides down the corridor",
+                            (3, 8),
+                            2,
+                        )
+                        .with_monster("Gelatinous Cube", "1", 2.0),
+                        EncounterEntry::new("A minotaur bellows in the labyrinth", (4, 10), 2)
+                            .with_monster("Minotaur", "1", 3.0),
+                        EncounterEntry::new("A wraith drifts through the walls", (7, 14), 2)
+                            .with_monster("Wraith", "1", 5.0),
+                        EncounterEntry::new("A beholder guards its lair", (13, 20), 1)
+                            .with_monster("Beholder", "1", 13.0),
+                    ],
+                ),
+                table(
+                    LocationType::Cave,
+                    13,
+                    vec![
+                        EncounterEntry::new("Stirges descend from the ceiling", (1, 4), 4)
+                            .with_monster("Stirge", "1d6+2", 0.125),
+                        EncounterEntry::new("Troglodytes defend their warren", (1, 6), 3)
+                            .with_monster("Troglodyte", "1d4+1", 0.25),
+                        EncounterEntry::new("Giant spiders lurk in webbed passages", (2, 8), 3)
+                            .with_monster("Giant Spider", "1d3", 1.0),
+                        EncounterEntry::new("Hook horrors click in the dark", (5, 12), 2)
+                            .with_monster("Hook Horror", "1d2", 3.0),
+                        EncounterEntry::new("A roper waits disguised as a stalagmite", (8, 16), 1)
+                            .with_monster("Roper", "1", 5.0),
+                        EncounterEntry::new("A purple worm bursts through the rock", (14, 20), 1)
+                            .with_monster("Purple Worm", "1", 15.0),
+                    ],
+                ),
+            ],
+        }
+    }
+}
+
+impl EncounterTables {
+    /// The table for a location type, if there is one.
+    pub fn table(&self, location_type: LocationType) -> Option<&EncounterTable> {
+        self.tables
+            .iter()
+            .find(|t| t.location_type == location_type)
+    }
+
+    /// Add or replace the table for its location type.
+    pub fn insert(&mut self, table: EncounterTable) {
+        self.tables
+            .retain(|t| t.location_type != table.location_type);
+        self.tables.push(table);
+    }
+
+    /// Add an entry to a location type's table, creating the table if needed.
+    pub fn add_entry(&mut self, location_type: LocationType, entry: EncounterEntry) {
+        match self
+            .tables
+            .iter_mut()
+            .find(|t| t.location_type == location_type)
+        {
+            Some(table) => table.entries.push(entry),
+            None => self.tables.push(EncounterTable {
+                location_type,
+                encounter_on: DEFAULT_ENCOUNTER_ON,
+                entries: vec![entry],
+            }),
+        }
+    }
+
+    /// Roll for an encounter at a location type.
+    ///
+    /// Returns `None` if the location type has no table.
+    pub fn roll<R: Rng>(
+        &self,
+        location_type: LocationType,
+        party_level: u8,
+        rng: &mut R,
+    ) -> Option<EncounterRoll> {
+        let table = self.table(location_type)?;
+        let roll = roll_dice("1d20", rng);
+        let encounter = if roll.total as u32 >= table.encounter_on {
+            pick_entry(&table.entries, party_level, rng).map(|entry| Encounter {
+                description: entry.description.clone(),
+                monsters: entry
+                    .monsters
+                    .iter()
+                    .map(|m| RolledMonster {
+                        name: m.name.clone(),
+                        count: roll_dice(&m.count, rng).total.max(1) as u32,
+                        challenge_rating: m.challenge_rating,
+                    })
+                    .collect(),
+            })
+        } else {
+            None
+        };
+        Some(EncounterRoll {
+            roll,
+            encounter_on: table.encounter_on,
+            encounter,
+        })
+    }
+}
+
+/// Pick an entry by weight, preferring those suited to the party's level.
+fn pick_entry<'a, R: Rng>(
+    entries: &'a [EncounterEntry],
+    party_level: u8,
+    rng: &mut R,
+) -> Option<&'a EncounterEntry> {
+    let mut candidates: Vec<_> = entries.iter().filter(|e| e.suits(party_level)).collect();
+    if candidates.is_empty() {
+        candidates = entries.iter().collect();
+    }
+    let total: u32 = candidates.iter().map(|e| e.weight).sum();
+    if total == 0 {
+        return None;
+    }
+    let mut pick = rng.gen_range(0..total);
+    candidates.into_iter().find(|entry| {
+        if pick < entry.weight {
+            true
+        } else {
+            pick -= entry.weight;
+            false
+        }
+    })
+}
+
+fn roll_dice<R: Rng>(notation: &str, rng: &mut R) -> RollResult {
+    DiceExpression::parse(notation)
+        .or_else(|_| DiceExpression::parse("1"))
+        .expect("constant dice expression parses")
+        .roll_with_rng(rng)
+}
+
+/// Format a challenge rating, showing fractions as "1/8", "1/4" and "1/2".
+pub fn format_challenge_rating(challenge_rating: f32) -> String {
+    match challenge_rating {
+        0.125 => "1/8".to_string(),
+        0.25 => "1/4".to_string(),
+        0.5 => "1/2".to_string(),
+        cr => format!("{cr}"),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use rand::{rngs::StdRng, SeedableRng};
+    use std::collections::HashSet;
+
+    fn descriptions(location_type: LocationType, level: u8) -> HashSet<String> {
+        let tables = EncounterTables::default();
+        let mut rng = StdRng::seed_from_u64(66);
+        (0..300)
+            .filter_map(|_| tables.roll(location_type, level, &mut rng)?.encounter)
+            .map(|e| e.description)
+            .collect()
+    }
+
+    #[test]
+    fn test_wilderness_and_town_use_different_tables() {
+        let wilderness = descriptions(LocationType::Wilderness, 3);
+        let town = descriptions(LocationType::Town, 3);
+        assert!(!wilderness.is_empty());
+        assert!(!town.is_empty());
+        assert!(wilderness.is_disjoint(&town));
+
+        let tables = EncounterTables::default();
+        let wilderness_table = tables.table(LocationType::Wilderness).unwrap();
+        assert!(wilderness
+            .iter()
+            .all(|d| wilderness_table.entries.iter().any(|e| &e.description == d)));
+    }
+
+    #[test]
+    fn test_no_encounter_is_possible() {
+        let tables = EncounterTables::default();
+        let mut rng = StdRng::seed_from_u64(3);
+        let rolls: Vec<_> = (0..50)
+            .map(|_| tables.roll(LocationType::Town, 1, &mut rng).unwrap())
+            .collect();
+        assert!(rolls.iter().any(|r| r.encounter.is_none()));
+        for roll in &rolls {
+            assert_eq!(
+                roll.encounter.is_some(),
+                roll.roll.total as u32 >= roll.encounter_on
+            );
+        }
+        assert!(tables.roll(LocationType::Room, 1, &mut rng).is_none());
+    }
+
+    #[test]
+    fn test_entries_respect_party_level() {
+        let low = descriptions(LocationType::Wilderness, 1);
+        assert!(!low.is_empty());
+        assert!(!low.iter().any(|d| d.contains("dragon")));
+    }
+
+    #[test]
+    fn test_homebrew_entries_extend_tables() {
+        let mut tables = EncounterTables::default();
+        tables.insert(EncounterTable {
+            location_type: LocationType::Room,
+            encounter_on: 1,
+            entries: vec![],
+        });
+        tables.add_entry(
+            LocationType::Room,
+            EncounterEntry::new("A mimic pretends to be a chest", (1, 20), 1)
+                .with_monster("Mimic", "1", 2.0),
+        );
+
+        let roll = tables
+            .roll(LocationType::Room, 4, &mut StdRng::seed_from_u64(0))
+            .unwrap();
+        let encounter = roll.encounter.unwrap();
+        assert!(encounter.is_combat());
+        assert_eq!(encounter.monster_summary(), "1 x Mimic (CR 2)");
+    }
+}
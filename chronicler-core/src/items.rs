@@ -45,6 +45,15 @@ pub fn get_adventuring_gear(name: &str) -> Option<Item> {
         .cloned()
 }
 
+/// Get a standard magic item by name.
+pub fn get_magic_item(name: &str) -> Option<Item> {
+    let name_lower = name.to_lowercase();
+    MAGIC_ITEMS
+        .iter()
+        .find(|i| i.name.to_lowercase() == name_lower)
+        .cloned()
+}
+
 /// Try to find any standard item by name.
 pub fn find_item(name: &str) -> Option<StandardItem> {
     if let Some(weapon) = get_weapon(name) {
@@ -59,6 +68,9 @@ pub fn find_item(name: &str) -> Option<StandardItem> {
     if let Some(item) = get_adventuring_gear(name) {
         return Some(StandardItem::Item(item));
     }
+    if let Some(item) = get_magic_item(name) {
+        return Some(StandardItem::Item(item));
+    }
     None
 }
 
@@ -348,6 +360,7 @@ static POTIONS: LazyLock<Vec<ConsumableItem>> = LazyLock::new(|| {
                 ),
                 item_type: ItemType::Potion,
                 magical: false,
+                requires_attunement: false,
             },
             effect: ConsumableEffect::GrantAdvantage {
                 roll_type: "poison saves".to_string(),
@@ -368,6 +381,7 @@ static ADVENTURING_GEAR: LazyLock<Vec<Item>> = LazyLock::new(|| {
             description: Some("A leather pack for carrying gear.".to_string()),
             item_type: ItemType::Adventuring,
             magical: false,
+            requires_attunement: false,
         },
         Item {
             name: "Bedroll".to_string(),
@@ -377,6 +391,7 @@ static ADVENTURING_GEAR: LazyLock<Vec<Item>> = LazyLock::new(|| {
             description: None,
             item_type: ItemType::Adventuring,
             magical: false,
+            requires_attunement: false,
         },
         Item {
             name: "Rope (50 feet)".to_string(),
@@ -386,6 +401,7 @@ static ADVENTURING_GEAR: LazyLock<Vec<Item>> = LazyLock::new(|| {
             description: Some("Hemp rope, 50 feet.".to_string()),
             item_type: ItemType::Adventuring,
             magical: false,
+            requires_attunement: false,
         },
         Item {
             name: "Torch".to_string(),
@@ -395,6 +411,7 @@ static ADVENTURING_GEAR: LazyLock<Vec<Item>> = LazyLock::new(|| {
             description: Some("Provides bright light in 20-foot radius, dim light for 20 feet beyond. Burns for 1 hour.".to_string()),
             item_type: ItemType::Adventuring,
             magical: false,
+            requires_attunement: false,
         },
         Item {
             name: "Rations (1 day)".to_string(),
@@ -404,6 +421,7 @@ static ADVENTURING_GEAR: LazyLock<Vec<Item>> = LazyLock::new(|| {
             description: Some("Trail rations for one day.".to_string()),
             item_type: ItemType::Adventuring,
             magical: false,
+            requires_attunement: false,
         },
         Item {
             name: "Waterskin".to_string(),
@@ -413,6 +431,7 @@ static ADVENTURING_GEAR: LazyLock<Vec<Item>> = LazyLock::new(|| {
             description: Some("Holds 4 pints of liquid.".to_string()),
             item_type: ItemType::Adventuring,
             magical: false,
+            requires_attunement: false,
         },
         Item {
             name: "Tinderbox".to_string(),
@@ -422,6 +441,7 @@ static ADVENTURING_GEAR: LazyLock<Vec<Item>> = LazyLock::new(|| {
             description: Some("Used to light fires.".to_string()),
             item_type: ItemType::Adventuring,
             magical: false,
+            requires_attunement: false,
         },
         Item {
             name: "Lantern".to_string(),
@@ -431,6 +451,7 @@ static ADVENTURING_GEAR: LazyLock<Vec<Item>> = LazyLock::new(|| {
             description: Some("A hooded lantern casts bright light in 30-foot radius.".to_string()),
             item_type: ItemType::Adventuring,
             magical: false,
+            requires_attunement: false,
         },
         Item {
             name: "Oil Flask".to_string(),
@@ -440,6 +461,7 @@ static ADVENTURING_GEAR: LazyLock<Vec<Item>> = LazyLock::new(|| {
             description: Some("Flask of oil for lanterns or as improvised weapon.".to_string()),
             item_type: ItemType::Adventuring,
             magical: false,
+            requires_attunement: false,
         },
         Item {
             name: "Grappling Hook".to_string(),
@@ -449,6 +471,7 @@ static ADVENTURING_GEAR: LazyLock<Vec<Item>> = LazyLock::new(|| {
             description: None,
             item_type: ItemType::Adventuring,
             magical: false,
+            requires_attunement: false,
         },
         Item {
             name: "Crowbar".to_string(),
@@ -458,6 +481,7 @@ static ADVENTURING_GEAR: LazyLock<Vec<Item>> = LazyLock::new(|| {
             description: Some("Grants advantage on Strength checks to pry things open.".to_string()),
             item_type: ItemType::Tool,
             magical: false,
+            requires_attunement: false,
         },
         Item {
             name: "Thieves' Tools".to_string(),
@@ -467,6 +491,7 @@ static ADVENTURING_GEAR: LazyLock<Vec<Item>> = LazyLock::new(|| {
             description: Some("Required for picking locks and disarming traps.".to_string()),
             item_type: ItemType::Tool,
             magical: false,
+            requires_attunement: false,
         },
         Item {
             name: "Holy Symbol".to_string(),
@@ -476,6 +501,7 @@ static ADVENTURING_GEAR: LazyLock<Vec<Item>> = LazyLock::new(|| {
             description: Some("A religious focus for spellcasting.".to_string()),
             item_type: ItemType::Adventuring,
             magical: false,
+            requires_attunement: false,
         },
         Item {
             name: "Arcane Focus".to_string(),
@@ -485,6 +511,7 @@ static ADVENTURING_GEAR: LazyLock<Vec<Item>> = LazyLock::new(|| {
             description: Some("A crystal, orb, or similar item used as a spellcasting focus.".to_string()),
             item_type: ItemType::Adventuring,
             magical: false,
+            requires_attunement: false,
         },
         Item {
             name: "Component Pouch".to_string(),
@@ -494,6 +521,7 @@ static ADVENTURING_GEAR: LazyLock<Vec<Item>> = LazyLock::new(|| {
             description: Some("A small pouch containing spell components.".to_string()),
             item_type: ItemType::Adventuring,
             magical: false,
+            requires_attunement: false,
         },
         Item {
             name: "Arrows (20)".to_string(),
@@ -503,6 +531,7 @@ static ADVENTURING_GEAR: LazyLock<Vec<Item>> = LazyLock::new(|| {
             description: Some("A quiver of 20 arrows.".to_string()),
             item_type: ItemType::Adventuring,
             magical: false,
+            requires_attunement: false,
         },
         Item {
             name: "Bolts (20)".to_string(),
@@ -512,6 +541,7 @@ static ADVENTURING_GEAR: LazyLock<Vec<Item>> = LazyLock::new(|| {
             description: Some("A case of 20 crossbow bolts.".to_string()),
             item_type: ItemType::Adventuring,
             magical: false,
+            requires_attunement: false,
         },
         Item {
             name: "Shield".to_string(),
@@ -521,10 +551,100 @@ static ADVENTURING_GEAR: LazyLock<Vec<Item>> = LazyLock::new(|| {
             description: Some("A wooden or metal shield. +2 AC when equipped.".to_string()),
             item_type: ItemType::Shield,
             magical: false,
+            requires_attunement: false,
         },
 ]
 });
 
+// ============================================================================
+// Magic Items
+// ============================================================================
+
+/// Common magic items from the treasure tables.
+static MAGIC_ITEMS: LazyLock<Vec<Item>> = LazyLock::new(|| {
+    let magic_item = |name: &str,
+                      item_type: ItemType,
+                      weight: f32,
+                      value_gp: f32,
+                      requires_attunement: bool,
+                      description: &str| Item {
+        name: name.to_string(),
+        quantity: 1,
+        weight,
+        value_gp,
+        description: Some(description.to_string()),
+        item_type,
+        magical: true,
+        requires_attunement,
+    };
+    vec![
+        magic_item(
+            "Ring of Protection",
+            ItemType::Ring,
+            0.0,
+            3500.0,
+            true,
+            "+1 bonus to AC and saving throws while attuned.",
+        ),
+        magic_item(
+            "Ring of Resistance",
+            ItemType::Ring,
+            0.0,
+            6000.0,
+            true,
+            "Resistance to one damage type while attuned.",
+        ),
+        magic_item(
+            "Cloak of Protection",
+            ItemType::Wondrous,
+            1.0,
+            3500.0,
+            true,
+            "+1 bonus to AC and saving throws while attuned.",
+        ),
+        magic_item(
+            "Amulet of Health",
+            ItemType::Wondrous,
+            0.0,
+            8000.0,
+            true,
+            "Constitution score becomes 19 while attuned.",
+        ),
+        magic_item(
+            "Gauntlets of Ogre Power",
+            ItemType::Wondrous,
+            1.0,
+            8000.0,
+            true,
+            "Strength score becomes 19 while attuned.",
+        ),
+        magic_item(
+            "Bag of Holding",
+            ItemType::Wondrous,
+            15.0,
+            4000.0,
+            false,
+            "Holds up to 500 pounds in an extradimensional space.",
+        ),
+        magic_item(
+            "Boots of Elvenkind",
+            ItemType::Wondrous,
+            1.0,
+            2500.0,
+            false,
+            "Your steps make no sound; advantage on Stealth checks that rely on moving silently.",
+        ),
+        magic_item(
+            "Wand of Magic Missiles",
+            ItemType::Wand,
+            1.0,
+            8000.0,
+            false,
+            "Expend charges to cast Magic Missile.",
+        ),
+    ]
+});
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -571,6 +691,13 @@ mod tests {
             find_item("Rope (50 feet)"),
             Some(StandardItem::Item(_))
         ));
+        assert!(matches!(
+            find_item("ring of protection"),
+            Some(StandardItem::Item(Item {
+                requires_attunement: true,
+                ..
+            }))
+        ));
         assert!(find_item("Nonexistent Item").is_none());
     }
 }
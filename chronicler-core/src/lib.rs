@@ -30,13 +30,17 @@ pub mod character_builder;
 pub mod class_data;
 pub mod dice;
 pub mod dm;
+pub mod downtime;
+pub mod encounter_tables;
 pub mod headless;
 pub mod items;
 pub mod persist;
 pub mod rules;
+pub mod rules_reference;
 pub mod session;
 pub mod spells;
 pub mod testing;
+pub mod treasure;
 pub mod world;
 
 // Primary public API
@@ -4,9 +4,10 @@
 //! supporting both JSON (human-readable) and bincode (compact) formats.
 
 use crate::dm::memory::{CampaignFact, FactCategory};
+use crate::dm::StoryMemory;
 use crate::world::{Character, GameWorld};
 use serde::{Deserialize, Serialize};
-use std::path::Path;
+use std::path::{Path, PathBuf};
 use thiserror::Error;
 use tokio::fs;
 
@@ -29,6 +30,25 @@ pub enum PersistError {
 /// Current save file version.
 const SAVE_VERSION: u32 = 1;
 
+/// Write a file atomically: write a temporary file beside it, then rename it
+/// into place, so a crash mid-save never leaves a truncated file behind.
+pub(crate) async fn write_atomic(
+    path: impl AsRef<Path>,
+    content: &str,
+) -> Result<(), PersistError> {
+    let path = path.as_ref();
+    let mut temp_name = path.file_name().unwrap_or_default().to_os_string();
+    temp_name.push(".tmp");
+    let temp_path = path.with_file_name(temp_name);
+
+    fs::write(&temp_path, content).await?;
+    if let Err(e) = fs::rename(&temp_path, path).await {
+        let _ = fs::remove_file(&temp_path).await;
+        return Err(e.into());
+    }
+    Ok(())
+}
+
 /// A saved campaign with all state needed to resume play.
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct SavedCampaign {
@@ -47,6 +67,10 @@ pub struct SavedCampaign {
     /// Summary of the conversation for context restoration.
     pub conversation_summary: Option<String>,
 
+    /// Entities, facts, and consequences tracked for narrative continuity.
+    #[serde(default)]
+    pub story_memory: Option<StoryMemory>,
+
     /// Metadata about the save.
     pub metadata: SaveMetadata,
 }
@@ -72,6 +96,59 @@ pub struct SaveMetadata {
     /// When the save was created (duplicated from parent for peek access).
     #[serde(default)]
     pub saved_at: String,
+
+    /// In-game date and time when saved.
+    #[serde(default)]
+    pub game_date: String,
+}
+
+impl SaveMetadata {
+    /// Describe a world for the load menu.
+    pub fn from_world(world: &GameWorld, saved_at: String) -> Self {
+        let time = &world.game_time;
+        Self {
+            character_name: world.player_character.name.clone(),
+            campaign_name: world.campaign_name.clone(),
+            level: world.player_character.level,
+            location: world.current_location.name.clone(),
+            days_elapsed: time.day as u32,
+            saved_at,
+            game_date: format!(
+                "Day {}, Month {}, Year {}, {}:{:02}",
+                time.day, time.month, time.year, time.hour, time.minute
+            ),
+        }
+    }
+
+    /// Describe a world saved just now.
+    pub fn now(world: &GameWorld) -> Self {
+        Self::from_world(world, chrono_now())
+    }
+
+    /// Write this metadata as a sidecar next to a save file.
+    pub async fn write_sidecar(&self, save_path: impl AsRef<Path>) -> Result<(), PersistError> {
+        let content = serde_json::to_string_pretty(self)?;
+        write_atomic(&metadata_path(save_path), &content).await?;
+        Ok(())
+    }
+
+    /// Read the metadata sidecar for a save file.
+    pub async fn read_sidecar(save_path: impl AsRef<Path>) -> Result<Self, PersistError> {
+        let content = fs::read_to_string(metadata_path(save_path)).await?;
+        Ok(serde_json::from_str(&content)?)
+    }
+}
+
+/// Path of the metadata sidecar for a save file (`name.json` -> `name.meta.json`).
+pub fn metadata_path(save_path: impl AsRef<Path>) -> PathBuf {
+    save_path.as_ref().with_extension("meta.json")
+}
+
+/// Whether a path is a metadata sidecar rather than a save.
+pub fn is_metadata_sidecar(path: impl AsRef<Path>) -> bool {
+    path.as_ref()
+        .file_name()
+        .is_some_and(|name| name.to_string_lossy().ends_with(".meta.json"))
 }
 
 impl SavedCampaign {
@@ -82,14 +159,7 @@ impl SavedCampaign {
         conversation_summary: Option<String>,
     ) -> Self {
         let saved_at = chrono_now();
-        let metadata = SaveMetadata {
-            character_name: world.player_character.name.clone(),
-            campaign_name: world.campaign_name.clone(),
-            level: world.player_character.level,
-            location: world.current_location.name.clone(),
-            days_elapsed: world.game_time.day as u32,
-            saved_at: saved_at.clone(),
-        };
+        let metadata = SaveMetadata::from_world(&world, saved_at.clone());
 
         Self {
             version: SAVE_VERSION,
@@ -97,15 +167,21 @@ impl SavedCampaign {
             world,
             campaign_facts,
             conversation_summary,
+            story_memory: None,
             metadata,
         }
     }
 
+    /// Include the DM's story memory in the save.
+    pub fn with_story_memory(mut self, story_memory: StoryMemory) -> Self {
+        self.story_memory = Some(story_memory);
+        self
+    }
+
     /// Save to a JSON file.
     pub async fn save_json(&self, path: impl AsRef<Path>) -> Result<(), PersistError> {
         let content = serde_json::to_string_pretty(self)?;
-        fs::write(path, content).await?;
-        Ok(())
+        write_atomic(path, &content).await
     }
 
     /// Load from a JSON file.
@@ -188,6 +264,23 @@ pub fn auto_save_path(base_dir: impl AsRef<Path>, campaign_name: &str) -> std::p
     base_dir.as_ref().join(format!("{sanitized}_autosave.json"))
 }
 
+/// Path for an exported character sheet (`<base>/exports/<name>.json`).
+///
+/// The Markdown rendering goes beside it with an `.md` extension.
+pub fn character_export_path(
+    base_dir: impl AsRef<Path>,
+    character_name: &str,
+) -> std::path::PathBuf {
+    let sanitized = character_name
+        .chars()
+        .map(|c| if c.is_alphanumeric() { c } else { '_' })
+        .collect::<String>();
+    base_dir
+        .as_ref()
+        .join("exports")
+        .join(format!("{sanitized}.json"))
+}
+
 /// Create a manual save file name with timestamp.
 pub fn manual_save_path(base_dir: impl AsRef<Path>, campaign_name: &str) -> std::path::PathBuf {
     let sanitized = campaign_name
@@ -379,8 +472,7 @@ impl SavedCharacter {
     /// Save to a JSON file.
     pub async fn save_json(&self, path: impl AsRef<Path>) -> Result<(), PersistError> {
         let content = serde_json::to_string_pretty(self)?;
-        fs::write(path, content).await?;
-        Ok(())
+        write_atomic(path, &content).await
     }
 
     /// Load from a JSON file.
@@ -489,6 +581,14 @@ mod tests {
         assert_eq!(saved.metadata.campaign_name, "Test Campaign");
     }
 
+    #[test]
+    fn test_metadata_sidecar_path() {
+        let path = metadata_path("/saves/My_Campaign_autosave.json");
+        assert_eq!(path, PathBuf::from("/saves/My_Campaign_autosave.meta.json"));
+        assert!(is_metadata_sidecar(&path));
+        assert!(!is_metadata_sidecar("/saves/My_Campaign_autosave.json"));
+    }
+
     #[test]
     fn test_auto_save_path() {
         let path = auto_save_path("/saves", "My Campaign!");
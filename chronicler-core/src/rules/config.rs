@@ -0,0 +1,31 @@
+//! Optional "house rules" that tables toggle on top of the core rules.
+
+use serde::{Deserialize, Serialize};
+
+/// Toggles for optional rules. The default follows the rules as written.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(default)]
+pub struct RulesConfig {
+    /// Melee attacks get advantage while a conscious ally is engaged with the target.
+    pub flanking: bool,
+    /// The extra dice of a critical hit deal their maximum instead of being rolled.
+    pub crit_max_damage: bool,
+    /// Drinking a potion takes a bonus action instead of an action.
+    pub bonus_action_potions: bool,
+    /// Features granted by feats apply; when off, feat-sourced features are ignored.
+    pub feats: bool,
+    /// Every combatant rerolls initiative at the start of each round.
+    pub reroll_initiative_each_round: bool,
+}
+
+impl Default for RulesConfig {
+    fn default() -> Self {
+        Self {
+            flanking: false,
+            crit_max_damage: false,
+            bonus_action_potions: false,
+            feats: true,
+            reroll_initiative_each_round: false,
+        }
+    }
+}
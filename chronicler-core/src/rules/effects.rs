@@ -1,10 +1,29 @@
 //! Effect application to the game world.
 
-use crate::rules::types::{Effect, RestType, StateType};
+use crate::rules::types::{Effect, Intent, RestType, StateType};
 use crate::world::{
-    Ability, CharacterClass, Combatant, Condition, GameWorld, Item, ItemType, SlotInfo, SpellSlots,
-    SpellcastingData,
+    multiclass_spell_slots, opposite_direction, Ability, CharacterClass, Combatant,
+    ConcentrationState, Condition, GameMode, GameWorld, Item, ItemType, LifeState,
+    LocationConnection, PactMagic, ProficiencyLevel, SlotInfo, SpellSlots, SpellcastingData,
 };
+use thiserror::Error;
+
+/// Why a batch of effects couldn't be applied.
+#[derive(Debug, Clone, PartialEq, Eq, Error)]
+pub enum ApplyError {
+    /// A spell slot was spent at a level with none left.
+    #[error("No level {level} spell slot is available.")]
+    NoSpellSlot { level: u8 },
+    /// Inspiration was spent without having it.
+    #[error("The character has no inspiration to spend.")]
+    NoInspiration,
+    /// A feature use was recorded for a feature the character doesn't have.
+    #[error("The character has no feature named '{feature_name}'.")]
+    UnknownFeature { feature_name: String },
+    /// More of an item was removed than the inventory holds.
+    #[error("The inventory doesn't hold {quantity} of '{item_name}'.")]
+    NotEnoughItems { item_name: String, quantity: u32 },
+}
 
 /// Apply effects to the game world.
 pub fn apply_effects(world: &mut GameWorld, effects: &[Effect]) {
@@ -13,62 +32,235 @@ pub fn apply_effects(world: &mut GameWorld, effects: &[Effect]) {
     }
 }
 
+/// Apply a batch of effects atomically.
+///
+/// Each effect is validated against the world as it stands after the ones
+/// before it. If any is invalid, the world is restored to how it was before
+/// the batch and the error is returned.
+pub fn try_apply_effects(world: &mut GameWorld, effects: &[Effect]) -> Result<(), ApplyError> {
+    let snapshot = world.clone();
+    for effect in effects {
+        if let Err(err) = validate_effect(world, effect) {
+            *world = snapshot;
+            return Err(err);
+        }
+        apply_effect(world, effect);
+    }
+    Ok(())
+}
+
+/// Check that an effect spends only resources the character spending them has.
+fn validate_effect(world: &GameWorld, effect: &Effect) -> Result<(), ApplyError> {
+    match effect {
+        Effect::SpellSlotUsed {
+            character_id,
+            level,
+            ..
+        } => {
+            let available = world
+                .character(*character_id)
+                .and_then(|character| character.spellcasting.as_ref())
+                .map_or(0, |spellcasting| spellcasting.available_slots(*level));
+            if available == 0 {
+                return Err(ApplyError::NoSpellSlot { level: *level });
+            }
+        }
+        Effect::InspirationSpent { character_id }
+            if !world
+                .character(*character_id)
+                .is_some_and(|character| character.has_inspiration) =>
+        {
+            return Err(ApplyError::NoInspiration);
+        }
+        Effect::FeatureUsed {
+            character_id,
+            feature_name,
+            ..
+        } if !world.character(*character_id).is_some_and(|character| {
+            character.features.iter().any(|f| f.name == *feature_name)
+        }) =>
+        {
+            return Err(ApplyError::UnknownFeature {
+                feature_name: feature_name.clone(),
+            });
+        }
+        Effect::ItemRemoved {
+            item_name,
+            quantity,
+            ..
+        } => {
+            let held = world
+                .player_character
+                .inventory
+                .find_item(item_name)
+                .map_or(0, |item| item.quantity);
+            if held < *quantity {
+                return Err(ApplyError::NotEnoughItems {
+                    item_name: item_name.clone(),
+                    quantity: *quantity,
+                });
+            }
+        }
+        _ => {}
+    }
+    Ok(())
+}
+
 /// Apply a single effect to the game world.
 pub fn apply_effect(world: &mut GameWorld, effect: &Effect) {
     match effect {
         Effect::HpChanged {
+            target_id, amount, ..
+        } if world.character(*target_id).is_none() => {
+            // Non-party combatants only track HP in the combat state
+            if let Some(ref mut combat) = world.combat {
+                combat.apply_hp_change(*target_id, *amount);
+            }
+        }
+        Effect::HpChanged {
+            target_id,
             amount,
             dropped_to_zero,
             ..
         } => {
-            let was_unconscious = world.player_character.hit_points.current <= 0;
+            let Some(character) = world.character_mut(*target_id) else {
+                return;
+            };
+            // The dead don't take damage or heal
+            if character.is_dead() {
+                return;
+            }
+            let was_unconscious = character.hit_points.current <= 0;
 
             if *amount < 0 {
-                world.player_character.hit_points.take_damage(-*amount);
+                character.hit_points.take_damage(-*amount);
             } else {
-                world.player_character.hit_points.heal(*amount);
+                character.hit_points.heal(*amount);
+            }
+
+            // Taking damage while concentrating calls for a concentration check
+            let concentration_check = character
+                .concentrating_on
+                .as_ref()
+                .filter(|_| *amount < 0 && character.is_conscious())
+                .map(|concentration| Intent::ConcentrationCheck {
+                    character_id: *target_id,
+                    damage_taken: -*amount,
+                    spell_name: concentration.spell_name.clone(),
+                });
+
+            // Falling unconscious ends concentration outright
+            if character.hit_points.current <= 0 {
+                character.concentrating_on = None;
             }
 
             // Add Unconscious condition if dropped to 0 (only if not already unconscious)
             if *dropped_to_zero {
-                world
-                    .player_character
-                    .add_condition(Condition::Unconscious, "Dropped to 0 HP");
+                character.add_condition(Condition::Unconscious, "Dropped to 0 HP");
+                character.life_state = LifeState::Unconscious;
+            } else if *amount < 0 && character.life_state == LifeState::Stable {
+                // Damage at 0 HP restarts the death saving throws
+                character.life_state = LifeState::Unconscious;
             }
 
             // Remove Unconscious condition and reset death saves if healed above 0
-            if was_unconscious && world.player_character.hit_points.current > 0 {
-                world
-                    .player_character
-                    .conditions
-                    .retain(|c| c.condition != Condition::Unconscious);
+            if was_unconscious && character.hit_points.current > 0 {
+                character.remove_condition(Condition::Unconscious);
                 // Reset death saves when regaining consciousness
-                world.player_character.death_saves.reset();
+                character.death_saves.reset();
+                character.life_state = LifeState::Alive;
             }
 
             // Sync HP to combat state if in combat
+            let current = character.hit_points.current;
             if let Some(ref mut combat) = world.combat {
-                let player_id = world.player_character.id;
-                combat.update_combatant_hp(player_id, world.player_character.hit_points.current);
+                combat.update_combatant_hp(*target_id, current);
+            }
+            world.queued_intents.extend(concentration_check);
+        }
+        Effect::TempHpGranted {
+            target_id, amount, ..
+        } => {
+            if let Some(character) = world.character_mut(*target_id) {
+                character.hit_points.add_temp_hp(*amount);
             }
         }
+        // Surprise only affects the turn order, so it lives on the combatant
         Effect::ConditionApplied {
+            target_id,
+            condition: Condition::Surprised,
+            ..
+        } => {
+            if let Some(ref mut combat) = world.combat {
+                combat.surprise(*target_id);
+            }
+        }
+        Effect::ConditionApplied {
+            target_id,
+            condition,
+            source,
+            duration_rounds,
+        } if world.character(*target_id).is_none() => {
+            if let Some(combatant) = world
+                .combat
+                .as_mut()
+                .and_then(|combat| combat.combatant_mut(*target_id))
+            {
+                combatant.add_condition_with_duration(*condition, source.clone(), *duration_rounds);
+            }
+        }
+        Effect::ConditionApplied {
+            target_id,
             condition,
             source,
             duration_rounds,
-            ..
         } => {
-            world.player_character.add_condition_with_duration(
-                *condition,
-                source.clone(),
-                *duration_rounds,
-            );
+            if let Some(character) = world.character_mut(*target_id) {
+                character.add_condition_with_duration(*condition, source.clone(), *duration_rounds);
+            }
         }
-        Effect::ConditionRemoved { condition, .. } => {
-            world
-                .player_character
-                .conditions
-                .retain(|c| c.condition != *condition);
+        Effect::OngoingEffectApplied { target_id, effect } => {
+            if let Some(character) = world.character_mut(*target_id) {
+                character.ongoing_effects.push(effect.clone());
+            } else if let Some(combatant) = world
+                .combat
+                .as_mut()
+                .and_then(|combat| combat.combatant_mut(*target_id))
+            {
+                combatant.ongoing_effects.push(effect.clone());
+            }
+        }
+        Effect::ConditionRemoved {
+            target_id,
+            condition,
+        } if world.character(*target_id).is_none() => {
+            if let Some(combatant) = world
+                .combat
+                .as_mut()
+                .and_then(|combat| combat.combatant_mut(*target_id))
+            {
+                combatant.remove_condition(*condition);
+            }
+        }
+        Effect::ConditionRemoved {
+            target_id,
+            condition,
+        } => {
+            if let Some(character) = world.character_mut(*target_id) {
+                character.remove_condition(*condition);
+            }
+        }
+        Effect::ExhaustionChanged {
+            target_id,
+            new_level,
+        } => {
+            let Some(character) = world.character_mut(*target_id) else {
+                return;
+            };
+            character.set_exhaustion_level(*new_level, "Exhaustion");
+            // Level 4 halves the hit point maximum
+            let max_hp = character.effective_max_hp();
+            character.hit_points.current = character.hit_points.current.min(max_hp);
         }
         Effect::CombatStarted => {
             world.start_combat();
@@ -80,21 +272,36 @@ pub fn apply_effect(world: &mut GameWorld, effect: &Effect) {
             id,
             name,
             initiative,
+            dex_modifier,
             is_ally,
             current_hp,
             max_hp,
             armor_class,
+            resistances,
+            legendary_actions,
+            position,
         } => {
+            let is_player = world.character(*id).is_some();
             if let Some(ref mut combat) = world.combat {
                 combat.add_combatant(Combatant {
                     id: *id,
                     name: name.clone(),
                     initiative: *initiative,
-                    is_player: *id == world.player_character.id,
+                    dex_modifier: *dex_modifier,
+                    is_player,
                     is_ally: *is_ally,
                     current_hp: *current_hp,
                     max_hp: *max_hp,
                     armor_class: *armor_class,
+                    conditions: Vec::new(),
+                    ongoing_effects: Vec::new(),
+                    resistances: resistances.clone(),
+                    reaction_used: false,
+                    dodging: false,
+                    helped_by: None,
+                    legendary_actions: *legendary_actions,
+                    legendary_max: *legendary_actions,
+                    position: *position,
                 });
             }
         }
@@ -103,28 +310,107 @@ pub fn apply_effect(world: &mut GameWorld, effect: &Effect) {
                 combat.next_turn();
             }
 
+            // Ongoing effects tick down when their bearer's turn starts
+            let current_id = world
+                .combat
+                .as_ref()
+                .and_then(|combat| combat.current_combatant())
+                .map(|c| c.id);
+            if let Some(id) = current_id {
+                if let Some(character) = world.character_mut(id) {
+                    character.ongoing_effects.retain_mut(|e| e.tick());
+                } else if let Some(combatant) = world
+                    .combat
+                    .as_mut()
+                    .and_then(|combat| combat.combatant_mut(id))
+                {
+                    combatant.ongoing_effects.retain_mut(|e| e.tick());
+                }
+            }
+
             // Decrement condition durations and remove expired conditions
-            world.player_character.conditions.retain_mut(|c| {
-                if let Some(ref mut duration) = c.duration_rounds {
-                    if *duration > 0 {
-                        *duration -= 1;
+            for character in world.party_members_mut() {
+                character.conditions.retain_mut(|c| {
+                    if let Some(ref mut duration) = c.duration_rounds {
+                        if *duration > 0 {
+                            *duration -= 1;
+                        }
+                        *duration > 0 // Keep only if duration remaining
+                    } else {
+                        true // Keep permanent conditions
                     }
-                    *duration > 0 // Keep only if duration remaining
-                } else {
-                    true // Keep permanent conditions
+                });
+            }
+            if let Some(ref mut combat) = world.combat {
+                for combatant in &mut combat.combatants {
+                    combatant
+                        .conditions
+                        .retain_mut(|c| match c.duration_rounds {
+                            Some(ref mut duration) => {
+                                *duration = duration.saturating_sub(1);
+                                *duration > 0
+                            }
+                            None => true,
+                        });
                 }
-            });
+            }
         }
         Effect::TimeAdvanced { minutes } => {
             world.game_time.advance_minutes(*minutes);
+            world.advance_weather(*minutes);
+            // The chance to prepare spells passes once the day gets going
+            if world.mode == GameMode::Rest {
+                world.mode = GameMode::Exploration;
+            }
+        }
+        Effect::WeatherChanged { weather } => {
+            world.set_weather(*weather);
+        }
+        Effect::SkillProficiencyGained {
+            character_id,
+            skill,
+        } => {
+            if let Some(character) = world.character_mut(*character_id) {
+                let level = character.skill_proficiencies.entry(*skill).or_default();
+                if matches!(level, ProficiencyLevel::None | ProficiencyLevel::Half) {
+                    *level = ProficiencyLevel::Proficient;
+                }
+            }
         }
         Effect::RestCompleted { rest_type } => match rest_type {
             RestType::Short => world.short_rest(),
             RestType::Long => world.long_rest(),
         },
+        Effect::HitDiceSpent {
+            character_id,
+            die_type,
+            count,
+        } => {
+            if let Some(character) = world.character_mut(*character_id) {
+                for _ in 0..*count {
+                    character.hit_dice.spend(*die_type);
+                }
+            }
+        }
         Effect::ExperienceGained { amount, .. } => {
             world.player_character.experience += amount;
         }
+        Effect::InspirationGranted { character_id } => {
+            if let Some(character) = world.character_mut(*character_id) {
+                character.has_inspiration = true;
+            }
+        }
+        Effect::InspirationSpent { character_id } => {
+            if let Some(character) = world.character_mut(*character_id) {
+                character.has_inspiration = false;
+                character.inspiration_active = true;
+            }
+        }
+        Effect::InspirationConsumed { character_id } => {
+            if let Some(character) = world.character_mut(*character_id) {
+                character.inspiration_active = false;
+            }
+        }
         Effect::LevelUp { new_level } => {
             let character = &mut world.player_character;
             let old_level = character.level;
@@ -135,8 +421,13 @@ pub fn apply_effect(world: &mut GameWorld, effect: &Effect) {
                 let class = class_level.class;
                 let hit_die = class.hit_die();
 
-                // Update class level
-                class_level.level = *new_level;
+                // Update class level (the primary class takes the new levels)
+                let old_class_level = class_level.level;
+                class_level.level = class_level
+                    .level
+                    .saturating_add(new_level.saturating_sub(old_level));
+                // Class features scale with the class's own level, not the total
+                let class_level = class_level.level;
 
                 // Add HP: roll hit die + CON modifier (use average for consistency)
                 // Average is (max/2 + 1), e.g., d8 = 5, d10 = 6, d12 = 7
@@ -151,9 +442,30 @@ pub fn apply_effect(world: &mut GameWorld, effect: &Effect) {
 
                 // Update spell slots for spellcasters
                 if let Some(spellcasting_ability) = class.spellcasting_ability() {
-                    let new_slots = class.spell_slots_at_level(*new_level);
+                    // Multiclass casters combine their caster levels for regular slots
+                    let slot_classes = character
+                        .classes
+                        .iter()
+                        .filter(|c| {
+                            c.class.spellcasting_ability().is_some()
+                                && c.class != CharacterClass::Warlock
+                        })
+                        .count();
+                    let new_slots = if slot_classes > 1 {
+                        multiclass_spell_slots(&character.classes)
+                    } else {
+                        class.spell_slots_at_level(class_level)
+                    };
+                    let new_pact = PactMagic::for_warlock_level(class_level)
+                        .filter(|_| class == CharacterClass::Warlock);
 
                     if let Some(ref mut spellcasting) = character.spellcasting {
+                        // Pact slots change level as a whole; keep expended slots expended
+                        if let Some(mut pact) = new_pact {
+                            let old_used = spellcasting.pact_slots.map_or(0, |old| old.used);
+                            pact.used = old_used.min(pact.total);
+                            spellcasting.pact_slots = Some(pact);
+                        }
                         for (i, &total) in new_slots.iter().enumerate() {
                             let old_total = spellcasting.spell_slots.slots[i].total;
                             spellcasting.spell_slots.slots[i].total = total;
@@ -166,7 +478,7 @@ pub fn apply_effect(world: &mut GameWorld, effect: &Effect) {
                                         .saturating_sub(gained);
                             }
                         }
-                    } else if new_slots.iter().any(|&s| s > 0) {
+                    } else if new_slots.iter().any(|&s| s > 0) || new_pact.is_some() {
                         // Class just gained spellcasting (e.g., Paladin/Ranger at level 2)
                         character.spellcasting = Some(SpellcastingData {
                             ability: spellcasting_ability,
@@ -179,55 +491,54 @@ pub fn apply_effect(world: &mut GameWorld, effect: &Effect) {
                                     used: 0,
                                 }),
                             },
+                            pact_slots: new_pact,
                         });
                     }
 
                     // Track spell learning capacity changes for narrative purposes
                     // (actual spell selection happens via DM tools or character choices)
-                    let old_cantrips = class.cantrips_known_at_level(old_level);
-                    let new_cantrips = class.cantrips_known_at_level(*new_level);
+                    let old_cantrips = class.cantrips_known_at_level(old_class_level);
+                    let new_cantrips = class.cantrips_known_at_level(class_level);
                     let _cantrips_gained = new_cantrips.saturating_sub(old_cantrips);
 
                     // For "known" casters, track new spell capacity
-                    if let Some(old_known) = class.spells_known_at_level(old_level) {
-                        if let Some(new_known) = class.spells_known_at_level(*new_level) {
+                    if let Some(old_known) = class.spells_known_at_level(old_class_level) {
+                        if let Some(new_known) = class.spells_known_at_level(class_level) {
                             let _spells_gained = new_known.saturating_sub(old_known);
                             // Player can now learn more spells up to new_known total
                         }
                     }
 
                     // For Wizard, track spellbook expansion
-                    let _wizard_spells_added = class.wizard_spellbook_spells_at_level(*new_level);
+                    let _wizard_spells_added = class.wizard_spellbook_spells_at_level(class_level);
                 }
 
                 // Update class resources based on class and level
                 match class {
                     CharacterClass::Monk => {
                         // Ki points = Monk level
-                        character.class_resources.max_ki_points = *new_level;
-                        character.class_resources.ki_points = *new_level;
+                        character.class_resources.max_ki_points = class_level;
+                        character.class_resources.ki_points = class_level;
                     }
-                    CharacterClass::Sorcerer => {
+                    CharacterClass::Sorcerer if class_level >= 2 => {
                         // Sorcery points = Sorcerer level (gained at level 2)
-                        if *new_level >= 2 {
-                            character.class_resources.max_sorcery_points = *new_level;
-                            // Give the new points
-                            let gained = *new_level - old_level;
-                            character.class_resources.sorcery_points =
-                                (character.class_resources.sorcery_points + gained)
-                                    .min(character.class_resources.max_sorcery_points);
-                        }
+                        character.class_resources.max_sorcery_points = class_level;
+                        // Give the new points
+                        let gained = class_level - old_class_level;
+                        character.class_resources.sorcery_points =
+                            (character.class_resources.sorcery_points + gained)
+                                .min(character.class_resources.max_sorcery_points);
                     }
                     CharacterClass::Paladin => {
                         // Lay on Hands pool = 5 × Paladin level
-                        character.class_resources.lay_on_hands_max = (*new_level as u32) * 5;
+                        character.class_resources.lay_on_hands_max = (class_level as u32) * 5;
                         // Restore to full on level up
                         character.class_resources.lay_on_hands_pool =
                             character.class_resources.lay_on_hands_max;
                     }
                     CharacterClass::Barbarian => {
                         // Rage uses increase at certain levels
-                        let rage_uses = match *new_level {
+                        let rage_uses = match class_level {
                             1..=2 => 2,
                             3..=5 => 3,
                             6..=11 => 4,
@@ -246,7 +557,7 @@ pub fn apply_effect(world: &mut GameWorld, effect: &Effect) {
                             }
                         }
                         // Rage damage bonus increases at levels 9 and 16
-                        character.class_resources.rage_damage_bonus = match *new_level {
+                        character.class_resources.rage_damage_bonus = match class_level {
                             1..=8 => 2,
                             9..=15 => 3,
                             16..=20 => 4,
@@ -258,23 +569,31 @@ pub fn apply_effect(world: &mut GameWorld, effect: &Effect) {
             }
         }
         Effect::FeatureUsed {
+            character_id,
             feature_name,
             uses_remaining,
         } => {
-            if let Some(feature) = world
-                .player_character
-                .features
-                .iter_mut()
-                .find(|f| f.name == *feature_name)
-            {
+            if let Some(feature) = world.character_mut(*character_id).and_then(|character| {
+                character
+                    .features
+                    .iter_mut()
+                    .find(|f| f.name == *feature_name)
+            }) {
                 if let Some(ref mut uses) = feature.uses {
                     uses.current = *uses_remaining;
                 }
             }
         }
-        Effect::SpellSlotUsed { level, .. } => {
-            if let Some(ref mut spellcasting) = world.player_character.spellcasting {
-                spellcasting.spell_slots.use_slot(*level);
+        Effect::SpellSlotUsed {
+            character_id,
+            level,
+            ..
+        } => {
+            if let Some(spellcasting) = world
+                .character_mut(*character_id)
+                .and_then(|character| character.spellcasting.as_mut())
+            {
+                spellcasting.use_slot(*level);
             }
         }
         // Effects that don't modify state (informational)
@@ -283,7 +602,16 @@ pub fn apply_effect(world: &mut GameWorld, effect: &Effect) {
         Effect::CheckFailed { .. } => {}
         Effect::AttackHit { .. } => {}
         Effect::AttackMissed { .. } => {}
-        Effect::InitiativeRolled { .. } => {}
+        // A combatant already in the fight keeps the new roll for the next round
+        Effect::InitiativeRolled {
+            character_id,
+            total,
+            ..
+        } => {
+            if let Some(ref mut combat) = world.combat {
+                combat.set_initiative(*character_id, *total);
+            }
+        }
         Effect::SneakAttackUsed { character_id, .. } => {
             // Mark that this character has used their sneak attack this turn
             if let Some(ref mut combat) = world.combat {
@@ -314,6 +642,7 @@ pub fn apply_effect(world: &mut GameWorld, effect: &Effect) {
                     description: None,
                     item_type: ItemType::Other,
                     magical: false,
+                    requires_attunement: false,
                 }
             };
             world.player_character.inventory.add_item(item);
@@ -331,27 +660,26 @@ pub fn apply_effect(world: &mut GameWorld, effect: &Effect) {
         Effect::ItemEquipped { item_name, slot } => {
             // Look up item from database for proper stats, fall back to defaults
             match slot.as_str() {
-                "armor" => {
+                "armor"
                     if world
                         .player_character
                         .inventory
                         .find_item(item_name)
-                        .is_some()
-                    {
-                        // Try to get proper armor stats from database
-                        let armor = if let Some(db_armor) = crate::items::get_armor(item_name) {
-                            db_armor
-                        } else {
-                            // Fall back to medium armor defaults
-                            crate::world::ArmorItem::new(
-                                item_name.clone(),
-                                crate::world::ArmorType::Medium,
-                                14,
-                            )
-                        };
-                        world.player_character.equipment.armor = Some(armor);
-                        world.player_character.inventory.remove_item(item_name, 1);
-                    }
+                        .is_some() =>
+                {
+                    // Try to get proper armor stats from database
+                    let armor = if let Some(db_armor) = crate::items::get_armor(item_name) {
+                        db_armor
+                    } else {
+                        // Fall back to medium armor defaults
+                        crate::world::ArmorItem::new(
+                            item_name.clone(),
+                            crate::world::ArmorType::Medium,
+                            14,
+                        )
+                    };
+                    world.player_character.equipment.armor = Some(armor);
+                    world.player_character.inventory.remove_item(item_name, 1);
                 }
                 "shield" => {
                     if let Some(item) = world.player_character.inventory.find_item(item_name) {
@@ -360,27 +688,26 @@ pub fn apply_effect(world: &mut GameWorld, effect: &Effect) {
                         world.player_character.inventory.remove_item(item_name, 1);
                     }
                 }
-                "main_hand" | "weapon" => {
+                "main_hand" | "weapon"
                     if world
                         .player_character
                         .inventory
                         .find_item(item_name)
-                        .is_some()
-                    {
-                        // Try to get proper weapon stats from database
-                        let weapon = if let Some(db_weapon) = crate::items::get_weapon(item_name) {
-                            db_weapon
-                        } else {
-                            // Fall back to generic 1d8 slashing
-                            crate::world::WeaponItem::new(
-                                item_name.clone(),
-                                "1d8",
-                                crate::world::WeaponDamageType::Slashing,
-                            )
-                        };
-                        world.player_character.equipment.main_hand = Some(weapon);
-                        world.player_character.inventory.remove_item(item_name, 1);
-                    }
+                        .is_some() =>
+                {
+                    // Try to get proper weapon stats from database
+                    let weapon = if let Some(db_weapon) = crate::items::get_weapon(item_name) {
+                        db_weapon
+                    } else {
+                        // Fall back to generic 1d8 slashing
+                        crate::world::WeaponItem::new(
+                            item_name.clone(),
+                            "1d8",
+                            crate::world::WeaponDamageType::Slashing,
+                        )
+                    };
+                    world.player_character.equipment.main_hand = Some(weapon);
+                    world.player_character.inventory.remove_item(item_name, 1);
                 }
                 "off_hand" => {
                     if let Some(item) = world.player_character.inventory.find_item(item_name) {
@@ -392,6 +719,17 @@ pub fn apply_effect(world: &mut GameWorld, effect: &Effect) {
                 _ => {}
             }
         }
+        Effect::ItemAttuned { item_name } => {
+            if !world.player_character.is_attuned_to(item_name) {
+                world.player_character.attuned.push(item_name.clone());
+            }
+        }
+        Effect::ItemUnattuned { item_name } => {
+            world
+                .player_character
+                .attuned
+                .retain(|name| !name.eq_ignore_ascii_case(item_name));
+        }
         Effect::ItemUnequipped { slot, .. } => match slot.as_str() {
             "armor" => {
                 if let Some(armor) = world.player_character.equipment.armor.take() {
@@ -426,37 +764,137 @@ pub fn apply_effect(world: &mut GameWorld, effect: &Effect) {
         // AcChanged is informational - AC is recalculated from equipment
         Effect::AcChanged { .. } => {}
 
-        Effect::DeathSaveFailure { failures, .. } => {
-            for _ in 0..*failures {
-                world.player_character.death_saves.add_failure();
+        Effect::DeathSaveFailure {
+            target_id,
+            failures,
+            ..
+        } => {
+            if let Some(character) = world.character_mut(*target_id) {
+                for _ in 0..*failures {
+                    character.death_saves.add_failure();
+                }
             }
         }
 
-        Effect::DeathSavesReset { .. } => {
-            world.player_character.death_saves.reset();
+        Effect::DeathSavesReset { target_id } => {
+            if let Some(character) = world.character_mut(*target_id) {
+                character.death_saves.reset();
+            }
         }
 
-        Effect::CharacterDied { .. } => {
-            // Character death is tracked via the effect itself
-            // The UI/game can check for this effect and handle appropriately
-            // For now, we don't modify world state further (could add a `dead: bool` flag)
+        Effect::CharacterDied { target_id, .. } => {
+            if let Some(character) = world.character_mut(*target_id) {
+                character.life_state = LifeState::Dead;
+            }
         }
 
         Effect::DeathSaveSuccess {
-            total_successes, ..
+            target_id,
+            total_successes,
+            ..
         } => {
-            world.player_character.death_saves.successes = *total_successes;
+            if let Some(character) = world.character_mut(*target_id) {
+                character.death_saves.successes = *total_successes;
+            }
         }
 
-        Effect::Stabilized { .. } => {
+        Effect::Stabilized { target_id } => {
             // Character is stable - still unconscious but no longer making death saves
-            world.player_character.death_saves.reset();
+            if let Some(character) = world.character_mut(*target_id) {
+                character.death_saves.reset();
+                if !character.is_conscious() && !character.is_dead() {
+                    character.life_state = LifeState::Stable;
+                }
+            }
             // Note: Character remains Unconscious until healed
         }
 
-        Effect::ConcentrationBroken { .. } => {
-            // Concentration tracking would be handled here if we had it
-            // For now, this is informational for the UI/narrative
+        Effect::DodgeActive { character_id } => {
+            if let Some(combatant) = world
+                .combat
+                .as_mut()
+                .and_then(|combat| combat.combatant_mut(*character_id))
+            {
+                combatant.dodging = true;
+            }
+        }
+
+        Effect::HelpGranted {
+            helper_id,
+            target_id,
+        } => {
+            if let Some(combatant) = world
+                .combat
+                .as_mut()
+                .and_then(|combat| combat.combatant_mut(*target_id))
+            {
+                combatant.helped_by = Some(*helper_id);
+            }
+        }
+
+        Effect::HelpConsumed { character_id } => {
+            if let Some(combatant) = world
+                .combat
+                .as_mut()
+                .and_then(|combat| combat.combatant_mut(*character_id))
+            {
+                combatant.helped_by = None;
+            }
+        }
+
+        Effect::ReactionUsed { character_id } => {
+            if let Some(ref mut combat) = world.combat {
+                combat.mark_reaction(*character_id);
+            }
+        }
+        Effect::BonusActionUsed { character_id } => {
+            if let Some(ref mut combat) = world.combat {
+                combat.mark_bonus_action(*character_id);
+            }
+        }
+        Effect::LairActionAdded { action } => {
+            if let Some(ref mut combat) = world.combat {
+                combat.lair_actions.push(action.clone());
+            }
+        }
+        Effect::LairActionTriggered { .. } => {
+            if let Some(ref mut combat) = world.combat {
+                combat.lair_action_used_this_round = true;
+            }
+        }
+        Effect::LegendaryActionUsed {
+            combatant_id,
+            remaining,
+            ..
+        } => {
+            if let Some(combatant) = world
+                .combat
+                .as_mut()
+                .and_then(|combat| combat.combatant_mut(*combatant_id))
+            {
+                combatant.legendary_actions = *remaining;
+            }
+        }
+        // The countered spell itself was never resolved, so there's nothing to undo
+        Effect::SpellCountered { .. } => {}
+
+        Effect::ConcentrationStarted {
+            character_id,
+            spell_name,
+            slot_level,
+        } => {
+            if let Some(character) = world.character_mut(*character_id) {
+                character.concentrating_on = Some(ConcentrationState {
+                    spell_name: spell_name.clone(),
+                    slot_level: *slot_level,
+                });
+            }
+        }
+
+        Effect::ConcentrationBroken { character_id, .. } => {
+            if let Some(character) = world.character_mut(*character_id) {
+                character.concentrating_on = None;
+            }
         }
 
         Effect::ConcentrationMaintained { .. } => {
@@ -470,7 +908,7 @@ pub fn apply_effect(world: &mut GameWorld, effect: &Effect) {
             // This effect is informational for the rules layer
         }
         Effect::ConsequenceTriggered { .. } => {
-            // Consequence triggering is handled by the relevance checker
+            // Consequence triggering is handled by story memory and the relevance checker
             // This effect is informational for the UI/narrative
         }
         Effect::ClassResourceUsed { .. } => {
@@ -478,16 +916,24 @@ pub fn apply_effect(world: &mut GameWorld, effect: &Effect) {
             // The actual state changes are handled by the DM based on the effect
             // This effect is informational for the narrative/UI
         }
-        Effect::RageStarted { damage_bonus, .. } => {
-            world.player_character.class_resources.rage_active = true;
-            world.player_character.class_resources.rage_damage_bonus = *damage_bonus;
-            world.player_character.class_resources.rage_rounds_remaining = Some(10);
-            // 1 minute = 10 rounds
+        Effect::RageStarted {
+            character_id,
+            damage_bonus,
+        } => {
+            if let Some(character) = world.character_mut(*character_id) {
+                let resources = &mut character.class_resources;
+                resources.rage_active = true;
+                resources.rage_damage_bonus = *damage_bonus;
+                resources.rage_rounds_remaining = Some(10); // 1 minute = 10 rounds
+            }
         }
-        Effect::RageEnded { .. } => {
-            world.player_character.class_resources.rage_active = false;
-            world.player_character.class_resources.rage_damage_bonus = 0;
-            world.player_character.class_resources.rage_rounds_remaining = None;
+        Effect::RageEnded { character_id, .. } => {
+            if let Some(character) = world.character_mut(*character_id) {
+                let resources = &mut character.class_resources;
+                resources.rage_active = false;
+                resources.rage_damage_bonus = 0;
+                resources.rage_rounds_remaining = None;
+            }
         }
 
         // Quest effects
@@ -643,25 +1089,19 @@ pub fn apply_effect(world: &mut GameWorld, effect: &Effect) {
             }
         }
 
+        Effect::ReputationChanged {
+            faction, current, ..
+        } => {
+            world.set_reputation(faction, *current);
+        }
+
         Effect::LocationCreated {
             name,
             location_type,
         } => {
             use crate::world::{Location, LocationType};
 
-            let loc_type = match location_type.to_lowercase().as_str() {
-                "wilderness" => LocationType::Wilderness,
-                "town" => LocationType::Town,
-                "city" => LocationType::City,
-                "dungeon" => LocationType::Dungeon,
-                "building" => LocationType::Building,
-                "room" => LocationType::Room,
-                "road" => LocationType::Road,
-                "cave" => LocationType::Cave,
-                _ => LocationType::Other,
-            };
-
-            let location = Location::new(name.clone(), loc_type);
+            let location = Location::new(name.clone(), LocationType::from_name(location_type));
             world.known_locations.insert(location.id, location);
         }
 
@@ -669,32 +1109,14 @@ pub fn apply_effect(world: &mut GameWorld, effect: &Effect) {
             from,
             to,
             direction,
+            travel_time_minutes,
+            bidirectional,
         } => {
-            use crate::world::LocationConnection;
-
-            // Find the source and destination locations
-            let from_id = world
-                .known_locations
-                .values()
-                .find(|l| l.name.eq_ignore_ascii_case(from))
-                .map(|l| l.id);
-
-            let to_loc = world
-                .known_locations
-                .values()
-                .find(|l| l.name.eq_ignore_ascii_case(to));
-
-            if let (Some(from_id), Some(to_loc)) = (from_id, to_loc) {
-                let connection = LocationConnection {
-                    destination_id: to_loc.id,
-                    destination_name: to_loc.name.clone(),
-                    direction: direction.clone(),
-                    travel_time_minutes: 0,
-                };
-
-                if let Some(from_loc) = world.known_locations.get_mut(&from_id) {
-                    from_loc.connections.push(connection);
-                }
+            let minutes = travel_time_minutes.unwrap_or(0);
+            connect_locations(world, from, to, direction.clone(), minutes);
+            if *bidirectional {
+                let back = direction.as_deref().and_then(opposite_direction);
+                connect_locations(world, to, from, back, minutes);
             }
         }
 
@@ -721,12 +1143,12 @@ pub fn apply_effect(world: &mut GameWorld, effect: &Effect) {
 
         Effect::SpellSlotRestored { level, .. } => {
             if let Some(ref mut spellcasting) = world.player_character.spellcasting {
-                if *level >= 1 && *level <= 9 {
-                    let slot_idx = (*level - 1) as usize;
-                    if spellcasting.spell_slots.slots[slot_idx].used > 0 {
-                        spellcasting.spell_slots.slots[slot_idx].used -= 1;
-                    }
-                }
+                spellcasting.restore_slot(*level);
+            }
+        }
+        Effect::SpellsPrepared { spells } => {
+            if let Some(ref mut spellcasting) = world.player_character.spellcasting {
+                spellcasting.spells_prepared = spells.clone();
             }
         }
         Effect::StateAsserted {
@@ -815,6 +1237,44 @@ pub fn apply_effect(world: &mut GameWorld, effect: &Effect) {
     }
 }
 
+/// Add (or update) a one-way path between two known locations, by name.
+fn connect_locations(
+    world: &mut GameWorld,
+    from: &str,
+    to: &str,
+    direction: Option<String>,
+    travel_time_minutes: u32,
+) {
+    let Some(to_loc) = world
+        .known_locations
+        .values()
+        .find(|l| l.name.eq_ignore_ascii_case(to))
+    else {
+        return;
+    };
+    let connection = LocationConnection {
+        destination_id: to_loc.id,
+        destination_name: to_loc.name.clone(),
+        direction,
+        travel_time_minutes,
+    };
+
+    if let Some(from_loc) = world
+        .known_locations
+        .values_mut()
+        .find(|l| l.name.eq_ignore_ascii_case(from))
+    {
+        match from_loc
+            .connections
+            .iter_mut()
+            .find(|c| c.destination_id == connection.destination_id)
+        {
+            Some(existing) => *existing = connection,
+            None => from_loc.connections.push(connection),
+        }
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -823,6 +1283,82 @@ mod tests {
         create_sample_monk, create_sample_paladin, create_sample_sorcerer, GameWorld,
     };
 
+    #[test]
+    fn test_try_apply_effects_rolls_back_invalid_spell_slot() {
+        let character = create_sample_cleric("Mira");
+        let mut world = GameWorld::new("Test", character);
+        let hp_before = world.player_character.hit_points.current;
+        let slots_before = world
+            .player_character
+            .spellcasting
+            .as_ref()
+            .unwrap()
+            .available_slots(1);
+        assert!(slots_before > 0);
+
+        let effects = vec![
+            Effect::HpChanged {
+                target_id: world.player_character.id,
+                amount: -3,
+                new_current: hp_before - 3,
+                new_max: world.player_character.hit_points.maximum,
+                dropped_to_zero: false,
+            },
+            Effect::SpellSlotUsed {
+                character_id: world.player_character.id,
+                level: 1,
+                remaining: slots_before - 1,
+            },
+            Effect::SpellSlotUsed {
+                character_id: world.player_character.id,
+                level: 9,
+                remaining: 0,
+            },
+        ];
+
+        let result = try_apply_effects(&mut world, &effects);
+        assert_eq!(result, Err(ApplyError::NoSpellSlot { level: 9 }));
+        assert_eq!(world.player_character.hit_points.current, hp_before);
+        assert_eq!(
+            world
+                .player_character
+                .spellcasting
+                .as_ref()
+                .unwrap()
+                .available_slots(1),
+            slots_before
+        );
+    }
+
+    #[test]
+    fn test_try_apply_effects_applies_valid_batch() {
+        let character = create_sample_cleric("Mira");
+        let mut world = GameWorld::new("Test", character);
+        let slots_before = world
+            .player_character
+            .spellcasting
+            .as_ref()
+            .unwrap()
+            .available_slots(1);
+
+        let effects = vec![Effect::SpellSlotUsed {
+            character_id: world.player_character.id,
+            level: 1,
+            remaining: slots_before - 1,
+        }];
+
+        assert!(try_apply_effects(&mut world, &effects).is_ok());
+        assert_eq!(
+            world
+                .player_character
+                .spellcasting
+                .as_ref()
+                .unwrap()
+                .available_slots(1),
+            slots_before - 1
+        );
+    }
+
     // Helper function to create a level up effect
     fn level_up_effect(new_level: u8) -> Effect {
         Effect::LevelUp { new_level }
@@ -830,6 +1366,63 @@ mod tests {
 
     // ========== Fighter Level Up Tests ==========
 
+    #[test]
+    fn test_effects_apply_to_the_party_member_named() {
+        let mut world = GameWorld::new("Test", create_sample_fighter("Roland"));
+        let cleric = create_sample_cleric("Mira");
+        let cleric_id = cleric.id;
+        world.add_party_member(cleric);
+        let member = |world: &GameWorld| world.character(cleric_id).unwrap().clone();
+        let slots_before = member(&world).spellcasting.unwrap().available_slots(1);
+
+        let effects = vec![
+            Effect::SpellSlotUsed {
+                character_id: cleric_id,
+                level: 1,
+                remaining: slots_before - 1,
+            },
+            Effect::InspirationGranted {
+                character_id: cleric_id,
+            },
+            Effect::ConditionApplied {
+                target_id: cleric_id,
+                condition: Condition::Frightened,
+                source: "Cause Fear".to_string(),
+                duration_rounds: Some(1),
+            },
+        ];
+        assert!(try_apply_effects(&mut world, &effects).is_ok());
+
+        let cleric = member(&world);
+        assert_eq!(
+            cleric.spellcasting.unwrap().available_slots(1),
+            slots_before - 1
+        );
+        assert!(cleric.has_inspiration);
+        assert!(!world.player_character.has_inspiration);
+
+        // Timed conditions run out on members who aren't the active character
+        apply_effect(
+            &mut world,
+            &Effect::TurnAdvanced {
+                round: 1,
+                current_combatant: "Roland".to_string(),
+            },
+        );
+        assert!(!member(&world).has_condition(Condition::Frightened));
+
+        // The fighter has no slots of their own to spend
+        let effects = vec![Effect::SpellSlotUsed {
+            character_id: world.player_character.id,
+            level: 1,
+            remaining: 0,
+        }];
+        assert_eq!(
+            try_apply_effects(&mut world, &effects),
+            Err(ApplyError::NoSpellSlot { level: 1 })
+        );
+    }
+
     #[test]
     fn test_fighter_level_up_hp_increases() {
         let character = create_sample_fighter("Roland");
@@ -1083,6 +1676,151 @@ mod tests {
         assert_eq!(spellcasting.spell_slots.slots[2].total, 2); // 3rd level
     }
 
+    #[test]
+    fn test_warlock_pact_slots_scale_with_level() {
+        let mut character = create_sample_sorcerer("Vex");
+        character.classes[0].class = CharacterClass::Warlock;
+        let spellcasting = character.spellcasting.as_mut().unwrap();
+        spellcasting.pact_slots = PactMagic::for_warlock_level(3);
+        spellcasting.use_slot(2);
+        let mut world = GameWorld::new("Test", character);
+
+        apply_effect(&mut world, &level_up_effect(4));
+        apply_effect(&mut world, &level_up_effect(5));
+
+        let spellcasting = world.player_character.spellcasting.as_ref().unwrap();
+        // Warlock level 5: two 3rd-level pact slots, the expended one stays expended
+        let pact = spellcasting.pact_slots.unwrap();
+        assert_eq!((pact.slot_level, pact.total, pact.used), (3, 2, 1));
+        assert!(spellcasting.spell_slots.slots.iter().all(|s| s.total == 0));
+    }
+
+    // ========== Multiclass Spell Slot Tests ==========
+
+    fn class_levels(levels: &[(CharacterClass, u8)]) -> Vec<crate::world::ClassLevel> {
+        levels
+            .iter()
+            .map(|&(class, level)| crate::world::ClassLevel {
+                class,
+                level,
+                subclass: None,
+            })
+            .collect()
+    }
+
+    #[test]
+    fn test_multiclass_level_up_scales_by_class_level() {
+        // Warlock 3 / Fighter 5 reaching level 9 is a 4th-level Warlock
+        let mut character = create_sample_sorcerer("Vex");
+        character.classes[0].class = CharacterClass::Warlock;
+        character.classes[0].level = 3;
+        character
+            .classes
+            .extend(class_levels(&[(CharacterClass::Fighter, 5)]));
+        character.level = 8;
+        character.spellcasting.as_mut().unwrap().pact_slots = PactMagic::for_warlock_level(3);
+        let mut world = GameWorld::new("Test", character);
+
+        apply_effect(&mut world, &level_up_effect(9));
+
+        let pact = world
+            .player_character
+            .spellcasting
+            .as_ref()
+            .unwrap()
+            .pact_slots
+            .unwrap();
+        assert_eq!((pact.slot_level, pact.total), (2, 2));
+
+        // Sorcerer 2 / Fighter 5 reaching level 8 is a 3rd-level Sorcerer
+        let mut character = create_sample_sorcerer("Ignis");
+        character.classes[0].level = 2;
+        character
+            .classes
+            .extend(class_levels(&[(CharacterClass::Fighter, 5)]));
+        character.level = 7;
+        let mut world = GameWorld::new("Test", character);
+
+        apply_effect(&mut world, &level_up_effect(8));
+
+        let character = &world.player_character;
+        let totals: Vec<u8> = character
+            .spellcasting
+            .as_ref()
+            .unwrap()
+            .spell_slots
+            .slots
+            .iter()
+            .map(|s| s.total)
+            .collect();
+        assert_eq!(totals, vec![4, 2, 0, 0, 0, 0, 0, 0, 0]);
+        assert_eq!(character.class_resources.max_sorcery_points, 3);
+    }
+
+    #[test]
+    fn test_multiclass_slots_full_casters() {
+        // Cleric 3 / Wizard 2 = caster level 5
+        let classes = class_levels(&[(CharacterClass::Cleric, 3), (CharacterClass::Wizard, 2)]);
+        assert_eq!(
+            multiclass_spell_slots(&classes),
+            [4, 3, 2, 0, 0, 0, 0, 0, 0]
+        );
+    }
+
+    #[test]
+    fn test_multiclass_slots_half_caster_rounds_down() {
+        // Paladin 5 / Sorcerer 3 = 2 + 3 = caster level 5
+        let classes = class_levels(&[(CharacterClass::Paladin, 5), (CharacterClass::Sorcerer, 3)]);
+        assert_eq!(
+            multiclass_spell_slots(&classes),
+            [4, 3, 2, 0, 0, 0, 0, 0, 0]
+        );
+
+        // Ranger 3 / Druid 2 = 1 + 2 = caster level 3
+        let classes = class_levels(&[(CharacterClass::Ranger, 3), (CharacterClass::Druid, 2)]);
+        assert_eq!(
+            multiclass_spell_slots(&classes),
+            [4, 2, 0, 0, 0, 0, 0, 0, 0]
+        );
+    }
+
+    #[test]
+    fn test_multiclass_slots_ignore_warlock_and_martial_levels() {
+        let classes = class_levels(&[
+            (CharacterClass::Wizard, 3),
+            (CharacterClass::Warlock, 2),
+            (CharacterClass::Fighter, 4),
+        ]);
+        assert_eq!(
+            multiclass_spell_slots(&classes),
+            [4, 2, 0, 0, 0, 0, 0, 0, 0]
+        );
+    }
+
+    #[test]
+    fn test_multiclass_level_up_combines_caster_levels() {
+        let mut character = create_sample_cleric("Sera");
+        character.classes[0].level = 3;
+        character
+            .classes
+            .extend(class_levels(&[(CharacterClass::Wizard, 1)]));
+        character.level = 4;
+        let mut world = GameWorld::new("Test", character);
+
+        apply_effect(&mut world, &level_up_effect(5));
+
+        // Cleric 4 / Wizard 1 = caster level 5
+        assert_eq!(world.player_character.classes[0].level, 4);
+        let spellcasting = world.player_character.spellcasting.as_ref().unwrap();
+        let totals: Vec<u8> = spellcasting
+            .spell_slots
+            .slots
+            .iter()
+            .map(|s| s.total)
+            .collect();
+        assert_eq!(totals, vec![4, 3, 2, 0, 0, 0, 0, 0, 0]);
+    }
+
     // ========== Full Caster Spell Slot Progression Tests ==========
 
     #[test]
@@ -1,32 +1,147 @@
 //! The RulesEngine struct and main resolve() dispatch method.
 
+use std::sync::{Mutex, PoisonError};
+
+use crate::dice::{self, DiceRng};
+use crate::rules::config::RulesConfig;
 use crate::rules::types::{Intent, Resolution};
-use crate::world::GameWorld;
+use crate::world::{Character, GameWorld};
 
 /// The rules engine resolves intents into effects using D&D 5e rules.
-pub struct RulesEngine;
+pub struct RulesEngine {
+    /// Seeded dice for repeatable resolution; `None` uses thread-local randomness.
+    rng: Option<Mutex<DiceRng>>,
+    /// Optional house rules the resolvers branch on.
+    pub(crate) config: RulesConfig,
+}
 
 impl RulesEngine {
     pub fn new() -> Self {
-        Self
+        Self::new_with_config(RulesConfig::default())
+    }
+
+    /// An engine that applies the given house rules.
+    pub fn new_with_config(config: RulesConfig) -> Self {
+        Self { rng: None, config }
+    }
+
+    /// An engine whose dice rolls repeat exactly for a given seed.
+    ///
+    /// Two engines with the same seed produce the same rolls when given the
+    /// same sequence of intents, which makes combat replayable.
+    pub fn with_seed(seed: u64) -> Self {
+        Self {
+            rng: Some(Mutex::new(DiceRng::seeded(seed))),
+            config: RulesConfig::default(),
+        }
+    }
+
+    /// Use the given house rules, keeping the engine's dice.
+    pub fn with_config(mut self, config: RulesConfig) -> Self {
+        self.config = config;
+        self
+    }
+
+    /// The house rules this engine resolves with.
+    pub fn config(&self) -> &RulesConfig {
+        &self.config
+    }
+
+    /// Whether a character has a feature, ignoring feat-granted ones when feats are off.
+    pub(crate) fn has_feature(&self, character: &Character, name: &str) -> bool {
+        character
+            .features
+            .iter()
+            .any(|f| f.name == name && (self.config.feats || f.source != "Feat"))
     }
 
     /// Resolve an intent and produce effects.
     pub fn resolve(&self, world: &GameWorld, intent: Intent) -> Resolution {
+        match &self.rng {
+            Some(rng) => {
+                let mut rng = rng.lock().unwrap_or_else(PoisonError::into_inner);
+                dice::with_rng(&mut rng, || self.resolve_intent(world, intent))
+            }
+            None => self.resolve_intent(world, intent),
+        }
+    }
+
+    fn resolve_intent(&self, world: &GameWorld, intent: Intent) -> Resolution {
         match intent {
             Intent::Attack {
                 attacker_id,
                 target_id,
                 weapon_name,
                 advantage,
-            } => self.resolve_attack(world, attacker_id, target_id, &weapon_name, advantage),
+                is_offhand,
+                cover,
+                adjacent_enemy,
+                attack_mode,
+            } => self.resolve_attack(
+                world,
+                attacker_id,
+                target_id,
+                &weapon_name,
+                advantage,
+                is_offhand,
+                cover,
+                adjacent_enemy,
+                attack_mode,
+            ),
+            Intent::MultiAttack {
+                attacker_id,
+                target_ids,
+                weapon_name,
+            } => self.resolve_multiattack(world, attacker_id, &target_ids, &weapon_name),
+            Intent::Grapple {
+                grappler_id,
+                target_id,
+                npc_modifier,
+            } => self.resolve_grapple(world, grappler_id, target_id, npc_modifier),
+            Intent::Shove {
+                shover_id,
+                target_id,
+                mode,
+                npc_modifier,
+            } => self.resolve_shove(world, shover_id, target_id, mode, npc_modifier),
+            Intent::OpportunityAttack {
+                attacker_id,
+                target_id,
+                weapon_name,
+            } => self.resolve_opportunity_attack(world, attacker_id, target_id, &weapon_name),
+            Intent::Dodge { character_id } => self.resolve_dodge(world, character_id),
+            Intent::Help {
+                helper_id,
+                target_id,
+                task,
+            } => self.resolve_help(world, helper_id, target_id, &task),
+            Intent::UseLegendaryAction {
+                combatant_id,
+                action_name,
+                cost,
+            } => self.resolve_use_legendary_action(world, combatant_id, &action_name, cost),
+            Intent::Counterspell {
+                caster_id,
+                target_spell_level,
+                slot_level,
+            } => self.resolve_counterspell(world, caster_id, target_spell_level, slot_level),
             Intent::CastSpell {
                 caster_id,
                 spell_name,
                 targets: _,
                 spell_level,
                 target_names,
-            } => self.resolve_cast_spell(world, caster_id, &spell_name, spell_level, &target_names),
+                cover,
+                ritual,
+            } => self.resolve_cast_spell(
+                world,
+                caster_id,
+                &spell_name,
+                spell_level,
+                &target_names,
+                cover,
+                ritual,
+            ),
             Intent::SkillCheck {
                 character_id,
                 skill,
@@ -60,12 +175,18 @@ impl RulesEngine {
                 amount,
                 damage_type,
                 source,
-            } => self.resolve_damage(world, target_id, amount, damage_type, &source),
+                is_critical,
+            } => self.resolve_damage(world, target_id, amount, damage_type, &source, is_critical),
             Intent::Heal {
                 target_id,
                 amount,
                 source,
             } => self.resolve_heal(world, target_id, amount, &source),
+            Intent::GrantTempHp {
+                target_id,
+                amount,
+                source,
+            } => self.resolve_grant_temp_hp(world, target_id, amount, &source),
             Intent::ApplyCondition {
                 target_id,
                 condition,
@@ -78,9 +199,42 @@ impl RulesEngine {
                 target_id,
                 condition,
             } => self.resolve_remove_condition(world, target_id, condition),
+            Intent::ApplyOngoingEffect { target_id, effect } => {
+                self.resolve_apply_ongoing_effect(world, target_id, effect)
+            }
+            Intent::ModifyExhaustion { target_id, delta } => {
+                self.resolve_modify_exhaustion(world, target_id, delta)
+            }
+            Intent::CastSpellAoe {
+                caster_id,
+                spell_name,
+                slot_level,
+                origin,
+                shape,
+                target_names,
+            } => self.resolve_cast_spell_aoe(
+                world,
+                caster_id,
+                &spell_name,
+                slot_level,
+                origin,
+                &shape,
+                &target_names,
+            ),
             Intent::ShortRest => self.resolve_short_rest(world),
             Intent::LongRest => self.resolve_long_rest(world),
-            Intent::StartCombat { combatants } => self.resolve_start_combat(world, combatants),
+            Intent::SpendHitDice {
+                character_id,
+                count,
+            } => self.resolve_spend_hit_dice(world, character_id, count),
+            Intent::StartCombat {
+                combatants,
+                lair_actions,
+                surprised_ids,
+            } => self.resolve_start_combat(world, combatants, lair_actions, &surprised_ids),
+            Intent::TriggerLairAction { index, target_ids } => {
+                self.resolve_trigger_lair_action(world, index, &target_ids)
+            }
             Intent::EndCombat => self.resolve_end_combat(world),
             Intent::NextTurn => self.resolve_next_turn(world),
             Intent::RollInitiative {
@@ -91,7 +245,17 @@ impl RulesEngine {
             } => self.resolve_roll_initiative(character_id, &name, modifier, is_player),
             Intent::RollDice { notation, purpose } => self.resolve_roll_dice(&notation, &purpose),
             Intent::AdvanceTime { minutes } => self.resolve_advance_time(minutes),
+            Intent::SetWeather { weather } => self.resolve_set_weather(world, weather),
+            Intent::Downtime { activity, days } => {
+                crate::downtime::resolve_downtime(&activity, days, world)
+            }
             Intent::GainExperience { amount } => self.resolve_gain_experience(world, amount),
+            Intent::GrantInspiration { character_id } => {
+                self.resolve_grant_inspiration(world, character_id)
+            }
+            Intent::SpendInspiration { character_id } => {
+                self.resolve_spend_inspiration(world, character_id)
+            }
             Intent::UseFeature {
                 character_id,
                 feature_name,
@@ -103,6 +267,7 @@ impl RulesEngine {
                 category,
                 related_entities,
                 importance,
+                supersedes,
             } => self.resolve_remember_fact(
                 &subject_name,
                 &subject_type,
@@ -110,6 +275,7 @@ impl RulesEngine {
                 &category,
                 &related_entities,
                 importance,
+                supersedes,
             ),
             // Inventory intents
             Intent::AddItem {
@@ -136,6 +302,8 @@ impl RulesEngine {
             } => self.resolve_remove_item(world, &item_name, quantity),
             Intent::EquipItem { item_name } => self.resolve_equip_item(world, &item_name),
             Intent::UnequipItem { slot } => self.resolve_unequip_item(world, &slot),
+            Intent::Attune { item_name } => self.resolve_attune(world, &item_name),
+            Intent::Unattune { item_name } => self.resolve_unattune(world, &item_name),
             Intent::UseItem {
                 item_name,
                 target_id,
@@ -146,7 +314,16 @@ impl RulesEngine {
             Intent::AdjustSilver { amount, reason } => {
                 self.resolve_adjust_silver(world, amount, &reason)
             }
+            Intent::GenerateLoot {
+                challenge_rating,
+                hoard,
+            } => self.resolve_generate_loot(world, challenge_rating, hoard),
             Intent::DeathSave { character_id } => self.resolve_death_save(world, character_id),
+            Intent::Stabilize {
+                medic_id,
+                target_id,
+                npc_modifier,
+            } => self.resolve_stabilize(world, medic_id, target_id, npc_modifier),
             Intent::ConcentrationCheck {
                 character_id,
                 damage_taken,
@@ -330,7 +507,12 @@ impl RulesEngine {
                 npc_name,
                 reason,
                 permanent,
-            } => self.resolve_remove_npc(&npc_name, &reason, permanent),
+            } => self.resolve_remove_npc(world, &npc_name, &reason, permanent),
+            Intent::AdjustReputation {
+                faction,
+                delta,
+                reason,
+            } => self.resolve_adjust_reputation(world, &faction, delta, &reason),
             Intent::CreateLocation {
                 name,
                 location_type,
@@ -359,6 +541,10 @@ impl RulesEngine {
                 travel_time_minutes,
                 bidirectional,
             ),
+            Intent::RollEncounter {
+                location_type,
+                party_level,
+            } => self.resolve_roll_encounter(world, location_type, party_level),
             Intent::UpdateLocation {
                 location_name,
                 new_description,
@@ -384,6 +570,7 @@ impl RulesEngine {
             Intent::RestoreSpellSlot { slot_level, source } => {
                 self.resolve_restore_spell_slot(world, slot_level, &source)
             }
+            Intent::PrepareSpells { spells } => self.resolve_prepare_spells(world, &spells),
 
             // State assertion
             Intent::AssertState {
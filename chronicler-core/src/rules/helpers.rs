@@ -1,6 +1,8 @@
 //! Helper functions for the rules engine.
 
 use crate::dice::{self, ComponentResult, DiceExpression, DieType, RollResult};
+use crate::rules::types::{Effect, Resolution};
+use crate::world::{Character, CharacterId, GameWorld};
 
 /// Roll dice with a fallback expression. If both fail, returns a minimal result.
 ///
@@ -38,6 +40,53 @@ pub fn sneak_attack_dice(rogue_level: u8) -> u8 {
     rogue_level.div_ceil(2)
 }
 
+/// The damage dice for a critical hit: the dice are doubled ("1d8" becomes "2d8").
+///
+/// With `max_extra`, the extra dice deal their maximum instead of being rolled
+/// ("1d8" becomes "1d8+8"). A flat value is simply doubled.
+pub fn critical_dice(damage_dice: &str, max_extra: bool) -> String {
+    let Some(d_pos) = damage_dice.find('d') else {
+        let flat: i32 = damage_dice.parse().unwrap_or(1);
+        return format!("{}", flat * 2);
+    };
+    let num_dice: i32 = damage_dice[..d_pos].parse().unwrap_or(1);
+    let die_type = &damage_dice[d_pos..];
+    if max_extra {
+        let sides: String = die_type[1..]
+            .chars()
+            .take_while(char::is_ascii_digit)
+            .collect();
+        let sides: i32 = sides.parse().unwrap_or(1);
+        format!("{damage_dice}+{}", num_dice * sides)
+    } else {
+        format!("{}{}", num_dice * 2, die_type)
+    }
+}
+
+/// Refuse a bonus action when the character already took one this turn.
+pub fn bonus_action_taken(world: &GameWorld, character: &Character) -> Option<Resolution> {
+    let combat = world.combat.as_ref()?;
+    (!combat.can_take_bonus_action(character.id)).then(|| {
+        Resolution::new(format!(
+            "{} has already taken a bonus action this turn.",
+            character.name
+        ))
+    })
+}
+
+/// Record the character's bonus action when it is taken in combat.
+pub fn spend_bonus_action(
+    world: &GameWorld,
+    character_id: CharacterId,
+    resolution: Resolution,
+) -> Resolution {
+    if world.combat.is_some() {
+        resolution.with_effect(Effect::BonusActionUsed { character_id })
+    } else {
+        resolution
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -86,6 +135,23 @@ mod tests {
         assert!(result.total >= 3 && result.total <= 18);
     }
 
+    // ========== critical_dice Tests ==========
+
+    #[test]
+    fn test_critical_dice_doubles_dice() {
+        assert_eq!(critical_dice("1d8", false), "2d8");
+        assert_eq!(critical_dice("2d6", false), "4d6");
+        assert_eq!(critical_dice("1", false), "2");
+    }
+
+    #[test]
+    fn test_critical_dice_max_extra() {
+        assert_eq!(critical_dice("1d8", true), "1d8+8");
+        assert_eq!(critical_dice("2d6", true), "2d6+12");
+        assert_eq!(critical_dice("2d10+3", true), "2d10+3+20");
+        assert_eq!(critical_dice("1", true), "2");
+    }
+
     // ========== sneak_attack_dice Tests ==========
 
     #[test]
@@ -9,6 +9,7 @@
 //! This separation ensures deterministic, testable game mechanics
 //! independent of AI decision-making.
 
+mod config;
 mod effects;
 mod engine;
 mod helpers;
@@ -18,6 +19,10 @@ mod tests;
 mod types;
 
 // Re-export public API
-pub use effects::{apply_effect, apply_effects};
+pub use config::RulesConfig;
+pub use effects::{apply_effect, apply_effects, try_apply_effects, ApplyError};
 pub use engine::RulesEngine;
-pub use types::{CombatantInit, DamageType, Effect, Intent, Resolution, RestType, StateType};
+pub use types::{
+    AreaShape, AttackMode, CombatantInit, Cover, DamageType, Effect, Intent, Resolution, RestType,
+    ShoveMode, StateType,
+};
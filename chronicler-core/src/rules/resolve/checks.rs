@@ -3,7 +3,9 @@
 use crate::dice::{self, Advantage, DiceExpression};
 use crate::rules::types::{Effect, Resolution};
 use crate::rules::RulesEngine;
-use crate::world::{Ability, CharacterId, Condition, GameWorld, Skill};
+use crate::world::{
+    Ability, CharacterClass, CharacterId, Condition, GameWorld, LightLevel, ProficiencyLevel, Skill,
+};
 
 impl RulesEngine {
     pub(crate) fn resolve_skill_check(
@@ -52,21 +54,118 @@ impl RulesEngine {
             advantage
         };
 
+        // Exhaustion level 1+ imposes disadvantage on ability checks
+        let exhausted = character.exhaustion_level() >= 1;
+        let roll_advantage = if exhausted {
+            effective_advantage.combine(Advantage::Disadvantage)
+        } else {
+            effective_advantage
+        };
+
+        // A heavy load hampers Strength, Dexterity, and Constitution checks
+        let encumbered = character.encumbrance().hampers(skill.ability());
+        let roll_advantage = if encumbered {
+            roll_advantage.combine(Advantage::Disadvantage)
+        } else {
+            roll_advantage
+        };
+
+        // An ally's Help grants advantage on the next ability check
+        let helped = world
+            .combat
+            .as_ref()
+            .and_then(|combat| combat.combatant(character.id))
+            .is_some_and(|c| c.helped_by.is_some());
+        let roll_advantage = if helped {
+            roll_advantage.combine(Advantage::Advantage)
+        } else {
+            roll_advantage
+        };
+
+        // Spent Heroic Inspiration grants advantage on the next roll
+        let inspired = character.inspiration_active;
+        let roll_advantage = if inspired {
+            roll_advantage.combine(Advantage::Advantage)
+        } else {
+            roll_advantage
+        };
+
+        // Poor light and obscuring weather hamper sight-based Perception
+        let (in_the_dark, obscured) = if skill == Skill::Perception {
+            (
+                world.light_level() != LightLevel::Bright && !character.race_type.has_darkvision(),
+                world.weather.obscures_vision(),
+            )
+        } else {
+            (false, false)
+        };
+        let roll_advantage = if in_the_dark || obscured {
+            roll_advantage.combine(Advantage::Disadvantage)
+        } else {
+            roll_advantage
+        };
+
         let expr = DiceExpression::parse(&format!("1d20+{modifier}")).unwrap();
-        let roll = expr.roll_with_advantage(effective_advantage);
+        let mut roll = expr.roll_with_advantage(roll_advantage);
+
+        // Reliable Talent: from 11th level, a rogue treats a d20 roll of 9 or
+        // lower as a 10 on checks they're proficient in
+        let proficient = matches!(
+            character.skill_proficiencies.get(&skill),
+            Some(ProficiencyLevel::Proficient | ProficiencyLevel::Expertise)
+        );
+        let rogue_level = character
+            .classes
+            .iter()
+            .find(|c| c.class == CharacterClass::Rogue)
+            .map(|c| c.level)
+            .unwrap_or(0);
+        let mut reliable = false;
+        if proficient && rogue_level >= 11 {
+            if let Some(d20) = roll.component_results.first_mut() {
+                if let Some(kept) = d20.kept.first_mut().filter(|k| **k < 10) {
+                    let raised = 10 - *kept as i32;
+                    *kept = 10;
+                    d20.subtotal += raised;
+                    roll.total += raised;
+                    reliable = true;
+                }
+            }
+        }
 
         let success = roll.total >= dc;
         let result_str = if success { "succeeds" } else { "fails" };
 
-        // Note if stealth disadvantage was applied
-        let disadvantage_note = if skill == Skill::Stealth
+        // Note any advantage or disadvantage sources applied
+        let mut roll_notes = if skill == Skill::Stealth
             && effective_advantage != advantage
             && matches!(effective_advantage, Advantage::Disadvantage)
         {
-            " [armor disadvantage]"
+            " [armor disadvantage]".to_string()
         } else {
-            ""
+            String::new()
         };
+        if exhausted {
+            roll_notes.push_str(" [exhaustion disadvantage]");
+        }
+        if encumbered {
+            roll_notes.push_str(" [encumbered]");
+        }
+        if helped {
+            roll_notes.push_str(" [helped]");
+        }
+        if inspired {
+            roll_notes.push_str(" [inspired]");
+        }
+        if reliable {
+            roll_notes.push_str(" [reliable talent]");
+        }
+        if in_the_dark {
+            roll_notes.push_str(&format!(" [{}]", world.light_level().name()));
+        }
+        if obscured {
+            roll_notes.push_str(&format!(" [{}]", world.weather.name()));
+        }
 
         let mut resolution = Resolution::new(format!(
             "{} {} ({} check: {} vs DC {}){}",
@@ -75,13 +174,23 @@ impl RulesEngine {
             skill.name(),
             roll.total,
             dc,
-            disadvantage_note
+            roll_notes
         ));
 
         resolution = resolution.with_effect(Effect::DiceRolled {
             roll: roll.clone(),
             purpose: format!("{} check - {}", skill.name(), description),
         });
+        if helped {
+            resolution = resolution.with_effect(Effect::HelpConsumed {
+                character_id: character.id,
+            });
+        }
+        if inspired {
+            resolution = resolution.with_effect(Effect::InspirationConsumed {
+                character_id: character.id,
+            });
+        }
 
         if success {
             resolution = resolution.with_effect(Effect::CheckSucceeded {
@@ -129,19 +238,44 @@ impl RulesEngine {
 
         let modifier = character.ability_scores.modifier(ability);
 
+        // Exhaustion level 1+ imposes disadvantage on ability checks
+        let exhausted = character.exhaustion_level() >= 1;
+        let advantage = if exhausted {
+            advantage.combine(Advantage::Disadvantage)
+        } else {
+            advantage
+        };
+
+        // A heavy load hampers Strength, Dexterity, and Constitution checks
+        let encumbered = character.encumbrance().hampers(ability);
+        let advantage = if encumbered {
+            advantage.combine(Advantage::Disadvantage)
+        } else {
+            advantage
+        };
+
         let expr = DiceExpression::parse(&format!("1d20+{modifier}")).unwrap();
         let roll = expr.roll_with_advantage(advantage);
 
         let success = roll.total >= dc;
         let result_str = if success { "succeeds" } else { "fails" };
 
+        let mut roll_notes = String::new();
+        if exhausted {
+            roll_notes.push_str(" [exhaustion disadvantage]");
+        }
+        if encumbered {
+            roll_notes.push_str(" [encumbered]");
+        }
+
         let mut resolution = Resolution::new(format!(
-            "{} {} ({} check: {} vs DC {})",
+            "{} {} ({} check: {} vs DC {}){}",
             character.name,
             result_str,
             ability.abbreviation(),
             roll.total,
-            dc
+            dc,
+            roll_notes
         ));
 
         resolution = resolution.with_effect(Effect::DiceRolled {
@@ -193,25 +327,66 @@ impl RulesEngine {
 
         let modifier = character.saving_throw_modifier(ability);
 
+        // Exhaustion level 3+ imposes disadvantage on saving throws
+        let exhausted = character.exhaustion_level() >= 3;
+        let advantage = if exhausted {
+            advantage.combine(Advantage::Disadvantage)
+        } else {
+            advantage
+        };
+
+        // A heavy load hampers Strength, Dexterity, and Constitution saves
+        let encumbered = character.encumbrance().hampers(ability);
+        let advantage = if encumbered {
+            advantage.combine(Advantage::Disadvantage)
+        } else {
+            advantage
+        };
+
+        // Spent Heroic Inspiration grants advantage on the next roll
+        let inspired = character.inspiration_active;
+        let advantage = if inspired {
+            advantage.combine(Advantage::Advantage)
+        } else {
+            advantage
+        };
+
         let expr = DiceExpression::parse(&format!("1d20+{modifier}")).unwrap();
         let roll = expr.roll_with_advantage(advantage);
 
         let success = roll.total >= dc;
         let result_str = if success { "succeeds" } else { "fails" };
 
+        let mut roll_notes = String::new();
+        if exhausted {
+            roll_notes.push_str(" [exhaustion disadvantage]");
+        }
+        if encumbered {
+            roll_notes.push_str(" [encumbered]");
+        }
+        if inspired {
+            roll_notes.push_str(" [inspired]");
+        }
+
         let mut resolution = Resolution::new(format!(
-            "{} {} on {} saving throw ({} vs DC {})",
+            "{} {} on {} saving throw ({} vs DC {}){}",
             character.name,
             result_str,
             ability.abbreviation(),
             roll.total,
-            dc
+            dc,
+            roll_notes
         ));
 
         resolution = resolution.with_effect(Effect::DiceRolled {
             roll: roll.clone(),
             purpose: format!("{} save vs {}", ability.abbreviation(), source),
         });
+        if inspired {
+            resolution = resolution.with_effect(Effect::InspirationConsumed {
+                character_id: character.id,
+            });
+        }
 
         if success {
             resolution.with_effect(Effect::CheckSucceeded {
@@ -360,6 +535,100 @@ mod tests {
             .any(|e| matches!(e, Effect::DiceRolled { .. })));
     }
 
+    #[test]
+    fn test_expertise_doubles_proficiency() {
+        let mut character = create_sample_fighter("Roland");
+        character.skill_proficiencies.remove(&Skill::Stealth);
+        let bonus = character.proficiency_bonus();
+        let base = character.skill_modifier(Skill::Stealth);
+
+        character
+            .skill_proficiencies
+            .insert(Skill::Stealth, ProficiencyLevel::Proficient);
+        assert_eq!(character.skill_modifier(Skill::Stealth), base + bonus);
+
+        character
+            .skill_proficiencies
+            .insert(Skill::Stealth, ProficiencyLevel::Expertise);
+        assert_eq!(character.skill_modifier(Skill::Stealth), base + 2 * bonus);
+    }
+
+    fn sample_rogue(level: u8) -> crate::world::Character {
+        let mut character = create_sample_fighter("Vex");
+        character.level = level;
+        character.classes = vec![crate::world::ClassLevel {
+            class: CharacterClass::Rogue,
+            level,
+            subclass: None,
+        }];
+        character
+            .skill_proficiencies
+            .insert(Skill::Stealth, ProficiencyLevel::Expertise);
+        character
+    }
+
+    fn check_rolls(world: &GameWorld, skill: Skill) -> Vec<(u32, i32, String)> {
+        (0..60)
+            .map(|seed| {
+                let resolution = RulesEngine::with_seed(seed).resolve(
+                    world,
+                    crate::rules::Intent::SkillCheck {
+                        character_id: world.player_character.id,
+                        skill,
+                        dc: 15,
+                        advantage: Advantage::Normal,
+                        description: "test".to_string(),
+                    },
+                );
+                let roll = resolution
+                    .effects
+                    .iter()
+                    .find_map(|e| match e {
+                        Effect::DiceRolled { roll, .. } => Some(roll.clone()),
+                        _ => None,
+                    })
+                    .unwrap();
+                (
+                    roll.component_results[0].kept[0],
+                    roll.total,
+                    resolution.narrative,
+                )
+            })
+            .collect()
+    }
+
+    #[test]
+    fn test_reliable_talent_never_rolls_below_ten() {
+        let world = GameWorld::new("Test", sample_rogue(11));
+        let modifier = world.player_character.skill_modifier(Skill::Stealth) as i32;
+
+        let rolls = check_rolls(&world, Skill::Stealth);
+        for (d20, total, _) in &rolls {
+            assert!(*d20 >= 10);
+            assert_eq!(*total, *d20 as i32 + modifier);
+        }
+        assert!(rolls
+            .iter()
+            .any(|(_, _, narrative)| narrative.contains("[reliable talent]")));
+    }
+
+    #[test]
+    fn test_reliable_talent_needs_level_and_proficiency() {
+        // A 10th-level rogue doesn't have it yet
+        let world = GameWorld::new("Test", sample_rogue(10));
+        assert!(check_rolls(&world, Skill::Stealth)
+            .iter()
+            .any(|(d20, _, _)| *d20 < 10));
+
+        // Nor does it help on checks the rogue isn't proficient in
+        let mut rogue = sample_rogue(11);
+        rogue.skill_proficiencies.remove(&Skill::Arcana);
+        let world = GameWorld::new("Test", rogue);
+        assert!(check_rolls(&world, Skill::Arcana)
+            .iter()
+            .any(|(d20, _, _)| *d20 < 10));
+    }
+
     // ========== Ability Check Tests ==========
 
     #[test]
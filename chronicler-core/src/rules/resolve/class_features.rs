@@ -1,6 +1,6 @@
 //! Class feature resolution methods.
 
-use crate::rules::helpers::roll_with_fallback;
+use crate::rules::helpers::{bonus_action_taken, roll_with_fallback, spend_bonus_action};
 use crate::rules::types::{Effect, Resolution};
 use crate::rules::RulesEngine;
 use crate::world::{CharacterClass, CharacterId, GameWorld};
@@ -9,14 +9,19 @@ impl RulesEngine {
     pub(crate) fn resolve_use_rage(
         &self,
         world: &GameWorld,
-        _character_id: CharacterId,
+        character_id: CharacterId,
     ) -> Resolution {
-        let character = &world.player_character;
+        let Some(character) = world.character(character_id) else {
+            return Resolution::new("No party member found to use that feature.");
+        };
 
         // Check if already raging
-        if world.player_character.class_resources.rage_active {
+        if character.class_resources.rage_active {
             return Resolution::new(format!("{} is already raging!", character.name));
         }
+        if let Some(refusal) = bonus_action_taken(world, character) {
+            return refusal;
+        }
 
         // Check for rage uses remaining
         let rage_feature = character.features.iter().find(|f| f.name == "Rage");
@@ -45,12 +50,12 @@ impl RulesEngine {
             _ => 4,
         };
 
-        Resolution::new(format!(
+        let resolution = Resolution::new(format!(
             "{} enters a RAGE! Gains: advantage on STR checks/saves, +{} rage damage to melee attacks, resistance to bludgeoning/piercing/slashing damage. Cannot cast spells or concentrate while raging.",
             character.name, rage_damage
         ))
         .with_effect(Effect::RageStarted {
-            character_id: world.player_character.id,
+            character_id: character.id,
             damage_bonus: rage_damage,
         })
         .with_effect(Effect::ClassResourceUsed {
@@ -59,20 +64,24 @@ impl RulesEngine {
             description: format!("Entered rage (1 minute, +{rage_damage} damage)"),
         })
         .with_effect(Effect::FeatureUsed {
+            character_id: character.id,
             feature_name: "Rage".to_string(),
             uses_remaining: 0,
-        })
+        });
+        spend_bonus_action(world, character.id, resolution)
     }
 
     pub(crate) fn resolve_end_rage(
         &self,
         world: &GameWorld,
-        _character_id: CharacterId,
+        character_id: CharacterId,
         reason: &str,
     ) -> Resolution {
-        let character = &world.player_character;
+        let Some(character) = world.character(character_id) else {
+            return Resolution::new("No party member found to use that feature.");
+        };
 
-        if !world.player_character.class_resources.rage_active {
+        if !character.class_resources.rage_active {
             return Resolution::new(format!("{} is not currently raging.", character.name));
         }
 
@@ -86,7 +95,7 @@ impl RulesEngine {
 
         Resolution::new(format!("{}'s rage ends. {}", character.name, reason_text))
             .with_effect(Effect::RageEnded {
-                character_id: world.player_character.id,
+                character_id: character.id,
                 reason: reason_text.to_string(),
             })
             .with_effect(Effect::ClassResourceUsed {
@@ -99,12 +108,14 @@ impl RulesEngine {
     pub(crate) fn resolve_use_ki(
         &self,
         world: &GameWorld,
-        _character_id: CharacterId,
+        character_id: CharacterId,
         points: u8,
         ability: &str,
     ) -> Resolution {
-        let character = &world.player_character;
-        let resources = &world.player_character.class_resources;
+        let Some(character) = world.character(character_id) else {
+            return Resolution::new("No party member found to use that feature.");
+        };
+        let resources = &character.class_resources;
 
         if resources.ki_points < points {
             return Resolution::new(format!(
@@ -113,6 +124,16 @@ impl RulesEngine {
             ));
         }
 
+        let is_bonus_action = matches!(
+            ability,
+            "flurry_of_blows" | "patient_defense" | "step_of_the_wind"
+        );
+        if is_bonus_action {
+            if let Some(refusal) = bonus_action_taken(world, character) {
+                return refusal;
+            }
+        }
+
         let ability_description = match ability {
             "flurry_of_blows" => "Flurry of Blows: Make two unarmed strikes as a bonus action.",
             "patient_defense" => "Patient Defense: Take the Dodge action as a bonus action.",
@@ -123,7 +144,7 @@ impl RulesEngine {
             _ => ability,
         };
 
-        Resolution::new(format!(
+        let resolution = Resolution::new(format!(
             "{} spends {} ki point{}. {}",
             character.name,
             points,
@@ -134,20 +155,27 @@ impl RulesEngine {
             character_name: character.name.clone(),
             resource_name: "Ki Points".to_string(),
             description: format!("Spent {points} ki for {ability}"),
-        })
+        });
+        if is_bonus_action {
+            spend_bonus_action(world, character.id, resolution)
+        } else {
+            resolution
+        }
     }
 
     pub(crate) fn resolve_use_lay_on_hands(
         &self,
         world: &GameWorld,
-        _character_id: CharacterId,
+        character_id: CharacterId,
         target_name: &str,
         hp_amount: u32,
         cure_disease: bool,
         neutralize_poison: bool,
     ) -> Resolution {
-        let character = &world.player_character;
-        let pool = world.player_character.class_resources.lay_on_hands_pool;
+        let Some(character) = world.character(character_id) else {
+            return Resolution::new("No party member found to use that feature.");
+        };
+        let pool = character.class_resources.lay_on_hands_pool;
 
         let total_cost =
             hp_amount + if cure_disease { 5 } else { 0 } + if neutralize_poison { 5 } else { 0 };
@@ -187,11 +215,13 @@ impl RulesEngine {
     pub(crate) fn resolve_use_divine_smite(
         &self,
         world: &GameWorld,
-        _character_id: CharacterId,
+        character_id: CharacterId,
         spell_slot_level: u8,
         target_is_undead_or_fiend: bool,
     ) -> Resolution {
-        let character = &world.player_character;
+        let Some(character) = world.character(character_id) else {
+            return Resolution::new("No party member found to use that feature.");
+        };
 
         // Check if they have spell slots available
         if let Some(ref spellcasting) = character.spellcasting {
@@ -243,20 +273,17 @@ impl RulesEngine {
     pub(crate) fn resolve_use_wild_shape(
         &self,
         world: &GameWorld,
-        _character_id: CharacterId,
+        character_id: CharacterId,
         beast_form: &str,
         beast_hp: i32,
         _beast_ac: Option<u8>,
     ) -> Resolution {
-        let character = &world.player_character;
+        let Some(character) = world.character(character_id) else {
+            return Resolution::new("No party member found to use that feature.");
+        };
 
         // Check if already in Wild Shape
-        if world
-            .player_character
-            .class_resources
-            .wild_shape_form
-            .is_some()
-        {
+        if character.class_resources.wild_shape_form.is_some() {
             return Resolution::new(format!("{} is already in Wild Shape form!", character.name));
         }
 
@@ -293,6 +320,7 @@ impl RulesEngine {
             description: format!("Transformed into {beast_form} ({beast_hp} HP)"),
         })
         .with_effect(Effect::FeatureUsed {
+            character_id: character.id,
             feature_name: "Wild Shape".to_string(),
             uses_remaining: 0,
         })
@@ -301,18 +329,15 @@ impl RulesEngine {
     pub(crate) fn resolve_end_wild_shape(
         &self,
         world: &GameWorld,
-        _character_id: CharacterId,
+        character_id: CharacterId,
         reason: &str,
         excess_damage: i32,
     ) -> Resolution {
-        let character = &world.player_character;
-
-        if world
-            .player_character
-            .class_resources
-            .wild_shape_form
-            .is_none()
-        {
+        let Some(character) = world.character(character_id) else {
+            return Resolution::new("No party member found to use that feature.");
+        };
+
+        if character.class_resources.wild_shape_form.is_none() {
             return Resolution::new(format!(
                 "{} is not currently in Wild Shape form.",
                 character.name
@@ -349,7 +374,7 @@ impl RulesEngine {
         // Apply excess damage if any
         if excess_damage > 0 {
             resolution = resolution.with_effect(Effect::HpChanged {
-                target_id: world.player_character.id,
+                target_id: character.id,
                 amount: -excess_damage,
                 new_current: (character.hit_points.current - excess_damage).max(0),
                 new_max: character.hit_points.maximum,
@@ -363,11 +388,13 @@ impl RulesEngine {
     pub(crate) fn resolve_use_channel_divinity(
         &self,
         world: &GameWorld,
-        _character_id: CharacterId,
+        character_id: CharacterId,
         option: &str,
         targets: &[String],
     ) -> Resolution {
-        let character = &world.player_character;
+        let Some(character) = world.character(character_id) else {
+            return Resolution::new("No party member found to use that feature.");
+        };
 
         // Check for Channel Divinity uses
         let cd_feature = character
@@ -414,6 +441,7 @@ impl RulesEngine {
             description: option.to_string(),
         })
         .with_effect(Effect::FeatureUsed {
+            character_id: character.id,
             feature_name: "Channel Divinity".to_string(),
             uses_remaining: 0,
         })
@@ -422,11 +450,13 @@ impl RulesEngine {
     pub(crate) fn resolve_use_bardic_inspiration(
         &self,
         world: &GameWorld,
-        _character_id: CharacterId,
+        character_id: CharacterId,
         target_name: &str,
         die_size: &str,
     ) -> Resolution {
-        let character = &world.player_character;
+        let Some(character) = world.character(character_id) else {
+            return Resolution::new("No party member found to use that feature.");
+        };
 
         // Check for Bardic Inspiration uses
         let bi_feature = character
@@ -454,6 +484,7 @@ impl RulesEngine {
             description: format!("Inspired {target_name} with a {die_size}"),
         })
         .with_effect(Effect::FeatureUsed {
+            character_id: character.id,
             feature_name: "Bardic Inspiration".to_string(),
             uses_remaining: 0,
         })
@@ -462,12 +493,14 @@ impl RulesEngine {
     pub(crate) fn resolve_use_action_surge(
         &self,
         world: &GameWorld,
-        _character_id: CharacterId,
+        character_id: CharacterId,
         action_taken: &str,
     ) -> Resolution {
-        let character = &world.player_character;
+        let Some(character) = world.character(character_id) else {
+            return Resolution::new("No party member found to use that feature.");
+        };
 
-        if world.player_character.class_resources.action_surge_used {
+        if character.class_resources.action_surge_used {
             return Resolution::new(format!(
                 "{} has already used Action Surge! (Recovers on short/long rest)",
                 character.name
@@ -484,6 +517,7 @@ impl RulesEngine {
             description: action_taken.to_string(),
         })
         .with_effect(Effect::FeatureUsed {
+            character_id: character.id,
             feature_name: "Action Surge".to_string(),
             uses_remaining: 0,
         })
@@ -492,16 +526,21 @@ impl RulesEngine {
     pub(crate) fn resolve_use_second_wind(
         &self,
         world: &GameWorld,
-        _character_id: CharacterId,
+        character_id: CharacterId,
     ) -> Resolution {
-        let character = &world.player_character;
+        let Some(character) = world.character(character_id) else {
+            return Resolution::new("No party member found to use that feature.");
+        };
 
-        if world.player_character.class_resources.second_wind_used {
+        if character.class_resources.second_wind_used {
             return Resolution::new(format!(
                 "{} has already used Second Wind! (Recovers on short/long rest)",
                 character.name
             ));
         }
+        if let Some(refusal) = bonus_action_taken(world, character) {
+            return refusal;
+        }
 
         // Calculate healing: 1d10 + fighter level
         let fighter_level = character
@@ -516,7 +555,7 @@ impl RulesEngine {
 
         let new_hp = (character.hit_points.current + healing).min(character.hit_points.maximum);
 
-        Resolution::new(format!(
+        let resolution = Resolution::new(format!(
             "{} catches their breath with Second Wind! Regains 1d10+{} = {} HP. (Now at {}/{})",
             character.name, fighter_level, healing, new_hp, character.hit_points.maximum
         ))
@@ -525,7 +564,7 @@ impl RulesEngine {
             purpose: "Second Wind healing".to_string(),
         })
         .with_effect(Effect::HpChanged {
-            target_id: world.player_character.id,
+            target_id: character.id,
             amount: healing,
             new_current: new_hp,
             new_max: character.hit_points.maximum,
@@ -537,22 +576,26 @@ impl RulesEngine {
             description: format!("Healed {healing} HP"),
         })
         .with_effect(Effect::FeatureUsed {
+            character_id: character.id,
             feature_name: "Second Wind".to_string(),
             uses_remaining: 0,
-        })
+        });
+        spend_bonus_action(world, character.id, resolution)
     }
 
     pub(crate) fn resolve_use_sorcery_points(
         &self,
         world: &GameWorld,
-        _character_id: CharacterId,
+        character_id: CharacterId,
         points: u8,
         metamagic: &str,
         spell_name: Option<&str>,
         slot_level: Option<u8>,
     ) -> Resolution {
-        let character = &world.player_character;
-        let resources = &world.player_character.class_resources;
+        let Some(character) = world.character(character_id) else {
+            return Resolution::new("No party member found to use that feature.");
+        };
+        let resources = &character.class_resources;
 
         // Handle slot conversion separately
         if metamagic == "convert_to_slot" {
@@ -1,21 +1,37 @@
 //! Combat-related resolution methods.
 
 use crate::dice::{self, Advantage, DiceExpression};
-use crate::rules::helpers::{roll_with_fallback, sneak_attack_dice};
-use crate::rules::types::{CombatantInit, DamageType, Effect, Resolution};
-use crate::rules::RulesEngine;
-use crate::world::{Ability, CharacterClass, CharacterId, Condition, GameWorld};
+use crate::rules::helpers::{critical_dice, roll_with_fallback, sneak_attack_dice};
+use crate::rules::types::{
+    AttackMode, CombatantInit, Cover, DamageType, Effect, Resolution, ShoveMode,
+};
+use crate::rules::{apply_effects, RulesEngine};
+use crate::world::{
+    Ability, CharacterClass, CharacterId, CombatState, Condition, FightingStyle, GameWorld,
+    LairAction, LifeState, OngoingEffect, OngoingKind, ResistanceLevel, Skill,
+};
 
 impl RulesEngine {
+    #[allow(clippy::too_many_arguments)]
     pub(crate) fn resolve_attack(
         &self,
         world: &GameWorld,
-        _attacker_id: CharacterId,
+        attacker_id: CharacterId,
         target_id: CharacterId,
         weapon_name: &str,
         advantage: Advantage,
+        is_offhand: bool,
+        cover: Cover,
+        adjacent_enemy: Option<bool>,
+        attack_mode: AttackMode,
     ) -> Resolution {
-        let attacker = &world.player_character;
+        let Some(attacker) = world.character(attacker_id) else {
+            return Resolution::new("No party member found to make the attack.");
+        };
+
+        if attacker.is_dead() {
+            return Resolution::new(format!("{} is dead and cannot attack.", attacker.name));
+        }
 
         // Unconscious characters cannot attack
         if attacker.has_condition(Condition::Unconscious) {
@@ -25,9 +41,24 @@ impl RulesEngine {
             ));
         }
 
-        // Get target AC from combat state, or use player AC if targeting self
-        let target_ac = if target_id == world.player_character.id {
-            world.player_character.current_ac()
+        // Total cover means the target can't be targeted at all
+        if cover == Cover::Total {
+            let target_name = Self::target_name(world, target_id).unwrap_or("The target");
+            return Resolution::new(format!(
+                "{} has total cover - {}'s attack with {} automatically misses.",
+                target_name, attacker.name, weapon_name
+            ))
+            .with_effect(Effect::AttackMissed {
+                attacker_name: attacker.name.clone(),
+                target_name: target_name.to_string(),
+                attack_roll: 0,
+                target_ac: 0,
+            });
+        }
+
+        // Get target AC from the party member's sheet or the combat state
+        let target_ac = if let Some(character) = world.character(target_id) {
+            character.current_ac()
         } else if let Some(ref combat) = world.combat {
             combat
                 .combatants
@@ -38,25 +69,55 @@ impl RulesEngine {
         } else {
             10 // Default AC outside combat
         };
+        let target_ac = target_ac.saturating_add(cover.bonus() as u8);
+        let cover_note = match cover {
+            Cover::Half => " [half cover +2 AC]",
+            Cover::ThreeQuarters => " [three-quarters cover +5 AC]",
+            Cover::None | Cover::Total => "",
+        };
 
         // Look up weapon from database or equipped weapon
         let weapon = crate::items::get_weapon(weapon_name);
         let equipped_weapon = attacker.equipment.main_hand.as_ref();
 
         // Determine the weapon properties
-        let (damage_dice, is_finesse, is_ranged) = if let Some(w) = &weapon {
-            (w.damage_dice.clone(), w.is_finesse(), w.is_ranged())
-        } else if let Some(w) = equipped_weapon {
-            (w.damage_dice.clone(), w.is_finesse(), w.is_ranged())
-        } else {
+        let weapon = weapon.as_ref().or(equipped_weapon);
+        let (damage_dice, is_finesse, is_ranged, is_light) = match (attack_mode, weapon) {
+            // Improvised weapons deal 1d4 and count as thrown or swung by STR
+            (AttackMode::Improvised, _) => ("1d4".to_string(), false, false, false),
+            (AttackMode::Thrown, Some(w)) if w.is_thrown() => {
+                (w.damage_dice.clone(), w.is_finesse(), true, w.is_light())
+            }
+            (AttackMode::Thrown, _) => {
+                return Resolution::new(format!(
+                    "{} isn't made for throwing; throw it as an improvised weapon instead.",
+                    weapon_name
+                ));
+            }
+            // A thrown weapon held in hand is a melee weapon
+            (AttackMode::Melee, Some(w)) => (
+                w.damage_dice.clone(),
+                w.is_finesse(),
+                w.is_ranged() && !w.is_thrown(),
+                w.is_light(),
+            ),
             // Default to unarmed strike
-            ("1".to_string(), false, false)
+            (AttackMode::Melee, None) => ("1".to_string(), false, false, false),
         };
+        let is_thrown = attack_mode == AttackMode::Thrown;
+
+        // Two-weapon fighting requires a light weapon in the off hand
+        if is_offhand && !is_light {
+            return Resolution::new(format!(
+                "{} cannot make an off-hand attack with {}: it is not a light weapon.",
+                attacker.name, weapon_name
+            ));
+        }
 
         // Determine which ability modifier to use
-        // Ranged: DEX only
         // Finesse: higher of STR or DEX
-        // Melee: STR only
+        // Ranged: DEX only
+        // Melee and thrown: STR only
         let str_mod = attacker.ability_scores.modifier(Ability::Strength);
         let dex_mod = attacker.ability_scores.modifier(Ability::Dexterity);
 
@@ -69,39 +130,200 @@ impl RulesEngine {
             true
         };
 
-        let ability_mod = if is_ranged {
-            dex_mod
-        } else if is_finesse {
+        let ability_mod = if is_finesse {
             str_mod.max(dex_mod)
+        } else if is_ranged && !is_thrown {
+            dex_mod
         } else {
             str_mod
         };
 
-        let attack_mod = ability_mod + attacker.proficiency_bonus();
+        // Collect advantage and disadvantage sources, noting each in the narrative
+        let mut advantage = advantage;
+        let mut roll_notes = String::from(cover_note);
+
+        // Exhaustion level 3+ imposes disadvantage on attack rolls
+        if attacker.exhaustion_level() >= 3 {
+            advantage = advantage.combine(Advantage::Disadvantage);
+            roll_notes.push_str(" [exhaustion disadvantage]");
+        }
+
+        // Attacks against a dodging target have disadvantage
+        let target_dodging = world
+            .combat
+            .as_ref()
+            .and_then(|combat| combat.combatant(target_id))
+            .is_some_and(|c| c.dodging);
+        if target_dodging {
+            advantage = advantage.combine(Advantage::Disadvantage);
+            roll_notes.push_str(" [target dodging]");
+        }
+
+        // An ally's Help grants advantage on the attacker's next attack
+        let helped = world
+            .combat
+            .as_ref()
+            .and_then(|combat| combat.combatant(attacker.id))
+            .is_some_and(|c| c.helped_by.is_some());
+        if helped {
+            advantage = advantage.combine(Advantage::Advantage);
+            roll_notes.push_str(" [helped]");
+        }
+
+        // Spent Heroic Inspiration grants advantage on the next roll
+        let inspired = attacker.inspiration_active;
+        if inspired {
+            advantage = advantage.combine(Advantage::Advantage);
+            roll_notes.push_str(" [inspired]");
+        }
+
+        // Ranged attacks with a hostile creature within 5 feet have disadvantage.
+        // Unless the DM says so, only tracked positions can show one is that close.
+        let in_melee = is_ranged
+            && adjacent_enemy.unwrap_or_else(|| {
+                world
+                    .combat
+                    .as_ref()
+                    .is_some_and(|combat| combat.enemy_within(attacker.id, 5))
+            });
+        if in_melee {
+            advantage = advantage.combine(Advantage::Disadvantage);
+            roll_notes.push_str(" [ranged in melee disadvantage]");
+        }
+
+        // A prone target is easier to hit up close and harder to hit from afar
+        let target_prone = if target_id == attacker.id {
+            attacker.has_condition(Condition::Prone)
+        } else {
+            world
+                .combat
+                .as_ref()
+                .and_then(|combat| combat.combatant(target_id))
+                .is_some_and(|c| c.has_condition(Condition::Prone))
+        };
+        if target_prone && is_ranged {
+            advantage = advantage.combine(Advantage::Disadvantage);
+            roll_notes.push_str(" [target prone: disadvantage]");
+        } else if target_prone {
+            advantage = advantage.combine(Advantage::Advantage);
+            roll_notes.push_str(" [target prone: advantage]");
+        }
+
+        // A prone attacker has disadvantage on all attacks
+        if attacker.has_condition(Condition::Prone) {
+            advantage = advantage.combine(Advantage::Disadvantage);
+            roll_notes.push_str(" [attacker prone]");
+        }
+
+        // Optional flanking: a conscious ally engaged with the target grants
+        // melee advantage. With positions tracked the ally must stand beside
+        // the target; without them any ally in the fight counts.
+        let flanking = self.config.flanking
+            && !is_ranged
+            && world.combat.as_ref().is_some_and(|combat| {
+                let positioned = combat.combatants.iter().any(|c| c.position.is_some());
+                if positioned {
+                    combat
+                        .enemies_within(target_id, 5)
+                        .iter()
+                        .any(|c| c.is_ally && !c.is_player && c.id != attacker.id)
+                } else {
+                    combat.combatants.iter().any(|c| {
+                        c.is_ally
+                            && !c.is_player
+                            && c.current_hp > 0
+                            && c.id != target_id
+                            && !c
+                                .conditions
+                                .iter()
+                                .any(|ac| ac.condition.is_incapacitating())
+                    })
+                }
+            });
+        if flanking {
+            advantage = advantage.combine(Advantage::Advantage);
+            roll_notes.push_str(" [flanking]");
+        }
+
+        // Improvised weapons only add proficiency with the right feature
+        let proficient =
+            attack_mode != AttackMode::Improvised || self.has_feature(attacker, "Tavern Brawler");
+        let proficiency = if proficient {
+            attacker.proficiency_bonus()
+        } else {
+            0
+        };
+        let attack_mode_note = match (attack_mode, weapon.and_then(|w| w.range)) {
+            (AttackMode::Thrown, Some((normal, long))) => format!(" [thrown {normal}/{long} ft]"),
+            (AttackMode::Thrown, None) => " [thrown]".to_string(),
+            (AttackMode::Improvised, _) => " [improvised]".to_string(),
+            (AttackMode::Melee, _) => String::new(),
+        };
+        roll_notes.insert_str(0, &attack_mode_note);
+        let attack_mod = ability_mod + proficiency;
         let attack_expr = DiceExpression::parse(&format!("1d20+{attack_mod}")).unwrap();
         let attack_roll = attack_expr.roll_with_advantage(advantage);
 
         let mut resolution = Resolution::new(format!(
-            "{} attacks with {} (roll: {} vs AC {})",
-            attacker.name, weapon_name, attack_roll.total, target_ac
+            "{} attacks with {} (roll: {} vs AC {}){}",
+            attacker.name, weapon_name, attack_roll.total, target_ac, roll_notes
         ));
 
         resolution = resolution.with_effect(Effect::DiceRolled {
             roll: attack_roll.clone(),
             purpose: format!("Attack with {weapon_name}"),
         });
+        if helped {
+            resolution = resolution.with_effect(Effect::HelpConsumed {
+                character_id: attacker.id,
+            });
+        }
+        if inspired {
+            resolution = resolution.with_effect(Effect::InspirationConsumed {
+                character_id: attacker.id,
+            });
+        }
 
         // Natural 1 always misses, natural 20 always hits (and crits)
         let hits = !attack_roll.is_fumble()
             && (attack_roll.total >= target_ac as i32 || attack_roll.is_critical());
 
+        // Melee hits against a paralyzed or unconscious target are critical
+        let helpless_condition = if is_ranged {
+            None
+        } else if let Some(character) = world.character(target_id) {
+            character
+                .conditions
+                .iter()
+                .find(|c| c.condition.grants_melee_crits())
+                .map(|c| c.condition)
+        } else {
+            world
+                .combat
+                .as_ref()
+                .and_then(|combat| combat.combatant(target_id))
+                .and_then(|c| {
+                    c.conditions
+                        .iter()
+                        .find(|ac| ac.condition.grants_melee_crits())
+                        .map(|ac| ac.condition)
+                })
+        };
+        let is_critical = attack_roll.is_critical() || (hits && helpless_condition.is_some());
+        if let (true, Some(condition)) = (hits, helpless_condition) {
+            resolution.narrative.push_str(&format!(
+                " [target {}: automatic critical]",
+                condition.name().to_lowercase()
+            ));
+        }
+
         if hits {
             resolution = resolution.with_effect(Effect::AttackHit {
                 attacker_name: attacker.name.clone(),
                 target_name: "target".to_string(),
                 attack_roll: attack_roll.total,
                 target_ac,
-                is_critical: attack_roll.is_critical(),
+                is_critical,
             });
 
             // Roll damage with ability modifier and rage bonus (if applicable)
@@ -110,21 +332,23 @@ impl RulesEngine {
             } else {
                 0
             };
-            let total_mod = ability_mod as i32 + rage_bonus;
-
-            let damage_expr = if attack_roll.is_critical() {
-                // Critical hit: double the number of dice
-                // Parse "XdY" and produce "2XdY"
-                let doubled_dice = if let Some(d_pos) = damage_dice.find('d') {
-                    let num_dice: i32 = damage_dice[..d_pos].parse().unwrap_or(1);
-                    let die_type = &damage_dice[d_pos..];
-                    format!("{}{}", num_dice * 2, die_type)
-                } else {
-                    // Not a dice expression, just double the flat value
-                    let flat: i32 = damage_dice.parse().unwrap_or(1);
-                    format!("{}", flat * 2)
-                };
-                format!("{doubled_dice}+{total_mod}")
+            // Off-hand attacks don't add a positive ability modifier to damage
+            // unless the attacker has the Two-Weapon Fighting style
+            let damage_ability_mod = if is_offhand
+                && ability_mod > 0
+                && !attacker
+                    .fighting_styles
+                    .contains(&FightingStyle::TwoWeaponFighting)
+            {
+                0
+            } else {
+                ability_mod as i32
+            };
+            let total_mod = damage_ability_mod + rage_bonus;
+
+            let damage_expr = if is_critical {
+                let crit_dice = critical_dice(&damage_dice, self.config.crit_max_damage);
+                format!("{crit_dice}+{total_mod}")
             } else {
                 format!("{damage_dice}+{total_mod}")
             };
@@ -167,8 +391,8 @@ impl RulesEngine {
 
                 if sneak_attack_available && (has_advantage || has_ally_adjacent) {
                     let sneak_dice = sneak_attack_dice(rogue_level);
-                    let sneak_expr = if attack_roll.is_critical() {
-                        format!("{}d6", sneak_dice * 2) // Double dice on crit
+                    let sneak_expr = if is_critical {
+                        critical_dice(&format!("{sneak_dice}d6"), self.config.crit_max_damage)
                     } else {
                         format!("{}d6", sneak_dice)
                     };
@@ -195,6 +419,356 @@ impl RulesEngine {
         resolution
     }
 
+    pub(crate) fn resolve_multiattack(
+        &self,
+        world: &GameWorld,
+        attacker_id: CharacterId,
+        target_ids: &[CharacterId],
+        weapon_name: &str,
+    ) -> Resolution {
+        let Some(&last_target) = target_ids.last() else {
+            return Resolution::new("No targets given for the Attack action.");
+        };
+        let Some(attacker) = world.character(attacker_id) else {
+            return Resolution::new("No party member found to make the attack.");
+        };
+        let attack_count = attacker.attacks_per_action() as usize;
+
+        // Later attacks must see per-turn state (Sneak Attack, Help) spent by earlier ones
+        let mut scratch = world.clone();
+        let mut effects = Vec::new();
+        let mut lines = Vec::new();
+        let mut hits = 0;
+        for i in 0..attack_count {
+            // Extra attacks beyond the listed targets go at the last target
+            let target_id = target_ids.get(i).copied().unwrap_or(last_target);
+            let attack = self.resolve_attack(
+                &scratch,
+                attacker_id,
+                target_id,
+                weapon_name,
+                Advantage::Normal,
+                false,
+                Cover::None,
+                None,
+                AttackMode::Melee,
+            );
+            if attack
+                .effects
+                .iter()
+                .any(|e| matches!(e, Effect::AttackHit { .. }))
+            {
+                hits += 1;
+            }
+            apply_effects(&mut scratch, &attack.effects);
+            lines.push(format!(
+                "Attack {} on {}: {}",
+                i + 1,
+                Self::target_name(world, target_id).unwrap_or("unknown target"),
+                attack.narrative
+            ));
+            effects.extend(attack.effects);
+        }
+
+        Resolution::new(format!(
+            "{} makes {} attacks with {} ({} hit, {} missed).\n{}",
+            attacker.name,
+            attack_count,
+            weapon_name,
+            hits,
+            attack_count - hits,
+            lines.join("\n")
+        ))
+        .with_effects(effects)
+    }
+
+    pub(crate) fn resolve_grapple(
+        &self,
+        world: &GameWorld,
+        grappler_id: CharacterId,
+        target_id: CharacterId,
+        npc_modifier: i8,
+    ) -> Resolution {
+        let (grappler_name, target_name, won, mut resolution) =
+            match Self::resolve_contest(world, grappler_id, target_id, npc_modifier, "grapple") {
+                Ok(contest) => contest,
+                Err(resolution) => return resolution,
+            };
+
+        if won {
+            resolution.narrative = format!(
+                "{} grapples {}! {} {}'s speed is 0 until they escape.",
+                grappler_name, target_name, resolution.narrative, target_name
+            );
+            resolution.with_effect(Effect::ConditionApplied {
+                target_id,
+                condition: Condition::Grappled,
+                source: format!("grappled by {grappler_name}"),
+                duration_rounds: None,
+            })
+        } else {
+            resolution.narrative = format!(
+                "{} slips out of {}'s grapple attempt. {}",
+                target_name, grappler_name, resolution.narrative
+            );
+            resolution
+        }
+    }
+
+    pub(crate) fn resolve_shove(
+        &self,
+        world: &GameWorld,
+        shover_id: CharacterId,
+        target_id: CharacterId,
+        mode: ShoveMode,
+        npc_modifier: i8,
+    ) -> Resolution {
+        let (shover_name, target_name, won, mut resolution) =
+            match Self::resolve_contest(world, shover_id, target_id, npc_modifier, "shove") {
+                Ok(contest) => contest,
+                Err(resolution) => return resolution,
+            };
+
+        if !won {
+            resolution.narrative = format!(
+                "{} holds their ground against {}'s shove. {}",
+                target_name, shover_name, resolution.narrative
+            );
+            return resolution;
+        }
+
+        match mode {
+            ShoveMode::Prone => {
+                resolution.narrative = format!(
+                    "{} shoves {} to the ground! {}",
+                    shover_name, target_name, resolution.narrative
+                );
+                resolution.with_effect(Effect::ConditionApplied {
+                    target_id,
+                    condition: Condition::Prone,
+                    source: format!("shoved by {shover_name}"),
+                    duration_rounds: None,
+                })
+            }
+            ShoveMode::Push => {
+                resolution.narrative = format!(
+                    "{} shoves {} 5 feet away! {}",
+                    shover_name, target_name, resolution.narrative
+                );
+                resolution
+            }
+        }
+    }
+
+    /// Roll a contested Athletics check against the defender's better of Athletics or
+    /// Acrobatics. Returns both names, whether the initiator won (ties go to the
+    /// defender), and a resolution holding the rolls.
+    fn resolve_contest<'w>(
+        world: &'w GameWorld,
+        initiator_id: CharacterId,
+        defender_id: CharacterId,
+        npc_modifier: i8,
+        action: &str,
+    ) -> Result<(&'w str, &'w str, bool, Resolution), Resolution> {
+        let (Some(initiator_name), Some(defender_name)) = (
+            Self::target_name(world, initiator_id),
+            Self::target_name(world, defender_id),
+        ) else {
+            return Err(Resolution::new(format!(
+                "Both creatures must be in combat to {action}."
+            )));
+        };
+        if initiator_id == defender_id {
+            return Err(Resolution::new(format!(
+                "{initiator_name} cannot {action} themselves."
+            )));
+        }
+
+        // Combatants have no skill scores, so the non-player side uses the given modifier
+        let player = &world.player_character;
+        let initiator_mod = if initiator_id == player.id {
+            player.skill_modifier(Skill::Athletics)
+        } else {
+            npc_modifier
+        };
+        let defender_mod = if defender_id == player.id {
+            player
+                .skill_modifier(Skill::Athletics)
+                .max(player.skill_modifier(Skill::Acrobatics))
+        } else {
+            npc_modifier
+        };
+
+        let initiator_roll = roll_with_fallback(&format!("1d20+{initiator_mod}"), "1d20");
+        let defender_roll = roll_with_fallback(&format!("1d20+{defender_mod}"), "1d20");
+        let won = initiator_roll.total > defender_roll.total;
+
+        let resolution = Resolution::new(format!(
+            "(contest: {} vs {})",
+            initiator_roll.total, defender_roll.total
+        ))
+        .with_effect(Effect::DiceRolled {
+            roll: initiator_roll,
+            purpose: format!("{initiator_name} Athletics ({action})"),
+        })
+        .with_effect(Effect::DiceRolled {
+            roll: defender_roll,
+            purpose: format!("{defender_name} resists {action}"),
+        });
+
+        Ok((initiator_name, defender_name, won, resolution))
+    }
+
+    pub(crate) fn resolve_opportunity_attack(
+        &self,
+        world: &GameWorld,
+        attacker_id: CharacterId,
+        target_id: CharacterId,
+        weapon_name: &str,
+    ) -> Resolution {
+        let Some(attacker) = world
+            .combat
+            .as_ref()
+            .and_then(|combat| combat.combatant(attacker_id))
+        else {
+            return Resolution::new("Opportunity attacks can only be made during combat.");
+        };
+
+        if !world
+            .combat
+            .as_ref()
+            .is_some_and(|c| c.can_react(attacker_id))
+        {
+            return Resolution::new(format!(
+                "{} has already used their reaction this round and can't make an opportunity attack.",
+                attacker.name
+            ));
+        }
+
+        let mut resolution = self.resolve_attack(
+            world,
+            attacker_id,
+            target_id,
+            weapon_name,
+            Advantage::Normal,
+            false,
+            Cover::None,
+            None,
+            AttackMode::Melee,
+        );
+        // A refused attack, such as one by a creature outside the party, costs no reaction
+        let attacked = resolution
+            .effects
+            .iter()
+            .any(|e| matches!(e, Effect::AttackHit { .. } | Effect::AttackMissed { .. }));
+        if !attacked {
+            return resolution;
+        }
+        resolution.narrative = format!("Opportunity attack! {}", resolution.narrative);
+        resolution.with_effect(Effect::ReactionUsed {
+            character_id: attacker_id,
+        })
+    }
+
+    pub(crate) fn resolve_use_legendary_action(
+        &self,
+        world: &GameWorld,
+        combatant_id: CharacterId,
+        action_name: &str,
+        cost: u8,
+    ) -> Resolution {
+        let Some(combat) = world.combat.as_ref() else {
+            return Resolution::new("Legendary actions can only be used during combat.");
+        };
+        let Some(combatant) = combat.combatant(combatant_id) else {
+            return Resolution::new("That creature isn't in the fight.");
+        };
+
+        if combatant.legendary_max == 0 {
+            return Resolution::new(format!("{} has no legendary actions.", combatant.name));
+        }
+        if combatant.current_hp <= 0 {
+            return Resolution::new(format!(
+                "{} is down and can't take legendary actions.",
+                combatant.name
+            ));
+        }
+        // Legendary actions happen at the end of other creatures' turns
+        if combat
+            .current_combatant()
+            .is_some_and(|c| c.id == combatant_id)
+        {
+            return Resolution::new(format!(
+                "{} can't take legendary actions on their own turn.",
+                combatant.name
+            ));
+        }
+        let cost = cost.max(1);
+        if combatant.legendary_actions < cost {
+            return Resolution::new(format!(
+                "{} doesn't have enough legendary actions left for {} (costs {}, {} remaining).",
+                combatant.name, action_name, cost, combatant.legendary_actions
+            ));
+        }
+
+        let remaining = combatant.legendary_actions - cost;
+        Resolution::new(format!(
+            "{} uses a legendary action: {} ({} remaining).",
+            combatant.name, action_name, remaining
+        ))
+        .with_effect(Effect::LegendaryActionUsed {
+            combatant_id,
+            action_name: action_name.to_string(),
+            cost,
+            remaining,
+        })
+    }
+
+    pub(crate) fn resolve_dodge(&self, world: &GameWorld, character_id: CharacterId) -> Resolution {
+        let Some(combatant) = world
+            .combat
+            .as_ref()
+            .and_then(|combat| combat.combatant(character_id))
+        else {
+            return Resolution::new("The Dodge action can only be taken during combat.");
+        };
+
+        Resolution::new(format!(
+            "{} takes the Dodge action. Attacks against them have disadvantage until their next turn.",
+            combatant.name
+        ))
+        .with_effect(Effect::DodgeActive { character_id })
+    }
+
+    pub(crate) fn resolve_help(
+        &self,
+        world: &GameWorld,
+        helper_id: CharacterId,
+        target_id: CharacterId,
+        task: &str,
+    ) -> Resolution {
+        let Some(combat) = world.combat.as_ref() else {
+            return Resolution::new("The Help action can only be taken during combat.");
+        };
+        let (Some(helper), Some(target)) =
+            (combat.combatant(helper_id), combat.combatant(target_id))
+        else {
+            return Resolution::new("Both the helper and the target must be in combat.");
+        };
+        if helper_id == target_id {
+            return Resolution::new(format!("{} cannot help themselves.", helper.name));
+        }
+
+        Resolution::new(format!(
+            "{} helps {} with {}. {} has advantage on their next ability check or attack roll.",
+            helper.name, target.name, task, target.name
+        ))
+        .with_effect(Effect::HelpGranted {
+            helper_id,
+            target_id,
+        })
+    }
+
     pub(crate) fn resolve_damage(
         &self,
         world: &GameWorld,
@@ -202,8 +776,23 @@ impl RulesEngine {
         amount: i32,
         damage_type: DamageType,
         source: &str,
+        is_critical: bool,
     ) -> Resolution {
-        let target = &world.player_character;
+        let Some(target) = world.character(target_id) else {
+            return self.resolve_combatant_damage(world, target_id, amount, damage_type, source);
+        };
+
+        let resistance = target.resistance_to(damage_type);
+        if resistance == Some(ResistanceLevel::Immune) {
+            return Resolution::new(format!(
+                "{} is immune to {} damage from {}",
+                target.name,
+                damage_type.name(),
+                source
+            ));
+        }
+        let amount = resistance.map_or(amount, |r| r.apply(amount));
+        let damage_text = damage_text(damage_type, resistance);
 
         // Special handling for damage while already at 0 HP
         if target.hit_points.current <= 0 {
@@ -211,7 +800,7 @@ impl RulesEngine {
             if amount >= target.hit_points.maximum {
                 return Resolution::new(format!(
                     "{} takes {} {} damage from {} while unconscious - INSTANT DEATH! (Damage {} >= max HP {})",
-                    target.name, amount, damage_type.name(), source, amount, target.hit_points.maximum
+                    target.name, amount, damage_text, source, amount, target.hit_points.maximum
                 ))
                 .with_effect(Effect::CharacterDied {
                     target_id,
@@ -219,19 +808,24 @@ impl RulesEngine {
                 });
             }
 
-            // Damage while at 0 HP causes death save failures
-            // (Critical hits cause 2 failures, but we don't know if this was a crit here)
-            let new_failures = target.death_saves.failures + 1;
+            // Damage while at 0 HP causes a death save failure, or two from a critical hit
+            let failures = if is_critical { 2 } else { 1 };
+            let new_failures = (target.death_saves.failures + failures).min(3);
             let died = new_failures >= 3;
+            let failure_text = if is_critical {
+                "critical hit - two death save failures!"
+            } else {
+                "death save failure!"
+            };
 
             if died {
                 return Resolution::new(format!(
-                    "{} takes {} {} damage from {} while unconscious - death save failure! Total failures: 3 - {} DIES!",
-                    target.name, amount, damage_type.name(), source, target.name
+                    "{} takes {} {} damage from {} while unconscious - {} Total failures: 3 - {} DIES!",
+                    target.name, amount, damage_text, source, failure_text, target.name
                 ))
                 .with_effect(Effect::DeathSaveFailure {
                     target_id,
-                    failures: 1,
+                    failures,
                     total_failures: new_failures,
                     source: source.to_string(),
                 })
@@ -242,12 +836,12 @@ impl RulesEngine {
             }
 
             return Resolution::new(format!(
-                "{} takes {} {} damage from {} while unconscious - death save failure! (Failures: {}/3)",
-                target.name, amount, damage_type.name(), source, new_failures
+                "{} takes {} {} damage from {} while unconscious - {} (Failures: {}/3)",
+                target.name, amount, damage_text, source, failure_text, new_failures
             ))
             .with_effect(Effect::DeathSaveFailure {
                 target_id,
-                failures: 1,
+                failures,
                 total_failures: new_failures,
                 source: source.to_string(),
             });
@@ -284,13 +878,20 @@ impl RulesEngine {
             format!(" (HP: {}/{})", hp.current, hp.maximum)
         };
 
+        // Temporary HP soaks damage before real HP
+        let absorbed = amount.min(target.hit_points.temporary);
+        let temp_status = if absorbed > 0 {
+            format!(
+                " ({} absorbed by temporary HP, {} remaining)",
+                absorbed, hp.temporary
+            )
+        } else {
+            String::new()
+        };
+
         let mut resolution = Resolution::new(format!(
-            "{} takes {} {} damage from {}{}",
-            target.name,
-            amount,
-            damage_type.name(),
-            source,
-            hp_status
+            "{} takes {} {} damage from {}{}{}",
+            target.name, amount, damage_text, source, temp_status, hp_status
         ));
 
         resolution = resolution.with_effect(Effect::HpChanged {
@@ -311,6 +912,64 @@ impl RulesEngine {
         resolution
     }
 
+    /// Apply damage to a non-player combatant tracked in the combat state.
+    fn resolve_combatant_damage(
+        &self,
+        world: &GameWorld,
+        target_id: CharacterId,
+        amount: i32,
+        damage_type: DamageType,
+        source: &str,
+    ) -> Resolution {
+        let Some(target) = world
+            .combat
+            .as_ref()
+            .and_then(|combat| combat.combatant(target_id))
+        else {
+            return Resolution::new(format!(
+                "No combatant found to take {} {} damage from {}.",
+                amount,
+                damage_type.name(),
+                source
+            ));
+        };
+
+        let resistance = target.resistance_to(damage_type);
+        if resistance == Some(ResistanceLevel::Immune) {
+            return Resolution::new(format!(
+                "{} is immune to {} damage from {}",
+                target.name,
+                damage_type.name(),
+                source
+            ));
+        }
+        let amount = resistance.map_or(amount, |r| r.apply(amount));
+        let damage_text = damage_text(damage_type, resistance);
+
+        let new_current = (target.current_hp - amount).max(0);
+        let dropped_to_zero = target.current_hp > 0 && new_current == 0;
+
+        let hp_status = if new_current == 0 {
+            format!(" (HP: 0/{} - DOWN!)", target.max_hp)
+        } else if new_current <= target.max_hp / 2 {
+            format!(" (HP: {}/{} - bloodied)", new_current, target.max_hp)
+        } else {
+            format!(" (HP: {}/{})", new_current, target.max_hp)
+        };
+
+        Resolution::new(format!(
+            "{} takes {} {} damage from {}{}",
+            target.name, amount, damage_text, source, hp_status
+        ))
+        .with_effect(Effect::HpChanged {
+            target_id,
+            amount: -amount,
+            new_current,
+            new_max: target.max_hp,
+            dropped_to_zero,
+        })
+    }
+
     pub(crate) fn resolve_heal(
         &self,
         world: &GameWorld,
@@ -318,8 +977,12 @@ impl RulesEngine {
         amount: i32,
         source: &str,
     ) -> Resolution {
-        let target = &world.player_character;
+        let Some(target) = world.character(target_id) else {
+            return self.resolve_combatant_heal(world, target_id, amount, source);
+        };
         let mut hp = target.hit_points.clone();
+        // Exhaustion can reduce how far the character can be healed
+        hp.maximum = target.effective_max_hp();
         let was_unconscious = hp.current <= 0;
         let healed = hp.heal(amount);
 
@@ -349,6 +1012,91 @@ impl RulesEngine {
         })
     }
 
+    pub(crate) fn resolve_grant_temp_hp(
+        &self,
+        world: &GameWorld,
+        target_id: CharacterId,
+        amount: i32,
+        source: &str,
+    ) -> Resolution {
+        let Some(target) = world.character(target_id) else {
+            return Resolution::new(format!(
+                "Temporary hit points from {source} can only be tracked for party members."
+            ));
+        };
+        let current_temp = target.hit_points.temporary;
+
+        // Temporary HP doesn't stack - keep whichever is higher
+        if amount <= current_temp {
+            return Resolution::new(format!(
+                "{} keeps their {} temporary hit points ({} from {} doesn't stack)",
+                target.name, current_temp, amount, source
+            ));
+        }
+
+        Resolution::new(format!(
+            "{} gains {} temporary hit points from {}",
+            target.name, amount, source
+        ))
+        .with_effect(Effect::TempHpGranted {
+            target_id,
+            amount,
+            new_temporary: amount,
+        })
+    }
+
+    /// Heal a non-player combatant tracked in the combat state.
+    fn resolve_combatant_heal(
+        &self,
+        world: &GameWorld,
+        target_id: CharacterId,
+        amount: i32,
+        source: &str,
+    ) -> Resolution {
+        let Some(target) = world
+            .combat
+            .as_ref()
+            .and_then(|combat| combat.combatant(target_id))
+        else {
+            return Resolution::new(format!(
+                "No combatant found to heal {amount} hit points from {source}."
+            ));
+        };
+
+        let healed = amount.min(target.max_hp - target.current_hp).max(0);
+        let new_current = target.current_hp + healed;
+
+        let hp_status = if new_current == target.max_hp {
+            format!(" (HP: {}/{} - fully healed)", new_current, target.max_hp)
+        } else {
+            format!(" (HP: {}/{})", new_current, target.max_hp)
+        };
+
+        Resolution::new(format!(
+            "{} heals {} hit points from {}{}",
+            target.name, healed, source, hp_status
+        ))
+        .with_effect(Effect::HpChanged {
+            target_id,
+            amount: healed,
+            new_current,
+            new_max: target.max_hp,
+            dropped_to_zero: false,
+        })
+    }
+
+    /// Look up the display name of a party member or a combatant.
+    fn target_name(world: &GameWorld, target_id: CharacterId) -> Option<&str> {
+        if let Some(character) = world.character(target_id) {
+            return Some(&character.name);
+        }
+        world
+            .combat
+            .as_ref()
+            .and_then(|combat| combat.combatant(target_id))
+            .map(|c| c.name.as_str())
+    }
+
     pub(crate) fn resolve_apply_condition(
         &self,
         world: &GameWorld,
@@ -357,7 +1105,13 @@ impl RulesEngine {
         source: &str,
         duration_rounds: Option<u32>,
     ) -> Resolution {
-        let target = &world.player_character;
+        let Some(target_name) = Self::target_name(world, target_id) else {
+            return Resolution::new(format!(
+                "No combatant found to become {} ({}).",
+                condition.name(),
+                source
+            ));
+        };
 
         let duration_text = duration_rounds
             .map(|d| format!(" for {} rounds", d))
@@ -365,51 +1119,209 @@ impl RulesEngine {
 
         let resolution = Resolution::new(format!(
             "{} is now {} ({}){}",
-            target.name,
+            target_name,
             condition.name(),
             source,
             duration_text
         ));
 
-        resolution.with_effect(Effect::ConditionApplied {
-            target_id,
-            condition,
-            source: source.to_string(),
-            duration_rounds,
-        })
+        resolution.with_effect(Effect::ConditionApplied {
+            target_id,
+            condition,
+            source: source.to_string(),
+            duration_rounds,
+        })
+    }
+
+    pub(crate) fn resolve_remove_condition(
+        &self,
+        world: &GameWorld,
+        target_id: CharacterId,
+        condition: Condition,
+    ) -> Resolution {
+        let Some(target_name) = Self::target_name(world, target_id) else {
+            return Resolution::new(format!(
+                "No combatant found to remove {} from.",
+                condition.name()
+            ));
+        };
+
+        let resolution =
+            Resolution::new(format!("{} is no longer {}", target_name, condition.name()));
+
+        resolution.with_effect(Effect::ConditionRemoved {
+            target_id,
+            condition,
+        })
+    }
+
+    pub(crate) fn resolve_apply_ongoing_effect(
+        &self,
+        world: &GameWorld,
+        target_id: CharacterId,
+        effect: OngoingEffect,
+    ) -> Resolution {
+        let Some(target_name) = Self::target_name(world, target_id) else {
+            return Resolution::new(format!("No combatant found to suffer {}.", effect.name));
+        };
+        if effect.remaining_rounds == 0 {
+            return Resolution::new(format!("{} must last at least one round.", effect.name));
+        }
+        let description = match &effect.kind {
+            OngoingKind::Damage { dice, damage_type } => {
+                if let Err(e) = DiceExpression::parse(dice) {
+                    return Resolution::new(format!("Invalid damage dice '{dice}': {e}"));
+                }
+                format!("{dice} {} damage", damage_type.name())
+            }
+            OngoingKind::Healing { dice } => {
+                if let Err(e) = DiceExpression::parse(dice) {
+                    return Resolution::new(format!("Invalid healing dice '{dice}': {e}"));
+                }
+                format!("{dice} healing")
+            }
+            OngoingKind::Modifier { description } => description.clone(),
+        };
+
+        Resolution::new(format!(
+            "{} is affected by {} ({} at the start of each turn) for {} rounds",
+            target_name, effect.name, description, effect.remaining_rounds
+        ))
+        .with_effect(Effect::OngoingEffectApplied { target_id, effect })
     }
 
-    pub(crate) fn resolve_remove_condition(
+    /// Trigger the ongoing effects on the creature whose turn is starting.
+    fn resolve_ongoing_effects(&self, world: &GameWorld, target_id: CharacterId) -> Resolution {
+        let effects = match world.character(target_id) {
+            Some(character) => &character.ongoing_effects,
+            None => match world.combat.as_ref().and_then(|c| c.combatant(target_id)) {
+                Some(combatant) => &combatant.ongoing_effects,
+                None => return Resolution::new(""),
+            },
+        };
+
+        let mut resolution = Resolution::new("");
+        for effect in effects {
+            let triggered = match &effect.kind {
+                OngoingKind::Damage { dice, damage_type } => {
+                    let roll = roll_with_fallback(dice, "1d4");
+                    let total = roll.total;
+                    resolution.effects.push(Effect::DiceRolled {
+                        roll,
+                        purpose: effect.name.clone(),
+                    });
+                    self.resolve_damage(world, target_id, total, *damage_type, &effect.name, false)
+                }
+                OngoingKind::Healing { dice } => {
+                    let roll = roll_with_fallback(dice, "1d4");
+                    let total = roll.total;
+                    resolution.effects.push(Effect::DiceRolled {
+                        roll,
+                        purpose: effect.name.clone(),
+                    });
+                    self.resolve_heal(world, target_id, total, &effect.name)
+                }
+                OngoingKind::Modifier { description } => {
+                    let name = Self::target_name(world, target_id).unwrap_or("Unknown");
+                    Resolution::new(format!(
+                        "{name} is still under {}: {description}",
+                        effect.name
+                    ))
+                }
+            };
+            resolution.narrative.push(' ');
+            resolution.narrative.push_str(&triggered.narrative);
+            if effect.remaining_rounds <= 1 {
+                resolution
+                    .narrative
+                    .push_str(&format!(" ({} ends)", effect.name));
+            }
+            resolution.effects.extend(triggered.effects);
+        }
+        resolution
+    }
+
+    pub(crate) fn resolve_modify_exhaustion(
         &self,
         world: &GameWorld,
         target_id: CharacterId,
-        condition: Condition,
+        delta: i8,
     ) -> Resolution {
-        let target = &world.player_character;
+        let Some(target) = world.character(target_id) else {
+            return Resolution::new(
+                "Exhaustion can only be tracked for party members.".to_string(),
+            );
+        };
+        let old_level = target.exhaustion_level();
+        let new_level = (old_level as i32 + delta as i32).clamp(0, 6) as u8;
 
-        let resolution =
-            Resolution::new(format!("{} is no longer {}", target.name, condition.name()));
+        if new_level == old_level {
+            return Resolution::new(format!(
+                "{}'s exhaustion remains at level {}",
+                target.name, old_level
+            ));
+        }
 
-        resolution.with_effect(Effect::ConditionRemoved {
+        let narrative = if new_level == 0 {
+            format!("{} is no longer exhausted", target.name)
+        } else {
+            format!(
+                "{}'s exhaustion {} to level {} ({})",
+                target.name,
+                if new_level > old_level {
+                    "rises"
+                } else {
+                    "falls"
+                },
+                new_level,
+                exhaustion_effects(new_level)
+            )
+        };
+
+        let mut resolution = Resolution::new(narrative).with_effect(Effect::ExhaustionChanged {
             target_id,
-            condition,
-        })
+            new_level,
+        });
+
+        if new_level >= 6 {
+            resolution = resolution.with_effect(Effect::CharacterDied {
+                target_id,
+                cause: "Exhaustion".to_string(),
+            });
+        }
+
+        resolution
     }
 
     pub(crate) fn resolve_start_combat(
         &self,
         world: &GameWorld,
         combatants: Vec<CombatantInit>,
+        lair_actions: Vec<LairAction>,
+        surprised_ids: &[CharacterId],
     ) -> Resolution {
         let mut resolution = Resolution::new("Combat begins! Roll for initiative.")
             .with_effect(Effect::CombatStarted);
+        for action in lair_actions {
+            resolution = resolution.with_effect(Effect::LairActionAdded { action });
+        }
+
+        let surprised: Vec<(CharacterId, String)> = combatants
+            .iter()
+            .filter(|init| surprised_ids.contains(&init.id))
+            .map(|init| (init.id, init.name.clone()))
+            .collect();
 
         // Roll initiative for each combatant
         for init in combatants {
-            let modifier = if init.is_player {
-                world.player_character.initiative_modifier()
+            let (modifier, dex_modifier) = if init.is_player {
+                let player = &world.player_character;
+                (
+                    player.initiative_modifier(),
+                    player.ability_scores.modifier(Ability::Dexterity),
+                )
             } else {
-                init.initiative_modifier
+                (init.initiative_modifier, init.dex_modifier)
             };
 
             let roll = dice::roll("1d20").unwrap();
@@ -426,13 +1338,103 @@ impl RulesEngine {
                 id: init.id,
                 name: init.name,
                 initiative: total,
+                dex_modifier,
                 is_ally: init.is_ally,
                 current_hp: init.current_hp,
                 max_hp: init.max_hp,
                 armor_class: init.armor_class,
+                resistances: init.resistances,
+                legendary_actions: init.legendary_actions,
+                position: init.position,
+            });
+        }
+
+        // Surprise is applied once the turn order is settled
+        for (id, name) in surprised {
+            resolution
+                .narrative
+                .push_str(&format!(" {name} is surprised!"));
+            resolution = resolution.with_effect(Effect::ConditionApplied {
+                target_id: id,
+                condition: Condition::Surprised,
+                source: "Surprise".to_string(),
+                duration_rounds: None,
+            });
+        }
+
+        resolution
+    }
+
+    pub(crate) fn resolve_trigger_lair_action(
+        &self,
+        world: &GameWorld,
+        index: usize,
+        target_ids: &[CharacterId],
+    ) -> Resolution {
+        let Some(combat) = world.combat.as_ref() else {
+            return Resolution::new("Lair actions only happen during combat.");
+        };
+        if combat.lair_action_used_this_round {
+            return Resolution::new(format!(
+                "The lair has already acted this round (round {}).",
+                combat.round
+            ));
+        }
+        let Some(action) = combat.lair_actions.get(index) else {
+            let available: Vec<String> = combat
+                .lair_actions
+                .iter()
+                .enumerate()
+                .map(|(i, a)| format!("{i}: {}", a.name))
+                .collect();
+            return Resolution::new(if available.is_empty() {
+                "There are no lair actions in this fight.".to_string()
+            } else {
+                format!(
+                    "No lair action #{index}. Available: {}",
+                    available.join(", ")
+                )
+            });
+        };
+
+        let mut narrative = vec![format!(
+            "Initiative count 20 - lair action: {}. {}",
+            action.name, action.description
+        )];
+        let mut resolution =
+            Resolution::new(String::new()).with_effect(Effect::LairActionTriggered {
+                name: action.name.clone(),
             });
+
+        // Apply the action's damage and condition to each target with the existing resolvers
+        for &target_id in target_ids {
+            if let Some(ref damage) = action.damage {
+                let roll = roll_with_fallback(damage, "1d6");
+                let damage_type = action.damage_type.unwrap_or(DamageType::Bludgeoning);
+                let hit = self.resolve_damage(
+                    world,
+                    target_id,
+                    roll.total,
+                    damage_type,
+                    &action.name,
+                    false,
+                );
+                narrative.push(hit.narrative);
+                resolution = resolution.with_effect(Effect::DiceRolled {
+                    roll,
+                    purpose: format!("{} damage", action.name),
+                });
+                resolution.effects.extend(hit.effects);
+            }
+            if let Some(condition) = action.condition {
+                let applied =
+                    self.resolve_apply_condition(world, target_id, condition, &action.name, None);
+                narrative.push(applied.narrative);
+                resolution.effects.extend(applied.effects);
+            }
         }
 
+        resolution.narrative = narrative.join(" ");
         resolution
     }
 
@@ -445,24 +1447,92 @@ impl RulesEngine {
             let mut combat_clone = combat.clone();
             combat_clone.next_turn();
 
+            // Optionally everyone rerolls initiative as a new round begins
+            let mut rerolls = Resolution::new("");
+            if combat_clone.round > combat.round
+                && (self.config.reroll_initiative_each_round || combat.reroll_initiative_each_round)
+            {
+                rerolls = self.reroll_initiative(world, combat);
+                combat_clone = combat.clone();
+                for effect in &rerolls.effects {
+                    if let Effect::InitiativeRolled {
+                        character_id,
+                        total,
+                        ..
+                    } = effect
+                    {
+                        combat_clone.set_initiative(*character_id, *total);
+                    }
+                }
+                combat_clone.next_turn();
+            }
+
             let current = combat_clone
                 .current_combatant()
                 .map(|c| c.name.clone())
                 .unwrap_or_else(|| "Unknown".to_string());
 
+            // Surprised combatants passed over lose their turn
+            let skipped: Vec<String> = combat
+                .combatants
+                .iter()
+                .filter(|c| {
+                    c.has_condition(Condition::Surprised)
+                        && combat_clone
+                            .combatant(c.id)
+                            .is_some_and(|after| !after.has_condition(Condition::Surprised))
+                })
+                .map(|c| format!("{} is surprised and loses their turn. ", c.name))
+                .collect();
+
+            let ongoing = combat_clone
+                .current_combatant()
+                .map(|c| self.resolve_ongoing_effects(world, c.id))
+                .unwrap_or_else(|| Resolution::new(""));
+
             Resolution::new(format!(
-                "Next turn: {} (Round {})",
-                current, combat_clone.round
+                "{}{}Next turn: {} (Round {}){}",
+                skipped.concat(),
+                rerolls.narrative,
+                current,
+                combat_clone.round,
+                ongoing.narrative
             ))
+            .with_effects(rerolls.effects)
             .with_effect(Effect::TurnAdvanced {
                 round: combat_clone.round,
                 current_combatant: current,
             })
+            .with_effects(ongoing.effects)
         } else {
             Resolution::new("No combat in progress")
         }
     }
 
+    /// Roll fresh initiative for every combatant. Party members use their own
+    /// initiative modifier; other creatures use their DEX modifier.
+    fn reroll_initiative(&self, world: &GameWorld, combat: &CombatState) -> Resolution {
+        let mut resolution = Resolution::new("");
+        let mut rolls = Vec::new();
+        for combatant in &combat.combatants {
+            let modifier = world
+                .character(combatant.id)
+                .map(|c| c.initiative_modifier())
+                .unwrap_or(combatant.dex_modifier);
+            let roll = dice::roll("1d20").unwrap();
+            let total = roll.total + modifier as i32;
+            rolls.push(format!("{} {}", combatant.name, total));
+            resolution = resolution.with_effect(Effect::InitiativeRolled {
+                character_id: combatant.id,
+                name: combatant.name.clone(),
+                roll: roll.total,
+                total,
+            });
+        }
+        resolution.narrative = format!("Initiative is rerolled: {}. ", rolls.join(", "));
+        resolution
+    }
+
     pub(crate) fn resolve_roll_initiative(
         &self,
         character_id: CharacterId,
@@ -489,12 +1559,94 @@ impl RulesEngine {
         })
     }
 
+    pub(crate) fn resolve_stabilize(
+        &self,
+        world: &GameWorld,
+        medic_id: CharacterId,
+        target_id: CharacterId,
+        npc_modifier: i8,
+    ) -> Resolution {
+        let (Some(medic_name), Some(target_name)) = (
+            Self::target_name(world, medic_id),
+            Self::target_name(world, target_id),
+        ) else {
+            return Resolution::new("Both the medic and the dying creature must be present.");
+        };
+        if medic_id == target_id {
+            return Resolution::new(format!("{medic_name} cannot stabilize themselves."));
+        }
+
+        let medic = world.character(medic_id);
+        if medic.is_some_and(|m| !m.is_conscious()) {
+            return Resolution::new(format!(
+                "{medic_name} is unconscious and cannot tend to anyone."
+            ));
+        }
+
+        let target_hp = if let Some(target) = world.character(target_id) {
+            target.hit_points.current
+        } else {
+            world
+                .combat
+                .as_ref()
+                .and_then(|combat| combat.combatant(target_id))
+                .map_or(0, |c| c.current_hp)
+        };
+        if target_hp > 0 {
+            return Resolution::new(format!(
+                "{target_name} is not dying and doesn't need to be stabilized."
+            ));
+        }
+
+        // Combatants have no skill scores, so a non-party medic uses the given modifier
+        let modifier = medic.map_or(npc_modifier, |m| m.skill_modifier(Skill::Medicine));
+        let roll = roll_with_fallback(&format!("1d20+{modifier}"), "1d20");
+        let dc = 10;
+
+        let resolution = Resolution::new(String::new()).with_effect(Effect::DiceRolled {
+            roll: roll.clone(),
+            purpose: format!("{medic_name} Medicine (stabilize {target_name})"),
+        });
+
+        if roll.total >= dc {
+            let mut resolution = resolution.with_effect(Effect::Stabilized { target_id });
+            resolution.narrative = format!(
+                "{} stabilizes {} (Medicine: {} vs DC {}). {} is stable at 0 HP.",
+                medic_name, target_name, roll.total, dc, target_name
+            );
+            resolution
+        } else {
+            let mut resolution = resolution;
+            resolution.narrative = format!(
+                "{} fails to stabilize {} (Medicine: {} vs DC {}). {} must keep making death saves.",
+                medic_name, target_name, roll.total, dc, target_name
+            );
+            resolution
+        }
+    }
+
     pub(crate) fn resolve_death_save(
         &self,
         world: &GameWorld,
         character_id: CharacterId,
     ) -> Resolution {
-        let character = &world.player_character;
+        let character = world
+            .character(character_id)
+            .unwrap_or(&world.player_character);
+        let character_id = character.id;
+
+        match character.life_state {
+            LifeState::Dead => {
+                return Resolution::new(format!("{} is already dead.", character.name));
+            }
+            LifeState::Stable => {
+                return Resolution::new(format!(
+                    "{} is stable and doesn't need to make a death save.",
+                    character.name
+                ));
+            }
+            LifeState::Alive | LifeState::Unconscious => {}
+        }
 
         // Must be at 0 HP to make death saves
         if character.hit_points.current > 0 {
@@ -628,7 +1780,9 @@ impl RulesEngine {
         damage_taken: i32,
         spell_name: &str,
     ) -> Resolution {
-        let character = &world.player_character;
+        let Some(character) = world.character(character_id) else {
+            return Resolution::new("No party member found to make a concentration check.");
+        };
 
         // Calculate DC: max(10, damage / 2)
         let dc = (damage_taken / 2).max(10);
@@ -679,10 +1833,31 @@ impl RulesEngine {
     }
 }
 
+/// Summarize the cumulative penalties for an exhaustion level.
+fn exhaustion_effects(level: u8) -> &'static str {
+    match level {
+        0 => "no effect",
+        1 => "disadvantage on ability checks",
+        2 => "disadvantage on ability checks, speed halved",
+        3 => "disadvantage on ability checks, attack rolls, and saving throws, speed halved",
+        4 => "disadvantage on ability checks, attack rolls, and saving throws, speed halved, hit point maximum halved",
+        5 => "disadvantage on ability checks, attack rolls, and saving throws, speed reduced to 0, hit point maximum halved",
+        _ => "death",
+    }
+}
+
+/// Describe a damage type, noting any resistance or vulnerability that applied.
+fn damage_text(damage_type: DamageType, resistance: Option<ResistanceLevel>) -> String {
+    match resistance {
+        Some(level) => format!("{} ({})", damage_type.name(), level.name()),
+        None => damage_type.name().to_string(),
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
-    use crate::rules::types::Effect;
+    use crate::rules::types::{Effect, Intent};
     use crate::world::{create_sample_fighter, CharacterId};
 
     // ========== Attack Tests ==========
@@ -700,6 +1875,10 @@ mod tests {
             target_id,
             "Longsword",
             Advantage::Normal,
+            false,
+            Cover::None,
+            None,
+            AttackMode::Melee,
         );
 
         // Should have at least one dice roll (attack roll)
@@ -729,6 +1908,10 @@ mod tests {
             target_id,
             "Longsword",
             Advantage::Normal,
+            false,
+            Cover::None,
+            None,
+            AttackMode::Melee,
         );
 
         assert!(resolution.narrative.contains("unconscious"));
@@ -751,6 +1934,7 @@ mod tests {
             10,
             DamageType::Slashing,
             "sword",
+            false,
         );
 
         assert!(resolution.narrative.contains("takes 10 slashing damage"));
@@ -773,6 +1957,7 @@ mod tests {
             10,
             DamageType::Slashing,
             "sword",
+            false,
         );
 
         assert!(resolution.narrative.contains("UNCONSCIOUS"));
@@ -800,6 +1985,7 @@ mod tests {
             38,
             DamageType::Force,
             "disintegration",
+            false,
         );
 
         assert!(resolution.narrative.contains("INSTANT DEATH"));
@@ -823,6 +2009,7 @@ mod tests {
             5,
             DamageType::Slashing,
             "sword",
+            false,
         );
 
         assert!(resolution.narrative.contains("death save failure"));
@@ -832,6 +2019,45 @@ mod tests {
             .any(|e| matches!(e, Effect::DeathSaveFailure { .. })));
     }
 
+    #[test]
+    fn test_critical_hit_while_down_causes_two_failures() {
+        let mut character = create_sample_fighter("Roland");
+        character.hit_points.current = 0;
+        character.add_condition(Condition::Unconscious, "test");
+        let mut world = GameWorld::new("Test", character);
+        let engine = RulesEngine::new();
+        let player_id = world.player_character.id;
+
+        let resolution =
+            engine.resolve_damage(&world, player_id, 5, DamageType::Slashing, "sword", true);
+        assert!(resolution.narrative.contains("two death save failures"));
+        assert!(resolution.effects.iter().any(|e| matches!(
+            e,
+            Effect::DeathSaveFailure {
+                failures: 2,
+                total_failures: 2,
+                ..
+            }
+        )));
+        apply_effects(&mut world, &resolution.effects);
+        assert_eq!(world.player_character.death_saves.failures, 2);
+        assert!(!world.player_character.is_dead());
+
+        // A second crit kills, capping the tally at three
+        let resolution =
+            engine.resolve_damage(&world, player_id, 5, DamageType::Slashing, "sword", true);
+        assert!(resolution.narrative.contains("DIES"));
+        assert!(resolution.effects.iter().any(|e| matches!(
+            e,
+            Effect::DeathSaveFailure {
+                total_failures: 3,
+                ..
+            }
+        )));
+        apply_effects(&mut world, &resolution.effects);
+        assert!(world.player_character.is_dead());
+    }
+
     // ========== Heal Tests ==========
 
     #[test]
@@ -929,9 +2155,13 @@ mod tests {
             max_hp: 28,
             armor_class: 18,
             initiative_modifier: 2,
+            dex_modifier: 2,
+            resistances: std::collections::HashMap::new(),
+            legendary_actions: 0,
+            position: None,
         }];
 
-        let resolution = engine.resolve_start_combat(&world, combatants);
+        let resolution = engine.resolve_start_combat(&world, combatants, vec![], &[]);
 
         assert!(resolution.narrative.contains("Combat begins"));
         assert!(resolution
@@ -970,6 +2200,54 @@ mod tests {
         assert!(resolution.narrative.contains("No combat"));
     }
 
+    #[test]
+    fn test_next_turn_reports_surprised_combatant() {
+        let mut world = GameWorld::new("Test", create_sample_fighter("Roland"));
+        let player_id = world.player_character.id;
+        let goblin_id = CharacterId::new();
+        let combat = world.start_combat();
+        for (id, name, initiative, is_player) in [
+            (player_id, "Roland", 15, true),
+            (goblin_id, "Goblin", 10, false),
+        ] {
+            combat.add_combatant(crate::world::Combatant {
+                id,
+                name: name.to_string(),
+                initiative,
+                dex_modifier: 0,
+                is_player,
+                is_ally: is_player,
+                current_hp: 10,
+                max_hp: 10,
+                armor_class: 12,
+                conditions: Vec::new(),
+                ongoing_effects: Vec::new(),
+                resistances: std::collections::HashMap::new(),
+                reaction_used: false,
+                dodging: false,
+                helped_by: None,
+                legendary_actions: 0,
+                legendary_max: 0,
+                position: None,
+            });
+        }
+        crate::rules::apply_effect(
+            &mut world,
+            &Effect::ConditionApplied {
+                target_id: goblin_id,
+                condition: Condition::Surprised,
+                source: "Surprise".to_string(),
+                duration_rounds: None,
+            },
+        );
+
+        let resolution = RulesEngine::new().resolve_next_turn(&world);
+        assert!(resolution
+            .narrative
+            .contains("Goblin is surprised and loses their turn"));
+        assert!(resolution.narrative.contains("Next turn: Roland (Round 2)"));
+    }
+
     // ========== Death Save Tests ==========
 
     #[test]
@@ -1004,6 +2282,91 @@ mod tests {
         )));
     }
 
+    // ========== Stabilize Tests ==========
+
+    /// A dying fighter with an ally cleric standing by.
+    fn dying_world_with_medic() -> (GameWorld, CharacterId) {
+        let mut character = create_sample_fighter("Roland");
+        character.hit_points.current = 0;
+        character.death_saves.successes = 1;
+        character.death_saves.failures = 2;
+        let mut world = GameWorld::new("Test", character);
+        let medic_id = CharacterId::new();
+        let combat = world.start_combat();
+        combat.add_combatant(crate::world::Combatant {
+            id: medic_id,
+            name: "Sister Mara".to_string(),
+            initiative: 10,
+            dex_modifier: 0,
+            is_player: false,
+            is_ally: true,
+            current_hp: 9,
+            max_hp: 9,
+            armor_class: 13,
+            conditions: Vec::new(),
+            ongoing_effects: Vec::new(),
+            resistances: std::collections::HashMap::new(),
+            reaction_used: false,
+            dodging: false,
+            helped_by: None,
+            legendary_actions: 0,
+            legendary_max: 0,
+            position: None,
+        });
+        (world, medic_id)
+    }
+
+    #[test]
+    fn test_stabilize_success_resets_death_saves() {
+        let (mut world, medic_id) = dying_world_with_medic();
+        let engine = RulesEngine::new();
+        let player_id = world.player_character.id;
+
+        let resolution = engine.resolve_stabilize(&world, medic_id, player_id, 30);
+        assert!(resolution
+            .narrative
+            .contains("Sister Mara stabilizes Roland"));
+        assert!(resolution
+            .effects
+            .iter()
+            .any(|e| matches!(e, Effect::Stabilized { target_id } if *target_id == player_id)));
+        assert!(!resolution
+            .effects
+            .iter()
+            .any(|e| matches!(e, Effect::HpChanged { .. })));
+
+        crate::rules::apply_effects(&mut world, &resolution.effects);
+        assert_eq!(world.player_character.hit_points.current, 0);
+        assert_eq!(world.player_character.death_saves.successes, 0);
+        assert_eq!(world.player_character.death_saves.failures, 0);
+    }
+
+    #[test]
+    fn test_stabilize_failure_keeps_dying() {
+        let (world, medic_id) = dying_world_with_medic();
+        let engine = RulesEngine::new();
+
+        let resolution = engine.resolve_stabilize(&world, medic_id, world.player_character.id, -30);
+        assert!(resolution
+            .narrative
+            .contains("must keep making death saves"));
+        assert!(!resolution
+            .effects
+            .iter()
+            .any(|e| matches!(e, Effect::Stabilized { .. })));
+    }
+
+    #[test]
+    fn test_stabilize_requires_dying_target() {
+        let (mut world, medic_id) = dying_world_with_medic();
+        world.player_character.hit_points.current = 10;
+        let engine = RulesEngine::new();
+
+        let resolution = engine.resolve_stabilize(&world, medic_id, world.player_character.id, 0);
+        assert!(resolution.narrative.contains("not dying"));
+        assert!(resolution.effects.is_empty());
+    }
+
     // ========== Concentration Check Tests ==========
 
     #[test]
@@ -1044,6 +2407,235 @@ mod tests {
         assert!(resolution.narrative.contains("DC 15"));
     }
 
+    #[test]
+    fn test_failed_concentration_check_clears_concentration() {
+        let mut character = create_sample_fighter("Roland");
+        character.concentrating_on = Some(crate::world::ConcentrationState {
+            spell_name: "Hold Person".to_string(),
+            slot_level: 2,
+        });
+        let mut world = GameWorld::new("Test", character);
+        let engine = RulesEngine::new();
+
+        // DC 100 can't be met
+        let resolution = engine.resolve_concentration_check(
+            &world,
+            world.player_character.id,
+            200,
+            "Hold Person",
+        );
+        assert!(resolution
+            .effects
+            .iter()
+            .any(|e| matches!(e, Effect::ConcentrationBroken { .. })));
+
+        crate::rules::apply_effects(&mut world, &resolution.effects);
+        assert!(world.player_character.concentrating_on.is_none());
+    }
+
+    #[test]
+    fn test_burning_deals_damage_each_turn_then_expires() {
+        let mut world = GameWorld::new("Test", create_sample_fighter("Roland"));
+        let player_id = world.player_character.id;
+        let goblin_id = CharacterId::new();
+        let combat = world.start_combat();
+        for (id, name, initiative, is_player) in [
+            (player_id, "Roland", 15, true),
+            (goblin_id, "Goblin", 10, false),
+        ] {
+            combat.add_combatant(crate::world::Combatant {
+                id,
+                name: name.to_string(),
+                initiative,
+                dex_modifier: 0,
+                is_player,
+                is_ally: is_player,
+                current_hp: 100,
+                max_hp: 100,
+                armor_class: 12,
+                conditions: Vec::new(),
+                ongoing_effects: Vec::new(),
+                resistances: std::collections::HashMap::new(),
+                reaction_used: false,
+                dodging: false,
+                helped_by: None,
+                legendary_actions: 0,
+                legendary_max: 0,
+                position: None,
+            });
+        }
+
+        let engine = RulesEngine::new();
+        let burning = OngoingEffect {
+            name: "Burning".to_string(),
+            kind: OngoingKind::Damage {
+                dice: "1d4".to_string(),
+                damage_type: DamageType::Fire,
+            },
+            remaining_rounds: 3,
+        };
+        let resolution = engine.resolve_apply_ongoing_effect(&world, goblin_id, burning);
+        apply_effects(&mut world, &resolution.effects);
+
+        let goblin_hp = |world: &GameWorld| {
+            world
+                .combat
+                .as_ref()
+                .and_then(|c| c.combatant(goblin_id))
+                .map(|c| c.current_hp)
+                .unwrap()
+        };
+
+        // Roland's turn is current; each other advance starts the goblin's turn
+        let mut hp = goblin_hp(&world);
+        for goblin_turn in 1..=4 {
+            for _ in 0..2 {
+                let resolution = engine.resolve_next_turn(&world);
+                apply_effects(&mut world, &resolution.effects);
+            }
+            let now = goblin_hp(&world);
+            if goblin_turn <= 3 {
+                assert!(now < hp, "burning should hurt on goblin turn {goblin_turn}");
+            } else {
+                assert_eq!(now, hp, "burning should have expired");
+            }
+            hp = now;
+        }
+
+        let goblin = world.combat.as_ref().unwrap().combatant(goblin_id).unwrap();
+        assert!(goblin.ongoing_effects.is_empty());
+    }
+
+    #[test]
+    fn test_damage_while_concentrating_queues_check() {
+        let mut character = create_sample_fighter("Roland");
+        character.hit_points = crate::world::HitPoints::new(60);
+        character.concentrating_on = Some(crate::world::ConcentrationState {
+            spell_name: "Bless".to_string(),
+            slot_level: 1,
+        });
+        let mut world = GameWorld::new("Test", character);
+        let player_id = world.player_character.id;
+
+        crate::rules::apply_effect(
+            &mut world,
+            &Effect::HpChanged {
+                target_id: player_id,
+                amount: -30,
+                new_current: 30,
+                new_max: 60,
+                dropped_to_zero: false,
+            },
+        );
+
+        let queued = world.take_queued_intents();
+        assert!(world.queued_intents.is_empty());
+        let [intent @ Intent::ConcentrationCheck {
+            damage_taken: 30, ..
+        }] = queued.as_slice()
+        else {
+            panic!("expected one concentration check, got {queued:?}");
+        };
+        let resolution = RulesEngine::new().resolve(&world, intent.clone());
+        assert!(resolution.narrative.contains("DC 15"));
+        assert!(resolution.narrative.contains("Bless"));
+
+        // Healing doesn't call for a check
+        crate::rules::apply_effect(
+            &mut world,
+            &Effect::HpChanged {
+                target_id: player_id,
+                amount: 10,
+                new_current: 40,
+                new_max: 60,
+                dropped_to_zero: false,
+            },
+        );
+        assert!(world.take_queued_intents().is_empty());
+    }
+
+    #[test]
+    fn test_party_member_concentration_uses_their_own_save() {
+        let mut world = GameWorld::new("Test", create_sample_fighter("Roland"));
+        let mut cleric = crate::world::create_sample_cleric("Brother Aldric");
+        cleric.hit_points = crate::world::HitPoints::new(60);
+        cleric.concentrating_on = Some(crate::world::ConcentrationState {
+            spell_name: "Spirit Guardians".to_string(),
+            slot_level: 3,
+        });
+        let cleric_id = cleric.id;
+        world.add_party_member(cleric);
+
+        crate::rules::apply_effect(
+            &mut world,
+            &Effect::HpChanged {
+                target_id: cleric_id,
+                amount: -30,
+                new_current: 30,
+                new_max: 60,
+                dropped_to_zero: false,
+            },
+        );
+        let queued = world.take_queued_intents();
+        let [intent @ Intent::ConcentrationCheck { character_id, .. }] = queued.as_slice() else {
+            panic!("expected one concentration check, got {queued:?}");
+        };
+        assert_eq!(*character_id, cleric_id);
+
+        let resolution = RulesEngine::new().resolve(&world, intent.clone());
+        assert!(resolution.narrative.starts_with("Brother Aldric makes"));
+        assert!(resolution.narrative.contains("Spirit Guardians"));
+
+        // A broken check ends the cleric's concentration, not the player's
+        world.player_character.concentrating_on = Some(crate::world::ConcentrationState {
+            spell_name: "Bless".to_string(),
+            slot_level: 1,
+        });
+        crate::rules::apply_effect(
+            &mut world,
+            &Effect::ConcentrationBroken {
+                character_id: cleric_id,
+                spell_name: "Spirit Guardians".to_string(),
+                damage_taken: 30,
+                roll: 5,
+                dc: 15,
+            },
+        );
+        assert!(world
+            .character(cleric_id)
+            .unwrap()
+            .concentrating_on
+            .is_none());
+        assert!(world.player_character.concentrating_on.is_some());
+    }
+
+    #[test]
+    fn test_dropping_to_zero_ends_concentration() {
+        let mut character = create_sample_fighter("Roland");
+        character.concentrating_on = Some(crate::world::ConcentrationState {
+            spell_name: "Bless".to_string(),
+            slot_level: 1,
+        });
+        let mut world = GameWorld::new("Test", character);
+        let (player_id, max) = (
+            world.player_character.id,
+            world.player_character.hit_points.maximum,
+        );
+
+        crate::rules::apply_effect(
+            &mut world,
+            &Effect::HpChanged {
+                target_id: player_id,
+                amount: -max,
+                new_current: 0,
+                new_max: max,
+                dropped_to_zero: true,
+            },
+        );
+        assert!(world.player_character.concentrating_on.is_none());
+        assert!(world.take_queued_intents().is_empty());
+    }
+
     // ========== Roll Initiative Tests ==========
 
     #[test]
@@ -1,9 +1,12 @@
 //! Inventory management resolution methods.
 
-use crate::rules::helpers::roll_with_fallback;
+use crate::rules::helpers::{bonus_action_taken, roll_with_fallback, spend_bonus_action};
 use crate::rules::types::{Effect, Resolution};
 use crate::rules::RulesEngine;
-use crate::world::{CharacterId, Condition, GameWorld, ItemType};
+use crate::treasure::{Loot, TreasureTable};
+use crate::world::{
+    Character, CharacterId, Condition, GameWorld, Item, ItemType, MAX_ATTUNED_ITEMS,
+};
 
 impl RulesEngine {
     #[allow(clippy::too_many_arguments)]
@@ -89,6 +92,10 @@ impl RulesEngine {
         let character = &world.player_character;
 
         if let Some(item) = character.inventory.find_item(item_name) {
+            if let Some(refusal) = require_attunement(character, item) {
+                return refusal;
+            }
+
             let slot = match item.item_type {
                 ItemType::Weapon => "main_hand",
                 ItemType::Armor => "armor",
@@ -205,6 +212,10 @@ impl RulesEngine {
     ) -> Resolution {
         let character = &world.player_character;
 
+        if character.is_dead() {
+            return Resolution::new(format!("{} is dead and cannot use items.", character.name));
+        }
+
         // Unconscious characters cannot use items themselves
         if character.has_condition(Condition::Unconscious) {
             return Resolution::new(format!(
@@ -214,9 +225,20 @@ impl RulesEngine {
         }
 
         if let Some(item) = character.inventory.find_item(item_name) {
+            if let Some(refusal) = require_attunement(character, item) {
+                return refusal;
+            }
+
             // Check if it's a consumable type
             match item.item_type {
                 ItemType::Potion => {
+                    // House rule: drinking a potion takes a bonus action
+                    if self.config.bonus_action_potions {
+                        if let Some(refusal) = bonus_action_taken(world, character) {
+                            return refusal;
+                        }
+                    }
+
                     // Look up proper healing amount from database, fall back to basic potion
                     let (dice_expr, bonus) =
                         if let Some(potion) = crate::items::get_potion(item_name) {
@@ -237,7 +259,7 @@ impl RulesEngine {
                     };
                     let heal_roll = roll_with_fallback(&heal_expr, "1d4");
 
-                    Resolution::new(format!(
+                    let resolution = Resolution::new(format!(
                         "{} drinks {} and heals for {} HP",
                         character.name, item_name, heal_roll.total
                     ))
@@ -257,7 +279,12 @@ impl RulesEngine {
                         item_name: item_name.to_string(),
                         quantity: 1,
                         remaining: item.quantity.saturating_sub(1),
-                    })
+                    });
+                    if self.config.bonus_action_potions {
+                        spend_bonus_action(world, character.id, resolution)
+                    } else {
+                        resolution
+                    }
                 }
                 ItemType::Scroll => Resolution::new(format!(
                     "{} reads {} and it crumbles to dust",
@@ -282,6 +309,64 @@ impl RulesEngine {
         }
     }
 
+    pub(crate) fn resolve_attune(&self, world: &GameWorld, item_name: &str) -> Resolution {
+        let character = &world.player_character;
+
+        if world.combat.is_some() {
+            return Resolution::new("Attuning to an item takes a short rest; not during combat!");
+        }
+        let Some(item) = held_item(character, item_name) else {
+            return Resolution::new(format!(
+                "{} doesn't have {} to attune to",
+                character.name, item_name
+            ));
+        };
+        if !item.requires_attunement {
+            return Resolution::new(format!("{} doesn't require attunement", item.name));
+        }
+        if character.is_attuned_to(&item.name) {
+            return Resolution::new(format!(
+                "{} is already attuned to {}",
+                character.name, item.name
+            ));
+        }
+        if character.attuned.len() >= MAX_ATTUNED_ITEMS {
+            return Resolution::new(format!(
+                "{} is already attuned to {} items ({}); end an attunement first",
+                character.name,
+                MAX_ATTUNED_ITEMS,
+                character.attuned.join(", ")
+            ));
+        }
+
+        Resolution::new(format!(
+            "{} spends a short rest attuning to {}",
+            character.name, item.name
+        ))
+        .with_effect(Effect::TimeAdvanced { minutes: 60 })
+        .with_effect(Effect::ItemAttuned {
+            item_name: item.name.clone(),
+        })
+    }
+
+    pub(crate) fn resolve_unattune(&self, world: &GameWorld, item_name: &str) -> Resolution {
+        let character = &world.player_character;
+
+        match character
+            .attuned
+            .iter()
+            .find(|name| name.eq_ignore_ascii_case(item_name))
+        {
+            Some(name) => {
+                Resolution::new(format!("{} ends attunement to {}", character.name, name))
+                    .with_effect(Effect::ItemUnattuned {
+                        item_name: name.clone(),
+                    })
+            }
+            None => Resolution::new(format!("{} isn't attuned to {}", character.name, item_name)),
+        }
+    }
+
     pub(crate) fn resolve_adjust_gold(
         &self,
         world: &GameWorld,
@@ -345,6 +430,102 @@ impl RulesEngine {
             })
         }
     }
+
+    pub(crate) fn resolve_generate_loot(
+        &self,
+        world: &GameWorld,
+        challenge_rating: u8,
+        hoard: bool,
+    ) -> Resolution {
+        let loot = crate::dice::with_current_rng(|mut rng| {
+            TreasureTable::default().roll(challenge_rating, hoard, &mut rng)
+        });
+        self.resolve_loot(world, &loot, hoard)
+    }
+
+    /// Turn rolled loot into gold and item effects.
+    fn resolve_loot(&self, world: &GameWorld, loot: &Loot, hoard: bool) -> Resolution {
+        let character = &world.player_character;
+        let source = if hoard { "the hoard" } else { "the creature" };
+
+        // Stack duplicate items into one effect each
+        let mut stacks: Vec<(&str, u32)> = Vec::new();
+        for item in &loot.items {
+            match stacks.iter_mut().find(|(name, _)| *name == item) {
+                Some((_, quantity)) => *quantity += 1,
+                None => stacks.push((item, 1)),
+            }
+        }
+
+        let mut found = Vec::new();
+        let mut effects = Vec::new();
+        if loot.gold > 0 {
+            found.push(format!("{} gp", loot.gold));
+            effects.push(Effect::GoldChanged {
+                amount: loot.gold,
+                new_total: character.inventory.gold + loot.gold,
+                reason: format!("loot from {source}"),
+            });
+        }
+        for (item_name, quantity) in stacks {
+            let existing_qty = character
+                .inventory
+                .find_item(item_name)
+                .map(|i| i.quantity)
+                .unwrap_or(0);
+            found.push(if quantity > 1 {
+                format!("{quantity} x {item_name}")
+            } else {
+                item_name.to_string()
+            });
+            effects.push(Effect::ItemAdded {
+                item_name: item_name.to_string(),
+                quantity,
+                new_total: existing_qty + quantity,
+            });
+        }
+
+        let narrative = if found.is_empty() {
+            format!(
+                "{} searches {} but finds nothing of value",
+                character.name, source
+            )
+        } else {
+            format!(
+                "{} searches {} and finds {}",
+                character.name,
+                source,
+                found.join(", ")
+            )
+        };
+        Resolution::new(narrative).with_effects(effects)
+    }
+}
+
+/// An item the character carries or has equipped.
+fn held_item<'a>(character: &'a Character, item_name: &str) -> Option<&'a Item> {
+    let equipment = &character.equipment;
+    character.inventory.find_item(item_name).or_else(|| {
+        [
+            equipment.armor.as_ref().map(|a| &a.base),
+            equipment.shield.as_ref(),
+            equipment.main_hand.as_ref().map(|w| &w.base),
+            equipment.off_hand.as_ref(),
+        ]
+        .into_iter()
+        .flatten()
+        .find(|i| i.name.eq_ignore_ascii_case(item_name))
+    })
+}
+
+/// Refuse to use an item whose magic needs an attunement the character lacks.
+fn require_attunement(character: &Character, item: &Item) -> Option<Resolution> {
+    (item.requires_attunement && !character.is_attuned_to(&item.name)).then(|| {
+        Resolution::new(format!(
+            "{} must attune to {} before using it",
+            character.name, item.name
+        ))
+    })
 }
 
 #[cfg(test)]
@@ -386,6 +567,7 @@ mod tests {
             description: None,
             item_type: ItemType::Potion,
             magical: true,
+            requires_attunement: false,
         });
         let world = GameWorld::new("Test", character);
         let engine = RulesEngine::new();
@@ -426,6 +608,7 @@ mod tests {
             description: None,
             item_type: ItemType::Potion,
             magical: true,
+            requires_attunement: false,
         });
         let world = GameWorld::new("Test", character);
         let engine = RulesEngine::new();
@@ -455,6 +638,7 @@ mod tests {
             description: None,
             item_type: ItemType::Potion,
             magical: true,
+            requires_attunement: false,
         });
         let world = GameWorld::new("Test", character);
         let engine = RulesEngine::new();
@@ -490,6 +674,7 @@ mod tests {
             description: None,
             item_type: ItemType::Weapon,
             magical: false,
+            requires_attunement: false,
         });
         let world = GameWorld::new("Test", character);
         let engine = RulesEngine::new();
@@ -515,6 +700,7 @@ mod tests {
             description: None,
             item_type: ItemType::Armor,
             magical: false,
+            requires_attunement: false,
         });
         let world = GameWorld::new("Test", character);
         let engine = RulesEngine::new();
@@ -535,6 +721,7 @@ mod tests {
             description: None,
             item_type: ItemType::Shield,
             magical: false,
+            requires_attunement: false,
         });
         let world = GameWorld::new("Test", character);
         let engine = RulesEngine::new();
@@ -555,6 +742,7 @@ mod tests {
             description: None,
             item_type: ItemType::Adventuring,
             magical: false,
+            requires_attunement: false,
         });
         let world = GameWorld::new("Test", character);
         let engine = RulesEngine::new();
@@ -653,6 +841,7 @@ mod tests {
             description: None,
             item_type: ItemType::Potion,
             magical: true,
+            requires_attunement: false,
         });
         let world = GameWorld::new("Test", character);
         let engine = RulesEngine::new();
@@ -686,6 +875,7 @@ mod tests {
             description: None,
             item_type: ItemType::Scroll,
             magical: true,
+            requires_attunement: false,
         });
         let world = GameWorld::new("Test", character);
         let engine = RulesEngine::new();
@@ -711,6 +901,7 @@ mod tests {
             description: None,
             item_type: ItemType::Weapon,
             magical: false,
+            requires_attunement: false,
         });
         let world = GameWorld::new("Test", character);
         let engine = RulesEngine::new();
@@ -744,6 +935,7 @@ mod tests {
             description: None,
             item_type: ItemType::Potion,
             magical: true,
+            requires_attunement: false,
         });
         character.add_condition(Condition::Unconscious, "test");
         let world = GameWorld::new("Test", character);
@@ -853,4 +1045,147 @@ mod tests {
         assert!(resolution.narrative.contains("doesn't have enough silver"));
         assert!(resolution.effects.is_empty());
     }
+
+    // ========== Loot Tests ==========
+
+    #[test]
+    fn test_loot_stacks_items_and_adds_gold() {
+        let mut character = create_sample_fighter("Roland");
+        character.inventory.gold = 10;
+        let world = GameWorld::new("Test", character);
+        let engine = RulesEngine::new();
+        let loot = Loot {
+            gold: 120,
+            items: vec![
+                "Potion of Healing".to_string(),
+                "Bag of Holding".to_string(),
+                "Potion of Healing".to_string(),
+            ],
+        };
+
+        let resolution = engine.resolve_loot(&world, &loot, true);
+
+        assert!(resolution
+            .narrative
+            .contains("finds 120 gp, 2 x Potion of Healing, Bag of Holding"));
+        assert!(resolution.effects.iter().any(|e| matches!(
+            e,
+            Effect::GoldChanged {
+                amount: 120,
+                new_total: 130,
+                ..
+            }
+        )));
+        assert!(resolution.effects.iter().any(|e| matches!(
+            e,
+            Effect::ItemAdded { item_name, quantity: 2, .. } if item_name == "Potion of Healing"
+        )));
+    }
+
+    #[test]
+    fn test_generate_loot_rolls_on_table() {
+        let world = GameWorld::new("Test", create_sample_fighter("Roland"));
+        let engine = RulesEngine::new();
+
+        let resolution = engine.resolve_generate_loot(&world, 5, true);
+
+        let table = TreasureTable::default();
+        assert!(resolution
+            .effects
+            .iter()
+            .any(|e| matches!(e, Effect::GoldChanged { amount, .. } if *amount > 0)));
+        for effect in &resolution.effects {
+            if let Effect::ItemAdded { item_name, .. } = effect {
+                assert!(table.items.iter().any(|i| &i.name == item_name));
+            }
+        }
+    }
+
+    // ========== Attunement Tests ==========
+
+    fn world_with_magic_items(names: &[&str]) -> GameWorld {
+        let mut world = GameWorld::new("Test", create_sample_fighter("Roland"));
+        for name in names {
+            let item = crate::items::get_magic_item(name).unwrap();
+            world.player_character.inventory.add_item(item);
+        }
+        world
+    }
+
+    fn attune(world: &mut GameWorld, item_name: &str) -> Resolution {
+        let resolution = RulesEngine::new().resolve_attune(world, item_name);
+        crate::rules::apply_effects(world, &resolution.effects);
+        resolution
+    }
+
+    #[test]
+    fn test_attunement_limited_to_three_items() {
+        let mut world = world_with_magic_items(&[
+            "Ring of Protection",
+            "Cloak of Protection",
+            "Amulet of Health",
+            "Gauntlets of Ogre Power",
+        ]);
+
+        attune(&mut world, "Ring of Protection");
+        attune(&mut world, "cloak of protection");
+        attune(&mut world, "Amulet of Health");
+        assert_eq!(world.player_character.attuned.len(), 3);
+
+        let resolution = attune(&mut world, "Gauntlets of Ogre Power");
+        assert!(resolution.effects.is_empty());
+        assert!(resolution.narrative.contains("already attuned to 3 items"));
+        assert!(!world
+            .player_character
+            .is_attuned_to("Gauntlets of Ogre Power"));
+    }
+
+    #[test]
+    fn test_unattuning_frees_a_slot() {
+        let mut world = world_with_magic_items(&[
+            "Ring of Protection",
+            "Cloak of Protection",
+            "Amulet of Health",
+            "Gauntlets of Ogre Power",
+        ]);
+        for name in [
+            "Ring of Protection",
+            "Cloak of Protection",
+            "Amulet of Health",
+        ] {
+            attune(&mut world, name);
+        }
+
+        let engine = RulesEngine::new();
+        let resolution = engine.resolve_unattune(&world, "ring of protection");
+        assert!(matches!(
+            &resolution.effects[..],
+            [Effect::ItemUnattuned { item_name }] if item_name == "Ring of Protection"
+        ));
+        crate::rules::apply_effects(&mut world, &resolution.effects);
+        assert_eq!(world.player_character.attuned.len(), 2);
+
+        attune(&mut world, "Gauntlets of Ogre Power");
+        assert!(world
+            .player_character
+            .is_attuned_to("Gauntlets of Ogre Power"));
+    }
+
+    #[test]
+    fn test_attunement_requirements() {
+        let mut world = world_with_magic_items(&["Bag of Holding", "Ring of Protection"]);
+        let engine = RulesEngine::new();
+
+        let resolution = engine.resolve_attune(&world, "Bag of Holding");
+        assert!(resolution.narrative.contains("doesn't require attunement"));
+        let resolution = engine.resolve_attune(&world, "Ring of Resistance");
+        assert!(resolution.effects.is_empty());
+
+        // The ring's magic can't be used until attuned
+        let resolution = engine.resolve_use_item(&world, "Ring of Protection", None);
+        assert!(resolution.narrative.contains("must attune"));
+        attune(&mut world, "Ring of Protection");
+        let resolution = engine.resolve_use_item(&world, "Ring of Protection", None);
+        assert!(!resolution.narrative.contains("must attune"));
+    }
 }
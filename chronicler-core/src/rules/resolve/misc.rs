@@ -34,13 +34,57 @@ impl RulesEngine {
         resolution
     }
 
+    pub(crate) fn resolve_grant_inspiration(
+        &self,
+        world: &GameWorld,
+        character_id: CharacterId,
+    ) -> Resolution {
+        let Some(character) = world.character(character_id) else {
+            return Resolution::new("No party member found for Heroic Inspiration.");
+        };
+        if character.has_inspiration {
+            // Inspiration doesn't stack
+            return Resolution::new(format!("{} already has inspiration.", character.name));
+        }
+        Resolution::new(format!("{} gains Heroic Inspiration!", character.name)).with_effect(
+            Effect::InspirationGranted {
+                character_id: character.id,
+            },
+        )
+    }
+
+    pub(crate) fn resolve_spend_inspiration(
+        &self,
+        world: &GameWorld,
+        character_id: CharacterId,
+    ) -> Resolution {
+        let Some(character) = world.character(character_id) else {
+            return Resolution::new("No party member found for Heroic Inspiration.");
+        };
+        if !character.has_inspiration {
+            return Resolution::new(format!(
+                "{} doesn't have inspiration to spend.",
+                character.name
+            ));
+        }
+        Resolution::new(format!(
+            "{} spends inspiration and has advantage on their next roll.",
+            character.name
+        ))
+        .with_effect(Effect::InspirationSpent {
+            character_id: character.id,
+        })
+    }
+
     pub(crate) fn resolve_use_feature(
         &self,
         world: &GameWorld,
-        _character_id: CharacterId,
+        character_id: CharacterId,
         feature_name: &str,
     ) -> Resolution {
-        let character = &world.player_character;
+        let Some(character) = world.character(character_id) else {
+            return Resolution::new("No party member found to use that feature.");
+        };
 
         if let Some(feature) = character.features.iter().find(|f| f.name == feature_name) {
             if let Some(ref uses) = feature.uses {
@@ -52,6 +96,7 @@ impl RulesEngine {
                         uses.current - 1
                     ))
                     .with_effect(Effect::FeatureUsed {
+                        character_id,
                         feature_name: feature_name.to_string(),
                         uses_remaining: uses.current - 1,
                     })
@@ -72,6 +117,7 @@ impl RulesEngine {
         }
     }
 
+    #[allow(clippy::too_many_arguments)]
     pub(crate) fn resolve_remember_fact(
         &self,
         subject_name: &str,
@@ -80,6 +126,7 @@ impl RulesEngine {
         category: &str,
         related_entities: &[String],
         importance: f32,
+        supersedes: bool,
     ) -> Resolution {
         // The actual storage is handled by the DM agent, not the rules engine.
         // We return a confirmation message and an effect that signals what to store.
@@ -99,6 +146,7 @@ impl RulesEngine {
             category: category.to_string(),
             related_entities: related_entities.to_vec(),
             importance,
+            supersedes,
         })
     }
 
@@ -285,7 +333,34 @@ mod tests {
         assert!(resolution
             .effects
             .iter()
-            .any(|e| matches!(e, Effect::FeatureUsed { feature_name, uses_remaining: 0 } if feature_name == "Second Wind")));
+            .any(|e| matches!(e, Effect::FeatureUsed { feature_name, uses_remaining: 0, .. } if feature_name == "Second Wind")));
+    }
+
+    #[test]
+    fn test_use_feature_charges_the_party_member_using_it() {
+        let mut fighter = create_sample_fighter("Roland");
+        fighter.features.push(Feature {
+            name: "Second Wind".to_string(),
+            description: "Regain HP".to_string(),
+            source: "Fighter".to_string(),
+            uses: Some(FeatureUses {
+                current: 1,
+                maximum: 1,
+                recharge: RechargeType::ShortRest,
+            }),
+        });
+        let fighter_id = fighter.id;
+        let mut world = GameWorld::new("Test", create_sample_fighter("Aria"));
+        world.add_party_member(fighter);
+        let engine = RulesEngine::new();
+
+        let resolution = engine.resolve_use_feature(&world, fighter_id, "Second Wind");
+
+        assert!(resolution.narrative.starts_with("Roland uses Second Wind"));
+        assert!(resolution.effects.iter().any(|e| matches!(
+            e,
+            Effect::FeatureUsed { character_id, .. } if *character_id == fighter_id
+        )));
     }
 
     #[test]
@@ -360,6 +435,7 @@ mod tests {
             "weakness",
             &[],
             0.8,
+            false,
         );
 
         assert!(resolution.narrative.contains("Goblin Chief"));
@@ -381,6 +457,7 @@ mod tests {
             "lore",
             &["Artifact".to_string(), "Evil Wizard".to_string()],
             0.9,
+            false,
         );
 
         assert!(resolution.narrative.contains("related:"));
@@ -1,23 +1,59 @@
 //! Spell casting resolution.
 
 use crate::dice;
-use crate::rules::helpers::roll_with_fallback;
-use crate::rules::types::{Effect, Resolution};
+use crate::rules::helpers::{critical_dice, roll_with_fallback};
+use crate::rules::types::{AreaShape, Cover, DamageType, Effect, Resolution};
 use crate::rules::RulesEngine;
-use crate::world::{CharacterId, GameWorld};
+use crate::spells::SpellClass;
+use crate::world::{Ability, CharacterClass, CharacterId, GameMode, GameWorld, NameLookupError};
 
 impl RulesEngine {
+    #[allow(clippy::too_many_arguments)]
     pub(crate) fn resolve_cast_spell(
         &self,
         world: &GameWorld,
-        _caster_id: CharacterId,
+        caster_id: CharacterId,
         spell_name: &str,
         slot_level: u8,
         target_names: &[String],
+        cover: Cover,
+        ritual: bool,
+    ) -> Resolution {
+        self.cast_spell_at(
+            world,
+            caster_id,
+            spell_name,
+            slot_level,
+            target_names,
+            None,
+            cover,
+            ritual,
+        )
+    }
+
+    /// Cast a spell at named targets. When `target_ids` is given, it holds the
+    /// combatant for each name, so targets that share a name stay distinct.
+    #[allow(clippy::too_many_arguments)]
+    fn cast_spell_at(
+        &self,
+        world: &GameWorld,
+        caster_id: CharacterId,
+        spell_name: &str,
+        slot_level: u8,
+        target_names: &[String],
+        target_ids: Option<&[CharacterId]>,
+        cover: Cover,
+        ritual: bool,
     ) -> Resolution {
         use crate::spells::{get_spell, SpellAttackType};
 
-        let caster = &world.player_character;
+        let Some(caster) = world.character(caster_id) else {
+            return Resolution::new("No party member found to cast the spell.");
+        };
+
+        if caster.is_dead() {
+            return Resolution::new(format!("{} is dead and cannot cast spells.", caster.name));
+        }
 
         // Look up the spell
         let spell = match get_spell(spell_name) {
@@ -30,11 +66,27 @@ impl RulesEngine {
             }
         };
 
+        if ritual && !spell.ritual {
+            return Resolution::new(format!("{} can't be cast as a ritual.", spell.name));
+        }
+
+        // Warlocks cast with Pact Magic slots when they can, always at the pact slot level
+        let pact = caster
+            .spellcasting
+            .as_ref()
+            .and_then(|sc| sc.pact_slots)
+            .filter(|pact| {
+                !ritual && pact.available() > 0 && spell.level > 0 && spell.level <= pact.slot_level
+            });
+
         // Determine the effective slot level
         let effective_slot = if spell.level == 0 {
             0 // Cantrips don't use slots
+        } else if ritual {
+            spell.level // Rituals are cast at their base level
         } else if slot_level == 0 {
-            spell.level // Use base spell level if not specified
+            // Use the pact slot level, or the base spell level if not specified
+            pact.map_or(spell.level, |pact| pact.slot_level)
         } else if slot_level < spell.level {
             return Resolution::new(format!(
                 "Cannot cast {} using a level {} slot - requires at least level {}.",
@@ -44,15 +96,13 @@ impl RulesEngine {
             slot_level
         };
 
-        // Check and consume spell slot (if not a cantrip)
+        // Check and consume spell slot (if not a cantrip or ritual)
         if spell.level > 0 {
             if let Some(ref spellcasting) = caster.spellcasting {
-                let slot_idx = (effective_slot - 1) as usize;
-                if slot_idx >= 9 {
+                if effective_slot > 9 {
                     return Resolution::new("Invalid spell slot level.");
                 }
-                let available = spellcasting.spell_slots.slots[slot_idx].available();
-                if available == 0 {
+                if !ritual && spellcasting.available_slots(effective_slot) == 0 {
                     return Resolution::new(format!(
                         "{} has no level {} spell slots remaining!",
                         caster.name, effective_slot
@@ -66,6 +116,71 @@ impl RulesEngine {
             }
         }
 
+        // Somatic components need a free hand
+        if spell.components.somatic
+            && caster.equipment.hands_full()
+            && !self.has_feature(caster, "War Caster")
+        {
+            return Resolution::new(format!(
+                "{} needs a free hand to cast {}.",
+                caster.name, spell.name
+            ));
+        }
+
+        // A costly material component must be on hand; a focus won't do
+        let material = spell
+            .components
+            .material
+            .as_deref()
+            .zip(spell.components.material_cost_gp());
+        let component = match material {
+            Some((material, cost)) => {
+                match caster.inventory.find_material_component(material, cost) {
+                    Some(item) => Some(item),
+                    None => {
+                        return Resolution::new(format!(
+                            "{} can't cast {} without its material component: {}.",
+                            caster.name, spell.name, material
+                        ));
+                    }
+                }
+            }
+            None => None,
+        };
+
+        // A target name that could mean several combatants needs clarifying first
+        if world.combat.is_some() && target_ids.is_none() {
+            for target_name in target_names {
+                if let Err(err @ NameLookupError::Ambiguous { .. }) =
+                    world.find_combatant_fuzzy(target_name)
+                {
+                    return Resolution::new(err.to_string());
+                }
+            }
+        }
+
+        // Total cover blocks attack and saving throw spells before anything is spent
+        if cover == Cover::Total && (spell.attack_type.is_some() || spell.save_type.is_some()) {
+            let target_name = if target_names.is_empty() {
+                "The target".to_string()
+            } else {
+                target_names.join(", ")
+            };
+            let mut resolution = Resolution::new(format!(
+                "{} has total cover - {} can't reach them.",
+                target_name, spell.name
+            ));
+            if spell.attack_type.is_some() {
+                resolution = resolution.with_effect(Effect::AttackMissed {
+                    attacker_name: caster.name.clone(),
+                    target_name,
+                    attack_roll: 0,
+                    target_ac: 0,
+                });
+            }
+            return resolution;
+        }
+
         // Get spellcasting ability modifier
         let spell_mod = caster
             .spellcasting
@@ -81,8 +196,13 @@ impl RulesEngine {
         let mut narrative_parts = Vec::new();
 
         // Casting announcement
+        let uses_pact_slot = pact.is_some_and(|pact| pact.slot_level == effective_slot);
         let slot_text = if spell.level == 0 {
             String::new()
+        } else if ritual {
+            " (ritual)".to_string()
+        } else if uses_pact_slot {
+            format!(" (level {} pact slot)", effective_slot)
         } else if effective_slot > spell.level {
             format!(" (upcast at level {})", effective_slot)
         } else {
@@ -93,14 +213,48 @@ impl RulesEngine {
             caster.name, spell.name, slot_text
         ));
 
-        // Handle concentration
+        if let Some(item) = component.filter(|_| spell.components.consumes_material()) {
+            narrative_parts.push(format!("The {} is consumed.", item.name));
+            resolution = resolution.with_effect(Effect::ItemRemoved {
+                item_name: item.name.clone(),
+                quantity: 1,
+                remaining: item.quantity - 1,
+            });
+        }
+
+        // Handle concentration - starting a new concentration spell ends the old one
         if spell.concentration {
             narrative_parts.push("(Concentration)".to_string());
+
+            if let Some(ref previous) = caster.concentrating_on {
+                narrative_parts.push(format!(
+                    "{} stops concentrating on {}.",
+                    caster.name, previous.spell_name
+                ));
+                resolution = resolution.with_effect(Effect::ConcentrationBroken {
+                    character_id: caster.id,
+                    spell_name: previous.spell_name.clone(),
+                    damage_taken: 0,
+                    roll: 0,
+                    dc: 0,
+                });
+            }
+
+            resolution = resolution.with_effect(Effect::ConcentrationStarted {
+                character_id: caster.id,
+                spell_name: spell.name.clone(),
+                slot_level: effective_slot,
+            });
         }
 
-        // Determine damage dice (accounting for cantrip scaling and upcasting)
+        // Determine damage dice (accounting for cantrip scaling and upcasting).
+        // Cantrips scale with total character level. A few pick a bigger die
+        // when the (first) target is already wounded.
         let caster_level = caster.level;
-        let damage_dice = spell.effective_damage_dice(caster_level, effective_slot);
+        let target_injured = target_names
+            .first()
+            .is_some_and(|name| Self::target_is_injured(world, name));
+        let damage_dice = spell.damage_dice_against(caster_level, effective_slot, target_injured);
 
         // Handle spell attack (if applicable)
         if let Some(attack_type) = &spell.attack_type {
@@ -117,24 +271,25 @@ impl RulesEngine {
                 purpose: format!("{} spell attack", attack_type_name),
             });
 
-            let target_name = target_names.first().map(|s| s.as_str()).unwrap_or("target");
-
-            // Look up target AC from combat state by name
-            let target_ac = if let Some(ref combat) = world.combat {
-                combat
-                    .combatants
-                    .iter()
-                    .find(|c| c.name.eq_ignore_ascii_case(target_name))
-                    .map(|c| c.armor_class)
-                    .unwrap_or(10)
-            } else {
-                10 // Default AC outside combat
-            };
+            // Look up the target in the combat state by name
+            let target = target_names.first().and_then(|name| {
+                let id = world.find_combatant_fuzzy(name).ok()?;
+                world.combat.as_ref()?.combatant(id)
+            });
+            let target_name = target
+                .map(|c| c.name.as_str())
+                .unwrap_or_else(|| target_names.first().map(|s| s.as_str()).unwrap_or("target"));
+            // Default AC for unknown targets and outside combat
+            let target_ac = target.map_or(10, |c| c.armor_class);
+            let target_ac = target_ac.saturating_add(cover.bonus() as u8);
 
             narrative_parts.push(format!(
                 "Makes a {} spell attack against {}: {} vs AC {}.",
                 attack_type_name, target_name, attack_roll.total, target_ac
             ));
+            if cover.bonus() > 0 {
+                narrative_parts.push(format!("({} +{} AC)", cover.name(), cover.bonus()));
+            }
 
             let hits = !attack_roll.is_fumble()
                 && (attack_roll.total >= target_ac as i32 || attack_roll.is_critical());
@@ -151,14 +306,8 @@ impl RulesEngine {
 
                 // Roll damage
                 if let Some(ref dice_str) = damage_dice {
-                    let damage_formula = if attack_roll.is_critical() {
-                        // Double dice on crit
-                        if let Some(d_pos) = dice_str.find('d') {
-                            let num: i32 = dice_str[..d_pos].parse().unwrap_or(1);
-                            format!("{}d{}", num * 2, &dice_str[d_pos + 1..])
-                        } else {
-                            dice_str.clone()
-                        }
+                    let damage_formula = if attack_roll.is_critical() && dice_str.contains('d') {
+                        critical_dice(dice_str, self.config.crit_max_damage)
                     } else {
                         dice_str.clone()
                     };
@@ -199,6 +348,20 @@ impl RulesEngine {
                 save_effect
             ));
 
+            // Cover only helps against Dexterity saves
+            let save_bonus = if save_ability == Ability::Dexterity {
+                cover.bonus()
+            } else {
+                0
+            };
+            if save_bonus > 0 {
+                narrative_parts.push(format!(
+                    "Targets have {} (+{} to the save).",
+                    cover.name(),
+                    save_bonus
+                ));
+            }
+
             // Roll damage (before save resolution)
             if let Some(ref dice_str) = damage_dice {
                 if let Ok(damage_roll) = dice::roll(dice_str) {
@@ -210,10 +373,33 @@ impl RulesEngine {
                         damage_roll.total, damage_type_name
                     ));
 
+                    let damage_total = damage_roll.total;
                     resolution = resolution.with_effect(Effect::DiceRolled {
                         roll: damage_roll,
                         purpose: format!("{} damage", spell.name),
                     });
+
+                    // Every target saves separately against the same damage roll
+                    let half_on_success = save_effect.starts_with("half");
+                    for (i, target_name) in target_names.iter().enumerate() {
+                        let target = match target_ids {
+                            Some(ids) => Ok(ids[i]),
+                            None => world.find_combatant_fuzzy(target_name),
+                        };
+                        let (effects, line) = Self::resolve_target_save(
+                            world,
+                            target_name,
+                            target,
+                            save_ability,
+                            spell_save_dc,
+                            save_bonus,
+                            damage_total,
+                            spell.damage_type,
+                            half_on_success,
+                        );
+                        resolution.effects.extend(effects);
+                        narrative_parts.push(line);
+                    }
                 }
             }
         }
@@ -238,9 +424,13 @@ impl RulesEngine {
             narrative_parts.push(spell.description.clone());
         }
 
-        // Add spell slot consumption effect (for leveled spells)
-        if spell.level > 0 {
+        // Rituals take 10 extra minutes instead of a spell slot
+        if ritual {
+            resolution = resolution.with_effect(Effect::TimeAdvanced { minutes: 10 });
+        } else if spell.level > 0 {
+            // Add spell slot consumption effect (for leveled spells)
             resolution = resolution.with_effect(Effect::SpellSlotUsed {
+                character_id: caster.id,
                 level: effective_slot,
                 remaining: 0, // Will be calculated by effect application
             });
@@ -250,6 +440,280 @@ impl RulesEngine {
         resolution
     }
 
+    /// Cast an area spell at every combatant standing in its area, or at the
+    /// named targets when no one in the fight has a position.
+    #[allow(clippy::too_many_arguments)]
+    pub(crate) fn resolve_cast_spell_aoe(
+        &self,
+        world: &GameWorld,
+        caster_id: CharacterId,
+        spell_name: &str,
+        slot_level: u8,
+        origin: (i32, i32),
+        shape: &AreaShape,
+        target_names: &[String],
+    ) -> Resolution {
+        let caught: Option<Vec<(String, CharacterId)>> = world
+            .combat
+            .as_ref()
+            .and_then(|combat| combat.combatants_in_area(origin, shape))
+            .map(|combatants| combatants.iter().map(|c| (c.name.clone(), c.id)).collect());
+        let Some(caught) = caught else {
+            return self.resolve_cast_spell(
+                world,
+                caster_id,
+                spell_name,
+                slot_level,
+                target_names,
+                Cover::None,
+                false,
+            );
+        };
+
+        let (names, ids): (Vec<String>, Vec<CharacterId>) = caught.into_iter().unzip();
+        let mut resolution = self.cast_spell_at(
+            world,
+            caster_id,
+            spell_name,
+            slot_level,
+            &names,
+            Some(&ids),
+            Cover::None,
+            false,
+        );
+        // Only describe the area once the spell actually goes off
+        if !resolution.effects.is_empty() {
+            let caught_text = if names.is_empty() {
+                "no one".to_string()
+            } else {
+                names.join(", ")
+            };
+            resolution.narrative = format!(
+                "The {} catches {}. {}",
+                shape.describe(),
+                caught_text,
+                resolution.narrative
+            );
+        }
+        resolution
+    }
+
+    /// Whether the named target is missing any hit points.
+    fn target_is_injured(world: &GameWorld, target_name: &str) -> bool {
+        if let Some(combatant) = world
+            .find_combatant_fuzzy(target_name)
+            .ok()
+            .and_then(|id| world.combat.as_ref()?.combatant(id))
+        {
+            return combatant.current_hp < combatant.max_hp;
+        }
+        world
+            .party_members()
+            .find(|c| c.name.eq_ignore_ascii_case(target_name))
+            .is_some_and(|c| c.hit_points.current < c.hit_points.maximum)
+    }
+
+    /// Roll one target's saving throw against a spell and work out the damage it takes.
+    ///
+    /// Targets are looked up by name in the combat state. Party members roll with their
+    /// own saving throw modifier; other combatants roll a flat d20. `save_bonus` adds
+    /// any cover bonus to the roll.
+    #[allow(clippy::too_many_arguments)]
+    fn resolve_target_save(
+        world: &GameWorld,
+        target_name: &str,
+        target: Result<CharacterId, NameLookupError>,
+        save_ability: Ability,
+        spell_save_dc: i8,
+        save_bonus: i8,
+        damage: i32,
+        damage_type: Option<DamageType>,
+        half_on_success: bool,
+    ) -> (Vec<Effect>, String) {
+        let target = match target {
+            Ok(id) => world
+                .combat
+                .as_ref()
+                .and_then(|combat| combat.combatant(id)),
+            Err(NameLookupError::NotFound { .. }) => None,
+            Err(err) => return (Vec::new(), err.to_string()),
+        };
+        let Some(target) = target else {
+            return (Vec::new(), format!("{target_name} is not in combat."));
+        };
+
+        let member = world.character(target.id);
+        let save_mod = member.map_or(0, |character| character.saving_throw_modifier(save_ability))
+            + save_bonus;
+        let save_roll = roll_with_fallback(&format!("1d20+{save_mod}"), "1d20");
+        let saved = save_roll.total >= spell_save_dc as i32;
+
+        let mut amount = match (saved, half_on_success) {
+            (false, _) => damage,
+            (true, true) => damage / 2,
+            (true, false) => 0,
+        };
+        let resistance = damage_type.and_then(|dt| {
+            if let Some(member) = member {
+                member.resistance_to(dt)
+            } else {
+                target.resistance_to(dt)
+            }
+        });
+        if let Some(level) = resistance {
+            amount = level.apply(amount);
+        }
+
+        let mut effects = vec![Effect::DiceRolled {
+            roll: save_roll.clone(),
+            purpose: format!("{} {} save", target.name, save_ability.abbreviation()),
+        }];
+
+        let outcome = if saved { "succeeds" } else { "fails" };
+        if amount <= 0 {
+            return (
+                effects,
+                format!(
+                    "{} {} ({} vs DC {}) and takes no damage.",
+                    target.name, outcome, save_roll.total, spell_save_dc
+                ),
+            );
+        }
+
+        let (current, max_hp) = match member {
+            Some(member) => (member.hit_points.current, member.hit_points.maximum),
+            None => (target.current_hp, target.max_hp),
+        };
+        let new_current = if let Some(member) = member {
+            let mut hp = member.hit_points.clone();
+            hp.take_damage(amount);
+            hp.current.max(0)
+        } else {
+            (current - amount).max(0)
+        };
+        let dropped_to_zero = current > 0 && new_current == 0;
+
+        effects.push(Effect::HpChanged {
+            target_id: target.id,
+            amount: -amount,
+            new_current,
+            new_max: max_hp,
+            dropped_to_zero,
+        });
+
+        let down = if dropped_to_zero { " - DOWN!" } else { "" };
+        (
+            effects,
+            format!(
+                "{} {} ({} vs DC {}) and takes {} damage (HP: {}/{}{}).",
+                target.name,
+                outcome,
+                save_roll.total,
+                spell_save_dc,
+                amount,
+                new_current,
+                max_hp,
+                down
+            ),
+        )
+    }
+
+    pub(crate) fn resolve_counterspell(
+        &self,
+        world: &GameWorld,
+        caster_id: CharacterId,
+        target_spell_level: u8,
+        slot_level: u8,
+    ) -> Resolution {
+        let Some(caster) = world.character(caster_id) else {
+            return Resolution::new("No party member found to cast Counterspell.");
+        };
+        let Some(spellcasting) = caster.spellcasting.as_ref() else {
+            return Resolution::new(format!(
+                "{} doesn't have spellcasting ability!",
+                caster.name
+            ));
+        };
+
+        // Counterspell is a reaction, so it needs one available during combat
+        if world
+            .combat
+            .as_ref()
+            .is_some_and(|combat| !combat.can_react(caster_id))
+        {
+            return Resolution::new(format!(
+                "{} has already used their reaction this round and can't cast Counterspell.",
+                caster.name
+            ));
+        }
+
+        if !(3..=9).contains(&slot_level) {
+            return Resolution::new(format!(
+                "Cannot cast Counterspell using a level {} slot - requires at least level 3.",
+                slot_level
+            ));
+        }
+        if spellcasting.available_slots(slot_level) == 0 {
+            return Resolution::new(format!(
+                "{} has no level {} spell slots remaining!",
+                caster.name, slot_level
+            ));
+        }
+
+        let mut resolution = Resolution::new(String::new());
+        let success = if slot_level >= target_spell_level {
+            resolution.narrative = format!(
+                "{} casts Counterspell (level {} slot) - the level {} spell is countered!",
+                caster.name, slot_level, target_spell_level
+            );
+            true
+        } else {
+            // Higher-level spells need a spellcasting ability check
+            let dc = 10 + target_spell_level as i32;
+            let modifier = caster.ability_scores.modifier(spellcasting.ability);
+            let roll = roll_with_fallback(&format!("1d20+{modifier}"), "1d20");
+            let success = roll.total >= dc;
+            resolution.narrative = format!(
+                "{} casts Counterspell (level {} slot) against a level {} spell: {} check {} vs DC {} - {}",
+                caster.name,
+                slot_level,
+                target_spell_level,
+                spellcasting.ability.name(),
+                roll.total,
+                dc,
+                if success {
+                    "the spell is countered!"
+                } else {
+                    "the spell goes off anyway."
+                }
+            );
+            resolution = resolution.with_effect(Effect::DiceRolled {
+                roll,
+                purpose: "Counterspell ability check".to_string(),
+            });
+            success
+        };
+
+        // The slot is spent whether or not the counter works
+        resolution = resolution
+            .with_effect(Effect::SpellCountered { success })
+            .with_effect(Effect::SpellSlotUsed {
+                character_id: caster.id,
+                level: slot_level,
+                remaining: 0,
+            });
+        let in_initiative = world
+            .combat
+            .as_ref()
+            .is_some_and(|combat| combat.combatant(caster_id).is_some());
+        if in_initiative {
+            resolution = resolution.with_effect(Effect::ReactionUsed {
+                character_id: caster_id,
+            });
+        }
+        resolution
+    }
+
     pub(crate) fn resolve_restore_spell_slot(
         &self,
         world: &GameWorld,
@@ -267,10 +731,7 @@ impl RulesEngine {
             .player_character
             .spellcasting
             .as_ref()
-            .map(|sc| {
-                let slot_idx = (slot_level - 1) as usize;
-                sc.spell_slots.slots[slot_idx].available() + 1
-            })
+            .map(|sc| sc.available_slots(slot_level) + 1)
             .unwrap_or(0);
 
         Resolution::new(format!(
@@ -282,13 +743,129 @@ impl RulesEngine {
             new_remaining,
         })
     }
+
+    /// Replace a prepared caster's prepared spells. Only allowed in Rest mode,
+    /// right after a long rest, and capped at the class's preparation limit.
+    pub(crate) fn resolve_prepare_spells(
+        &self,
+        world: &GameWorld,
+        spells: &[String],
+    ) -> Resolution {
+        use crate::spells::get_spell;
+
+        let caster = &world.player_character;
+        let Some(ref spellcasting) = caster.spellcasting else {
+            return Resolution::new(format!("{} can't cast spells.", caster.name));
+        };
+        let ability_mod = caster.ability_scores.modifier(spellcasting.ability);
+        let Some((class_level, max_prepared)) = caster.classes.iter().find_map(|c| {
+            c.class
+                .max_prepared_spells(c.level, ability_mod)
+                .map(|max| (c, max))
+        }) else {
+            return Resolution::new(format!(
+                "{} doesn't prepare spells; they cast from the spells they know.",
+                caster.name
+            ));
+        };
+        if world.mode != GameMode::Rest {
+            return Resolution::new(format!(
+                "{} can only change their prepared spells at the end of a long rest.",
+                caster.name
+            ));
+        }
+
+        let class = class_level.class;
+        let max_level = class.max_spell_level(class_level.level);
+        let mut prepared: Vec<String> = Vec::new();
+        for name in spells {
+            let Some(spell) = get_spell(name) else {
+                return Resolution::new(format!("Unknown spell: {}", name));
+            };
+            if spell.is_cantrip() {
+                return Resolution::new(format!(
+                    "{} is a cantrip; cantrips are always ready and aren't prepared.",
+                    spell.name
+                ));
+            }
+            if !spell.classes.contains(&spell_class(class)) {
+                return Resolution::new(format!(
+                    "{} isn't on the {} spell list.",
+                    spell.name,
+                    class.name()
+                ));
+            }
+            if spell.level > max_level {
+                return Resolution::new(format!(
+                    "{} is a level {} spell, but a level {} {} can prepare spells of at most level {}.",
+                    spell.name,
+                    spell.level,
+                    class_level.level,
+                    class.name(),
+                    max_level
+                ));
+            }
+            if class == CharacterClass::Wizard
+                && !spellcasting
+                    .spells_known
+                    .iter()
+                    .any(|known| known.eq_ignore_ascii_case(&spell.name))
+            {
+                return Resolution::new(format!(
+                    "{} isn't in {}'s spellbook.",
+                    spell.name, caster.name
+                ));
+            }
+            if !prepared.contains(&spell.name) {
+                prepared.push(spell.name.clone());
+            }
+        }
+
+        if prepared.len() > max_prepared {
+            return Resolution::new(format!(
+                "{} can prepare at most {} spells, not {}.",
+                caster.name,
+                max_prepared,
+                prepared.len()
+            ));
+        }
+
+        Resolution::new(format!(
+            "{} prepares {} spells: {}",
+            caster.name,
+            prepared.len(),
+            prepared.join(", ")
+        ))
+        .with_effect(Effect::SpellsPrepared { spells: prepared })
+    }
+}
+
+/// The spell list a class casts from.
+fn spell_class(class: CharacterClass) -> SpellClass {
+    match class {
+        CharacterClass::Bard => SpellClass::Bard,
+        CharacterClass::Cleric => SpellClass::Cleric,
+        CharacterClass::Druid => SpellClass::Druid,
+        CharacterClass::Paladin => SpellClass::Paladin,
+        CharacterClass::Ranger => SpellClass::Ranger,
+        CharacterClass::Sorcerer => SpellClass::Sorcerer,
+        CharacterClass::Warlock => SpellClass::Warlock,
+        // Fighters and rogues only cast through subclasses that use the wizard list
+        CharacterClass::Wizard
+        | CharacterClass::Fighter
+        | CharacterClass::Rogue
+        | CharacterClass::Barbarian
+        | CharacterClass::Monk => SpellClass::Wizard,
+    }
 }
 
 #[cfg(test)]
 mod tests {
     use super::*;
     use crate::rules::types::Effect;
-    use crate::world::{create_sample_cleric, create_sample_fighter, GameWorld};
+    use crate::world::{
+        create_sample_cleric, create_sample_fighter, create_sample_sorcerer, GameWorld,
+    };
 
     // ========== Cast Spell Tests ==========
 
@@ -304,6 +881,8 @@ mod tests {
             "Nonexistent Spell",
             1,
             &[],
+            Cover::None,
+            false,
         );
 
         assert!(resolution.narrative.contains("Unknown spell"));
@@ -321,6 +900,8 @@ mod tests {
             "Sacred Flame",
             0,
             &["Goblin".to_string()],
+            Cover::None,
+            false,
         );
 
         assert!(resolution.narrative.contains("casts Sacred Flame"));
@@ -344,6 +925,8 @@ mod tests {
             "Spiritual Weapon",
             1,
             &[],
+            Cover::None,
+            false,
         );
 
         assert!(resolution.narrative.contains("Cannot cast"));
@@ -362,6 +945,8 @@ mod tests {
             "Cure Wounds",
             1,
             &["Roland".to_string()],
+            Cover::None,
+            false,
         );
 
         assert!(resolution.narrative.contains("doesn't have spellcasting"));
@@ -379,6 +964,8 @@ mod tests {
             "Cure Wounds",
             1,
             &["Ally".to_string()],
+            Cover::None,
+            false,
         );
 
         assert!(resolution.narrative.contains("casts Cure Wounds"));
@@ -388,6 +975,184 @@ mod tests {
             .any(|e| matches!(e, Effect::SpellSlotUsed { level: 1, .. })));
     }
 
+    fn gold_dust(value_gp: f32) -> crate::world::Item {
+        crate::world::Item {
+            name: "Gold Dust".to_string(),
+            quantity: 2,
+            weight: 0.0,
+            value_gp,
+            description: None,
+            item_type: crate::world::ItemType::Other,
+            magical: false,
+            requires_attunement: false,
+        }
+    }
+
+    #[test]
+    fn test_costly_material_component_is_required_and_consumed() {
+        let engine = RulesEngine::new();
+        let cast = |world: &GameWorld| {
+            engine.resolve_cast_spell(
+                world,
+                world.player_character.id,
+                "Arcane Lock",
+                2,
+                &[],
+                Cover::None,
+                false,
+            )
+        };
+
+        let mut world = GameWorld::new("Test", create_sample_cleric("Sera"));
+        let resolution = cast(&world);
+        assert!(resolution
+            .narrative
+            .contains("without its material component: gold dust worth at least 25 gp"));
+        assert!(resolution.effects.is_empty());
+
+        // Dust worth too little doesn't count
+        world.player_character.inventory.add_item(gold_dust(10.0));
+        assert!(cast(&world).effects.is_empty());
+
+        world.player_character.inventory.items.clear();
+        world.player_character.inventory.add_item(gold_dust(25.0));
+        let resolution = cast(&world);
+        assert!(resolution.narrative.contains("The Gold Dust is consumed."));
+        assert!(resolution.effects.iter().any(|e| matches!(
+            e,
+            Effect::ItemRemoved { item_name, quantity: 1, remaining: 1 } if item_name == "Gold Dust"
+        )));
+        crate::rules::apply_effects(&mut world, &resolution.effects);
+        assert_eq!(
+            world
+                .player_character
+                .inventory
+                .find_item("Gold Dust")
+                .unwrap()
+                .quantity,
+            1
+        );
+    }
+
+    #[test]
+    fn test_material_cost_parsing() {
+        use crate::spells::get_spell;
+
+        let cost = |name: &str| get_spell(name).unwrap().components.material_cost_gp();
+        assert_eq!(cost("Revivify"), Some(300));
+        assert_eq!(cost("Chromatic Orb"), Some(50));
+        assert_eq!(cost("Cure Wounds"), None);
+        assert!(get_spell("Revivify")
+            .unwrap()
+            .components
+            .consumes_material());
+        assert!(!get_spell("Chromatic Orb")
+            .unwrap()
+            .components
+            .consumes_material());
+    }
+
+    #[test]
+    fn test_somatic_spell_needs_a_free_hand() {
+        let mut character = create_sample_cleric("Sera");
+        character.equipment.main_hand = Some(crate::world::WeaponItem::new(
+            "Mace",
+            "1d6",
+            crate::world::WeaponDamageType::Bludgeoning,
+        ));
+        character.equipment.shield = Some(crate::world::Item {
+            name: "Shield".to_string(),
+            item_type: crate::world::ItemType::Shield,
+            ..gold_dust(10.0)
+        });
+        let mut world = GameWorld::new("Test", character);
+        let engine = RulesEngine::new();
+        let cast = |world: &GameWorld| {
+            engine.resolve_cast_spell(
+                world,
+                world.player_character.id,
+                "Cure Wounds",
+                1,
+                &["Sera".to_string()],
+                Cover::None,
+                false,
+            )
+        };
+
+        let resolution = cast(&world);
+        assert!(resolution.narrative.contains("needs a free hand"));
+
+        world.player_character.features.push(crate::world::Feature {
+            name: "War Caster".to_string(),
+            description: String::new(),
+            source: "Feat".to_string(),
+            uses: None,
+        });
+        assert!(cast(&world).narrative.contains("casts Cure Wounds"));
+    }
+
+    #[test]
+    fn test_cast_ritual_uses_no_slot() {
+        let character = create_sample_cleric("Sera");
+        let mut world = GameWorld::new("Test", character);
+        let engine = RulesEngine::new();
+        let slots_before = world
+            .player_character
+            .spellcasting
+            .as_ref()
+            .unwrap()
+            .available_slots(1);
+
+        let resolution = engine.resolve_cast_spell(
+            &world,
+            world.player_character.id,
+            "Detect Magic",
+            0,
+            &[],
+            Cover::None,
+            true,
+        );
+
+        assert!(resolution.narrative.contains("casts Detect Magic (ritual)"));
+        assert!(!resolution
+            .effects
+            .iter()
+            .any(|e| matches!(e, Effect::SpellSlotUsed { .. })));
+        assert!(resolution
+            .effects
+            .iter()
+            .any(|e| matches!(e, Effect::TimeAdvanced { minutes: 10 })));
+
+        crate::rules::apply_effects(&mut world, &resolution.effects);
+        let slots_after = world
+            .player_character
+            .spellcasting
+            .as_ref()
+            .unwrap()
+            .available_slots(1);
+        assert_eq!(slots_after, slots_before);
+    }
+
+    #[test]
+    fn test_cast_ritual_rejects_non_ritual_spell() {
+        let character = create_sample_cleric("Sera");
+        let world = GameWorld::new("Test", character);
+        let engine = RulesEngine::new();
+
+        let resolution = engine.resolve_cast_spell(
+            &world,
+            world.player_character.id,
+            "Cure Wounds",
+            1,
+            &["Ally".to_string()],
+            Cover::None,
+            true,
+        );
+
+        assert!(resolution.narrative.contains("can't be cast as a ritual"));
+        assert!(resolution.effects.is_empty());
+    }
+
     #[test]
     fn test_cast_spell_upcast() {
         let mut character = create_sample_cleric("Sera");
@@ -404,6 +1169,8 @@ mod tests {
             "Cure Wounds",
             2,
             &["Ally".to_string()],
+            Cover::None,
+            false,
         );
 
         assert!(resolution.narrative.contains("upcast at level 2"));
@@ -426,9 +1193,69 @@ mod tests {
             "Bless",
             1,
             &["Ally".to_string()],
+            Cover::None,
+            false,
         );
 
         assert!(resolution.narrative.contains("Concentration"));
+        assert!(resolution.effects.iter().any(|e| matches!(
+            e,
+            Effect::ConcentrationStarted { spell_name, .. } if spell_name == "Bless"
+        )));
+    }
+
+    #[test]
+    fn test_new_concentration_spell_ends_previous() {
+        let mut character = create_sample_cleric("Sera");
+        if let Some(ref mut spellcasting) = character.spellcasting {
+            spellcasting.spell_slots.slots[0] = crate::world::SlotInfo { total: 4, used: 0 };
+        }
+        let mut world = GameWorld::new("Test", character);
+        let engine = RulesEngine::new();
+        let id = world.player_character.id;
+
+        let bless = engine.resolve_cast_spell(
+            &world,
+            id,
+            "Bless",
+            1,
+            &["Ally".to_string()],
+            Cover::None,
+            false,
+        );
+        crate::rules::apply_effects(&mut world, &bless.effects);
+        assert_eq!(
+            world
+                .player_character
+                .concentrating_on
+                .as_ref()
+                .map(|c| c.spell_name.as_str()),
+            Some("Bless")
+        );
+
+        let bane = engine.resolve_cast_spell(
+            &world,
+            id,
+            "Bane",
+            1,
+            &["Goblin".to_string()],
+            Cover::None,
+            false,
+        );
+        assert!(bane.narrative.contains("stops concentrating on Bless"));
+        assert!(bane.effects.iter().any(|e| matches!(
+            e,
+            Effect::ConcentrationBroken { spell_name, .. } if spell_name == "Bless"
+        )));
+        crate::rules::apply_effects(&mut world, &bane.effects);
+        assert_eq!(
+            world
+                .player_character
+                .concentrating_on
+                .as_ref()
+                .map(|c| c.spell_name.as_str()),
+            Some("Bane")
+        );
     }
 
     #[test]
@@ -443,6 +1270,8 @@ mod tests {
             "Cure Wounds",
             1,
             &["Roland".to_string()],
+            Cover::None,
+            false,
         );
 
         assert!(resolution.narrative.contains("heals"));
@@ -465,12 +1294,548 @@ mod tests {
             "Sacred Flame",
             0,
             &["Goblin".to_string()],
+            Cover::None,
+            false,
         );
 
         assert!(resolution.narrative.contains("saving throw"));
         assert!(resolution.narrative.contains("DC"));
     }
 
+    fn add_enemy(world: &mut GameWorld, name: &str, hp: i32) -> CharacterId {
+        let id = CharacterId::new();
+        let combat = world
+            .combat
+            .get_or_insert_with(crate::world::CombatState::new);
+        combat.add_combatant(crate::world::Combatant {
+            id,
+            name: name.to_string(),
+            initiative: 10,
+            dex_modifier: 0,
+            is_player: false,
+            is_ally: false,
+            current_hp: hp,
+            max_hp: hp,
+            armor_class: 12,
+            conditions: Vec::new(),
+            ongoing_effects: Vec::new(),
+            resistances: std::collections::HashMap::new(),
+            reaction_used: false,
+            dodging: false,
+            helped_by: None,
+            legendary_actions: 0,
+            legendary_max: 0,
+            position: None,
+        });
+        id
+    }
+
+    /// The damage dice rolled by a cast, from its DiceRolled effect.
+    fn damage_notation(resolution: &Resolution, spell_name: &str) -> String {
+        resolution
+            .effects
+            .iter()
+            .find_map(|e| match e {
+                Effect::DiceRolled { roll, purpose }
+                    if *purpose == format!("{spell_name} damage") =>
+                {
+                    Some(roll.expression.original.clone())
+                }
+                _ => None,
+            })
+            .expect("damage should be rolled")
+    }
+
+    #[test]
+    fn test_save_cantrip_scales_with_character_level() {
+        let engine = RulesEngine::new();
+        let expected = [
+            (4, "1d8"),
+            (5, "2d8"),
+            (10, "2d8"),
+            (11, "3d8"),
+            (16, "3d8"),
+            (17, "4d8"),
+        ];
+        for (level, dice) in expected {
+            let mut character = create_sample_cleric("Sera");
+            character.level = level;
+            let mut world = GameWorld::new("Test", character);
+            add_enemy(&mut world, "Goblin", 7);
+
+            let resolution = engine.resolve_cast_spell(
+                &world,
+                world.player_character.id,
+                "Sacred Flame",
+                0,
+                &["Goblin".to_string()],
+                Cover::None,
+                false,
+            );
+            assert_eq!(
+                damage_notation(&resolution, "Sacred Flame"),
+                dice,
+                "Sacred Flame at character level {level}"
+            );
+        }
+    }
+
+    #[test]
+    fn test_toll_the_dead_uses_d12_against_wounded_target() {
+        let engine = RulesEngine::new();
+        let mut character = create_sample_cleric("Sera");
+        character.level = 5;
+        let mut world = GameWorld::new("Test", character);
+        add_enemy(&mut world, "Goblin", 7);
+        let ogre = add_enemy(&mut world, "Ogre", 59);
+
+        let cast_at = |world: &GameWorld, target: &str| {
+            let resolution = engine.resolve_cast_spell(
+                world,
+                world.player_character.id,
+                "Toll the Dead",
+                0,
+                &[target.to_string()],
+                Cover::None,
+                false,
+            );
+            damage_notation(&resolution, "Toll the Dead")
+        };
+
+        assert_eq!(cast_at(&world, "Ogre"), "2d8");
+
+        world.combat.as_mut().unwrap().apply_hp_change(ogre, -10);
+        assert_eq!(cast_at(&world, "Ogre"), "2d12");
+        assert_eq!(cast_at(&world, "Goblin"), "2d8");
+    }
+
+    #[test]
+    fn test_cast_fireball_at_multiple_targets() {
+        let mut character = create_sample_sorcerer("Ignis");
+        if let Some(ref mut spellcasting) = character.spellcasting {
+            spellcasting.spell_slots.slots[2] = crate::world::SlotInfo { total: 1, used: 0 };
+        }
+        let mut world = GameWorld::new("Test", character);
+        let goblin = add_enemy(&mut world, "Goblin", 7);
+        let orc = add_enemy(&mut world, "Orc", 15);
+        let ogre = add_enemy(&mut world, "Ogre", 59);
+        let engine = RulesEngine::new();
+
+        let resolution = engine.resolve_cast_spell(
+            &world,
+            world.player_character.id,
+            "Fireball",
+            3,
+            &[
+                "Goblin".to_string(),
+                "Orc".to_string(),
+                "Ogre".to_string(),
+                "Dragon".to_string(),
+            ],
+            Cover::None,
+            false,
+        );
+
+        let damage = resolution
+            .effects
+            .iter()
+            .find_map(|e| match e {
+                Effect::DiceRolled { roll, purpose } if purpose == "Fireball damage" => {
+                    Some(roll.total)
+                }
+                _ => None,
+            })
+            .expect("Fireball should roll damage once");
+
+        // Each target rolls its own save and takes full or half damage
+        for (id, name) in [(goblin, "Goblin"), (orc, "Orc"), (ogre, "Ogre")] {
+            assert!(resolution.effects.iter().any(|e| matches!(
+                e,
+                Effect::DiceRolled { purpose, .. } if purpose == &format!("{name} DEX save")
+            )));
+            let taken = resolution
+                .effects
+                .iter()
+                .find_map(|e| match e {
+                    Effect::HpChanged {
+                        target_id, amount, ..
+                    } if *target_id == id => Some(-amount),
+                    _ => None,
+                })
+                .expect("each target takes damage");
+            assert!(taken == damage || taken == damage / 2);
+            assert!(resolution.narrative.contains(name));
+        }
+        assert!(resolution.narrative.contains("Dragon is not in combat"));
+    }
+
+    fn place(world: &mut GameWorld, id: CharacterId, position: (i32, i32)) {
+        world
+            .combat
+            .as_mut()
+            .unwrap()
+            .combatant_mut(id)
+            .unwrap()
+            .position = Some(position);
+    }
+
+    fn damaged(resolution: &Resolution, id: CharacterId) -> bool {
+        resolution.effects.iter().any(|e| {
+            matches!(e, Effect::HpChanged { target_id, amount, .. } if *target_id == id && *amount < 0)
+        })
+    }
+
+    #[test]
+    fn test_fireball_sphere_catches_combatants_in_range() {
+        let mut character = create_sample_sorcerer("Ignis");
+        if let Some(ref mut spellcasting) = character.spellcasting {
+            spellcasting.spell_slots.slots[2] = crate::world::SlotInfo { total: 1, used: 0 };
+        }
+        let mut world = GameWorld::new("Test", character);
+        let goblin = add_enemy(&mut world, "Goblin", 7);
+        let orc = add_enemy(&mut world, "Orc", 15);
+        let ogre = add_enemy(&mut world, "Ogre", 59);
+        place(&mut world, goblin, (50, 50));
+        place(&mut world, orc, (62, 66)); // exactly 20 feet away
+        place(&mut world, ogre, (80, 50));
+        let engine = RulesEngine::new();
+
+        let resolution = engine.resolve_cast_spell_aoe(
+            &world,
+            world.player_character.id,
+            "Fireball",
+            3,
+            (50, 50),
+            &AreaShape::Sphere { radius: 20 },
+            // Named targets are ignored once positions are known
+            &["Ogre".to_string()],
+        );
+
+        assert!(damaged(&resolution, goblin));
+        assert!(damaged(&resolution, orc));
+        assert!(!damaged(&resolution, ogre));
+        assert!(resolution
+            .narrative
+            .contains("The 20-foot sphere catches Goblin, Orc."));
+    }
+
+    #[test]
+    fn test_area_spell_hits_combatants_that_share_a_name() {
+        let mut character = create_sample_sorcerer("Ignis");
+        if let Some(ref mut spellcasting) = character.spellcasting {
+            spellcasting.spell_slots.slots[2] = crate::world::SlotInfo { total: 1, used: 0 };
+        }
+        let mut world = GameWorld::new("Test", character);
+        let first = add_enemy(&mut world, "Goblin", 7);
+        let second = add_enemy(&mut world, "Goblin", 7);
+        place(&mut world, first, (0, 0));
+        place(&mut world, second, (10, 0));
+
+        let resolution = RulesEngine::new().resolve_cast_spell_aoe(
+            &world,
+            world.player_character.id,
+            "Fireball",
+            3,
+            (0, 0),
+            &AreaShape::Sphere { radius: 20 },
+            &[],
+        );
+
+        assert!(damaged(&resolution, first));
+        assert!(damaged(&resolution, second));
+    }
+
+    #[test]
+    fn test_area_spell_falls_back_to_named_targets_without_positions() {
+        let mut character = create_sample_sorcerer("Ignis");
+        if let Some(ref mut spellcasting) = character.spellcasting {
+            spellcasting.spell_slots.slots[2] = crate::world::SlotInfo { total: 1, used: 0 };
+        }
+        let mut world = GameWorld::new("Test", character);
+        let goblin = add_enemy(&mut world, "Goblin", 7);
+        let orc = add_enemy(&mut world, "Orc", 15);
+        let engine = RulesEngine::new();
+
+        let resolution = engine.resolve_cast_spell_aoe(
+            &world,
+            world.player_character.id,
+            "Fireball",
+            3,
+            (0, 0),
+            &AreaShape::Sphere { radius: 20 },
+            &["Orc".to_string()],
+        );
+
+        assert!(damaged(&resolution, orc));
+        assert!(!damaged(&resolution, goblin));
+        assert!(!resolution.narrative.contains("sphere catches"));
+    }
+
+    #[test]
+    fn test_single_target_save_spell_deals_damage() {
+        let mut character = create_sample_sorcerer("Ignis");
+        if let Some(ref mut spellcasting) = character.spellcasting {
+            spellcasting.spell_slots.slots[2] = crate::world::SlotInfo { total: 1, used: 0 };
+        }
+        let mut world = GameWorld::new("Test", character);
+        let ogre = add_enemy(&mut world, "Ogre", 59);
+
+        let (mut failures, mut successes) = (0, 0);
+        for seed in 0..40 {
+            let resolution = RulesEngine::with_seed(seed).resolve(
+                &world,
+                crate::rules::Intent::CastSpell {
+                    caster_id: world.player_character.id,
+                    spell_name: "Fireball".to_string(),
+                    targets: Vec::new(),
+                    spell_level: 3,
+                    target_names: vec!["Ogre".to_string()],
+                    cover: Cover::None,
+                    ritual: false,
+                },
+            );
+            let damage = resolution
+                .effects
+                .iter()
+                .find_map(|e| match e {
+                    Effect::DiceRolled { roll, purpose } if purpose == "Fireball damage" => {
+                        Some(roll.total)
+                    }
+                    _ => None,
+                })
+                .unwrap();
+
+            let mut after = world.clone();
+            crate::rules::apply_effects(&mut after, &resolution.effects);
+            let hp = after
+                .combat
+                .as_ref()
+                .unwrap()
+                .combatant(ogre)
+                .unwrap()
+                .current_hp;
+            if resolution.narrative.contains("Ogre fails") {
+                assert_eq!(hp, 59 - damage);
+                failures += 1;
+            } else {
+                assert!(resolution.narrative.contains("Ogre succeeds"));
+                assert_eq!(hp, 59 - damage / 2);
+                successes += 1;
+            }
+        }
+        assert!(failures > 0 && successes > 0);
+    }
+
+    #[test]
+    fn test_spell_targets_match_partial_names() {
+        let mut character = create_sample_sorcerer("Ignis");
+        if let Some(ref mut spellcasting) = character.spellcasting {
+            spellcasting.spell_slots.slots[2] = crate::world::SlotInfo { total: 1, used: 0 };
+        }
+        let mut world = GameWorld::new("Test", character);
+        let archer = add_enemy(&mut world, "Goblin Archer", 59);
+        add_enemy(&mut world, "Orc", 15);
+        let engine = RulesEngine::new();
+
+        let resolution = engine.resolve_cast_spell(
+            &world,
+            world.player_character.id,
+            "Fireball",
+            3,
+            &["goblin".to_string()],
+            Cover::None,
+            false,
+        );
+        assert!(resolution.narrative.contains("Goblin Archer"));
+        assert!(resolution.effects.iter().any(|e| matches!(
+            e,
+            Effect::HpChanged { target_id, .. } if *target_id == archer
+        )));
+
+        // A second goblin makes the short name ambiguous
+        add_enemy(&mut world, "Goblin Boss", 21);
+        let resolution = engine.resolve_cast_spell(
+            &world,
+            world.player_character.id,
+            "Fireball",
+            3,
+            &["goblin".to_string()],
+            Cover::None,
+            false,
+        );
+        assert!(resolution.narrative.contains("ambiguous"));
+        assert!(resolution.effects.is_empty());
+    }
+
+    #[test]
+    fn test_cover_bonus_to_dex_saves() {
+        let mut character = create_sample_sorcerer("Ignis");
+        if let Some(ref mut spellcasting) = character.spellcasting {
+            spellcasting.spell_slots.slots[2] = crate::world::SlotInfo { total: 1, used: 0 };
+        }
+        let mut world = GameWorld::new("Test", character);
+        add_enemy(&mut world, "Goblin", 7);
+        add_enemy(&mut world, "Orc", 15);
+        let engine = RulesEngine::new();
+
+        let resolution = engine.resolve_cast_spell(
+            &world,
+            world.player_character.id,
+            "Fireball",
+            3,
+            &["Goblin".to_string(), "Orc".to_string()],
+            Cover::ThreeQuarters,
+            false,
+        );
+
+        let save_modifiers: Vec<i32> = resolution
+            .effects
+            .iter()
+            .filter_map(|e| match e {
+                Effect::DiceRolled { roll, purpose } if purpose.ends_with("DEX save") => {
+                    Some(roll.modifier)
+                }
+                _ => None,
+            })
+            .collect();
+        assert_eq!(save_modifiers, vec![5, 5]);
+        assert!(resolution.narrative.contains("three-quarters cover"));
+    }
+
+    #[test]
+    fn test_total_cover_blocks_spell_attack() {
+        let character = create_sample_sorcerer("Ignis");
+        let mut world = GameWorld::new("Test", character);
+        add_enemy(&mut world, "Goblin", 7);
+        let engine = RulesEngine::new();
+
+        let resolution = engine.resolve_cast_spell(
+            &world,
+            world.player_character.id,
+            "Fire Bolt",
+            0,
+            &["Goblin".to_string()],
+            Cover::Total,
+            false,
+        );
+
+        assert!(resolution.narrative.contains("Goblin has total cover"));
+        assert!(!resolution
+            .effects
+            .iter()
+            .any(|e| matches!(e, Effect::DiceRolled { .. })));
+        assert!(resolution
+            .effects
+            .iter()
+            .any(|e| matches!(e, Effect::AttackMissed { .. })));
+    }
+
+    // ========== Counterspell Tests ==========
+
+    fn sorcerer_with_third_level_slot(charisma: u8) -> GameWorld {
+        let mut character = create_sample_sorcerer("Ignis");
+        character.ability_scores.charisma = charisma;
+        if let Some(ref mut spellcasting) = character.spellcasting {
+            spellcasting.spell_slots.slots[2] = crate::world::SlotInfo { total: 1, used: 0 };
+        }
+        GameWorld::new("Test", character)
+    }
+
+    #[test]
+    fn test_counterspell_auto_succeeds_with_high_enough_slot() {
+        let world = sorcerer_with_third_level_slot(16);
+        let engine = RulesEngine::new();
+
+        let resolution = engine.resolve_counterspell(&world, world.player_character.id, 3, 3);
+
+        assert!(resolution.narrative.contains("is countered"));
+        assert!(resolution
+            .effects
+            .iter()
+            .any(|e| matches!(e, Effect::SpellCountered { success: true })));
+        assert!(resolution
+            .effects
+            .iter()
+            .any(|e| matches!(e, Effect::SpellSlotUsed { level: 3, .. })));
+        assert!(!resolution
+            .effects
+            .iter()
+            .any(|e| matches!(e, Effect::DiceRolled { .. })));
+    }
+
+    #[test]
+    fn test_counterspell_against_higher_level_needs_check() {
+        // CHA 1 (-5) can never reach DC 19 against a 9th-level spell
+        let world = sorcerer_with_third_level_slot(1);
+        let engine = RulesEngine::new();
+
+        let resolution = engine.resolve_counterspell(&world, world.player_character.id, 9, 3);
+
+        assert!(resolution.narrative.contains("vs DC 19"));
+        assert!(resolution
+            .effects
+            .iter()
+            .any(|e| matches!(e, Effect::DiceRolled { .. })));
+        assert!(resolution
+            .effects
+            .iter()
+            .any(|e| matches!(e, Effect::SpellCountered { success: false })));
+        // The slot is spent even though the counter failed
+        assert!(resolution
+            .effects
+            .iter()
+            .any(|e| matches!(e, Effect::SpellSlotUsed { level: 3, .. })));
+    }
+
+    #[test]
+    fn test_counterspell_requires_reaction() {
+        let mut world = sorcerer_with_third_level_slot(16);
+        let engine = RulesEngine::new();
+        let player_id = world.player_character.id;
+        add_enemy(&mut world, "Cultist", 9);
+
+        let first = engine.resolve_counterspell(&world, player_id, 2, 3);
+        assert!(first
+            .effects
+            .iter()
+            .all(|e| !matches!(e, Effect::ReactionUsed { .. })));
+
+        // Once the player is in the initiative order, the reaction is spent
+        world
+            .combat
+            .as_mut()
+            .unwrap()
+            .add_combatant(crate::world::Combatant {
+                id: player_id,
+                name: "Ignis".to_string(),
+                initiative: 12,
+                dex_modifier: 0,
+                is_player: true,
+                is_ally: true,
+                current_hp: 20,
+                max_hp: 20,
+                armor_class: 12,
+                conditions: Vec::new(),
+                ongoing_effects: Vec::new(),
+                resistances: std::collections::HashMap::new(),
+                reaction_used: false,
+                dodging: false,
+                helped_by: None,
+                legendary_actions: 0,
+                legendary_max: 0,
+                position: None,
+            });
+        let second = engine.resolve_counterspell(&world, player_id, 2, 3);
+        assert!(second.effects.iter().any(
+            |e| matches!(e, Effect::ReactionUsed { character_id } if *character_id == player_id)
+        ));
+        crate::rules::apply_effects(&mut world, &second.effects);
+
+        let third = engine.resolve_counterspell(&world, player_id, 2, 3);
+        assert!(third.narrative.contains("already used their reaction"));
+        assert!(third.effects.is_empty());
+    }
+
     // ========== Restore Spell Slot Tests ==========
 
     #[test]
@@ -512,4 +1877,78 @@ mod tests {
         assert!(resolution.narrative.contains("Invalid spell slot level"));
         assert!(resolution.effects.is_empty());
     }
+
+    // ========== Prepare Spells Tests ==========
+
+    fn spell_list(names: &[&str]) -> Vec<String> {
+        names.iter().map(|n| n.to_string()).collect()
+    }
+
+    #[test]
+    fn test_prepare_spells_after_long_rest() {
+        let character = create_sample_cleric("Sera");
+        let mut world = GameWorld::new("Test", character);
+        world.long_rest();
+        let engine = RulesEngine::new();
+
+        let resolution =
+            engine.resolve_prepare_spells(&world, &spell_list(&["bless", "Spiritual Weapon"]));
+
+        assert!(resolution.effects.iter().any(|e| matches!(
+            e,
+            Effect::SpellsPrepared { spells } if spells == &spell_list(&["Bless", "Spiritual Weapon"])
+        )));
+    }
+
+    #[test]
+    fn test_prepare_spells_over_limit_fails() {
+        // Level 3 cleric with WIS 16 prepares 3 + 3 = 6 spells
+        let character = create_sample_cleric("Sera");
+        let mut world = GameWorld::new("Test", character);
+        world.long_rest();
+        let engine = RulesEngine::new();
+
+        let seven = spell_list(&[
+            "Bless",
+            "Cure Wounds",
+            "Guiding Bolt",
+            "Shield of Faith",
+            "Healing Word",
+            "Command",
+            "Sanctuary",
+        ]);
+        let resolution = engine.resolve_prepare_spells(&world, &seven);
+
+        assert!(resolution.narrative.contains("at most 6 spells"));
+        assert!(resolution.effects.is_empty());
+    }
+
+    #[test]
+    fn test_prepare_spells_outside_rest_rejected() {
+        let character = create_sample_cleric("Sera");
+        let mut world = GameWorld::new("Test", character);
+        let engine = RulesEngine::new();
+
+        let resolution = engine.resolve_prepare_spells(&world, &spell_list(&["Bless"]));
+        assert!(resolution.narrative.contains("end of a long rest"));
+        assert!(resolution.effects.is_empty());
+
+        // Time moving on after the rest closes the window again
+        world.long_rest();
+        crate::rules::apply_effect(&mut world, &Effect::TimeAdvanced { minutes: 10 });
+        let resolution = engine.resolve_prepare_spells(&world, &spell_list(&["Bless"]));
+        assert!(resolution.effects.is_empty());
+    }
+
+    #[test]
+    fn test_prepare_spells_rejects_known_casters() {
+        let character = create_sample_sorcerer("Vex");
+        let mut world = GameWorld::new("Test", character);
+        world.long_rest();
+        let engine = RulesEngine::new();
+
+        let resolution = engine.resolve_prepare_spells(&world, &spell_list(&["Shield"]));
+        assert!(resolution.narrative.contains("doesn't prepare spells"));
+        assert!(resolution.effects.is_empty());
+    }
 }
@@ -1,8 +1,10 @@
 //! Time-related resolution methods (rests, time advancement).
 
+use crate::dice::DieType;
+use crate::rules::helpers::roll_with_fallback;
 use crate::rules::types::{Effect, Resolution, RestType};
 use crate::rules::RulesEngine;
-use crate::world::GameWorld;
+use crate::world::{Ability, CharacterId, GameWorld, Weather};
 
 impl RulesEngine {
     pub(crate) fn resolve_short_rest(&self, world: &GameWorld) -> Resolution {
@@ -31,6 +33,87 @@ impl RulesEngine {
             })
     }
 
+    /// Roll hit dice, largest first, healing each die's roll plus the
+    /// Constitution modifier (at least 1).
+    pub(crate) fn resolve_spend_hit_dice(
+        &self,
+        world: &GameWorld,
+        character_id: CharacterId,
+        count: u8,
+    ) -> Resolution {
+        let Some(character) = world.character(character_id) else {
+            return Resolution::new("No such character to spend hit dice.");
+        };
+        let mut pool: Vec<(DieType, u8)> = character
+            .hit_dice
+            .remaining
+            .iter()
+            .filter(|(_, &remaining)| remaining > 0)
+            .map(|(&die, &remaining)| (die, remaining))
+            .collect();
+        if pool.is_empty() {
+            return Resolution::new(format!(
+                "{} has no hit dice left to spend. (Half recover on a long rest)",
+                character.name
+            ));
+        }
+        if count == 0 {
+            return Resolution::new("Spend at least one hit die.");
+        }
+        pool.sort_by_key(|(die, _)| std::cmp::Reverse(die.sides()));
+
+        let con_mod = character.ability_scores.modifier(Ability::Constitution);
+        let mut resolution = Resolution::new(String::new());
+        let mut to_spend = count;
+        let mut spent = 0;
+        let mut healing = 0;
+        for (die, remaining) in pool {
+            let used = remaining.min(to_spend);
+            if used == 0 {
+                break;
+            }
+            for _ in 0..used {
+                let roll = roll_with_fallback(&format!("1d{}{:+}", die.sides(), con_mod), "1d4");
+                healing += roll.total.max(1);
+                resolution = resolution.with_effect(Effect::DiceRolled {
+                    roll,
+                    purpose: "Hit die healing".to_string(),
+                });
+            }
+            resolution = resolution.with_effect(Effect::HitDiceSpent {
+                character_id,
+                die_type: die,
+                count: used,
+            });
+            to_spend -= used;
+            spent += used;
+        }
+
+        let max_hp = character.effective_max_hp();
+        let new_hp = (character.hit_points.current + healing).min(max_hp);
+        resolution.narrative = format!(
+            "{} spends {} hit {} and regains {} HP. (Now at {}/{})",
+            character.name,
+            spent,
+            if spent == 1 { "die" } else { "dice" },
+            new_hp - character.hit_points.current,
+            new_hp,
+            max_hp
+        );
+        if spent < count {
+            resolution
+                .narrative
+                .push_str(&format!(" Only {spent} hit dice were left."));
+        }
+        resolution.with_effect(Effect::HpChanged {
+            target_id: character_id,
+            amount: new_hp - character.hit_points.current,
+            new_current: new_hp,
+            new_max: max_hp,
+            dropped_to_zero: false,
+        })
+    }
+
     pub(crate) fn resolve_advance_time(&self, minutes: u32) -> Resolution {
         let hours = minutes / 60;
         let mins = minutes % 60;
@@ -45,6 +128,19 @@ impl RulesEngine {
 
         Resolution::new(format!("{time_str} pass.")).with_effect(Effect::TimeAdvanced { minutes })
     }
+
+    pub(crate) fn resolve_set_weather(&self, world: &GameWorld, weather: Weather) -> Resolution {
+        if world.weather == weather {
+            return Resolution::new(format!("The weather is already {}.", weather.name()));
+        }
+
+        Resolution::new(format!(
+            "The weather turns from {} to {}.",
+            world.weather.name(),
+            weather.name()
+        ))
+        .with_effect(Effect::WeatherChanged { weather })
+    }
 }
 
 #[cfg(test)]
@@ -89,14 +185,28 @@ mod tests {
                 id: CharacterId::new(),
                 name: "Roland".to_string(),
                 initiative: 15,
+                dex_modifier: 0,
                 is_player: true,
                 is_ally: true,
                 current_hp: 20,
                 max_hp: 20,
                 armor_class: 16,
+                conditions: Vec::new(),
+                ongoing_effects: Vec::new(),
+                resistances: std::collections::HashMap::new(),
+                reaction_used: false,
+                dodging: false,
+                helped_by: None,
+                legendary_actions: 0,
+                legendary_max: 0,
+                position: None,
             }],
             sneak_attack_used: std::collections::HashSet::new(),
             attacks_this_turn: std::collections::HashMap::new(),
+            bonus_action_used: std::collections::HashSet::new(),
+            lair_actions: Vec::new(),
+            lair_action_used_this_round: false,
+            reroll_initiative_each_round: false,
         });
         let engine = RulesEngine::new();
 
@@ -143,14 +253,28 @@ mod tests {
                 id: CharacterId::new(),
                 name: "Roland".to_string(),
                 initiative: 15,
+                dex_modifier: 0,
                 is_player: true,
                 is_ally: true,
                 current_hp: 20,
                 max_hp: 20,
                 armor_class: 16,
+                conditions: Vec::new(),
+                ongoing_effects: Vec::new(),
+                resistances: std::collections::HashMap::new(),
+                reaction_used: false,
+                dodging: false,
+                helped_by: None,
+                legendary_actions: 0,
+                legendary_max: 0,
+                position: None,
             }],
             sneak_attack_used: std::collections::HashSet::new(),
             attacks_this_turn: std::collections::HashMap::new(),
+            bonus_action_used: std::collections::HashSet::new(),
+            lair_actions: Vec::new(),
+            lair_action_used_this_round: false,
+            reroll_initiative_each_round: false,
         });
         let engine = RulesEngine::new();
 
@@ -203,4 +327,76 @@ mod tests {
             .iter()
             .any(|e| matches!(e, Effect::TimeAdvanced { minutes: 90 })));
     }
+
+    // ========== Hit Dice Tests ==========
+
+    fn wounded_fighter_world(hit_dice: u8) -> GameWorld {
+        let mut character = create_sample_fighter("Roland");
+        character.hit_dice = crate::world::HitDice::new();
+        character.hit_dice.add(DieType::D10, hit_dice);
+        character.hit_points.current = 1;
+        GameWorld::new("Test", character)
+    }
+
+    fn remaining_d10(world: &GameWorld) -> u8 {
+        world.player_character.hit_dice.remaining[&DieType::D10]
+    }
+
+    #[test]
+    fn test_spend_two_hit_dice_heals_within_range() {
+        let engine = RulesEngine::new();
+        let con = create_sample_fighter("Roland")
+            .ability_scores
+            .modifier(Ability::Constitution) as i32;
+        for _ in 0..50 {
+            let mut world = wounded_fighter_world(4);
+            let id = world.player_character.id;
+
+            let resolution = engine.resolve_spend_hit_dice(&world, id, 2);
+            let healed = resolution
+                .effects
+                .iter()
+                .find_map(|e| match e {
+                    Effect::HpChanged { amount, .. } => Some(*amount),
+                    _ => None,
+                })
+                .unwrap();
+            assert!(
+                (2 * (1 + con).max(1)..=2 * (10 + con)).contains(&healed),
+                "healed {healed}"
+            );
+            assert!(resolution.narrative.contains("spends 2 hit dice"));
+
+            crate::rules::apply_effects(&mut world, &resolution.effects);
+            assert_eq!(remaining_d10(&world), 2);
+            assert_eq!(world.player_character.hit_points.current, 1 + healed);
+        }
+    }
+
+    #[test]
+    fn test_spend_hit_dice_refused_when_pool_empty() {
+        let engine = RulesEngine::new();
+        let world = wounded_fighter_world(0);
+
+        let resolution = engine.resolve_spend_hit_dice(&world, world.player_character.id, 1);
+
+        assert!(resolution.narrative.contains("no hit dice left"));
+        assert!(resolution.effects.is_empty());
+    }
+
+    #[test]
+    fn test_long_rest_restores_half_of_spent_hit_dice() {
+        let engine = RulesEngine::new();
+        let mut world = wounded_fighter_world(4);
+        let id = world.player_character.id;
+
+        let spend = engine.resolve_spend_hit_dice(&world, id, 6);
+        assert!(spend.narrative.contains("Only 4 hit dice were left"));
+        crate::rules::apply_effects(&mut world, &spend.effects);
+        assert_eq!(remaining_d10(&world), 0);
+
+        let rest = engine.resolve_long_rest(&world);
+        crate::rules::apply_effects(&mut world, &rest.effects);
+        assert_eq!(remaining_d10(&world), 2);
+    }
 }
@@ -1,8 +1,9 @@
 //! World building resolution methods (NPCs, locations, state assertions).
 
+use crate::encounter_tables::EncounterTables;
 use crate::rules::types::{Effect, Resolution, StateType};
 use crate::rules::RulesEngine;
-use crate::world::GameWorld;
+use crate::world::{clamp_reputation, GameWorld, LocationType, NameLookupError, ReputationTier};
 
 impl RulesEngine {
     #[allow(clippy::too_many_arguments)]
@@ -56,15 +57,13 @@ impl RulesEngine {
         new_description: Option<&str>,
         new_personality: Option<&str>,
     ) -> Resolution {
-        // Check if NPC exists
-        let npc_exists = world
-            .npcs
-            .values()
-            .any(|n| n.name.eq_ignore_ascii_case(npc_name));
-
-        if !npc_exists {
-            return Resolution::new(format!("NPC '{}' not found in the world", npc_name));
-        }
+        let npc_name = match world.find_npc_fuzzy(npc_name) {
+            Ok(npc) => npc.name.as_str(),
+            Err(NameLookupError::NotFound { .. }) => {
+                return Resolution::new(format!("NPC '{}' not found in the world", npc_name));
+            }
+            Err(err) => return Resolution::new(err.to_string()),
+        };
 
         let mut changes = Vec::new();
         if disposition.is_some() {
@@ -101,11 +100,15 @@ impl RulesEngine {
         destination: &str,
         reason: Option<&str>,
     ) -> Resolution {
+        let npc = match world.find_npc_fuzzy(npc_name) {
+            Ok(npc) => Some(npc),
+            Err(NameLookupError::NotFound { .. }) => None,
+            Err(err) => return Resolution::new(err.to_string()),
+        };
+        let npc_name = npc.map_or(npc_name, |n| n.name.as_str());
+
         // Find the NPC's current location
-        let from_location = world
-            .npcs
-            .values()
-            .find(|n| n.name.eq_ignore_ascii_case(npc_name))
+        let from_location = npc
             .and_then(|n| n.location_id)
             .and_then(|loc_id| world.known_locations.get(&loc_id))
             .map(|loc| loc.name.clone());
@@ -125,10 +128,17 @@ impl RulesEngine {
 
     pub(crate) fn resolve_remove_npc(
         &self,
+        world: &GameWorld,
         npc_name: &str,
         reason: &str,
         permanent: bool,
     ) -> Resolution {
+        let npc_name = match world.find_npc_fuzzy(npc_name) {
+            Ok(npc) => npc.name.as_str(),
+            Err(NameLookupError::NotFound { .. }) => npc_name,
+            Err(err) => return Resolution::new(err.to_string()),
+        };
+
         let permanence = if permanent {
             "permanently"
         } else {
@@ -145,6 +155,42 @@ impl RulesEngine {
         })
     }
 
+    pub(crate) fn resolve_adjust_reputation(
+        &self,
+        world: &GameWorld,
+        faction: &str,
+        delta: i32,
+        reason: &str,
+    ) -> Resolution {
+        let name = world.faction_name(faction);
+        let previous = world.reputation(faction);
+        let current = clamp_reputation(previous.saturating_add(delta));
+
+        if current == previous {
+            return Resolution::new(format!(
+                "Reputation with {name} stays at {current} ({reason})."
+            ));
+        }
+
+        let direction = if current > previous { "rises" } else { "falls" };
+        let mut narrative =
+            format!("Reputation with {name} {direction} from {previous} to {current} ({reason}).");
+        let tier = ReputationTier::from_score(current);
+        if tier != ReputationTier::from_score(previous) {
+            narrative.push_str(&format!(
+                " {name} now regards the party as {}.",
+                tier.name()
+            ));
+        }
+
+        Resolution::new(narrative).with_effect(Effect::ReputationChanged {
+            faction: name.to_string(),
+            previous,
+            current,
+            reason: reason.to_string(),
+        })
+    }
+
     pub(crate) fn resolve_create_location(
         &self,
         name: &str,
@@ -178,6 +224,54 @@ impl RulesEngine {
         })
     }
 
+    pub(crate) fn resolve_roll_encounter(
+        &self,
+        world: &GameWorld,
+        location_type: Option<LocationType>,
+        party_level: Option<u8>,
+    ) -> Resolution {
+        // The current location's name is kept up to date, so prefer the known location's type
+        let location_type = location_type.unwrap_or_else(|| {
+            world
+                .known_locations
+                .values()
+                .find(|l| l.name.eq_ignore_ascii_case(&world.current_location.name))
+                .unwrap_or(&world.current_location)
+                .location_type
+        });
+        let party_level = party_level.unwrap_or(world.player_character.level);
+
+        let tables = EncounterTables::default();
+        let Some(result) = crate::dice::with_current_rng(|mut rng| {
+            tables.roll(location_type, party_level, &mut rng)
+        }) else {
+            return Resolution::new(format!(
+                "There is no random encounter table for {} locations.",
+                location_type.name()
+            ));
+        };
+
+        let check = format!(
+            "Encounter roll: {} (encounter on {}+)",
+            result.roll.total, result.encounter_on
+        );
+        let narrative = match &result.encounter {
+            None => format!("{check} - all is quiet, no encounter."),
+            Some(encounter) if encounter.is_combat() => format!(
+                "{} - {}! Suggested encounter: {}. Use start_combat to run it.",
+                check,
+                encounter.description,
+                encounter.monster_summary()
+            ),
+            Some(encounter) => format!("{} - {}.", check, encounter.description),
+        };
+
+        Resolution::new(narrative).with_effect(Effect::DiceRolled {
+            roll: result.roll,
+            purpose: format!("Random encounter ({})", location_type.name()),
+        })
+    }
+
     pub(crate) fn resolve_connect_locations(
         &self,
         from_location: &str,
@@ -206,6 +300,8 @@ impl RulesEngine {
             from: from_location.to_string(),
             to: to_location.to_string(),
             direction: direction.map(|s| s.to_string()),
+            travel_time_minutes,
+            bidirectional,
         })
     }
 
@@ -279,31 +375,36 @@ impl RulesEngine {
         reason: &str,
         target_entity: Option<&str>,
     ) -> Resolution {
-        // Try to find the NPC to get old value
-        let old_value = world
-            .npcs
-            .values()
-            .find(|npc| npc.name.eq_ignore_ascii_case(entity_name))
-            .and_then(|npc| {
-                match state_type {
-                    StateType::Disposition => {
-                        let disp_str = match npc.disposition {
-                            crate::world::Disposition::Hostile => "hostile",
-                            crate::world::Disposition::Unfriendly => "unfriendly",
-                            crate::world::Disposition::Neutral => "neutral",
-                            crate::world::Disposition::Friendly => "friendly",
-                            crate::world::Disposition::Helpful => "helpful",
-                        };
-                        Some(disp_str.to_string())
-                    }
-                    StateType::Location => npc
-                        .location_id
-                        .and_then(|id| world.known_locations.get(&id))
-                        .map(|loc| loc.name.clone()),
-                    StateType::Status => None, // NPC struct doesn't have a status field
-                    _ => None,
+        // The entity may not be an NPC at all, but a partial name that could be
+        // several of them needs clarifying
+        let npc = match world.find_npc_fuzzy(entity_name) {
+            Ok(npc) => Some(npc),
+            Err(NameLookupError::NotFound { .. }) => None,
+            Err(err) => return Resolution::new(err.to_string()),
+        };
+        let entity_name = npc.map_or(entity_name, |n| n.name.as_str());
+
+        // Use the NPC's current state as the old value
+        let old_value = npc.and_then(|npc| {
+            match state_type {
+                StateType::Disposition => {
+                    let disp_str = match npc.disposition {
+                        crate::world::Disposition::Hostile => "hostile",
+                        crate::world::Disposition::Unfriendly => "unfriendly",
+                        crate::world::Disposition::Neutral => "neutral",
+                        crate::world::Disposition::Friendly => "friendly",
+                        crate::world::Disposition::Helpful => "helpful",
+                    };
+                    Some(disp_str.to_string())
                 }
-            });
+                StateType::Location => npc
+                    .location_id
+                    .and_then(|id| world.known_locations.get(&id))
+                    .map(|loc| loc.name.clone()),
+                StateType::Status => None, // NPC struct doesn't have a status field
+                _ => None,
+            }
+        });
 
         let narrative = match state_type {
             StateType::Disposition => {
@@ -473,6 +574,113 @@ mod tests {
         create_sample_fighter, CharacterId, Disposition, GameWorld, Location, LocationType, NPC,
     };
 
+    #[test]
+    fn test_roll_encounter() {
+        let world = GameWorld::new("Test", create_sample_fighter("Roland"));
+        let engine = RulesEngine::with_seed(66);
+
+        for _ in 0..20 {
+            let resolution = engine.resolve_roll_encounter(&world, None, None);
+            assert!(resolution.narrative.starts_with("Encounter roll:"));
+            assert!(matches!(
+                &resolution.effects[..],
+                [Effect::DiceRolled { purpose, .. }] if purpose == "Random encounter (town)"
+            ));
+        }
+
+        let resolution = engine.resolve_roll_encounter(&world, Some(LocationType::Room), Some(3));
+        assert!(resolution.narrative.contains("no random encounter table"));
+        assert!(resolution.effects.is_empty());
+    }
+
+    #[test]
+    fn test_npc_resolvers_match_partial_names() {
+        let mut world = GameWorld::new("Test", create_sample_fighter("Roland"));
+        for name in ["Captain Mara Voss", "Old Tom"] {
+            let npc = NPC::new(name);
+            world.npcs.insert(npc.id, npc);
+        }
+        let engine = RulesEngine::new();
+
+        let resolution =
+            engine.resolve_update_npc(&world, "mara", Some("friendly"), &[], None, None);
+        assert!(matches!(
+            &resolution.effects[..],
+            [Effect::NpcUpdated { npc_name, .. }] if npc_name == "Captain Mara Voss"
+        ));
+
+        let resolution = engine.resolve_assert_state(
+            &world,
+            "tom",
+            StateType::Status,
+            "asleep",
+            "Long day",
+            None,
+        );
+        assert!(resolution.narrative.starts_with("Old Tom's status"));
+
+        // "o" appears in both names
+        let resolution = engine.resolve_remove_npc(&world, "o", "Left town", true);
+        assert!(resolution.narrative.contains("ambiguous"));
+        assert!(resolution.effects.is_empty());
+    }
+
+    // ========== Reputation Tests ==========
+
+    #[test]
+    fn test_adjust_reputation_clamps() {
+        let mut world = GameWorld::new("Test", create_sample_fighter("Roland"));
+        let engine = RulesEngine::new();
+
+        let resolution =
+            engine.resolve_adjust_reputation(&world, "The Harpers", 150, "Saved the city");
+        crate::rules::apply_effects(&mut world, &resolution.effects);
+        assert_eq!(world.reputation("the harpers"), 100);
+
+        // Already at the cap: nothing changes
+        let resolution =
+            engine.resolve_adjust_reputation(&world, "the harpers", 10, "Another favor");
+        assert!(resolution.effects.is_empty());
+
+        let resolution = engine.resolve_adjust_reputation(&world, "The Harpers", -500, "Betrayal");
+        crate::rules::apply_effects(&mut world, &resolution.effects);
+        assert_eq!(world.reputation("The Harpers"), -100);
+        assert_eq!(world.factions.len(), 1);
+    }
+
+    #[test]
+    fn test_adjust_reputation_reports_tier_change() {
+        let mut world = GameWorld::new("Test", create_sample_fighter("Roland"));
+        let engine = RulesEngine::new();
+
+        let resolution = engine.resolve_adjust_reputation(&world, "City Watch", 5, "Paid a fine");
+        assert!(!resolution.narrative.contains("now regards"));
+        crate::rules::apply_effects(&mut world, &resolution.effects);
+
+        let resolution =
+            engine.resolve_adjust_reputation(&world, "City Watch", 10, "Caught a thief");
+        assert!(
+            resolution
+                .narrative
+                .contains("now regards the party as Friendly"),
+            "{}",
+            resolution.narrative
+        );
+        assert!(matches!(
+            &resolution.effects[..],
+            [Effect::ReputationChanged {
+                previous: 5,
+                current: 15,
+                ..
+            }]
+        ));
+        crate::rules::apply_effects(&mut world, &resolution.effects);
+        assert_eq!(
+            world.reputation_tier("City Watch"),
+            ReputationTier::Friendly
+        );
+    }
+
     // ========== Create NPC Tests ==========
 
     #[test]
@@ -520,6 +728,7 @@ mod tests {
                 disposition: Disposition::Friendly,
                 location_id: None,
                 known_information: vec![],
+                dialogue_history: vec![],
             },
         );
 
@@ -560,6 +769,7 @@ mod tests {
                 disposition: Disposition::Neutral,
                 location_id: None,
                 known_information: vec![],
+                dialogue_history: vec![],
             },
         );
 
@@ -616,6 +826,7 @@ mod tests {
                 disposition: Disposition::Neutral,
                 location_id: None,
                 known_information: vec![],
+                dialogue_history: vec![],
             },
         );
 
@@ -636,9 +847,10 @@ mod tests {
 
     #[test]
     fn test_remove_npc_permanent() {
+        let world = GameWorld::new("Test", create_sample_fighter("Roland"));
         let engine = RulesEngine::new();
 
-        let resolution = engine.resolve_remove_npc("Villain", "defeated in combat", true);
+        let resolution = engine.resolve_remove_npc(&world, "Villain", "defeated in combat", true);
 
         assert!(resolution.narrative.contains("Villain"));
         assert!(resolution.narrative.contains("permanently"));
@@ -650,9 +862,11 @@ mod tests {
 
     #[test]
     fn test_remove_npc_temporary() {
+        let world = GameWorld::new("Test", create_sample_fighter("Roland"));
         let engine = RulesEngine::new();
 
-        let resolution = engine.resolve_remove_npc("Merchant", "left town for supplies", false);
+        let resolution =
+            engine.resolve_remove_npc(&world, "Merchant", "left town for supplies", false);
 
         assert!(resolution.narrative.contains("temporarily"));
     }
@@ -833,6 +1047,7 @@ mod tests {
                 disposition: Disposition::Neutral,
                 location_id: None,
                 known_information: vec![],
+                dialogue_history: vec![],
             },
         );
 
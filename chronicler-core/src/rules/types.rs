@@ -1,8 +1,13 @@
 //! Core types for the Intent/Effect rules system.
 
-use crate::dice::RollResult;
-use crate::world::{Ability, CharacterId, Condition, Skill};
+use crate::dice::{DieType, RollResult};
+use crate::downtime::DowntimeActivity;
+use crate::world::{
+    Ability, CharacterId, Condition, LairAction, LocationType, OngoingEffect, ResistanceLevel,
+    Skill, Weather,
+};
 use serde::{Deserialize, Serialize};
+use std::collections::HashMap;
 
 /// An intent represents what a character wants to do.
 /// The AI generates intents, the RulesEngine resolves them.
@@ -14,6 +19,82 @@ pub enum Intent {
         target_id: CharacterId,
         weapon_name: String,
         advantage: crate::dice::Advantage,
+        /// Off-hand (bonus action) attack while two-weapon fighting
+        #[serde(default)]
+        is_offhand: bool,
+        /// Cover the target has against this attack
+        #[serde(default)]
+        cover: Cover,
+        /// Whether a hostile creature is within 5 feet of the attacker (inferred when unset)
+        #[serde(default)]
+        adjacent_enemy: Option<bool>,
+        /// How the weapon is used: wielded, thrown, or as an improvised weapon
+        #[serde(default)]
+        attack_mode: AttackMode,
+    },
+
+    /// Take the Attack action, making one attack per Extra Attack
+    MultiAttack {
+        attacker_id: CharacterId,
+        target_ids: Vec<CharacterId>,
+        weapon_name: String,
+    },
+
+    /// Grapple a creature (contested Athletics vs. Athletics or Acrobatics)
+    Grapple {
+        grappler_id: CharacterId,
+        target_id: CharacterId,
+        /// Contest modifier for the non-player side (combatants have no skill scores)
+        #[serde(default)]
+        npc_modifier: i8,
+    },
+
+    /// Shove a creature prone or away (contested Athletics vs. Athletics or Acrobatics)
+    Shove {
+        shover_id: CharacterId,
+        target_id: CharacterId,
+        mode: ShoveMode,
+        /// Contest modifier for the non-player side (combatants have no skill scores)
+        #[serde(default)]
+        npc_modifier: i8,
+    },
+
+    /// Make an opportunity attack as a reaction (e.g., when a creature leaves reach)
+    OpportunityAttack {
+        attacker_id: CharacterId,
+        target_id: CharacterId,
+        weapon_name: String,
+    },
+
+    /// Cast Counterspell as a reaction to interrupt another creature's spell
+    Counterspell {
+        caster_id: CharacterId,
+        target_spell_level: u8,
+        slot_level: u8,
+    },
+
+    /// Trigger one of the lair's actions on initiative count 20 (once per round)
+    TriggerLairAction {
+        index: usize,
+        /// Creatures affected by the lair action's damage or condition
+        target_ids: Vec<CharacterId>,
+    },
+
+    /// Spend a boss monster's legendary actions at the end of another creature's turn
+    UseLegendaryAction {
+        combatant_id: CharacterId,
+        action_name: String,
+        cost: u8,
+    },
+
+    /// Take the Dodge action (attacks against the character have disadvantage)
+    Dodge { character_id: CharacterId },
+
+    /// Take the Help action (the target gains advantage on their next check or attack)
+    Help {
+        helper_id: CharacterId,
+        target_id: CharacterId,
+        task: String,
     },
 
     /// Cast a spell
@@ -24,6 +105,12 @@ pub enum Intent {
         spell_level: u8,
         /// Target names (for when we don't have CharacterIds)
         target_names: Vec<String>,
+        /// Cover the targets have against the spell
+        #[serde(default)]
+        cover: Cover,
+        /// Cast as a ritual: no spell slot, 10 extra minutes
+        #[serde(default)]
+        ritual: bool,
     },
 
     /// Make a skill check
@@ -59,6 +146,9 @@ pub enum Intent {
         amount: i32,
         damage_type: DamageType,
         source: String,
+        /// A critical hit against a creature at 0 HP costs two death save failures
+        #[serde(default)]
+        is_critical: bool,
     },
 
     /// Heal a target
@@ -68,6 +158,13 @@ pub enum Intent {
         source: String,
     },
 
+    /// Grant temporary hit points to a target
+    GrantTempHp {
+        target_id: CharacterId,
+        amount: i32,
+        source: String,
+    },
+
     /// Apply a condition to a target
     ApplyCondition {
         target_id: CharacterId,
@@ -82,6 +179,15 @@ pub enum Intent {
         condition: Condition,
     },
 
+    /// Attach an effect that triggers at the start of each of the target's turns
+    ApplyOngoingEffect {
+        target_id: CharacterId,
+        effect: OngoingEffect,
+    },
+
+    /// Raise or lower a target's exhaustion level
+    ModifyExhaustion { target_id: CharacterId, delta: i8 },
+
     /// Move to a different location or position
     Move {
         character_id: CharacterId,
@@ -89,14 +195,42 @@ pub enum Intent {
         distance_feet: u32,
     },
 
+    /// Cast an area spell at everyone standing in its area. Without
+    /// positions, the named targets are used instead.
+    CastSpellAoe {
+        caster_id: CharacterId,
+        spell_name: String,
+        slot_level: u8,
+        /// Point of origin, in feet
+        origin: (i32, i32),
+        shape: AreaShape,
+        /// Who the area catches when combatants have no positions
+        #[serde(default)]
+        target_names: Vec<String>,
+    },
+
     /// Take a short rest
     ShortRest,
 
     /// Take a long rest
     LongRest,
 
+    /// Spend hit dice to regain hit points, as during a short rest
+    SpendHitDice {
+        character_id: CharacterId,
+        count: u8,
+    },
+
     /// Start combat
-    StartCombat { combatants: Vec<CombatantInit> },
+    StartCombat {
+        combatants: Vec<CombatantInit>,
+        /// Actions the lair can take on initiative count 20
+        #[serde(default)]
+        lair_actions: Vec<LairAction>,
+        /// Combatants caught by surprise, who lose their first turn
+        #[serde(default)]
+        surprised_ids: Vec<CharacterId>,
+    },
 
     /// End combat
     EndCombat,
@@ -118,9 +252,24 @@ pub enum Intent {
     /// Advance game time
     AdvanceTime { minutes: u32 },
 
+    /// Change the weather
+    SetWeather { weather: Weather },
+
+    /// Spend days of downtime on an activity
+    Downtime {
+        activity: DowntimeActivity,
+        days: u32,
+    },
+
     /// Add experience points
     GainExperience { amount: u32 },
 
+    /// Award Heroic Inspiration (a character can only hold one)
+    GrantInspiration { character_id: CharacterId },
+
+    /// Spend Heroic Inspiration for advantage on the next attack, check, or save
+    SpendInspiration { character_id: CharacterId },
+
     /// Use a class feature
     UseFeature {
         character_id: CharacterId,
@@ -135,6 +284,9 @@ pub enum Intent {
         category: String,
         related_entities: Vec<String>,
         importance: f32,
+        /// Replace earlier facts this one contradicts instead of being refused
+        #[serde(default)]
+        supersedes: bool,
     },
 
     // Inventory management
@@ -158,6 +310,12 @@ pub enum Intent {
     /// Unequip an item from a slot
     UnequipItem { slot: String },
 
+    /// Attune to a magic item (takes a short rest)
+    Attune { item_name: String },
+
+    /// End attunement to a magic item
+    Unattune { item_name: String },
+
     /// Use a consumable item
     UseItem {
         item_name: String,
@@ -170,9 +328,21 @@ pub enum Intent {
     /// Adjust the player's silver
     AdjustSilver { amount: i32, reason: String },
 
+    /// Roll loot on the treasure table for one creature or a hoard
+    GenerateLoot { challenge_rating: u8, hoard: bool },
+
     /// Make a death saving throw (when at 0 HP)
     DeathSave { character_id: CharacterId },
 
+    /// Stabilize a dying creature with a DC 10 Medicine check
+    Stabilize {
+        medic_id: CharacterId,
+        target_id: CharacterId,
+        /// Medicine modifier when the medic is not the player (combatants have no skill scores)
+        #[serde(default)]
+        npc_modifier: i8,
+    },
+
     /// Make a concentration check (when taking damage while concentrating)
     ConcentrationCheck {
         character_id: CharacterId,
@@ -368,6 +538,13 @@ pub enum Intent {
         permanent: bool,
     },
 
+    /// Raise or lower the party's reputation with a faction
+    AdjustReputation {
+        faction: String,
+        delta: i32,
+        reason: String,
+    },
+
     /// Create a new location in the world
     CreateLocation {
         name: String,
@@ -387,6 +564,12 @@ pub enum Intent {
         bidirectional: bool,
     },
 
+    /// Roll for a random encounter (defaults to the current location and party level)
+    RollEncounter {
+        location_type: Option<LocationType>,
+        party_level: Option<u8>,
+    },
+
     /// Update an existing location's attributes
     UpdateLocation {
         location_name: String,
@@ -408,6 +591,9 @@ pub enum Intent {
     /// Restore a spell slot
     RestoreSpellSlot { slot_level: u8, source: String },
 
+    /// Replace the prepared spell list (prepared casters, after a long rest)
+    PrepareSpells { spells: Vec<String> },
+
     // ========================================================================
     // State Assertion Intents (declarative state changes)
     // ========================================================================
@@ -523,10 +709,21 @@ pub struct CombatantInit {
     pub armor_class: u8,
     /// Initiative modifier (DEX mod for most creatures)
     pub initiative_modifier: i8,
+    /// DEX modifier, which breaks initiative ties
+    pub dex_modifier: i8,
+    /// Damage resistances, vulnerabilities, and immunities
+    #[serde(default)]
+    pub resistances: HashMap<DamageType, ResistanceLevel>,
+    /// Legendary actions per round (boss monsters)
+    #[serde(default)]
+    pub legendary_actions: u8,
+    /// Where the combatant stands, in feet, if the scene tracks positions
+    #[serde(default)]
+    pub position: Option<(i32, i32)>,
 }
 
 /// Common D&D damage types.
-#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Serialize, Deserialize)]
 pub enum DamageType {
     Slashing,
     Piercing,
@@ -611,6 +808,13 @@ pub enum Effect {
         dropped_to_zero: bool,
     },
 
+    /// Temporary hit points granted (replaces any lower existing temp HP)
+    TempHpGranted {
+        target_id: CharacterId,
+        amount: i32,
+        new_temporary: i32,
+    },
+
     /// A condition was applied
     ConditionApplied {
         target_id: CharacterId,
@@ -625,6 +829,18 @@ pub enum Effect {
         condition: Condition,
     },
 
+    /// An ongoing effect was attached to a creature
+    OngoingEffectApplied {
+        target_id: CharacterId,
+        effect: OngoingEffect,
+    },
+
+    /// Exhaustion level changed (0 removes exhaustion entirely)
+    ExhaustionChanged {
+        target_id: CharacterId,
+        new_level: u8,
+    },
+
     /// Combat started
     CombatStarted,
 
@@ -650,33 +866,70 @@ pub enum Effect {
         id: CharacterId,
         name: String,
         initiative: i32,
+        #[serde(default)]
+        dex_modifier: i8,
         is_ally: bool,
         current_hp: i32,
         max_hp: i32,
         armor_class: u8,
+        resistances: HashMap<DamageType, ResistanceLevel>,
+        #[serde(default)]
+        legendary_actions: u8,
+        #[serde(default)]
+        position: Option<(i32, i32)>,
     },
 
     /// Time advanced
     TimeAdvanced { minutes: u32 },
 
-    /// Experience gained
+    /// The weather changed
+    WeatherChanged { weather: Weather },
+
+    /// A character became proficient in a skill
+    SkillProficiencyGained {
+        character_id: CharacterId,
+        skill: Skill,
+    },
+
+    /// The active character gained experience
     ExperienceGained { amount: u32, new_total: u32 },
 
-    /// Level up occurred
+    /// Character gained Heroic Inspiration
+    InspirationGranted { character_id: CharacterId },
+
+    /// Character spent Heroic Inspiration; their next roll has advantage
+    InspirationSpent { character_id: CharacterId },
+
+    /// The advantage from spent inspiration was applied to a roll
+    InspirationConsumed { character_id: CharacterId },
+
+    /// The active character levelled up
     LevelUp { new_level: u8 },
 
-    /// Feature use consumed
+    /// A character used up one use of a feature
     FeatureUsed {
+        character_id: CharacterId,
         feature_name: String,
         uses_remaining: u8,
     },
 
-    /// Spell slot consumed
-    SpellSlotUsed { level: u8, remaining: u8 },
+    /// A character spent a spell slot
+    SpellSlotUsed {
+        character_id: CharacterId,
+        level: u8,
+        remaining: u8,
+    },
 
     /// Rest completed
     RestCompleted { rest_type: RestType },
 
+    /// Hit dice of one size were spent from a character's pool
+    HitDiceSpent {
+        character_id: CharacterId,
+        die_type: DieType,
+        count: u8,
+    },
+
     /// A check succeeded
     CheckSucceeded {
         check_type: String,
@@ -716,9 +969,11 @@ pub enum Effect {
         category: String,
         related_entities: Vec<String>,
         importance: f32,
+        #[serde(default)]
+        supersedes: bool,
     },
 
-    // Inventory effects
+    // Inventory effects always apply to the active character
     /// An item was added to inventory
     ItemAdded {
         item_name: String,
@@ -739,6 +994,12 @@ pub enum Effect {
     /// An item was unequipped
     ItemUnequipped { item_name: String, slot: String },
 
+    /// The player attuned to a magic item
+    ItemAttuned { item_name: String },
+
+    /// The player ended attunement to a magic item
+    ItemUnattuned { item_name: String },
+
     /// An item was used (consumable)
     ItemUsed { item_name: String, result: String },
 
@@ -786,6 +1047,48 @@ pub enum Effect {
     /// Character stabilized (3 death save successes)
     Stabilized { target_id: CharacterId },
 
+    /// A combatant is dodging until the start of their next turn
+    DodgeActive { character_id: CharacterId },
+
+    /// A combatant was helped and has advantage on their next check or attack
+    HelpGranted {
+        helper_id: CharacterId,
+        target_id: CharacterId,
+    },
+
+    /// A combatant used the advantage granted by the Help action
+    HelpConsumed { character_id: CharacterId },
+
+    /// A combatant spent their reaction
+    ReactionUsed { character_id: CharacterId },
+
+    /// A combatant spent their bonus action for the turn
+    BonusActionUsed { character_id: CharacterId },
+
+    /// A lair action became available for this combat
+    LairActionAdded { action: LairAction },
+
+    /// The lair took its action for the round
+    LairActionTriggered { name: String },
+
+    /// A boss monster spent legendary actions
+    LegendaryActionUsed {
+        combatant_id: CharacterId,
+        action_name: String,
+        cost: u8,
+        remaining: u8,
+    },
+
+    /// A Counterspell was attempted against another creature's spell
+    SpellCountered { success: bool },
+
+    /// Character began concentrating on a spell
+    ConcentrationStarted {
+        character_id: CharacterId,
+        spell_name: String,
+        slot_level: u8,
+    },
+
     /// Concentration was broken
     ConcentrationBroken {
         character_id: CharacterId,
@@ -910,6 +1213,14 @@ pub enum Effect {
     /// An NPC was removed from the world
     NpcRemoved { npc_name: String, reason: String },
 
+    /// Reputation with a faction changed (scores are already clamped)
+    ReputationChanged {
+        faction: String,
+        previous: i32,
+        current: i32,
+        reason: String,
+    },
+
     /// A location was created
     LocationCreated { name: String, location_type: String },
 
@@ -918,6 +1229,9 @@ pub enum Effect {
         from: String,
         to: String,
         direction: Option<String>,
+        travel_time_minutes: Option<u32>,
+        /// Whether the path can also be travelled from `to` back to `from`
+        bidirectional: bool,
     },
 
     /// A location was updated
@@ -936,6 +1250,9 @@ pub enum Effect {
     /// A spell slot was restored
     SpellSlotRestored { level: u8, new_remaining: u8 },
 
+    /// The caster's prepared spells were replaced
+    SpellsPrepared { spells: Vec<String> },
+
     // ========================================================================
     // State Assertion Effects
     // ========================================================================
@@ -982,6 +1299,117 @@ pub enum Effect {
     },
 }
 
+/// How a weapon is used in an attack.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Serialize, Deserialize)]
+pub enum AttackMode {
+    /// Wielded as intended: swung in melee, or fired if it's a ranged weapon
+    #[default]
+    Melee,
+    /// Thrown, using a weapon with the Thrown property
+    Thrown,
+    /// An object that isn't a weapon, like a chair or a bottle: 1d4 damage
+    Improvised,
+}
+
+/// Cover between an attacker and its target.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Serialize, Deserialize)]
+pub enum Cover {
+    #[default]
+    None,
+    /// +2 to AC and Dexterity saving throws
+    Half,
+    /// +5 to AC and Dexterity saving throws
+    ThreeQuarters,
+    /// Can't be targeted directly
+    Total,
+}
+
+impl Cover {
+    /// Bonus to AC and Dexterity saving throws (total cover blocks targeting instead).
+    pub fn bonus(&self) -> i8 {
+        match self {
+            Cover::Half => 2,
+            Cover::ThreeQuarters => 5,
+            Cover::None | Cover::Total => 0,
+        }
+    }
+
+    pub fn name(&self) -> &'static str {
+        match self {
+            Cover::None => "no cover",
+            Cover::Half => "half cover",
+            Cover::ThreeQuarters => "three-quarters cover",
+            Cover::Total => "total cover",
+        }
+    }
+}
+
+/// The area an area-of-effect spell fills, in feet from its point of origin.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+pub enum AreaShape {
+    /// Everything within `radius` feet of the origin
+    Sphere { radius: u32 },
+    /// Spreads from the origin toward a point, as wide at any distance as it is far
+    Cone { length: u32, toward: (i32, i32) },
+    /// Runs from the origin toward a point
+    Line {
+        length: u32,
+        width: u32,
+        toward: (i32, i32),
+    },
+}
+
+impl AreaShape {
+    /// Whether `point` lies inside the area when it starts at `origin`.
+    ///
+    /// Cones and lines leave out their own point of origin, so a caster
+    /// standing there isn't caught.
+    pub fn contains(&self, origin: (i32, i32), point: (i32, i32)) -> bool {
+        let (dx, dy) = ((point.0 - origin.0) as f64, (point.1 - origin.1) as f64);
+        let (length, half_width, toward) = match *self {
+            AreaShape::Sphere { radius } => {
+                return dx.hypot(dy) <= radius as f64;
+            }
+            AreaShape::Cone { length, toward } => (length, None, toward),
+            AreaShape::Line {
+                length,
+                width,
+                toward,
+            } => (length, Some(width as f64 / 2.0), toward),
+        };
+        let (ax, ay) = ((toward.0 - origin.0) as f64, (toward.1 - origin.1) as f64);
+        let axis = ax.hypot(ay);
+        if axis == 0.0 {
+            return false;
+        }
+        // Distance along the aim and away from it
+        let along = (dx * ax + dy * ay) / axis;
+        let across = (dx * ay - dy * ax).abs() / axis;
+        if along <= 0.0 || along > length as f64 {
+            return false;
+        }
+        across <= half_width.unwrap_or(along / 2.0)
+    }
+
+    /// A short description, e.g. "20-foot sphere".
+    pub fn describe(&self) -> String {
+        match self {
+            AreaShape::Sphere { radius } => format!("{radius}-foot sphere"),
+            AreaShape::Cone { length, .. } => format!("{length}-foot cone"),
+            AreaShape::Line { length, .. } => format!("{length}-foot line"),
+        }
+    }
+}
+
+/// What a successful shove does to the target.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+pub enum ShoveMode {
+    /// Knock the target prone
+    Prone,
+    /// Push the target 5 feet away
+    Push,
+}
+
 #[derive(Debug, Clone, Copy, Serialize, Deserialize)]
 pub enum RestType {
     Short,
@@ -0,0 +1,212 @@
+//! Structured SRD rules text for conditions, damage types, and class features.
+//!
+//! The DM looks terms up here (via the `rules_lookup` tool) instead of
+//! relying on memory, so rulings match what the engine actually enforces.
+
+use crate::rules::DamageType;
+use crate::world::{CharacterClass, Subclass};
+
+/// What kind of rule a reference entry describes.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum RuleCategory {
+    Condition,
+    DamageType,
+    ClassFeature,
+}
+
+impl RuleCategory {
+    pub fn name(&self) -> &'static str {
+        match self {
+            RuleCategory::Condition => "condition",
+            RuleCategory::DamageType => "damage type",
+            RuleCategory::ClassFeature => "class feature",
+        }
+    }
+}
+
+/// A single rules reference entry.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct RuleEntry {
+    pub term: String,
+    pub category: RuleCategory,
+    /// Where the rule comes from, e.g. the class that grants a feature.
+    pub source: Option<String>,
+    pub description: String,
+}
+
+impl RuleEntry {
+    /// Render the entry as a short block of text for the DM.
+    pub fn format(&self) -> String {
+        match &self.source {
+            Some(source) => format!(
+                "{} ({}, {}): {}",
+                self.term,
+                self.category.name(),
+                source,
+                self.description
+            ),
+            None => format!(
+                "{} ({}): {}",
+                self.term,
+                self.category.name(),
+                self.description
+            ),
+        }
+    }
+}
+
+const CONDITIONS: &[(&str, &str)] = &[
+    ("Blinded", "Can't see and automatically fails any ability check that requires sight. Attack rolls against the creature have advantage, and its attack rolls have disadvantage."),
+    ("Charmed", "Can't attack the charmer or target the charmer with harmful abilities or magical effects. The charmer has advantage on ability checks to interact socially with the creature."),
+    ("Deafened", "Can't hear and automatically fails any ability check that requires hearing."),
+    ("Exhaustion", "Measured in six levels, each adding to the last: 1) disadvantage on ability checks; 2) speed halved; 3) disadvantage on attack rolls and saving throws; 4) hit point maximum halved; 5) speed reduced to 0; 6) death. A long rest with food and drink removes one level."),
+    ("Frightened", "Has disadvantage on ability checks and attack rolls while the source of its fear is within line of sight, and can't willingly move closer to the source."),
+    ("Grappled", "Speed becomes 0 and can't benefit from any bonus to speed. Ends if the grappler is incapacitated, or if an effect removes the creature from the grappler's reach."),
+    ("Incapacitated", "Can't take actions or reactions."),
+    ("Invisible", "Impossible to see without magic or a special sense; heavily obscured for hiding. Attack rolls against the creature have disadvantage, and its attack rolls have advantage."),
+    ("Paralyzed", "Incapacitated and can't move or speak. Automatically fails Strength and Dexterity saving throws. Attack rolls against it have advantage, and any hit from within 5 feet is a critical hit."),
+    ("Petrified", "Transformed into stone, incapacitated, and can't move or speak. Attack rolls against it have advantage. Automatically fails Strength and Dexterity saving throws, has resistance to all damage, and is immune to poison and disease."),
+    ("Poisoned", "Has disadvantage on attack rolls and ability checks."),
+    ("Prone", "Can only crawl unless it stands up, which costs half its speed. Has disadvantage on attack rolls. Attacks against it have advantage from within 5 feet and disadvantage from farther away."),
+    ("Restrained", "Speed becomes 0. Attack rolls against it have advantage, and its attack rolls have disadvantage. Has disadvantage on Dexterity saving throws."),
+    ("Stunned", "Incapacitated, can't move, and can speak only falteringly. Automatically fails Strength and Dexterity saving throws. Attack rolls against it have advantage."),
+    ("Surprised", "Can't move or take an action on its first turn of combat, and can't take a reaction until that turn ends."),
+    ("Unconscious", "Incapacitated, can't move or speak, unaware of its surroundings, and drops whatever it's holding and falls prone. Automatically fails Strength and Dexterity saving throws. Attack rolls against it have advantage, and any hit from within 5 feet is a critical hit."),
+];
+
+fn damage_type_description(damage_type: DamageType) -> &'static str {
+    match damage_type {
+        DamageType::Slashing => "Cuts from swords, axes, and claws.",
+        DamageType::Piercing => "Punctures from arrows, spears, and bites.",
+        DamageType::Bludgeoning => "Blunt force from hammers, falling, and constriction.",
+        DamageType::Fire => "Flames from red dragon breath and spells like Fire Bolt and Fireball.",
+        DamageType::Cold => {
+            "Freezing chill from an ice devil's spear or a white dragon's frigid breath."
+        }
+        DamageType::Lightning => "Electricity from Lightning Bolt or a blue dragon's breath.",
+        DamageType::Thunder => "A concussive burst of sound, such as Thunderwave.",
+        DamageType::Acid => "Corrosive spray from a black dragon's breath or dissolving enzymes.",
+        DamageType::Poison => "Venomous stings and toxic gas. Many creatures are immune.",
+        DamageType::Necrotic => "Life-draining energy from undead and spells like Chill Touch.",
+        DamageType::Radiant => "Searing divine energy from Guiding Bolt or a Divine Smite.",
+        DamageType::Force => "Pure magical energy, as from Magic Missile. Rarely resisted.",
+        DamageType::Psychic => {
+            "Mental assault from psionic abilities, such as a mind flayer's blast."
+        }
+    }
+}
+
+const DAMAGE_TYPES: &[DamageType] = &[
+    DamageType::Slashing,
+    DamageType::Piercing,
+    DamageType::Bludgeoning,
+    DamageType::Fire,
+    DamageType::Cold,
+    DamageType::Lightning,
+    DamageType::Thunder,
+    DamageType::Acid,
+    DamageType::Poison,
+    DamageType::Necrotic,
+    DamageType::Radiant,
+    DamageType::Force,
+    DamageType::Psychic,
+];
+
+/// Look up a condition, damage type, or class feature by name (case-insensitive).
+///
+/// Returns `None` when the term isn't in the reference data.
+pub fn lookup(term: &str) -> Option<RuleEntry> {
+    let wanted = term.trim().to_lowercase();
+    if wanted.is_empty() {
+        return None;
+    }
+
+    if let Some((name, description)) = CONDITIONS
+        .iter()
+        .find(|(name, _)| name.to_lowercase() == wanted)
+    {
+        return Some(RuleEntry {
+            term: name.to_string(),
+            category: RuleCategory::Condition,
+            source: None,
+            description: description.to_string(),
+        });
+    }
+
+    let damage_name = wanted.strip_suffix(" damage").unwrap_or(&wanted);
+    if let Some(damage_type) = DAMAGE_TYPES.iter().find(|d| d.name() == damage_name) {
+        return Some(RuleEntry {
+            term: damage_type.name().to_string(),
+            category: RuleCategory::DamageType,
+            source: None,
+            description: damage_type_description(*damage_type).to_string(),
+        });
+    }
+
+    for class in CharacterClass::all() {
+        if let Some(feature) = class
+            .data()
+            .level_1_features
+            .into_iter()
+            .find(|f| f.name.to_lowercase() == wanted)
+        {
+            return Some(RuleEntry {
+                term: feature.name,
+                category: RuleCategory::ClassFeature,
+                source: Some(format!("{} 1", class.name())),
+                description: feature.description,
+            });
+        }
+    }
+
+    for subclass in Subclass::all() {
+        for &level in subclass.feature_levels() {
+            if let Some(feature) = subclass
+                .features_at_level(level)
+                .into_iter()
+                .find(|f| f.name.to_lowercase() == wanted)
+            {
+                return Some(RuleEntry {
+                    term: feature.name,
+                    category: RuleCategory::ClassFeature,
+                    source: Some(format!("{} {}", subclass.name(), level)),
+                    description: feature.description,
+                });
+            }
+        }
+    }
+
+    None
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_lookup_condition_is_case_insensitive() {
+        let entry = lookup("grappled").unwrap();
+        assert_eq!(entry.term, "Grappled");
+        assert_eq!(entry.category, RuleCategory::Condition);
+        assert!(entry.description.contains("Speed becomes 0"));
+    }
+
+    #[test]
+    fn test_lookup_damage_type_and_class_feature() {
+        let fire = lookup("Fire damage").unwrap();
+        assert_eq!(fire.category, RuleCategory::DamageType);
+
+        let rage = lookup("Rage").unwrap();
+        assert_eq!(rage.category, RuleCategory::ClassFeature);
+        assert_eq!(rage.source.as_deref(), Some("Barbarian 1"));
+
+        let frenzy = lookup("frenzy").unwrap();
+        assert_eq!(frenzy.source.as_deref(), Some("Path of the Berserker 3"));
+    }
+
+    #[test]
+    fn test_lookup_unknown_term() {
+        assert!(lookup("Flumph Dance").is_none());
+        assert!(lookup("   ").is_none());
+    }
+}
@@ -5,8 +5,9 @@
 //! persistence logic into a single, easy-to-use API.
 
 use crate::dm::{DmConfig, DmError, DmResponse, DungeonMaster};
+use crate::persist::{write_atomic, SaveMetadata};
 use crate::rules::Effect;
-use crate::world::{create_sample_fighter, Character, GameWorld};
+use crate::world::{create_sample_fighter, Character, CharacterId, GameWorld};
 use claude::{Claude, Message, Request};
 use std::path::Path;
 use thiserror::Error;
@@ -23,6 +24,9 @@ pub enum SessionError {
 
     #[error("Serialization error: {0}")]
     Serialization(#[from] serde_json::Error),
+
+    #[error("Persistence error: {0}")]
+    Persist(#[from] crate::persist::PersistError),
 }
 
 /// Configuration for creating a new game session.
@@ -256,11 +260,18 @@ impl GameSession {
             *session.dm.story_memory_mut() = story_memory;
         }
 
+        for warning in session.world.validate() {
+            eprintln!("Warning: {warning}");
+        }
+
         Ok(session)
     }
 
     /// Save the current session to a file.
+    ///
+    /// Also writes a `.meta.json` sidecar describing the save for load menus.
     pub async fn save(&self, path: impl AsRef<Path>) -> Result<(), SessionError> {
+        let path = path.as_ref();
         let saved = SavedSession {
             world: self.world.clone(),
             campaign_facts: self.dm.memory().campaign_facts.to_vec(),
@@ -269,7 +280,8 @@ impl GameSession {
         };
 
         let content = serde_json::to_string_pretty(&saved)?;
-        fs::write(path, content).await?;
+        write_atomic(path, &content).await?;
+        SaveMetadata::now(&self.world).write_sidecar(path).await?;
         Ok(())
     }
 
@@ -309,7 +321,7 @@ impl GameSession {
     where
         F: FnMut(&str) + Send,
     {
-        self.player_action_streaming_with_effects(input, on_text, |_| {})
+        self.player_action_streaming_with_effects(input, None, on_text, |_| {})
             .await
     }
 
@@ -318,9 +330,11 @@ impl GameSession {
     /// The `on_text` callback is invoked with each text chunk as it arrives.
     /// The `on_effect` callback is invoked immediately when effects are generated,
     /// allowing real-time sound and animation triggering synchronized with the narrative.
+    /// `target` is the combatant the player selected for this action, if any.
     pub async fn player_action_streaming_with_effects<F, E>(
         &mut self,
         input: &str,
+        target: Option<CharacterId>,
         on_text: F,
         on_effect: E,
     ) -> Result<Response, SessionError>
@@ -330,7 +344,13 @@ impl GameSession {
     {
         let dm_response = self
             .dm
-            .process_input_streaming_with_effects(input, &mut self.world, on_text, on_effect)
+            .process_input_streaming_with_effects(
+                input,
+                &mut self.world,
+                target,
+                on_text,
+                on_effect,
+            )
             .await?;
 
         let in_combat = self.world.combat.is_some();
@@ -350,6 +370,25 @@ impl GameSession {
         })
     }
 
+    /// Revert the most recently applied action.
+    ///
+    /// Returns false if there is nothing to undo.
+    pub fn undo(&mut self) -> bool {
+        self.dm.history_mut().undo(&mut self.world)
+    }
+
+    /// Reapply the most recently undone action.
+    ///
+    /// Returns false if there is nothing to redo.
+    pub fn redo(&mut self) -> bool {
+        self.dm.history_mut().redo(&mut self.world)
+    }
+
+    /// Write a short "previously on..." recap for a player returning to this game.
+    pub async fn generate_recap(&self, n_recent_facts: usize) -> Result<String, SessionError> {
+        Ok(self.dm.generate_recap(&self.world, n_recent_facts).await?)
+    }
+
     /// Get a reference to the game world.
     pub fn world(&self) -> &GameWorld {
         &self.world
@@ -522,4 +561,48 @@ mod tests {
         assert_eq!(response.narrative, "You see a dragon!");
         assert!(!response.in_combat);
     }
+
+    #[tokio::test]
+    async fn test_save_writes_metadata_sidecar() {
+        let dir = tempfile::tempdir().unwrap();
+        let path = dir.path().join("quest.json");
+        let world = GameWorld::new("Epic Quest", create_sample_fighter("Hero"));
+        let session = GameSession::with_world(DungeonMaster::new("test-key"), world);
+
+        session.save(&path).await.unwrap();
+
+        assert!(path.exists());
+        assert!(!dir.path().join("quest.json.tmp").exists());
+        assert!(dir.path().join("quest.meta.json").exists());
+        let metadata = SaveMetadata::read_sidecar(&path).await.unwrap();
+        assert_eq!(metadata.campaign_name, "Epic Quest");
+        assert_eq!(metadata.character_name, "Hero");
+        assert_eq!(metadata.level, 3);
+        assert_eq!(metadata.game_date, "Day 1, Month 3, Year 1492, 10:00");
+        assert!(!metadata.saved_at.is_empty());
+    }
+
+    #[test]
+    fn test_undo_and_redo_restore_world() {
+        let world = GameWorld::new("Test", create_sample_fighter("Hero"));
+        let mut session = GameSession::with_world(DungeonMaster::new("test-key"), world);
+        assert!(!session.undo());
+
+        let before = session.world().player_character.hit_points.current;
+        let snapshot = session.world().clone();
+        session.dm_mut().history_mut().record(&snapshot);
+        session
+            .world_mut()
+            .player_character
+            .hit_points
+            .take_damage(4);
+
+        assert!(session.undo());
+        assert_eq!(session.world().player_character.hit_points.current, before);
+        assert!(session.redo());
+        assert_eq!(
+            session.world().player_character.hit_points.current,
+            before - 4
+        );
+    }
 }
@@ -592,7 +592,7 @@ pub fn register_cantrips(db: &mut HashMap<String, SpellData>) {
         description: "You point at one creature you can see within range, and the sound of a dolorous bell fills the air around it for a moment. The target must succeed on a Wisdom saving throw or take 1d8 necrotic damage. If the target is missing any of its hit points, it instead takes 1d12 necrotic damage.".to_string(),
         damage_dice: Some("1d8".to_string()),
         damage_type: Some(DamageType::Necrotic),
-        damage_scaling: DamageScaling::CantripScaling,
+        damage_scaling: DamageScaling::CantripScalingVsInjured { injured_die: "d12".to_string() },
         healing_dice: None,
         save_type: Some(Ability::Wisdom),
         save_effect: Some("no damage".to_string()),
@@ -92,6 +92,45 @@ mod tests {
         assert_eq!(fire_bolt.cantrip_dice_count(17), 4);
     }
 
+    #[test]
+    fn test_cantrip_scaling_breakpoints() {
+        let fire_bolt = get_spell("fire bolt").expect("Fire Bolt should exist");
+        let expected = [
+            (4, "1d10"),
+            (5, "2d10"),
+            (10, "2d10"),
+            (11, "3d10"),
+            (16, "3d10"),
+            (17, "4d10"),
+        ];
+        for (level, dice) in expected {
+            assert_eq!(
+                fire_bolt.effective_damage_dice(level, 0).as_deref(),
+                Some(dice),
+                "Fire Bolt at character level {level}"
+            );
+        }
+        // An unset level never scales past the first die
+        assert_eq!(fire_bolt.cantrip_dice_count(0), 1);
+    }
+
+    #[test]
+    fn test_toll_the_dead_die_depends_on_target_hp() {
+        let toll = get_spell("toll the dead").expect("Toll the Dead should exist");
+        assert_eq!(
+            toll.damage_dice_against(5, 0, false).as_deref(),
+            Some("2d8")
+        );
+        assert_eq!(
+            toll.damage_dice_against(5, 0, true).as_deref(),
+            Some("2d12")
+        );
+        assert_eq!(
+            toll.damage_dice_against(17, 0, true).as_deref(),
+            Some("4d12")
+        );
+    }
+
     #[test]
     fn test_spell_classes() {
         let cure_wounds = get_spell("cure wounds").expect("Cure Wounds should exist");
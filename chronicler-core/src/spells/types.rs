@@ -133,6 +133,27 @@ impl Components {
         }
     }
 
+    /// The gold cost of the material component, if it names one (e.g.,
+    /// "diamonds worth 300 gp"). A component pouch or focus can't stand in
+    /// for a costly component.
+    pub fn material_cost_gp(&self) -> Option<u32> {
+        let material = self.material.as_ref()?;
+        let words: Vec<&str> = material.split_whitespace().collect();
+        words.windows(2).find_map(|pair| {
+            if pair[1].trim_end_matches([',', ';', '.']) != "gp" {
+                return None;
+            }
+            pair[0].replace(',', "").parse().ok()
+        })
+    }
+
+    /// Whether casting the spell uses up its material component.
+    pub fn consumes_material(&self) -> bool {
+        self.material
+            .as_ref()
+            .is_some_and(|m| m.to_lowercase().contains("consume"))
+    }
+
     pub fn description(&self) -> String {
         let mut parts = Vec::new();
         if self.verbal {
@@ -202,6 +223,9 @@ pub enum AreaOfEffect {
 pub enum DamageScaling {
     /// Cantrip scaling: increases at levels 5, 11, 17
     CantripScaling,
+    /// Cantrip scaling with a bigger die against a target that is missing
+    /// any hit points (Toll the Dead's d8 becomes a d12)
+    CantripScalingVsInjured { injured_die: String },
     /// Scales with upcast level (e.g., +1d6 per level above base)
     PerSlotLevel { extra_dice: String },
     /// No scaling
@@ -256,9 +280,12 @@ impl SpellData {
     }
 
     /// Get the number of damage dice based on caster level (for cantrips).
+    ///
+    /// Cantrips scale with total character level, not the level in the class
+    /// that grants them.
     pub fn cantrip_dice_count(&self, caster_level: u8) -> u8 {
         match caster_level {
-            1..=4 => 1,
+            0..=4 => 1,
             5..=10 => 2,
             11..=16 => 3,
             _ => 4,
@@ -267,6 +294,18 @@ impl SpellData {
 
     /// Calculate damage dice for a given caster level and slot level.
     pub fn effective_damage_dice(&self, caster_level: u8, slot_level: u8) -> Option<String> {
+        self.damage_dice_against(caster_level, slot_level, false)
+    }
+
+    /// Calculate damage dice against a particular target. `target_injured`
+    /// (missing any hit points) only matters for cantrips whose die grows
+    /// against wounded targets.
+    pub fn damage_dice_against(
+        &self,
+        caster_level: u8,
+        slot_level: u8,
+        target_injured: bool,
+    ) -> Option<String> {
         let base_dice = self.damage_dice.as_ref()?;
 
         match &self.damage_scaling {
@@ -280,6 +319,15 @@ impl SpellData {
                     Some(base_dice.clone())
                 }
             }
+            DamageScaling::CantripScalingVsInjured { injured_die } => {
+                let count = self.cantrip_dice_count(caster_level);
+                let die_type = if target_injured {
+                    injured_die.as_str()
+                } else {
+                    base_dice.find('d').map_or("", |d_pos| &base_dice[d_pos..])
+                };
+                Some(format!("{}{}", count, die_type))
+            }
             DamageScaling::PerSlotLevel { extra_dice } => {
                 if slot_level > self.level {
                     let extra_levels = slot_level - self.level;
@@ -295,6 +295,7 @@ mod tests {
                 amount: 5,
                 damage_type: DamageType::Piercing,
                 source: "Goblin dagger".to_string(),
+                is_critical: false,
             }],
         ));
 
@@ -346,6 +347,10 @@ mod tests {
                         max_hp: 10,
                         armor_class: 10,
                         initiative_modifier: 0,
+                        dex_modifier: 0,
+                        resistances: std::collections::HashMap::new(),
+                        legendary_actions: 0,
+                        position: None,
                     },
                     CombatantInit {
                         id: CharacterId::new(),
@@ -356,8 +361,14 @@ mod tests {
                         max_hp: 7,
                         armor_class: 13,        // Goblin AC from SRD
                         initiative_modifier: 2, // Goblin DEX +2 from SRD
+                        dex_modifier: 2,
+                        resistances: std::collections::HashMap::new(),
+                        legendary_actions: 0,
+                        position: None,
                     },
                 ],
+                lair_actions: vec![],
+                surprised_ids: vec![],
             }],
         ));
 
@@ -0,0 +1,255 @@
+//! Treasure tables for generating loot.
+//!
+//! Coins follow the DMG's challenge rating tiers (0-4, 5-10, 11-16, 17+),
+//! simplified to a gold-piece total. Items are drawn from a weighted table,
+//! where rarer items only appear at higher challenge ratings.
+
+use crate::dice::DiceExpression;
+use rand::Rng;
+
+/// Coins rolled as dice times a multiplier (e.g. 2d6 x 100 gp).
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct CoinRoll {
+    pub dice: String,
+    pub multiplier: i32,
+}
+
+impl CoinRoll {
+    pub fn new(dice: impl Into<String>, multiplier: i32) -> Self {
+        Self {
+            dice: dice.into(),
+            multiplier,
+        }
+    }
+
+    fn roll<R: Rng>(&self, rng: &mut R) -> i32 {
+        roll_total(&self.dice, rng) * self.multiplier
+    }
+}
+
+/// Roll a dice expression, treating invalid notation as zero.
+fn roll_total<R: Rng>(notation: &str, rng: &mut R) -> i32 {
+    DiceExpression::parse(notation)
+        .map(|expr| expr.roll_with_rng(rng).total)
+        .unwrap_or(0)
+}
+
+/// Treasure for one band of challenge ratings.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct TreasureTier {
+    /// Highest challenge rating this tier covers.
+    pub max_cr: u8,
+    /// Gold carried by a single creature.
+    pub individual_gold: CoinRoll,
+    /// Gold in a hoard.
+    pub hoard_gold: CoinRoll,
+    /// Number of items in a hoard.
+    pub hoard_items: String,
+}
+
+/// An item that can turn up as treasure.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct TreasureItem {
+    pub name: String,
+    /// Relative chance of this item among those available.
+    pub weight: u32,
+    /// Lowest challenge rating at which the item appears.
+    pub min_cr: u8,
+}
+
+impl TreasureItem {
+    pub fn new(name: impl Into<String>, weight: u32, min_cr: u8) -> Self {
+        Self {
+            name: name.into(),
+            weight,
+            min_cr,
+        }
+    }
+}
+
+/// The result of a treasure roll.
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct Loot {
+    pub gold: i32,
+    pub items: Vec<String>,
+}
+
+/// A configurable treasure table of coin tiers and weighted items.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct TreasureTable {
+    /// Coin tiers in ascending order of `max_cr`.
+    pub tiers: Vec<TreasureTier>,
+    pub items: Vec<TreasureItem>,
+}
+
+impl Default for TreasureTable {
+    fn default() -> Self {
+        let tier = |max_cr, individual: CoinRoll, hoard: CoinRoll, items: &str| TreasureTier {
+            max_cr,
+            individual_gold: individual,
+            hoard_gold: hoard,
+            hoard_items: items.to_string(),
+        };
+        Self {
+            tiers: vec![
+                tier(4, CoinRoll::new("3d6", 1), CoinRoll::new("2d6", 100), "1d4"),
+                tier(
+                    10,
+                    CoinRoll::new("4d6", 10),
+                    CoinRoll::new("6d6", 100),
+                    "1d6",
+                ),
+                tier(
+                    16,
+                    CoinRoll::new("5d6", 100),
+                    CoinRoll::new("4d6", 1000),
+                    "1d8",
+                ),
+                tier(
+                    u8::MAX,
+                    CoinRoll::new("2d6", 1000),
+                    CoinRoll::new("12d6", 1000),
+                    "1d10",
+                ),
+            ],
+            items: vec![
+                TreasureItem::new("Potion of Healing", 10, 0),
+                TreasureItem::new("Antitoxin", 4, 0),
+                TreasureItem::new("Spell Scroll (Cantrip)", 6, 0),
+                TreasureItem::new("Spell Scroll (1st Level)", 5, 0),
+                TreasureItem::new("Potion of Greater Healing", 6, 5),
+                TreasureItem::new("Spell Scroll (3rd Level)", 3, 5),
+                TreasureItem::new("Bag of Holding", 2, 5),
+                TreasureItem::new("Cloak of Protection", 1, 5),
+                TreasureItem::new("Potion of Superior Healing", 4, 11),
+                TreasureItem::new("Ring of Protection", 1, 11),
+                TreasureItem::new("Potion of Supreme Healing", 2, 17),
+            ],
+        }
+    }
+}
+
+impl TreasureTable {
+    /// The coin tier covering a challenge rating.
+    pub fn tier(&self, challenge_rating: u8) -> Option<&TreasureTier> {
+        self.tiers
+            .iter()
+            .find(|t| challenge_rating <= t.max_cr)
+            .or(self.tiers.last())
+    }
+
+    /// Roll treasure for a single creature or a hoard.
+    ///
+    /// A hoard rolls its tier's number of items; a single creature has a
+    /// one-in-four chance of carrying one.
+    pub fn roll<R: Rng>(&self, challenge_rating: u8, hoard: bool, rng: &mut R) -> Loot {
+        let Some(tier) = self.tier(challenge_rating) else {
+            return Loot::default();
+        };
+
+        let gold = if hoard {
+            tier.hoard_gold.roll(rng)
+        } else {
+            tier.individual_gold.roll(rng)
+        };
+        let item_count = if hoard {
+            roll_total(&tier.hoard_items, rng)
+        } else {
+            i32::from(rng.gen_range(0..4) == 0)
+        };
+        let items = (0..item_count)
+            .filter_map(|_| self.roll_item(challenge_rating, rng))
+            .collect();
+
+        Loot { gold, items }
+    }
+
+    /// Pick one item available at the challenge rating, by weight.
+    fn roll_item<R: Rng>(&self, challenge_rating: u8, rng: &mut R) -> Option<String> {
+        let available: Vec<_> = self
+            .items
+            .iter()
+            .filter(|i| i.min_cr <= challenge_rating && i.weight > 0)
+            .collect();
+        let total: u32 = available.iter().map(|i| i.weight).sum();
+        if total == 0 {
+            return None;
+        }
+        let mut pick = rng.gen_range(0..total);
+        for item in available {
+            if pick < item.weight {
+                return Some(item.name.clone());
+            }
+            pick -= item.weight;
+        }
+        None
+    }
+}
+
+/// Roll a treasure hoard on the standard table.
+pub fn roll_hoard(challenge_rating: u8) -> Loot {
+    crate::dice::with_current_rng(|mut rng| {
+        TreasureTable::default().roll(challenge_rating, true, &mut rng)
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use rand::{rngs::StdRng, SeedableRng};
+
+    fn average_gold(challenge_rating: u8, hoard: bool) -> i32 {
+        let table = TreasureTable::default();
+        let mut rng = StdRng::seed_from_u64(64);
+        let total: i32 = (0..200)
+            .map(|_| table.roll(challenge_rating, hoard, &mut rng).gold)
+            .sum();
+        total / 200
+    }
+
+    #[test]
+    fn test_higher_cr_yields_more_gold() {
+        assert!(average_gold(8, true) > average_gold(2, true));
+        assert!(average_gold(14, true) > average_gold(8, true));
+        assert!(average_gold(20, true) > average_gold(14, true));
+        assert!(average_gold(8, false) > average_gold(2, false));
+    }
+
+    #[test]
+    fn test_items_come_from_table() {
+        let table = TreasureTable::default();
+        let mut rng = StdRng::seed_from_u64(7);
+        for _ in 0..50 {
+            let loot = table.roll(3, true, &mut rng);
+            assert!(!loot.items.is_empty());
+            for item in &loot.items {
+                let entry = table.items.iter().find(|i| &i.name == item).unwrap();
+                assert!(entry.min_cr <= 3, "{item} is too rare for CR 3");
+            }
+        }
+    }
+
+    #[test]
+    fn test_seeded_rolls_are_deterministic() {
+        let table = TreasureTable::default();
+        let first = table.roll(12, true, &mut StdRng::seed_from_u64(1));
+        let second = table.roll(12, true, &mut StdRng::seed_from_u64(1));
+        assert_eq!(first, second);
+    }
+
+    #[test]
+    fn test_custom_table() {
+        let table = TreasureTable {
+            tiers: vec![TreasureTier {
+                max_cr: u8::MAX,
+                individual_gold: CoinRoll::new("1", 5),
+                hoard_gold: CoinRoll::new("1", 50),
+                hoard_items: "2".to_string(),
+            }],
+            items: vec![TreasureItem::new("Lucky Coin", 1, 0)],
+        };
+        let loot = table.roll(30, true, &mut StdRng::seed_from_u64(0));
+        assert_eq!(loot.gold, 50);
+        assert_eq!(loot.items, vec!["Lucky Coin", "Lucky Coin"]);
+    }
+}
@@ -4,10 +4,13 @@
 //! for non-player characters, along with supporting types like Race and Disposition.
 
 use super::{
-    Ability, AbilityScores, ActiveCondition, ArmorClass, ArmorType, Background, CharacterId,
-    ClassLevel, ClassResources, Condition, DeathSaves, Equipment, Feature, HitDice, HitPoints,
-    Inventory, LocationId, ProficiencyLevel, RaceType, Skill, Speed, SpellcastingData,
+    Ability, AbilityScores, ActiveCondition, ArmorClass, ArmorType, Background, CharacterClass,
+    CharacterId, ClassLevel, ClassResources, ConcentrationState, Condition, DeathSaves,
+    Encumbrance, Equipment, Feature, FightingStyle, HitDice, HitPoints, Inventory, LifeState,
+    LocationId, OngoingEffect, ProficiencyLevel, RaceType, ResistanceLevel, Skill, Speed,
+    SpellcastingData,
 };
+use crate::rules::DamageType;
 use serde::{Deserialize, Serialize};
 use std::collections::{HashMap, HashSet};
 
@@ -15,6 +18,9 @@ use std::collections::{HashMap, HashSet};
 // Character
 // ============================================================================
 
+/// The most magic items a character can be attuned to at once.
+pub const MAX_ATTUNED_ITEMS: usize = 3;
+
 /// D&D race (legacy struct for compatibility).
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct Race {
@@ -40,19 +46,37 @@ pub struct Character {
     pub hit_points: HitPoints,
     pub hit_dice: HitDice,
     pub death_saves: DeathSaves,
+    #[serde(default)]
+    pub life_state: LifeState,
 
     // Combat
     pub armor_class: ArmorClass,
     pub speed: Speed,
     pub conditions: Vec<ActiveCondition>,
+    /// Effects that trigger at the start of this character's turn in combat
+    #[serde(default)]
+    pub ongoing_effects: Vec<OngoingEffect>,
+    #[serde(default)]
+    pub resistances: HashMap<DamageType, ResistanceLevel>,
+
+    // Heroic Inspiration
+    #[serde(default)]
+    pub has_inspiration: bool,
+    /// Inspiration was spent and grants advantage on the next roll
+    #[serde(default)]
+    pub inspiration_active: bool,
 
     // Class features
     pub classes: Vec<ClassLevel>,
     pub features: Vec<Feature>,
     pub class_resources: ClassResources,
+    #[serde(default)]
+    pub fighting_styles: Vec<FightingStyle>,
 
     // Spellcasting
     pub spellcasting: Option<SpellcastingData>,
+    #[serde(default)]
+    pub concentrating_on: Option<ConcentrationState>,
 
     // Skills & proficiencies
     pub skill_proficiencies: HashMap<Skill, ProficiencyLevel>,
@@ -63,6 +87,9 @@ pub struct Character {
     // Equipment
     pub inventory: Inventory,
     pub equipment: Equipment,
+    /// Names of magic items the character is attuned to (at most [`MAX_ATTUNED_ITEMS`]).
+    #[serde(default)]
+    pub attuned: Vec<String>,
 
     // Background and race
     pub race: Race,
@@ -86,13 +113,20 @@ impl Character {
             hit_points: HitPoints::new(10),
             hit_dice: HitDice::new(),
             death_saves: DeathSaves::default(),
+            life_state: LifeState::Alive,
             armor_class: ArmorClass::default(),
             speed: Speed::default(),
             conditions: Vec::new(),
+            ongoing_effects: Vec::new(),
+            resistances: HashMap::new(),
+            has_inspiration: false,
+            inspiration_active: false,
             classes: Vec::new(),
             features: Vec::new(),
             class_resources: ClassResources::new(),
+            fighting_styles: Vec::new(),
             spellcasting: None,
+            concentrating_on: None,
             skill_proficiencies: HashMap::new(),
             saving_throw_proficiencies: HashSet::new(),
             tool_proficiencies: HashSet::new(),
@@ -103,6 +137,7 @@ impl Character {
                 silver: 0,
             },
             equipment: Equipment::default(),
+            attuned: Vec::new(),
             race: Race {
                 name: "Human".to_string(),
                 subrace: None,
@@ -191,6 +226,10 @@ impl Character {
         self.hit_points.current > 0
     }
 
+    pub fn is_dead(&self) -> bool {
+        self.life_state.is_dead()
+    }
+
     /// Check if the character has a specific condition.
     pub fn has_condition(&self, condition: Condition) -> bool {
         self.conditions
@@ -203,25 +242,152 @@ impl Character {
         self.add_condition_with_duration(condition, source, None)
     }
 
-    /// Add a condition with optional duration. Returns true if the condition was added.
+    /// Add a condition with optional duration, along with the conditions it
+    /// implies. Returns true if the condition was added.
     pub fn add_condition_with_duration(
         &mut self,
         condition: Condition,
         source: impl Into<String>,
         duration_rounds: Option<u32>,
     ) -> bool {
-        if self.has_condition(condition) {
-            false
+        let mut active = ActiveCondition::new(condition, source);
+        if let Some(duration) = duration_rounds {
+            active = active.with_duration(duration);
+        }
+        ActiveCondition::add_to(&mut self.conditions, active)
+    }
+
+    /// Remove a condition along with any conditions it implied.
+    pub fn remove_condition(&mut self, condition: Condition) {
+        ActiveCondition::remove_from(&mut self.conditions, condition);
+    }
+
+    /// Current exhaustion level (0 if not exhausted).
+    pub fn exhaustion_level(&self) -> u8 {
+        self.conditions
+            .iter()
+            .find_map(|c| match c.condition {
+                Condition::Exhaustion(level) => Some(level),
+                _ => None,
+            })
+            .unwrap_or(0)
+    }
+
+    /// Replace any existing exhaustion with the given level (0 removes it).
+    pub fn set_exhaustion_level(&mut self, level: u8, source: impl Into<String>) {
+        self.conditions
+            .retain(|c| !matches!(c.condition, Condition::Exhaustion(_)));
+        if level > 0 {
+            self.add_condition(Condition::Exhaustion(level), source);
+        }
+    }
+
+    /// Whether the character is attuned to a magic item.
+    pub fn is_attuned_to(&self, item_name: &str) -> bool {
+        self.attuned
+            .iter()
+            .any(|name| name.eq_ignore_ascii_case(item_name))
+    }
+
+    /// Total weight of inventory and equipped items, in pounds.
+    pub fn carried_weight(&self) -> f32 {
+        let equipped = [
+            self.equipment.armor.as_ref().map(|a| a.base.weight),
+            self.equipment.shield.as_ref().map(|s| s.weight),
+            self.equipment.main_hand.as_ref().map(|w| w.base.weight),
+            self.equipment.off_hand.as_ref().map(|i| i.weight),
+        ];
+        self.inventory.total_weight() + equipped.into_iter().flatten().sum::<f32>()
+    }
+
+    /// Pounds the character can carry (15 x Strength).
+    pub fn carrying_capacity(&self) -> f32 {
+        f32::from(self.ability_scores.strength) * 15.0
+    }
+
+    /// How weighed down the character is by what they carry.
+    pub fn encumbrance(&self) -> Encumbrance {
+        Encumbrance::from_weight(self.carried_weight(), self.ability_scores.strength)
+    }
+
+    /// Walking speed after encumbrance and exhaustion penalties (halved at
+    /// exhaustion level 2, zero at level 5). A grappled character can't move
+    /// at all, and one carrying more than their capacity moves only 5 feet.
+    pub fn walking_speed(&self) -> u32 {
+        if self.has_condition(Condition::Grappled) {
+            return 0;
+        }
+        let encumbrance = self.encumbrance();
+        let speed = if encumbrance == Encumbrance::OverCapacity {
+            self.speed.walk.min(5)
         } else {
-            let mut active = ActiveCondition::new(condition, source);
-            if let Some(duration) = duration_rounds {
-                active = active.with_duration(duration);
-            }
-            self.conditions.push(active);
-            true
+            self.speed.walk.saturating_sub(encumbrance.speed_penalty())
+        };
+        match self.exhaustion_level() {
+            0..=1 => speed,
+            2..=4 => speed / 2,
+            _ => 0,
         }
     }
 
+    /// Hit point maximum after exhaustion penalties (halved at level 4).
+    pub fn effective_max_hp(&self) -> i32 {
+        if self.exhaustion_level() >= 4 {
+            self.hit_points.maximum / 2
+        } else {
+            self.hit_points.maximum
+        }
+    }
+
+    /// How this character is affected by a damage type, if at all.
+    ///
+    /// Explicit resistances take precedence; otherwise being petrified grants
+    /// resistance to all damage, and an active rage to bludgeoning, piercing,
+    /// and slashing damage.
+    pub fn resistance_to(&self, damage_type: DamageType) -> Option<ResistanceLevel> {
+        if let Some(level) = self.resistances.get(&damage_type) {
+            return Some(*level);
+        }
+        if self
+            .conditions
+            .iter()
+            .any(|c| c.condition.resists_all_damage())
+        {
+            return Some(ResistanceLevel::Resistant);
+        }
+        let physical = matches!(
+            damage_type,
+            DamageType::Bludgeoning | DamageType::Piercing | DamageType::Slashing
+        );
+        if physical && self.class_resources.rage_active {
+            return Some(ResistanceLevel::Resistant);
+        }
+        None
+    }
+
+    /// Number of weapon attacks made with the Attack action (Extra Attack).
+    ///
+    /// Extra Attack from multiple classes doesn't stack, so the best class wins.
+    pub fn attacks_per_action(&self) -> u8 {
+        self.classes
+            .iter()
+            .map(|c| match (c.class, c.level) {
+                (CharacterClass::Fighter, 20..) => 4,
+                (CharacterClass::Fighter, 11..) => 3,
+                (
+                    CharacterClass::Fighter
+                    | CharacterClass::Barbarian
+                    | CharacterClass::Monk
+                    | CharacterClass::Paladin
+                    | CharacterClass::Ranger,
+                    5..,
+                ) => 2,
+                _ => 1,
+            })
+            .max()
+            .unwrap_or(1)
+    }
+
     pub fn passive_perception(&self) -> i8 {
         10 + self.skill_modifier(Skill::Perception)
     }
@@ -242,6 +408,45 @@ pub struct NPC {
     pub location_id: Option<LocationId>,
     pub disposition: Disposition,
     pub known_information: Vec<String>,
+    /// Recent exchanges with the player, oldest first, capped at
+    /// [`MAX_DIALOGUE_TURNS`].
+    #[serde(default)]
+    pub dialogue_history: Vec<DialogueTurn>,
+}
+
+/// How many dialogue turns an NPC remembers.
+pub const MAX_DIALOGUE_TURNS: usize = 8;
+
+/// Longest excerpt of either side of a turn shown in a dialogue recap.
+const RECAP_EXCERPT_CHARS: usize = 100;
+
+/// One exchange between the player and an NPC.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct DialogueTurn {
+    /// What the player said or did.
+    pub player: String,
+    /// How the scene answered, as narrated by the DM.
+    pub response: String,
+}
+
+impl DialogueTurn {
+    /// A single-line summary of the exchange with both sides shortened.
+    pub fn summary(&self) -> String {
+        format!(
+            "Player: \"{}\" -> \"{}\"",
+            excerpt(&self.player),
+            excerpt(&self.response)
+        )
+    }
+}
+
+fn excerpt(text: &str) -> String {
+    let text = text.split_whitespace().collect::<Vec<_>>().join(" ");
+    if text.chars().count() <= RECAP_EXCERPT_CHARS {
+        return text;
+    }
+    let cut: String = text.chars().take(RECAP_EXCERPT_CHARS).collect();
+    format!("{}...", cut.trim_end())
 }
 
 impl NPC {
@@ -255,7 +460,38 @@ impl NPC {
             location_id: None,
             disposition: Disposition::Neutral,
             known_information: Vec::new(),
+            dialogue_history: Vec::new(),
+        }
+    }
+
+    /// Remember an exchange, forgetting the oldest once the history is full.
+    pub fn record_dialogue(&mut self, player: impl Into<String>, response: impl Into<String>) {
+        self.dialogue_history.push(DialogueTurn {
+            player: player.into(),
+            response: response.into(),
+        });
+        if self.dialogue_history.len() > MAX_DIALOGUE_TURNS {
+            let excess = self.dialogue_history.len() - MAX_DIALOGUE_TURNS;
+            self.dialogue_history.drain(..excess);
+        }
+    }
+
+    /// The last `count` exchanges, oldest first.
+    pub fn recent_dialogue(&self, count: usize) -> &[DialogueTurn] {
+        let start = self.dialogue_history.len().saturating_sub(count);
+        &self.dialogue_history[start..]
+    }
+
+    /// A compact recap of the remembered exchanges, or `None` if there are none.
+    pub fn dialogue_recap(&self) -> Option<String> {
+        if self.dialogue_history.is_empty() {
+            return None;
+        }
+        let mut recap = format!("Earlier conversations with {}:\n", self.name);
+        for turn in &self.dialogue_history {
+            recap.push_str(&format!("- {}\n", turn.summary()));
         }
+        Some(recap)
     }
 }
 
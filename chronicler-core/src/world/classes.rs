@@ -7,7 +7,7 @@ use crate::dice::DieType;
 use serde::{Deserialize, Serialize};
 use std::fmt;
 
-use super::Ability;
+use super::{Ability, PactMagic};
 
 // ============================================================================
 // Classes and Features
@@ -93,12 +93,10 @@ impl CharacterClass {
             CharacterClass::Barbarian
             | CharacterClass::Monk
             | CharacterClass::Paladin
-            | CharacterClass::Ranger => {
-                if level >= 5 {
-                    2
-                } else {
-                    1
-                }
+            | CharacterClass::Ranger
+                if level >= 5 =>
+            {
+                2
             }
             // Casters and Rogues don't get Extra Attack (Rogues get Sneak Attack instead)
             _ => 1,
@@ -313,9 +311,10 @@ impl CharacterClass {
     /// Returns the maximum number of spells a prepared caster can prepare.
     /// Formula: spellcasting ability modifier + class level (minimum 1).
     /// For half-casters (Paladin, Ranger), it's ability mod + half class level.
+    /// Wizards prepare from their spellbook by the same full-caster formula.
     pub fn max_prepared_spells(&self, level: u8, ability_modifier: i8) -> Option<usize> {
         let base = match self {
-            CharacterClass::Cleric | CharacterClass::Druid => {
+            CharacterClass::Cleric | CharacterClass::Druid | CharacterClass::Wizard => {
                 (ability_modifier as i32 + level as i32).max(1) as usize
             }
             CharacterClass::Paladin => {
@@ -333,6 +332,9 @@ impl CharacterClass {
 
     /// Returns the highest spell level this class can cast at a given character level.
     pub fn max_spell_level(&self, level: u8) -> u8 {
+        if *self == CharacterClass::Warlock {
+            return PactMagic::for_warlock_level(level).map_or(0, |pact| pact.slot_level);
+        }
         let slots = self.spell_slots_at_level(level);
         for (spell_level, &count) in slots.iter().enumerate().rev() {
             if count > 0 {
@@ -347,7 +349,6 @@ impl CharacterClass {
     pub fn spell_slots_at_level(&self, level: u8) -> [u8; 9] {
         // Full casters: Bard, Cleric, Druid, Sorcerer, Wizard
         // Half casters: Paladin, Ranger (start at level 2)
-        // Pact Magic: Warlock (different progression)
 
         match self {
             // Full casters (standard progression)
@@ -360,8 +361,8 @@ impl CharacterClass {
             // Half casters (Paladin, Ranger)
             CharacterClass::Paladin | CharacterClass::Ranger => half_caster_slots(level),
 
-            // Warlock uses Pact Magic
-            CharacterClass::Warlock => warlock_slots(level),
+            // Warlock Pact Magic slots are tracked separately (see `PactMagic`)
+            CharacterClass::Warlock => [0; 9],
 
             // Non-casters
             _ => [0; 9],
@@ -369,6 +370,27 @@ impl CharacterClass {
     }
 }
 
+/// Spell slots for a multiclass caster, from the combined caster level (D&D 5e PHB).
+///
+/// Full casters add all their levels and Paladins and Rangers add half (rounded down).
+/// Third casters would add a third, but none of the SRD subclasses here are third casters.
+/// Warlock levels don't count; Pact Magic slots are tracked separately.
+pub fn multiclass_spell_slots(classes: &[ClassLevel]) -> [u8; 9] {
+    let caster_level: u8 = classes
+        .iter()
+        .map(|class_level| match class_level.class {
+            CharacterClass::Bard
+            | CharacterClass::Cleric
+            | CharacterClass::Druid
+            | CharacterClass::Sorcerer
+            | CharacterClass::Wizard => class_level.level,
+            CharacterClass::Paladin | CharacterClass::Ranger => class_level.level / 2,
+            _ => 0,
+        })
+        .sum();
+    full_caster_slots(caster_level.min(20))
+}
+
 /// Standard full caster spell slot progression (D&D 5e SRD).
 fn full_caster_slots(level: u8) -> [u8; 9] {
     match level {
@@ -423,36 +445,6 @@ fn half_caster_slots(level: u8) -> [u8; 9] {
     }
 }
 
-/// Warlock Pact Magic slot progression.
-fn warlock_slots(level: u8) -> [u8; 9] {
-    // Warlocks have fewer slots but they're all at their highest available level
-    // and recharge on short rest. For simplicity, we track them in the slot array
-    // at the appropriate level.
-    match level {
-        1 => [1, 0, 0, 0, 0, 0, 0, 0, 0], // 1 slot, 1st level
-        2 => [2, 0, 0, 0, 0, 0, 0, 0, 0], // 2 slots, 1st level
-        3 => [0, 2, 0, 0, 0, 0, 0, 0, 0], // 2 slots, 2nd level
-        4 => [0, 2, 0, 0, 0, 0, 0, 0, 0],
-        5 => [0, 0, 2, 0, 0, 0, 0, 0, 0], // 2 slots, 3rd level
-        6 => [0, 0, 2, 0, 0, 0, 0, 0, 0],
-        7 => [0, 0, 0, 2, 0, 0, 0, 0, 0], // 2 slots, 4th level
-        8 => [0, 0, 0, 2, 0, 0, 0, 0, 0],
-        9 => [0, 0, 0, 0, 2, 0, 0, 0, 0], // 2 slots, 5th level
-        10 => [0, 0, 0, 0, 2, 0, 0, 0, 0],
-        11 => [0, 0, 0, 0, 3, 0, 0, 0, 0], // 3 slots, 5th level
-        12 => [0, 0, 0, 0, 3, 0, 0, 0, 0],
-        13 => [0, 0, 0, 0, 3, 0, 0, 0, 0],
-        14 => [0, 0, 0, 0, 3, 0, 0, 0, 0],
-        15 => [0, 0, 0, 0, 3, 0, 0, 0, 0],
-        16 => [0, 0, 0, 0, 3, 0, 0, 0, 0],
-        17 => [0, 0, 0, 0, 4, 0, 0, 0, 0], // 4 slots, 5th level
-        18 => [0, 0, 0, 0, 4, 0, 0, 0, 0],
-        19 => [0, 0, 0, 0, 4, 0, 0, 0, 0],
-        20 => [0, 0, 0, 0, 4, 0, 0, 0, 0],
-        _ => [0; 9],
-    }
-}
-
 impl fmt::Display for CharacterClass {
     fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
         write!(f, "{}", self.name())
@@ -492,6 +484,17 @@ pub enum RechargeType {
     Dawn,
 }
 
+/// Fighting styles available to Fighters, Paladins, and Rangers.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+pub enum FightingStyle {
+    Archery,
+    Defense,
+    Dueling,
+    GreatWeaponFighting,
+    Protection,
+    TwoWeaponFighting,
+}
+
 // ============================================================================
 // Class Resources
 // ============================================================================
@@ -573,12 +576,10 @@ impl ClassResources {
                 self.bardic_inspiration_uses = 1;
                 self.max_bardic_inspiration = 1;
             }
-            CharacterClass::Monk => {
+            CharacterClass::Monk if level >= 2 => {
                 // Ki points equal Monk level (starting at level 2)
-                if level >= 2 {
-                    self.ki_points = level;
-                    self.max_ki_points = level;
-                }
+                self.ki_points = level;
+                self.max_ki_points = level;
             }
             CharacterClass::Cleric => {
                 // Channel Divinity starts fresh
@@ -591,12 +592,10 @@ impl ClassResources {
                 // Channel Divinity starts fresh
                 self.channel_divinity_used = false;
             }
-            CharacterClass::Sorcerer => {
+            CharacterClass::Sorcerer if level >= 2 => {
                 // Sorcery points equal Sorcerer level (starting at level 2)
-                if level >= 2 {
-                    self.sorcery_points = level;
-                    self.max_sorcery_points = level;
-                }
+                self.sorcery_points = level;
+                self.max_sorcery_points = level;
             }
             CharacterClass::Fighter => {
                 self.action_surge_used = false;
@@ -612,11 +611,9 @@ impl ClassResources {
     /// Reset resources on a short rest
     pub fn short_rest_recovery(&mut self, class: CharacterClass, level: u8) {
         match class {
-            CharacterClass::Bard => {
+            CharacterClass::Bard if level >= 5 => {
                 // Font of Inspiration (level 5+) allows recovery on short rest
-                if level >= 5 {
-                    self.bardic_inspiration_uses = self.max_bardic_inspiration;
-                }
+                self.bardic_inspiration_uses = self.max_bardic_inspiration;
             }
             CharacterClass::Fighter => {
                 self.action_surge_used = false;
@@ -627,12 +624,11 @@ impl ClassResources {
                 self.channel_divinity_used = false;
             }
             CharacterClass::Monk => {
-                // Ki points don't recover on short rest in base rules
-                // (Uncanny Metabolism at level 2 lets them recover some)
+                // Focus (ki) points recover on a short rest
+                self.ki_points = self.max_ki_points;
             }
             _ => {}
         }
-        let _ = level; // Used for Bard Font of Inspiration check
     }
 
     /// Reset resources on a long rest
@@ -645,6 +641,11 @@ impl ClassResources {
                 self.rage_active = false;
                 self.rage_rounds_remaining = None;
             }
+            CharacterClass::Druid => {
+                // No beast form lasts through eight hours of rest
+                self.wild_shape_form = None;
+                self.wild_shape_hp = None;
+            }
             CharacterClass::Bard => {
                 // Full recovery on long rest
                 self.bardic_inspiration_uses = self.max_bardic_inspiration;
@@ -4,9 +4,11 @@
 //! initiative tracking, combatant management, and turn order.
 
 use serde::{Deserialize, Serialize};
-use std::collections::HashSet;
+use std::cmp::Ordering;
+use std::collections::{HashMap, HashSet};
 
-use super::CharacterId;
+use super::{ActiveCondition, CharacterId, Condition, OngoingEffect, ResistanceLevel};
+use crate::rules::{AreaShape, DamageType};
 
 /// Combat participant.
 #[derive(Debug, Clone, Serialize, Deserialize)]
@@ -14,11 +16,140 @@ pub struct Combatant {
     pub id: CharacterId,
     pub name: String,
     pub initiative: i32,
+    /// DEX modifier, which breaks initiative ties
+    #[serde(default)]
+    pub dex_modifier: i8,
     pub is_player: bool,
     pub is_ally: bool,
     pub current_hp: i32,
     pub max_hp: i32,
     pub armor_class: u8,
+    /// Conditions currently affecting this combatant
+    #[serde(default)]
+    pub conditions: Vec<ActiveCondition>,
+    /// Effects that trigger at the start of this combatant's turn
+    #[serde(default)]
+    pub ongoing_effects: Vec<OngoingEffect>,
+    /// Damage resistances, vulnerabilities, and immunities
+    #[serde(default)]
+    pub resistances: HashMap<DamageType, ResistanceLevel>,
+    /// Whether this combatant has used their reaction since their last turn began
+    #[serde(default)]
+    pub reaction_used: bool,
+    /// Whether this combatant took the Dodge action (attacks against them have disadvantage)
+    #[serde(default)]
+    pub dodging: bool,
+    /// The ally whose Help action grants advantage on this combatant's next check or attack
+    #[serde(default)]
+    pub helped_by: Option<CharacterId>,
+    /// Legendary actions remaining until the start of this combatant's next turn
+    #[serde(default)]
+    pub legendary_actions: u8,
+    /// Legendary actions this combatant gets each round (0 for most creatures)
+    #[serde(default)]
+    pub legendary_max: u8,
+    /// Where this combatant stands, in feet, if the scene tracks positions
+    #[serde(default)]
+    pub position: Option<(i32, i32)>,
+}
+
+/// How badly hurt a combatant is, using the same thresholds as damage narration.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum HealthStatus {
+    /// Above half HP
+    Healthy,
+    /// At or below half HP
+    Bloodied,
+    /// At or below a quarter HP
+    Critical,
+    /// At 0 HP
+    Down,
+}
+
+impl HealthStatus {
+    /// Classify current HP against maximum HP.
+    pub fn from_hp(current: i32, max: i32) -> Self {
+        if current <= 0 {
+            HealthStatus::Down
+        } else if current <= max / 4 {
+            HealthStatus::Critical
+        } else if current <= max / 2 {
+            HealthStatus::Bloodied
+        } else {
+            HealthStatus::Healthy
+        }
+    }
+
+    pub fn name(&self) -> &'static str {
+        match self {
+            HealthStatus::Healthy => "healthy",
+            HealthStatus::Bloodied => "bloodied",
+            HealthStatus::Critical => "critical",
+            HealthStatus::Down => "down",
+        }
+    }
+}
+
+impl Combatant {
+    /// How badly hurt this combatant is.
+    pub fn health_status(&self) -> HealthStatus {
+        HealthStatus::from_hp(self.current_hp, self.max_hp)
+    }
+
+    /// How this combatant is affected by a damage type, if at all.
+    ///
+    /// Explicit resistances take precedence; otherwise being petrified grants
+    /// resistance to all damage.
+    pub fn resistance_to(&self, damage_type: DamageType) -> Option<ResistanceLevel> {
+        self.resistances.get(&damage_type).copied().or_else(|| {
+            self.conditions
+                .iter()
+                .any(|c| c.condition.resists_all_damage())
+                .then_some(ResistanceLevel::Resistant)
+        })
+    }
+
+    /// Check if the combatant has a specific condition.
+    pub fn has_condition(&self, condition: Condition) -> bool {
+        self.conditions
+            .iter()
+            .any(|c| std::mem::discriminant(&c.condition) == std::mem::discriminant(&condition))
+    }
+
+    /// Add a condition with optional duration, along with the conditions it
+    /// implies. Returns true if the condition was added.
+    pub fn add_condition_with_duration(
+        &mut self,
+        condition: Condition,
+        source: impl Into<String>,
+        duration_rounds: Option<u32>,
+    ) -> bool {
+        let mut active = ActiveCondition::new(condition, source);
+        if let Some(duration) = duration_rounds {
+            active = active.with_duration(duration);
+        }
+        ActiveCondition::add_to(&mut self.conditions, active)
+    }
+
+    /// Remove a condition along with any conditions it implied.
+    pub fn remove_condition(&mut self, condition: Condition) {
+        ActiveCondition::remove_from(&mut self.conditions, condition);
+    }
+}
+
+/// An action a boss's lair takes on initiative count 20.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct LairAction {
+    pub name: String,
+    pub description: String,
+    /// Damage dealt to each target, in dice notation (e.g., "2d6")
+    #[serde(default)]
+    pub damage: Option<String>,
+    #[serde(default)]
+    pub damage_type: Option<DamageType>,
+    /// Condition applied to each target
+    #[serde(default)]
+    pub condition: Option<Condition>,
 }
 
 /// Combat state tracking.
@@ -33,7 +164,20 @@ pub struct CombatState {
     pub sneak_attack_used: HashSet<CharacterId>,
     /// Number of attacks each character has made this turn
     #[serde(default)]
-    pub attacks_this_turn: std::collections::HashMap<CharacterId, u8>,
+    pub attacks_this_turn: HashMap<CharacterId, u8>,
+    /// Characters who have taken a bonus action since their last turn began
+    #[serde(default)]
+    pub bonus_action_used: HashSet<CharacterId>,
+    /// Actions the lair can take on initiative count 20
+    #[serde(default)]
+    pub lair_actions: Vec<LairAction>,
+    /// Whether the lair has acted this round
+    #[serde(default)]
+    pub lair_action_used_this_round: bool,
+    /// Reroll everyone's initiative at the start of each round, even when the
+    /// engine's `RulesConfig` leaves it off
+    #[serde(default)]
+    pub reroll_initiative_each_round: bool,
 }
 
 impl CombatState {
@@ -44,35 +188,128 @@ impl CombatState {
             turn_index: 0,
             combatants: Vec::new(),
             sneak_attack_used: HashSet::new(),
-            attacks_this_turn: std::collections::HashMap::new(),
+            attacks_this_turn: HashMap::new(),
+            bonus_action_used: HashSet::new(),
+            lair_actions: Vec::new(),
+            lair_action_used_this_round: false,
+            reroll_initiative_each_round: false,
         }
     }
 
+    /// Add a combatant, keeping the combatants sorted in initiative order so
+    /// [`CombatState::next_turn`] walks them from first to last.
     pub fn add_combatant(&mut self, combatant: Combatant) {
         self.combatants.push(combatant);
-        self.combatants
-            .sort_by_key(|c| std::cmp::Reverse(c.initiative));
+        self.combatants.sort_by(initiative_order);
     }
 
     pub fn current_combatant(&self) -> Option<&Combatant> {
         self.combatants.get(self.turn_index)
     }
 
+    /// Advance to the next combatant's turn, skipping anyone who is still
+    /// surprised. A skipped combatant's surprise ends with the lost turn.
     pub fn next_turn(&mut self) {
+        self.advance_turn();
+        self.skip_surprised();
+    }
+
+    /// Mark a combatant as surprised. If it's already their turn, it passes.
+    pub fn surprise(&mut self, id: CharacterId) {
+        if let Some(combatant) = self.combatant_mut(id) {
+            combatant.add_condition_with_duration(Condition::Surprised, "Surprise", None);
+        }
+        self.skip_surprised();
+    }
+
+    /// Skip surprised combatants at the front of the turn order. Each skip
+    /// clears that combatant's surprise, so this always ends.
+    fn skip_surprised(&mut self) {
+        while let Some(current) = self.combatants.get_mut(self.turn_index) {
+            if !current.has_condition(Condition::Surprised) {
+                break;
+            }
+            current
+                .conditions
+                .retain(|c| c.condition != Condition::Surprised);
+            self.advance_turn();
+        }
+    }
+
+    fn advance_turn(&mut self) {
         self.turn_index += 1;
         if self.turn_index >= self.combatants.len() {
             self.turn_index = 0;
             self.round += 1;
+            self.lair_action_used_this_round = false;
+            // Initiatives rerolled during the last round take effect as the new one begins
+            self.combatants.sort_by(initiative_order);
         }
         // Reset per-turn tracking for the new combatant
         self.sneak_attack_used.clear();
         self.attacks_this_turn.clear();
+        // A combatant regains their reaction, bonus action and legendary actions and
+        // stops dodging at the start of their turn
+        if let Some(current) = self.combatants.get_mut(self.turn_index) {
+            current.reaction_used = false;
+            self.bonus_action_used.remove(&current.id);
+            current.dodging = false;
+            current.legendary_actions = current.legendary_max;
+            let current_id = current.id;
+            // Unused help from this combatant expires
+            for combatant in &mut self.combatants {
+                if combatant.helped_by == Some(current_id) {
+                    combatant.helped_by = None;
+                }
+            }
+        }
     }
 
     pub fn end_combat(&mut self) {
         self.active = false;
     }
 
+    /// Whether a character still has their reaction. Characters outside the
+    /// initiative order are never limited.
+    pub fn can_react(&self, id: CharacterId) -> bool {
+        self.combatant(id)
+            .is_none_or(|c| !c.reaction_used && !c.has_condition(Condition::Surprised))
+    }
+
+    /// Spend a combatant's reaction until the start of their next turn.
+    pub fn mark_reaction(&mut self, id: CharacterId) {
+        if let Some(combatant) = self.combatant_mut(id) {
+            combatant.reaction_used = true;
+        }
+    }
+
+    /// Whether a character can still take a bonus action this turn.
+    pub fn can_take_bonus_action(&self, id: CharacterId) -> bool {
+        !self.bonus_action_used.contains(&id)
+    }
+
+    /// Spend a character's bonus action until the start of their next turn.
+    pub fn mark_bonus_action(&mut self, id: CharacterId) {
+        self.bonus_action_used.insert(id);
+    }
+
+    /// Set a combatant's initiative. The turn order changes at the start of the next round.
+    pub fn set_initiative(&mut self, id: CharacterId, initiative: i32) {
+        if let Some(combatant) = self.combatant_mut(id) {
+            combatant.initiative = initiative;
+        }
+    }
+
+    /// Look up a combatant by ID.
+    pub fn combatant(&self, id: CharacterId) -> Option<&Combatant> {
+        self.combatants.iter().find(|c| c.id == id)
+    }
+
+    /// Look up a combatant by ID for modification.
+    pub fn combatant_mut(&mut self, id: CharacterId) -> Option<&mut Combatant> {
+        self.combatants.iter_mut().find(|c| c.id == id)
+    }
+
     /// Update a combatant's HP
     pub fn update_combatant_hp(&mut self, id: CharacterId, new_hp: i32) {
         if let Some(combatant) = self.combatants.iter_mut().find(|c| c.id == id) {
@@ -80,10 +317,136 @@ impl CombatState {
         }
     }
 
+    /// Apply an HP change (negative for damage, positive for healing) to a combatant,
+    /// clamped between 0 and their maximum HP.
+    pub fn apply_hp_change(&mut self, id: CharacterId, amount: i32) {
+        if let Some(combatant) = self.combatant_mut(id) {
+            combatant.current_hp = (combatant.current_hp + amount).clamp(0, combatant.max_hp);
+        }
+    }
+
     /// Get non-player combatants (enemies and allies)
     pub fn get_enemies(&self) -> Vec<&Combatant> {
         self.combatants.iter().filter(|c| !c.is_player).collect()
     }
+
+    /// A compact recap of the fight: the round, whose turn it is, and each
+    /// combatant's HP, health, conditions and ongoing effects in initiative order.
+    pub fn summary(&self) -> String {
+        let current = self
+            .current_combatant()
+            .map(|c| c.name.as_str())
+            .unwrap_or("nobody");
+        let mut summary = format!("Round {} - {}'s turn\n", self.round, current);
+        for (combatant, is_current) in self.turn_order() {
+            let side = if combatant.is_player {
+                " (player)"
+            } else if combatant.is_ally {
+                " (ally)"
+            } else {
+                ""
+            };
+            let mut effects: Vec<&str> = combatant
+                .conditions
+                .iter()
+                .map(|c| c.condition.name())
+                .collect();
+            effects.extend(combatant.ongoing_effects.iter().map(|e| e.name.as_str()));
+            let effects = if effects.is_empty() {
+                String::new()
+            } else {
+                format!(" [{}]", effects.join(", "))
+            };
+            summary.push_str(&format!(
+                "{} {}{}: {}/{} HP, {}{}\n",
+                if is_current { ">" } else { "-" },
+                combatant.name,
+                side,
+                combatant.current_hp,
+                combatant.max_hp,
+                combatant.health_status().name(),
+                effects
+            ));
+        }
+        summary
+    }
+
+    /// The combatant at a 1-based position in the initiative list shown by
+    /// [`CombatState::turn_order`], or `None` if the position is out of range.
+    pub fn combatant_at_position(&self, position: usize) -> Option<CharacterId> {
+        let index = position.checked_sub(1)?;
+        self.turn_order().get(index).map(|(c, _)| c.id)
+    }
+
+    /// Combatants in initiative order, each with whether it's their turn.
+    pub fn turn_order(&self) -> Vec<(&Combatant, bool)> {
+        let mut order: Vec<(usize, &Combatant)> = self.combatants.iter().enumerate().collect();
+        order.sort_by(|(_, a), (_, b)| initiative_order(a, b));
+        order
+            .into_iter()
+            .map(|(i, c)| (c, i == self.turn_index))
+            .collect()
+    }
+
+    /// Combatants standing inside an area, in turn order, or `None` when no
+    /// one in the fight has a position.
+    pub fn combatants_in_area(
+        &self,
+        origin: (i32, i32),
+        shape: &AreaShape,
+    ) -> Option<Vec<&Combatant>> {
+        if self.combatants.iter().all(|c| c.position.is_none()) {
+            return None;
+        }
+        Some(
+            self.turn_order()
+                .into_iter()
+                .map(|(c, _)| c)
+                .filter(|c| c.position.is_some_and(|p| shape.contains(origin, p)))
+                .collect(),
+        )
+    }
+
+    /// Whether a conscious, hostile combatant stands within `feet` of `id`,
+    /// counting diagonal squares as 5 feet like the grid does. False when
+    /// positions aren't tracked.
+    pub fn enemy_within(&self, id: CharacterId, feet: i32) -> bool {
+        !self.enemies_within(id, feet).is_empty()
+    }
+
+    /// Conscious, hostile combatants standing within `feet` of `id`. Empty
+    /// when `id` has no position.
+    pub fn enemies_within(&self, id: CharacterId, feet: i32) -> Vec<&Combatant> {
+        let Some(me) = self.combatant(id) else {
+            return Vec::new();
+        };
+        let Some((x, y)) = me.position else {
+            return Vec::new();
+        };
+        self.combatants
+            .iter()
+            .filter(|c| {
+                c.is_ally != me.is_ally
+                    && c.current_hp > 0
+                    && !c
+                        .conditions
+                        .iter()
+                        .any(|ac| ac.condition.is_incapacitating())
+                    && c.position
+                        .is_some_and(|(cx, cy)| (cx - x).abs().max((cy - y).abs()) <= feet)
+            })
+            .collect()
+    }
+}
+
+/// Initiative order: highest initiative first, then highest DEX modifier, then
+/// player characters before other creatures. Remaining ties keep the order the
+/// combatants joined in.
+fn initiative_order(a: &Combatant, b: &Combatant) -> Ordering {
+    b.initiative
+        .cmp(&a.initiative)
+        .then(b.dex_modifier.cmp(&a.dex_modifier))
+        .then(b.is_player.cmp(&a.is_player))
 }
 
 impl Default for CombatState {
@@ -91,3 +454,238 @@ impl Default for CombatState {
         Self::new()
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn combatant(name: &str, initiative: i32, current_hp: i32) -> Combatant {
+        Combatant {
+            id: CharacterId::new(),
+            name: name.to_string(),
+            initiative,
+            dex_modifier: 0,
+            is_player: false,
+            is_ally: false,
+            current_hp,
+            max_hp: 20,
+            armor_class: 12,
+            conditions: Vec::new(),
+            ongoing_effects: Vec::new(),
+            resistances: HashMap::new(),
+            reaction_used: false,
+            dodging: false,
+            helped_by: None,
+            legendary_actions: 0,
+            legendary_max: 0,
+            position: None,
+        }
+    }
+
+    #[test]
+    fn test_turn_order_sorts_by_initiative_and_marks_active() {
+        let mut combat = CombatState::new();
+        // Pushed directly so the stored order isn't already sorted
+        combat.combatants = vec![
+            combatant("Goblin", 8, 20),
+            combatant("Hero", 17, 20),
+            combatant("Wolf", 12, 20),
+        ];
+        combat.turn_index = 2;
+
+        let order: Vec<(&str, bool)> = combat
+            .turn_order()
+            .into_iter()
+            .map(|(c, active)| (c.name.as_str(), active))
+            .collect();
+        assert_eq!(
+            order,
+            vec![("Hero", false), ("Wolf", true), ("Goblin", false)]
+        );
+    }
+
+    #[test]
+    fn test_turn_order_keeps_ties_in_turn_order() {
+        let mut combat = CombatState::new();
+        combat.add_combatant(combatant("Orc", 10, 20));
+        combat.add_combatant(combatant("Ogre", 10, 20));
+        combat.add_combatant(combatant("Imp", 15, 20));
+        combat.next_turn();
+
+        let order: Vec<(&str, bool)> = combat
+            .turn_order()
+            .into_iter()
+            .map(|(c, active)| (c.name.as_str(), active))
+            .collect();
+        assert_eq!(order, vec![("Imp", false), ("Orc", true), ("Ogre", false)]);
+    }
+
+    #[test]
+    fn test_initiative_ties_go_to_higher_dex() {
+        let mut combat = CombatState::new();
+        combat.add_combatant(combatant("Ogre", 14, 20));
+        let mut rogue = combatant("Rogue", 14, 20);
+        rogue.dex_modifier = 4;
+        combat.add_combatant(rogue);
+
+        assert_eq!(combat.current_combatant().unwrap().name, "Rogue");
+        combat.next_turn();
+        assert_eq!(combat.current_combatant().unwrap().name, "Ogre");
+    }
+
+    #[test]
+    fn test_surprised_combatant_loses_first_turn() {
+        let mut combat = CombatState::new();
+        combat.add_combatant(combatant("Hero", 15, 20));
+        combat.add_combatant(combatant("Goblin", 10, 20));
+        combat.add_combatant(combatant("Orc", 5, 20));
+        let goblin = combat.combatants[1].id;
+        combat.surprise(goblin);
+        assert!(!combat.can_react(goblin));
+
+        // The goblin's first turn is skipped, ending its surprise
+        combat.next_turn();
+        assert_eq!(combat.current_combatant().unwrap().name, "Orc");
+        assert!(combat.can_react(goblin));
+
+        // It acts normally the following round
+        combat.next_turn();
+        assert_eq!(combat.round, 2);
+        combat.next_turn();
+        assert_eq!(combat.current_combatant().unwrap().name, "Goblin");
+    }
+
+    #[test]
+    fn test_surprise_passes_the_current_turn() {
+        let mut combat = CombatState::new();
+        combat.add_combatant(combatant("Ambusher", 20, 20));
+        combat.add_combatant(combatant("Hero", 10, 20));
+        let ambusher = combat.combatants[0].id;
+        combat.surprise(ambusher);
+
+        assert_eq!(combat.current_combatant().unwrap().name, "Hero");
+        assert_eq!(combat.round, 1);
+    }
+
+    #[test]
+    fn test_full_initiative_ties_go_to_players() {
+        let mut combat = CombatState::new();
+        combat.add_combatant(combatant("Goblin", 12, 20));
+        let mut hero = combatant("Hero", 12, 20);
+        hero.is_player = true;
+        combat.add_combatant(hero);
+
+        let names: Vec<&str> = combat.combatants.iter().map(|c| c.name.as_str()).collect();
+        assert_eq!(names, vec!["Hero", "Goblin"]);
+    }
+
+    #[test]
+    fn test_new_initiative_takes_effect_next_round() {
+        let mut combat = CombatState::new();
+        combat.add_combatant(combatant("Hero", 15, 20));
+        combat.add_combatant(combatant("Goblin", 10, 20));
+        let goblin = combat.combatants[1].id;
+
+        combat.set_initiative(goblin, 20);
+        combat.next_turn();
+        assert_eq!(combat.current_combatant().unwrap().name, "Goblin");
+        assert_eq!(combat.round, 1);
+
+        combat.next_turn();
+        assert_eq!(combat.round, 2);
+        assert_eq!(combat.current_combatant().unwrap().name, "Goblin");
+        combat.next_turn();
+        assert_eq!(combat.current_combatant().unwrap().name, "Hero");
+    }
+
+    #[test]
+    fn test_combatant_at_position_follows_initiative_list() {
+        let mut combat = CombatState::new();
+        combat.combatants = vec![
+            combatant("Goblin", 8, 20),
+            combatant("Hero", 17, 20),
+            combatant("Wolf", 12, 20),
+        ];
+        let id = |name: &str| {
+            combat
+                .combatants
+                .iter()
+                .find(|c| c.name == name)
+                .unwrap()
+                .id
+        };
+
+        assert_eq!(combat.combatant_at_position(1), Some(id("Hero")));
+        assert_eq!(combat.combatant_at_position(2), Some(id("Wolf")));
+        assert_eq!(combat.combatant_at_position(3), Some(id("Goblin")));
+    }
+
+    #[test]
+    fn test_combatant_at_position_out_of_range() {
+        let mut combat = CombatState::new();
+        assert_eq!(combat.combatant_at_position(1), None);
+
+        combat.add_combatant(combatant("Hero", 17, 20));
+        combat.add_combatant(combatant("Goblin", 8, 20));
+        assert_eq!(combat.combatant_at_position(0), None);
+        assert_eq!(combat.combatant_at_position(3), None);
+        assert_eq!(combat.combatant_at_position(9), None);
+    }
+
+    #[test]
+    fn test_combatants_in_area_by_shape() {
+        let mut combat = CombatState::new();
+        assert!(combat
+            .combatants_in_area((0, 0), &AreaShape::Sphere { radius: 20 })
+            .is_none());
+
+        for (name, position) in [("Hero", (0, 0)), ("Goblin", (15, 0)), ("Wolf", (12, 5))] {
+            let mut c = combatant(name, 10, 20);
+            c.position = Some(position);
+            combat.add_combatant(c);
+        }
+        let caught = |shape: AreaShape| -> Vec<String> {
+            let mut names: Vec<String> = combat
+                .combatants_in_area((0, 0), &shape)
+                .unwrap()
+                .iter()
+                .map(|c| c.name.clone())
+                .collect();
+            names.sort();
+            names
+        };
+
+        assert_eq!(
+            caught(AreaShape::Sphere { radius: 15 }),
+            vec!["Goblin", "Hero", "Wolf"]
+        );
+        // The caster at the cone's point is spared
+        assert_eq!(
+            caught(AreaShape::Cone {
+                length: 15,
+                toward: (1, 0)
+            }),
+            vec!["Goblin", "Wolf"]
+        );
+        assert_eq!(
+            caught(AreaShape::Line {
+                length: 30,
+                width: 5,
+                toward: (1, 0)
+            }),
+            vec!["Goblin"]
+        );
+    }
+
+    #[test]
+    fn test_health_status_thresholds() {
+        assert_eq!(HealthStatus::from_hp(11, 20), HealthStatus::Healthy);
+        assert_eq!(HealthStatus::from_hp(10, 20), HealthStatus::Bloodied);
+        assert_eq!(HealthStatus::from_hp(5, 20), HealthStatus::Critical);
+        assert_eq!(HealthStatus::from_hp(0, 20), HealthStatus::Down);
+        assert_eq!(
+            combatant("Goblin", 5, -3).health_status(),
+            HealthStatus::Down
+        );
+    }
+}
@@ -7,6 +7,8 @@ use std::fmt;
 
 use serde::{Deserialize, Serialize};
 
+use crate::rules::DamageType;
+
 // ============================================================================
 // Conditions
 // ============================================================================
@@ -29,6 +31,8 @@ pub enum Condition {
     Stunned,
     Unconscious,
     Exhaustion(u8),
+    /// Caught off guard: loses the first turn of combat and can't react until it passes
+    Surprised,
 }
 
 impl Condition {
@@ -49,9 +53,33 @@ impl Condition {
             Condition::Stunned => "Stunned",
             Condition::Unconscious => "Unconscious",
             Condition::Exhaustion(_) => "Exhaustion",
+            Condition::Surprised => "Surprised",
+        }
+    }
+
+    /// Conditions that come along with this one and end with it, e.g. an
+    /// unconscious creature is also incapacitated and prone.
+    pub fn implied(&self) -> &'static [Condition] {
+        match self {
+            Condition::Unconscious => &[Condition::Incapacitated, Condition::Prone],
+            Condition::Paralyzed | Condition::Petrified | Condition::Stunned => {
+                &[Condition::Incapacitated]
+            }
+            _ => &[],
         }
     }
 
+    /// Whether a creature with this condition resists all damage (Petrified).
+    pub fn resists_all_damage(&self) -> bool {
+        matches!(self, Condition::Petrified)
+    }
+
+    /// Whether melee hits from within 5 feet against a creature with this
+    /// condition are automatically critical hits.
+    pub fn grants_melee_crits(&self) -> bool {
+        matches!(self, Condition::Paralyzed | Condition::Unconscious)
+    }
+
     pub fn is_incapacitating(&self) -> bool {
         matches!(
             self,
@@ -79,6 +107,9 @@ pub struct ActiveCondition {
     pub condition: Condition,
     pub source: String,
     pub duration_rounds: Option<u32>,
+    /// The condition that brought this one along; it ends when that one does.
+    #[serde(default)]
+    pub implied_by: Option<Condition>,
 }
 
 impl ActiveCondition {
@@ -87,15 +118,81 @@ impl ActiveCondition {
             condition,
             source: source.into(),
             duration_rounds: None,
+            implied_by: None,
         }
     }
 
+    /// Add `active` to `conditions` along with everything it implies, skipping
+    /// conditions already present. Returns true if `active` itself was added.
+    pub fn add_to(conditions: &mut Vec<ActiveCondition>, active: ActiveCondition) -> bool {
+        let present = |conditions: &[ActiveCondition], condition: Condition| {
+            conditions
+                .iter()
+                .any(|c| std::mem::discriminant(&c.condition) == std::mem::discriminant(&condition))
+        };
+        if present(conditions, active.condition) {
+            return false;
+        }
+        for &implied in active.condition.implied() {
+            if !present(conditions, implied) {
+                conditions.push(ActiveCondition {
+                    condition: implied,
+                    source: active.source.clone(),
+                    duration_rounds: active.duration_rounds,
+                    implied_by: Some(active.condition),
+                });
+            }
+        }
+        conditions.push(active);
+        true
+    }
+
+    /// Remove `condition` from `conditions` along with the conditions it brought.
+    pub fn remove_from(conditions: &mut Vec<ActiveCondition>, condition: Condition) {
+        conditions.retain(|c| c.condition != condition && c.implied_by != Some(condition));
+    }
+
     pub fn with_duration(mut self, rounds: u32) -> Self {
         self.duration_rounds = Some(rounds);
         self
     }
 }
 
+// ============================================================================
+// Ongoing Effects
+// ============================================================================
+
+/// What an ongoing effect does each time it triggers.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub enum OngoingKind {
+    /// Roll damage, e.g. burning or bleeding
+    Damage {
+        dice: String,
+        damage_type: DamageType,
+    },
+    /// Roll healing, e.g. regeneration
+    Healing { dice: String },
+    /// A bonus or penalty that is only narrated, e.g. "-2 to attack rolls"
+    Modifier { description: String },
+}
+
+/// An effect that triggers at the start of each of the creature's turns
+/// until its rounds run out.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct OngoingEffect {
+    pub name: String,
+    pub kind: OngoingKind,
+    pub remaining_rounds: u32,
+}
+
+impl OngoingEffect {
+    /// Count down one round, returning whether the effect is still active.
+    pub fn tick(&mut self) -> bool {
+        self.remaining_rounds = self.remaining_rounds.saturating_sub(1);
+        self.remaining_rounds > 0
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -119,6 +216,7 @@ mod tests {
         assert_eq!(Condition::Stunned.name(), "Stunned");
         assert_eq!(Condition::Unconscious.name(), "Unconscious");
         assert_eq!(Condition::Exhaustion(3).name(), "Exhaustion");
+        assert_eq!(Condition::Surprised.name(), "Surprised");
     }
 
     #[test]
@@ -213,4 +311,44 @@ mod tests {
 
         assert_eq!(ac1.source, ac2.source);
     }
+
+    // ========== Implied Condition Tests ==========
+
+    #[test]
+    fn test_condition_implied() {
+        assert_eq!(
+            Condition::Unconscious.implied(),
+            &[Condition::Incapacitated, Condition::Prone]
+        );
+        assert_eq!(Condition::Paralyzed.implied(), &[Condition::Incapacitated]);
+        assert_eq!(Condition::Stunned.implied(), &[Condition::Incapacitated]);
+        assert_eq!(Condition::Petrified.implied(), &[Condition::Incapacitated]);
+        assert!(Condition::Petrified.resists_all_damage());
+        assert!(Condition::Poisoned.implied().is_empty());
+    }
+
+    #[test]
+    fn test_implied_conditions_come_and_go_with_parent() {
+        let mut conditions = vec![ActiveCondition::new(Condition::Prone, "tripped")];
+        assert!(ActiveCondition::add_to(
+            &mut conditions,
+            ActiveCondition::new(Condition::Unconscious, "sleep").with_duration(10),
+        ));
+        let names: Vec<_> = conditions.iter().map(|c| c.condition).collect();
+        assert_eq!(
+            names,
+            vec![
+                Condition::Prone,
+                Condition::Incapacitated,
+                Condition::Unconscious
+            ]
+        );
+        assert_eq!(conditions[1].implied_by, Some(Condition::Unconscious));
+        assert_eq!(conditions[1].duration_rounds, Some(10));
+
+        // Prone was there first, so it outlasts the unconsciousness
+        ActiveCondition::remove_from(&mut conditions, Condition::Unconscious);
+        let names: Vec<_> = conditions.iter().map(|c| c.condition).collect();
+        assert_eq!(names, vec![Condition::Prone]);
+    }
 }
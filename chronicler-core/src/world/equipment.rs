@@ -5,6 +5,7 @@
 
 use serde::{Deserialize, Serialize};
 
+use super::abilities::Ability;
 use super::conditions::Condition;
 use super::defense::ArmorType;
 
@@ -22,6 +23,9 @@ pub struct Item {
     pub description: Option<String>,
     pub item_type: ItemType,
     pub magical: bool,
+    /// Whether the item's magic only works for an attuned character.
+    #[serde(default)]
+    pub requires_attunement: bool,
 }
 
 impl Item {
@@ -83,6 +87,14 @@ impl Equipment {
     pub fn new() -> Self {
         Self::default()
     }
+
+    /// Whether both hands are holding something, leaving none free for
+    /// somatic spell components.
+    pub fn hands_full(&self) -> bool {
+        let main_hand = self.main_hand.is_some();
+        let other_hand = self.shield.is_some() || self.off_hand.is_some();
+        main_hand && other_hand
+    }
 }
 
 /// Armor with D&D 5e properties.
@@ -106,6 +118,7 @@ impl ArmorItem {
                 description: None,
                 item_type: ItemType::Armor,
                 magical: false,
+                requires_attunement: false,
             },
             armor_type,
             base_ac,
@@ -165,6 +178,7 @@ impl WeaponItem {
                 description: None,
                 item_type: ItemType::Weapon,
                 magical: false,
+                requires_attunement: false,
             },
             damage_dice: damage_dice.into(),
             damage_type,
@@ -206,6 +220,14 @@ impl WeaponItem {
         self.range.is_some() || self.properties.contains(&WeaponProperty::Thrown)
     }
 
+    pub fn is_thrown(&self) -> bool {
+        self.properties.contains(&WeaponProperty::Thrown)
+    }
+
+    pub fn is_light(&self) -> bool {
+        self.properties.contains(&WeaponProperty::Light)
+    }
+
     pub fn is_two_handed(&self) -> bool {
         self.properties.contains(&WeaponProperty::TwoHanded)
     }
@@ -302,6 +324,7 @@ impl ConsumableItem {
                 ),
                 item_type: ItemType::Potion,
                 magical: true,
+                requires_attunement: false,
             },
             effect: ConsumableEffect::Healing {
                 dice: dice.into(),
@@ -322,6 +345,7 @@ impl ConsumableItem {
                 description: Some("A magical scroll containing a spell.".to_string()),
                 item_type: ItemType::Scroll,
                 magical: true,
+                requires_attunement: false,
             },
             effect: ConsumableEffect::CastSpell {
                 spell_name: spell_name_str,
@@ -331,6 +355,61 @@ impl ConsumableItem {
     }
 }
 
+/// How weighed down a character is, under the variant encumbrance rules.
+///
+/// Over 5 x STR pounds is encumbered, over 10 x STR heavily encumbered, and
+/// over the 15 x STR carrying capacity the character can barely move.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord)]
+pub enum Encumbrance {
+    Unencumbered,
+    Encumbered,
+    HeavilyEncumbered,
+    OverCapacity,
+}
+
+impl Encumbrance {
+    /// Classify a carried weight for a Strength score.
+    pub fn from_weight(weight: f32, strength: u8) -> Self {
+        let strength = f32::from(strength);
+        if weight > strength * 15.0 {
+            Encumbrance::OverCapacity
+        } else if weight > strength * 10.0 {
+            Encumbrance::HeavilyEncumbered
+        } else if weight > strength * 5.0 {
+            Encumbrance::Encumbered
+        } else {
+            Encumbrance::Unencumbered
+        }
+    }
+
+    pub fn name(&self) -> &'static str {
+        match self {
+            Encumbrance::Unencumbered => "Unencumbered",
+            Encumbrance::Encumbered => "Encumbered",
+            Encumbrance::HeavilyEncumbered => "Heavily Encumbered",
+            Encumbrance::OverCapacity => "Over Capacity",
+        }
+    }
+
+    /// Feet of speed lost to the load.
+    pub fn speed_penalty(&self) -> u32 {
+        match self {
+            Encumbrance::Unencumbered => 0,
+            Encumbrance::Encumbered => 10,
+            Encumbrance::HeavilyEncumbered | Encumbrance::OverCapacity => 20,
+        }
+    }
+
+    /// Whether checks and saving throws with an ability have disadvantage.
+    pub fn hampers(&self, ability: Ability) -> bool {
+        *self >= Encumbrance::HeavilyEncumbered
+            && matches!(
+                ability,
+                Ability::Strength | Ability::Dexterity | Ability::Constitution
+            )
+    }
+}
+
 impl Inventory {
     pub fn total_weight(&self) -> f32 {
         self.items
@@ -387,6 +466,17 @@ impl Inventory {
             .find(|i| i.name.to_lowercase() == name.to_lowercase())
     }
 
+    /// Find an item that can serve as a costly material component: its name
+    /// appears in the component's description and it is worth at least `cost_gp`.
+    pub fn find_material_component(&self, material: &str, cost_gp: u32) -> Option<&Item> {
+        let material = material.to_lowercase();
+        self.items.iter().find(|i| {
+            i.quantity > 0
+                && i.value_gp >= cost_gp as f32
+                && material.contains(&i.name.to_lowercase())
+        })
+    }
+
     /// Check if the inventory contains an item.
     pub fn has_item(&self, name: &str) -> bool {
         self.find_item(name).is_some()
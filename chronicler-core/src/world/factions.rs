@@ -0,0 +1,68 @@
+//! Faction reputation.
+//!
+//! Reputation is a score from [`MIN_REPUTATION`] to [`MAX_REPUTATION`] kept
+//! per faction, with thresholds mapping it onto a [`ReputationTier`].
+
+use serde::{Deserialize, Serialize};
+
+/// Lowest possible reputation with a faction.
+pub const MIN_REPUTATION: i32 = -100;
+/// Highest possible reputation with a faction.
+pub const MAX_REPUTATION: i32 = 100;
+
+/// How a faction regards the party.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Serialize, Deserialize)]
+pub enum ReputationTier {
+    Hostile,
+    Unfriendly,
+    Neutral,
+    Friendly,
+    Honored,
+    Allied,
+}
+
+impl ReputationTier {
+    /// The tier a reputation score falls into.
+    pub fn from_score(score: i32) -> Self {
+        match score {
+            i32::MIN..=-50 => ReputationTier::Hostile,
+            -49..=-10 => ReputationTier::Unfriendly,
+            -9..=9 => ReputationTier::Neutral,
+            10..=39 => ReputationTier::Friendly,
+            40..=74 => ReputationTier::Honored,
+            75..=i32::MAX => ReputationTier::Allied,
+        }
+    }
+
+    pub fn name(&self) -> &'static str {
+        match self {
+            ReputationTier::Hostile => "Hostile",
+            ReputationTier::Unfriendly => "Unfriendly",
+            ReputationTier::Neutral => "Neutral",
+            ReputationTier::Friendly => "Friendly",
+            ReputationTier::Honored => "Honored",
+            ReputationTier::Allied => "Allied",
+        }
+    }
+}
+
+/// Clamp a reputation score to the allowed range.
+pub fn clamp_reputation(score: i32) -> i32 {
+    score.clamp(MIN_REPUTATION, MAX_REPUTATION)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_tier_thresholds() {
+        assert_eq!(ReputationTier::from_score(-100), ReputationTier::Hostile);
+        assert_eq!(ReputationTier::from_score(-10), ReputationTier::Unfriendly);
+        assert_eq!(ReputationTier::from_score(0), ReputationTier::Neutral);
+        assert_eq!(ReputationTier::from_score(10), ReputationTier::Friendly);
+        assert_eq!(ReputationTier::from_score(40), ReputationTier::Honored);
+        assert_eq!(ReputationTier::from_score(100), ReputationTier::Allied);
+        assert!(ReputationTier::Allied > ReputationTier::Hostile);
+    }
+}
@@ -7,12 +7,14 @@
 //! [`mechanics`](super::mechanics) submodule and called from the methods here.
 
 use super::{
-    mechanics, Ability, ArmorType, Character, CharacterClass, CharacterId, ClassLevel, CombatState,
-    Feature, FeatureUses, GameTime, HitPoints, Location, LocationId, LocationType,
-    ProficiencyLevel, Quest, RechargeType, Skill, SlotInfo, SpellSlots, SpellcastingData, Subclass,
-    NPC,
+    clamp_reputation, find_by_name, mechanics, Ability, ArmorType, Character, CharacterClass,
+    CharacterId, ClassLevel, CombatState, Feature, FeatureUses, GameTime, HitPoints, LightLevel,
+    Location, LocationId, LocationType, NameLookupError, ProficiencyLevel, Quest, RechargeType,
+    ReputationTier, Skill, SlotInfo, SpellSlots, SpellcastingData, Subclass, Weather, NPC,
+    WEATHER_EASE_MINUTES,
 };
 use crate::dice::DieType;
+use crate::rules::Intent;
 use serde::{Deserialize, Serialize};
 use std::collections::HashMap;
 use uuid::Uuid;
@@ -54,9 +56,14 @@ pub struct GameWorld {
     pub session_id: Uuid,
     pub campaign_name: String,
 
-    // Player character
+    // Player character (the active party member)
     pub player_character: Character,
 
+    /// The other members of the party. The active member lives in
+    /// `player_character`; use [`GameWorld::character`] to look up any member.
+    #[serde(default)]
+    pub party: Vec<Character>,
+
     // NPCs
     pub npcs: HashMap<CharacterId, NPC>,
 
@@ -65,13 +72,28 @@ pub struct GameWorld {
     pub combat: Option<CombatState>,
     pub current_location: Location,
     pub game_time: GameTime,
+    #[serde(default)]
+    pub weather: Weather,
+    /// Minutes since the weather last changed, used to ease it over time.
+    #[serde(default)]
+    pub weather_elapsed_minutes: u32,
 
     // Locations
     pub known_locations: HashMap<LocationId, Location>,
 
+    /// Reputation with each faction, keyed by faction name.
+    #[serde(default)]
+    pub factions: HashMap<String, i32>,
+
     // Campaign progress
     pub quests: Vec<Quest>,
     pub narrative_history: Vec<NarrativeEntry>,
+
+    /// Intents triggered while applying effects, such as the concentration
+    /// check owed after a concentrating character takes damage. They are
+    /// waiting for the caller to resolve them.
+    #[serde(skip)]
+    pub queued_intents: Vec<Intent>,
 }
 
 impl GameWorld {
@@ -86,15 +108,75 @@ impl GameWorld {
             session_id: Uuid::new_v4(),
             campaign_name: campaign_name.into(),
             player_character,
+            party: Vec::new(),
             npcs: HashMap::new(),
             mode: GameMode::Exploration,
             combat: None,
             current_location: starting_location,
             game_time: GameTime::default(),
+            weather: Weather::default(),
+            weather_elapsed_minutes: 0,
             known_locations,
+            factions: HashMap::new(),
             quests: Vec::new(),
             narrative_history: Vec::new(),
+            queued_intents: Vec::new(),
+        }
+    }
+
+    /// Take the intents queued while applying effects, leaving none behind.
+    pub fn take_queued_intents(&mut self) -> Vec<Intent> {
+        std::mem::take(&mut self.queued_intents)
+    }
+
+    /// The id of the party member the player is currently controlling.
+    pub fn active_character_id(&self) -> CharacterId {
+        self.player_character.id
+    }
+
+    /// Look up a party member by id.
+    pub fn character(&self, id: CharacterId) -> Option<&Character> {
+        if self.player_character.id == id {
+            return Some(&self.player_character);
+        }
+        self.party.iter().find(|c| c.id == id)
+    }
+
+    /// Look up a party member by id for modification.
+    pub fn character_mut(&mut self, id: CharacterId) -> Option<&mut Character> {
+        if self.player_character.id == id {
+            return Some(&mut self.player_character);
+        }
+        self.party.iter_mut().find(|c| c.id == id)
+    }
+
+    /// All party members, starting with the active one.
+    pub fn party_members(&self) -> impl Iterator<Item = &Character> {
+        std::iter::once(&self.player_character).chain(self.party.iter())
+    }
+
+    /// All party members for modification, starting with the active one.
+    pub fn party_members_mut(&mut self) -> impl Iterator<Item = &mut Character> {
+        std::iter::once(&mut self.player_character).chain(self.party.iter_mut())
+    }
+
+    /// Add a member to the party without changing the active character.
+    pub fn add_party_member(&mut self, character: Character) {
+        self.party.push(character);
+    }
+
+    /// Make another party member the active character.
+    ///
+    /// Returns false if no party member has the given id.
+    pub fn set_active_character(&mut self, id: CharacterId) -> bool {
+        if self.player_character.id == id {
+            return true;
         }
+        let Some(index) = self.party.iter().position(|c| c.id == id) else {
+            return false;
+        };
+        std::mem::swap(&mut self.player_character, &mut self.party[index]);
+        true
     }
 
     /// Start combat, transitioning to combat mode.
@@ -118,8 +200,9 @@ impl GameWorld {
 
     /// Take a short rest (1 hour).
     ///
-    /// - Warlocks recover all spell slots (Pact Magic)
+    /// - Warlocks recover their Pact Magic slots
     /// - Features that recharge on short rest are restored
+    /// - Rage, Channel Divinity, and Wild Shape regain one use
     /// - Class-specific resources that recharge on short rest are restored
     pub fn short_rest(&mut self) {
         self.game_time.advance_hours(1);
@@ -135,9 +218,125 @@ impl GameWorld {
     /// - Recover all spell slots
     /// - All features that recharge on short or long rest are restored
     /// - Class-specific resources that recharge on long rest are restored
+    /// - The world enters Rest mode, when prepared casters may change their
+    ///   prepared spells, until time moves on again
     pub fn long_rest(&mut self) {
         self.game_time.advance_hours(8);
         mechanics::apply_long_rest(&mut self.player_character);
+        self.mode = GameMode::Rest;
+    }
+
+    /// A compact recap of the current fight, or None outside combat.
+    ///
+    /// Party members keep their conditions and ongoing effects on their
+    /// character sheet, so those are folded into their combatant entries.
+    pub fn combat_summary(&self) -> Option<String> {
+        let mut combat = self.combat.clone()?;
+        for combatant in &mut combat.combatants {
+            if let Some(character) = self.character(combatant.id) {
+                combatant.conditions = character.conditions.clone();
+                combatant.ongoing_effects = character.ongoing_effects.clone();
+            }
+        }
+        Some(combat.summary())
+    }
+
+    /// Find a combatant in the current combat by a possibly partial name.
+    pub fn find_combatant_fuzzy(&self, name: &str) -> Result<CharacterId, NameLookupError> {
+        let combatants = self.combat.iter().flat_map(|combat| &combat.combatants);
+        find_by_name(name, combatants.map(|c| (c.name.as_str(), c.id)))
+    }
+
+    /// Find an NPC by a possibly partial name.
+    pub fn find_npc_fuzzy(&self, name: &str) -> Result<&NPC, NameLookupError> {
+        find_by_name(name, self.npcs.values().map(|npc| (npc.name.as_str(), npc)))
+    }
+
+    /// NPCs the player addresses or refers to in `input`, by full name or by
+    /// any distinctive word of it ("Mira" for "Mira the Innkeeper").
+    pub fn npcs_mentioned_in(&self, input: &str) -> Vec<CharacterId> {
+        let input = input.to_lowercase();
+        let words: Vec<&str> = input
+            .split(|c: char| !c.is_alphanumeric() && c != '\'')
+            .filter(|word| !word.is_empty())
+            .collect();
+        let mut mentioned: Vec<&NPC> = self
+            .npcs
+            .values()
+            .filter(|npc| {
+                let name = npc.name.to_lowercase();
+                input.contains(&name)
+                    || name
+                        .split_whitespace()
+                        .filter(|part| part.len() >= 3 && !matches!(*part, "the" | "and"))
+                        .any(|part| words.contains(&part))
+            })
+            .collect();
+        mentioned.sort_by(|a, b| a.name.cmp(&b.name));
+        mentioned.into_iter().map(|npc| npc.id).collect()
+    }
+
+    /// Add an exchange to the dialogue history of every NPC mentioned in the
+    /// player's input.
+    pub fn record_npc_dialogue(&mut self, player_input: &str, response: &str) {
+        for id in self.npcs_mentioned_in(player_input) {
+            if let Some(npc) = self.npcs.get_mut(&id) {
+                npc.record_dialogue(player_input, response);
+            }
+        }
+    }
+
+    /// The stored name of a faction, matched case-insensitively.
+    pub fn faction_name<'a>(&'a self, faction: &'a str) -> &'a str {
+        self.factions
+            .keys()
+            .find(|name| name.eq_ignore_ascii_case(faction))
+            .map_or(faction, String::as_str)
+    }
+
+    /// Reputation with a faction; unknown factions are neutral.
+    pub fn reputation(&self, faction: &str) -> i32 {
+        self.factions
+            .get(self.faction_name(faction))
+            .copied()
+            .unwrap_or(0)
+    }
+
+    /// The reputation tier for a faction.
+    pub fn reputation_tier(&self, faction: &str) -> ReputationTier {
+        ReputationTier::from_score(self.reputation(faction))
+    }
+
+    /// Set reputation with a faction, clamped to the allowed range.
+    pub fn set_reputation(&mut self, faction: &str, score: i32) {
+        let name = self.faction_name(faction).to_string();
+        self.factions.insert(name, clamp_reputation(score));
+    }
+
+    /// Whether the sun is up.
+    pub fn is_daytime(&self) -> bool {
+        self.game_time.is_daytime()
+    }
+
+    /// Natural light at the current time of day.
+    pub fn light_level(&self) -> LightLevel {
+        LightLevel::at_hour(self.game_time.hour)
+    }
+
+    /// Set the weather, restarting the clock on how long it lasts.
+    pub fn set_weather(&mut self, weather: Weather) {
+        self.weather = weather;
+        self.weather_elapsed_minutes = 0;
+    }
+
+    /// Let the weather ease one step for every
+    /// [`WEATHER_EASE_MINUTES`] that pass.
+    pub fn advance_weather(&mut self, minutes: u32) {
+        self.weather_elapsed_minutes = self.weather_elapsed_minutes.saturating_add(minutes);
+        while self.weather_elapsed_minutes >= WEATHER_EASE_MINUTES {
+            self.weather_elapsed_minutes -= WEATHER_EASE_MINUTES;
+            self.weather = self.weather.eased();
+        }
     }
 
     pub fn add_narrative(&mut self, content: String, entry_type: NarrativeType) {
@@ -393,6 +592,7 @@ pub fn create_sample_paladin(name: &str) -> Character {
             slots.slots[0] = SlotInfo { total: 3, used: 0 }; // 3 first-level slots at level 3
             slots
         },
+        pact_slots: None,
     });
 
     character.features.push(Feature {
@@ -483,6 +683,7 @@ pub fn create_sample_druid(name: &str) -> Character {
             slots.slots[1] = SlotInfo { total: 2, used: 0 }; // 2 second-level slots
             slots
         },
+        pact_slots: None,
     });
 
     character.features.push(Feature {
@@ -558,6 +759,7 @@ pub fn create_sample_cleric(name: &str) -> Character {
             slots.slots[1] = SlotInfo { total: 2, used: 0 };
             slots
         },
+        pact_slots: None,
     });
 
     character.features.push(Feature {
@@ -643,6 +845,7 @@ pub fn create_sample_bard(name: &str) -> Character {
             slots.slots[1] = SlotInfo { total: 2, used: 0 };
             slots
         },
+        pact_slots: None,
     });
 
     character.features.push(Feature {
@@ -730,6 +933,7 @@ pub fn create_sample_sorcerer(name: &str) -> Character {
             slots.slots[1] = SlotInfo { total: 2, used: 0 };
             slots
         },
+        pact_slots: None,
     });
 
     character.features.push(Feature {
@@ -761,7 +965,7 @@ mod tests {
     use super::*;
     use crate::world::{
         AbilityScores, ArmorItem, ArmorType, Inventory, Item, ItemType, WeaponDamageType,
-        WeaponItem, WeaponProperty,
+        WeaponItem, WeaponProperty, MAX_DIALOGUE_TURNS,
     };
 
     #[test]
@@ -843,6 +1047,7 @@ mod tests {
             description: None,
             item_type: ItemType::Weapon,
             magical: false,
+            requires_attunement: false,
         };
         inventory.add_item(sword);
 
@@ -858,6 +1063,7 @@ mod tests {
             description: None,
             item_type: ItemType::Weapon,
             magical: false,
+            requires_attunement: false,
         };
         inventory.add_item(sword2);
 
@@ -872,6 +1078,7 @@ mod tests {
             description: None,
             item_type: ItemType::Potion,
             magical: true,
+            requires_attunement: false,
         };
         inventory.add_item(potion1);
         assert_eq!(inventory.items.len(), 3);
@@ -884,6 +1091,7 @@ mod tests {
             description: None,
             item_type: ItemType::Potion,
             magical: true,
+            requires_attunement: false,
         };
         inventory.add_item(potion2);
         assert_eq!(inventory.items.len(), 3); // Still 3 - potions stacked
@@ -901,6 +1109,7 @@ mod tests {
             description: None,
             item_type: ItemType::Potion,
             magical: true,
+            requires_attunement: false,
         };
         inventory.add_item(potion);
 
@@ -966,6 +1175,7 @@ mod tests {
             description: None,
             item_type: ItemType::Shield,
             magical: false,
+            requires_attunement: false,
         });
         // With shield but no armor: 10 + 3 + 2 = 15
         assert_eq!(character.current_ac(), 15);
@@ -1021,6 +1231,7 @@ mod tests {
             description: None,
             item_type: ItemType::Weapon,
             magical: false,
+            requires_attunement: false,
         };
         assert!(!sword.is_stackable());
 
@@ -1033,6 +1244,7 @@ mod tests {
             description: None,
             item_type: ItemType::Armor,
             magical: false,
+            requires_attunement: false,
         };
         assert!(!armor.is_stackable());
 
@@ -1045,6 +1257,7 @@ mod tests {
             description: None,
             item_type: ItemType::Potion,
             magical: true,
+            requires_attunement: false,
         };
         assert!(potion.is_stackable());
 
@@ -1057,6 +1270,7 @@ mod tests {
             description: None,
             item_type: ItemType::Adventuring,
             magical: false,
+            requires_attunement: false,
         };
         assert!(rope.is_stackable());
     }
@@ -1076,4 +1290,47 @@ mod tests {
             "A wandering adventurer seeking glory."
         );
     }
+
+    #[test]
+    fn test_npc_dialogue_accumulates_for_mentioned_npcs() {
+        let mut world = GameWorld::new("Test", create_sample_fighter("Roland"));
+        let mira = NPC::new("Mira the Innkeeper");
+        let mira_id = mira.id;
+        let tom = NPC::new("Old Tom");
+        let tom_id = tom.id;
+        world.npcs.insert(mira_id, mira);
+        world.npcs.insert(tom_id, tom);
+
+        world.record_npc_dialogue("I ask Mira about rooms", "Mira quotes two silver.");
+        world.record_npc_dialogue("I haggle with mira", "She sighs and agrees to one.");
+        world.record_npc_dialogue("I look around the tavern", "Smoke hangs low.");
+
+        let history = &world.npcs[&mira_id].dialogue_history;
+        assert_eq!(history.len(), 2);
+        assert_eq!(history[0].player, "I ask Mira about rooms");
+        assert_eq!(history[1].response, "She sighs and agrees to one.");
+        assert!(world.npcs[&tom_id].dialogue_history.is_empty());
+
+        let recent = world.npcs[&mira_id].recent_dialogue(1);
+        assert_eq!(recent.len(), 1);
+        assert_eq!(recent[0].player, "I haggle with mira");
+    }
+
+    #[test]
+    fn test_npc_dialogue_recap_is_bounded() {
+        let mut npc = NPC::new("Mira");
+        assert!(npc.dialogue_recap().is_none());
+
+        let total = MAX_DIALOGUE_TURNS + 5;
+        for i in 1..=total {
+            npc.record_dialogue(format!("question {i}"), "x".repeat(500));
+        }
+
+        assert_eq!(npc.dialogue_history.len(), MAX_DIALOGUE_TURNS);
+        let recap = npc.dialogue_recap().unwrap();
+        assert_eq!(recap.lines().count(), MAX_DIALOGUE_TURNS + 1);
+        assert!(recap.contains(&format!("question {total}\"")));
+        assert!(!recap.contains("question 5\""));
+        assert!(recap.lines().all(|line| line.len() < 300));
+    }
 }
@@ -1,6 +1,7 @@
 //! Hit points and health-related types.
 //!
-//! Contains types for tracking hit points, hit dice, and death saving throws.
+//! Contains types for tracking hit points, hit dice, death saving throws, and
+//! whether a character is still alive.
 
 use crate::dice::DieType;
 use serde::{Deserialize, Serialize};
@@ -69,6 +70,36 @@ impl HitPoints {
     }
 }
 
+/// How a creature is affected by a particular damage type.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+pub enum ResistanceLevel {
+    /// Takes half damage (rounded down)
+    Resistant,
+    /// Takes double damage
+    Vulnerable,
+    /// Takes no damage
+    Immune,
+}
+
+impl ResistanceLevel {
+    /// Adjust a raw damage amount for this resistance level.
+    pub fn apply(&self, amount: i32) -> i32 {
+        match self {
+            ResistanceLevel::Resistant => amount / 2,
+            ResistanceLevel::Vulnerable => amount * 2,
+            ResistanceLevel::Immune => 0,
+        }
+    }
+
+    pub fn name(&self) -> &'static str {
+        match self {
+            ResistanceLevel::Resistant => "resisted",
+            ResistanceLevel::Vulnerable => "vulnerable",
+            ResistanceLevel::Immune => "immune",
+        }
+    }
+}
+
 /// Result of taking damage.
 #[derive(Debug, Clone)]
 pub struct DamageResult {
@@ -103,14 +134,48 @@ impl HitDice {
         false
     }
 
+    /// Regain spent dice, up to half of all hit dice (rounded up), largest
+    /// dice first.
     pub fn recover_half(&mut self) {
-        for (die_type, total) in &self.total {
-            let to_recover = (*total as f32 / 2.0).ceil() as u8;
-            if let Some(remaining) = self.remaining.get_mut(die_type) {
-                *remaining = (*remaining + to_recover).min(*total);
-            }
+        let total: u32 = self.total.values().map(|&count| count as u32).sum();
+        let mut budget = total.div_ceil(2) as u8;
+        let mut die_types: Vec<DieType> = self.total.keys().copied().collect();
+        die_types.sort_by_key(|die| std::cmp::Reverse(die.sides()));
+        for die_type in die_types {
+            let total = self.total[&die_type];
+            let remaining = self.remaining.entry(die_type).or_insert(0);
+            let recovered = total.saturating_sub(*remaining).min(budget);
+            *remaining += recovered;
+            budget -= recovered;
+        }
+    }
+}
+
+/// Where a character stands between life and death.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default, Serialize, Deserialize)]
+pub enum LifeState {
+    #[default]
+    Alive,
+    /// At 0 HP and making death saving throws
+    Unconscious,
+    /// At 0 HP but no longer making death saving throws
+    Stable,
+    Dead,
+}
+
+impl LifeState {
+    pub fn name(&self) -> &'static str {
+        match self {
+            LifeState::Alive => "alive",
+            LifeState::Unconscious => "unconscious",
+            LifeState::Stable => "stable",
+            LifeState::Dead => "dead",
         }
     }
+
+    pub fn is_dead(&self) -> bool {
+        *self == LifeState::Dead
+    }
 }
 
 /// Death saving throws.
@@ -405,6 +470,20 @@ mod tests {
         assert_eq!(hd.remaining.get(&DieType::D10), Some(&3)); // Rounded up
     }
 
+    #[test]
+    fn test_hit_dice_recover_half_across_die_types() {
+        let mut hd = HitDice::new();
+        hd.add(DieType::D10, 1);
+        hd.add(DieType::D6, 2); // 3 total, recover ceil(3/2) = 2
+        hd.spend(DieType::D10);
+        hd.spend(DieType::D6);
+        hd.spend(DieType::D6);
+
+        hd.recover_half();
+        assert_eq!(hd.remaining.get(&DieType::D10), Some(&1)); // Largest first
+        assert_eq!(hd.remaining.get(&DieType::D6), Some(&1));
+    }
+
     #[test]
     fn test_hit_dice_recover_capped_at_total() {
         let mut hd = HitDice::new();
@@ -0,0 +1,151 @@
+//! Undo/redo snapshots of the game world.
+
+use super::GameWorld;
+
+/// Default number of snapshots kept for undo.
+pub const DEFAULT_UNDO_DEPTH: usize = 20;
+
+/// Bounded undo/redo stacks of world snapshots.
+///
+/// A snapshot is recorded before each batch of effects is applied, so undo
+/// steps back one applied action at a time.
+#[derive(Debug, Clone)]
+pub struct WorldHistory {
+    undo: Vec<GameWorld>,
+    redo: Vec<GameWorld>,
+    max_depth: usize,
+}
+
+impl Default for WorldHistory {
+    fn default() -> Self {
+        Self::new(DEFAULT_UNDO_DEPTH)
+    }
+}
+
+impl WorldHistory {
+    /// Create a history keeping at most `max_depth` undo snapshots.
+    pub fn new(max_depth: usize) -> Self {
+        Self {
+            undo: Vec::new(),
+            redo: Vec::new(),
+            max_depth,
+        }
+    }
+
+    /// Record the world as it is before a change. Clears the redo stack.
+    pub fn record(&mut self, world: &GameWorld) {
+        if self.max_depth == 0 {
+            return;
+        }
+        self.undo.push(world.clone());
+        if self.undo.len() > self.max_depth {
+            self.undo.remove(0);
+        }
+        self.redo.clear();
+    }
+
+    /// Restore the most recent snapshot. Returns false if there is nothing to undo.
+    pub fn undo(&mut self, world: &mut GameWorld) -> bool {
+        let Some(previous) = self.undo.pop() else {
+            return false;
+        };
+        self.redo.push(std::mem::replace(world, previous));
+        true
+    }
+
+    /// Reapply the most recently undone change. Returns false if there is nothing to redo.
+    pub fn redo(&mut self, world: &mut GameWorld) -> bool {
+        let Some(next) = self.redo.pop() else {
+            return false;
+        };
+        self.undo.push(std::mem::replace(world, next));
+        true
+    }
+
+    /// Whether there is a snapshot to undo to.
+    pub fn can_undo(&self) -> bool {
+        !self.undo.is_empty()
+    }
+
+    /// Whether there is an undone change to redo.
+    pub fn can_redo(&self) -> bool {
+        !self.redo.is_empty()
+    }
+
+    /// Drop all snapshots, e.g. after loading a different game.
+    pub fn clear(&mut self) {
+        self.undo.clear();
+        self.redo.clear();
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::rules::{apply_effects, Effect};
+    use crate::world::create_sample_fighter;
+
+    fn damage(world: &mut GameWorld, history: &mut WorldHistory, amount: i32) {
+        history.record(world);
+        let target_id = world.player_character.id;
+        let new_current = world.player_character.hit_points.current - amount;
+        apply_effects(
+            world,
+            &[Effect::HpChanged {
+                target_id,
+                amount: -amount,
+                new_current,
+                new_max: world.player_character.hit_points.maximum,
+                dropped_to_zero: new_current <= 0,
+            }],
+        );
+    }
+
+    #[test]
+    fn test_undo_restores_hp_and_redo_reapplies() {
+        let mut world = GameWorld::new("Test", create_sample_fighter("Hero"));
+        let mut history = WorldHistory::default();
+        let full = world.player_character.hit_points.current;
+
+        damage(&mut world, &mut history, 5);
+        assert_eq!(world.player_character.hit_points.current, full - 5);
+
+        assert!(history.undo(&mut world));
+        assert_eq!(world.player_character.hit_points.current, full);
+        assert!(!history.undo(&mut world));
+
+        assert!(history.redo(&mut world));
+        assert_eq!(world.player_character.hit_points.current, full - 5);
+        assert!(!history.redo(&mut world));
+    }
+
+    #[test]
+    fn test_new_change_clears_redo() {
+        let mut world = GameWorld::new("Test", create_sample_fighter("Hero"));
+        let mut history = WorldHistory::default();
+
+        damage(&mut world, &mut history, 3);
+        history.undo(&mut world);
+        assert!(history.can_redo());
+
+        damage(&mut world, &mut history, 2);
+        assert!(!history.can_redo());
+    }
+
+    #[test]
+    fn test_depth_is_bounded() {
+        let mut world = GameWorld::new("Test", create_sample_fighter("Hero"));
+        let mut history = WorldHistory::new(2);
+        let full = world.player_character.hit_points.current;
+
+        for _ in 0..3 {
+            damage(&mut world, &mut history, 1);
+        }
+
+        assert!(history.undo(&mut world));
+        assert!(history.undo(&mut world));
+        assert!(!history.undo(&mut world));
+        // The oldest snapshot (full HP) was dropped
+        assert_eq!(world.player_character.hit_points.current, full - 1);
+    }
+}
@@ -0,0 +1,201 @@
+//! Integrity checks for references between world entities.
+//!
+//! Over a long campaign entities get removed while others still point at
+//! them: an NPC standing in a location that no longer exists, a quest given
+//! by someone the world never heard of. [`GameWorld::validate`] finds these.
+
+use std::fmt;
+
+use super::{CharacterId, GameWorld, LocationId};
+
+/// A reference from one entity to another that doesn't resolve.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum IntegrityWarning {
+    /// An NPC's location isn't a known location.
+    NpcLocationMissing {
+        npc_name: String,
+        location_id: LocationId,
+    },
+    /// A location connects to a destination that isn't a known location.
+    ConnectionMissing {
+        location_name: String,
+        destination_name: String,
+    },
+    /// A location lists an NPC as present who doesn't exist.
+    PresentNpcMissing {
+        location_name: String,
+        npc_id: CharacterId,
+    },
+    /// A combatant marked as a party member matches no party character.
+    CombatantWithoutCharacter { combatant_name: String },
+    /// A quest names a giver who is neither an NPC nor a party member.
+    QuestGiverMissing { quest_name: String, giver: String },
+}
+
+impl fmt::Display for IntegrityWarning {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            IntegrityWarning::NpcLocationMissing { npc_name, .. } => {
+                write!(f, "NPC '{npc_name}' is in a location that no longer exists")
+            }
+            IntegrityWarning::ConnectionMissing {
+                location_name,
+                destination_name,
+            } => write!(
+                f,
+                "'{location_name}' connects to unknown location '{destination_name}'"
+            ),
+            IntegrityWarning::PresentNpcMissing { location_name, .. } => {
+                write!(f, "'{location_name}' lists an NPC who no longer exists")
+            }
+            IntegrityWarning::CombatantWithoutCharacter { combatant_name } => write!(
+                f,
+                "Party combatant '{combatant_name}' doesn't match any party member"
+            ),
+            IntegrityWarning::QuestGiverMissing { quest_name, giver } => {
+                write!(f, "Quest '{quest_name}' was given by unknown '{giver}'")
+            }
+        }
+    }
+}
+
+impl GameWorld {
+    /// Scan the world for references to entities that don't exist.
+    ///
+    /// Nothing is repaired; the warnings tell the player (or the DM) what
+    /// looks out of place after a long campaign or a hand-edited save.
+    pub fn validate(&self) -> Vec<IntegrityWarning> {
+        let mut warnings = Vec::new();
+        let location_known = |id: LocationId| {
+            id == self.current_location.id || self.known_locations.contains_key(&id)
+        };
+
+        let mut npcs: Vec<_> = self.npcs.values().collect();
+        npcs.sort_by(|a, b| a.name.cmp(&b.name));
+        for npc in npcs {
+            if let Some(location_id) = npc.location_id {
+                if !location_known(location_id) {
+                    warnings.push(IntegrityWarning::NpcLocationMissing {
+                        npc_name: npc.name.clone(),
+                        location_id,
+                    });
+                }
+            }
+        }
+
+        let mut locations: Vec<_> = self.known_locations.values().collect();
+        if !self.known_locations.contains_key(&self.current_location.id) {
+            locations.push(&self.current_location);
+        }
+        locations.sort_by(|a, b| a.name.cmp(&b.name));
+        for location in locations {
+            for connection in &location.connections {
+                if !location_known(connection.destination_id) {
+                    warnings.push(IntegrityWarning::ConnectionMissing {
+                        location_name: location.name.clone(),
+                        destination_name: connection.destination_name.clone(),
+                    });
+                }
+            }
+            for npc_id in &location.npcs_present {
+                if !self.npcs.contains_key(npc_id) {
+                    warnings.push(IntegrityWarning::PresentNpcMissing {
+                        location_name: location.name.clone(),
+                        npc_id: *npc_id,
+                    });
+                }
+            }
+        }
+
+        if let Some(ref combat) = self.combat {
+            for combatant in combat.combatants.iter().filter(|c| c.is_player) {
+                if self.character(combatant.id).is_none() {
+                    warnings.push(IntegrityWarning::CombatantWithoutCharacter {
+                        combatant_name: combatant.name.clone(),
+                    });
+                }
+            }
+        }
+
+        for quest in &self.quests {
+            let Some(ref giver) = quest.giver else {
+                continue;
+            };
+            let known = self
+                .npcs
+                .values()
+                .map(|npc| &npc.name)
+                .chain(self.party_members().map(|c| &c.name))
+                .any(|name| name.eq_ignore_ascii_case(giver));
+            if !known {
+                warnings.push(IntegrityWarning::QuestGiverMissing {
+                    quest_name: quest.name.clone(),
+                    giver: giver.clone(),
+                });
+            }
+        }
+
+        warnings
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::world::{Character, Quest, NPC};
+
+    fn test_world() -> GameWorld {
+        GameWorld::new("Test", Character::new("Hero"))
+    }
+
+    #[test]
+    fn test_clean_world_has_no_warnings() {
+        let mut world = test_world();
+        let mut npc = NPC::new("Thoric");
+        npc.location_id = Some(world.current_location.id);
+        world.npcs.insert(npc.id, npc);
+        let mut quest = Quest::new("Rats", "Clear the cellar");
+        quest.giver = Some("thoric".to_string());
+        world.quests.push(quest);
+
+        assert!(world.validate().is_empty());
+    }
+
+    #[test]
+    fn test_dangling_npc_location() {
+        let mut world = test_world();
+        let mut npc = NPC::new("Thoric");
+        let missing = LocationId::new();
+        npc.location_id = Some(missing);
+        world.npcs.insert(npc.id, npc);
+
+        assert_eq!(
+            world.validate(),
+            vec![IntegrityWarning::NpcLocationMissing {
+                npc_name: "Thoric".to_string(),
+                location_id: missing,
+            }]
+        );
+    }
+
+    #[test]
+    fn test_missing_quest_giver() {
+        let mut world = test_world();
+        let mut quest = Quest::new("Rats", "Clear the cellar");
+        quest.giver = Some("Old Marta".to_string());
+        world.quests.push(quest);
+
+        let warnings = world.validate();
+        assert_eq!(
+            warnings,
+            vec![IntegrityWarning::QuestGiverMissing {
+                quest_name: "Rats".to_string(),
+                giver: "Old Marta".to_string(),
+            }]
+        );
+        assert_eq!(
+            warnings[0].to_string(),
+            "Quest 'Rats' was given by unknown 'Old Marta'"
+        );
+    }
+}
@@ -38,7 +38,7 @@ impl Location {
     }
 }
 
-#[derive(Debug, Clone, Copy, Serialize, Deserialize)]
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Serialize, Deserialize)]
 pub enum LocationType {
     Wilderness,
     Town,
@@ -51,6 +51,58 @@ pub enum LocationType {
     Other,
 }
 
+impl LocationType {
+    /// Parse a location type name, case-insensitively ("village" counts as a town).
+    pub fn from_name(name: &str) -> Self {
+        match name.trim().to_lowercase().as_str() {
+            "wilderness" => LocationType::Wilderness,
+            "town" | "village" => LocationType::Town,
+            "city" => LocationType::City,
+            "dungeon" => LocationType::Dungeon,
+            "building" => LocationType::Building,
+            "room" => LocationType::Room,
+            "road" => LocationType::Road,
+            "cave" => LocationType::Cave,
+            _ => LocationType::Other,
+        }
+    }
+
+    pub fn name(&self) -> &'static str {
+        match self {
+            LocationType::Wilderness => "wilderness",
+            LocationType::Town => "town",
+            LocationType::City => "city",
+            LocationType::Dungeon => "dungeon",
+            LocationType::Building => "building",
+            LocationType::Room => "room",
+            LocationType::Road => "road",
+            LocationType::Cave => "cave",
+            LocationType::Other => "other",
+        }
+    }
+}
+
+/// The compass direction leading back the way a direction came
+/// ("north" -> "south", "up" -> "down"), if it is one.
+pub fn opposite_direction(direction: &str) -> Option<String> {
+    let opposite = match direction.trim().to_lowercase().as_str() {
+        "north" => "south",
+        "south" => "north",
+        "east" => "west",
+        "west" => "east",
+        "northeast" => "southwest",
+        "southwest" => "northeast",
+        "northwest" => "southeast",
+        "southeast" => "northwest",
+        "up" => "down",
+        "down" => "up",
+        "in" => "out",
+        "out" => "in",
+        _ => return None,
+    };
+    Some(opposite.to_string())
+}
+
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct LocationConnection {
     pub destination_id: LocationId,
@@ -0,0 +1,230 @@
+//! A drawable graph of the locations the party knows about.
+
+use std::collections::{HashMap, HashSet, VecDeque};
+
+use super::{GameWorld, LocationId};
+
+/// A location placed on the map grid.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct MapNode {
+    pub id: LocationId,
+    pub name: String,
+    pub column: usize,
+    pub row: usize,
+    pub is_current: bool,
+}
+
+/// A path between two locations.
+///
+/// Connections that exist in both directions are merged into a single edge
+/// with `bidirectional` set; the label describes the `from` → `to` leg.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct MapEdge {
+    pub from: LocationId,
+    pub to: LocationId,
+    pub label: String,
+    pub bidirectional: bool,
+}
+
+/// Known locations laid out on a grid, ready to draw.
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct LocationMap {
+    pub nodes: Vec<MapNode>,
+    pub edges: Vec<MapEdge>,
+    pub columns: usize,
+    pub rows: usize,
+}
+
+impl LocationMap {
+    /// Build the map from the world's known locations.
+    ///
+    /// The layout is deterministic: locations are visited breadth-first from
+    /// the current location (neighbours in name order), followed by any
+    /// unreachable locations in name order, and placed row by row.
+    pub fn from_world(world: &GameWorld) -> Self {
+        let locations = &world.known_locations;
+        if locations.is_empty() {
+            return Self::default();
+        }
+
+        let mut by_name: Vec<_> = locations.values().collect();
+        by_name.sort_by(|a, b| a.name.cmp(&b.name).then(a.id.0.cmp(&b.id.0)));
+
+        let current = by_name
+            .iter()
+            .find(|l| l.id == world.current_location.id)
+            .or_else(|| {
+                by_name
+                    .iter()
+                    .find(|l| l.name.eq_ignore_ascii_case(&world.current_location.name))
+            })
+            .map(|l| l.id);
+
+        let mut order = Vec::with_capacity(by_name.len());
+        let mut visited = HashSet::new();
+        let starts = current.into_iter().chain(by_name.iter().map(|l| l.id));
+        for start in starts {
+            if !visited.insert(start) {
+                continue;
+            }
+            let mut queue = VecDeque::from([start]);
+            while let Some(id) = queue.pop_front() {
+                order.push(id);
+                let mut neighbours: Vec<_> = locations[&id]
+                    .connections
+                    .iter()
+                    .filter_map(|c| locations.get(&c.destination_id))
+                    .collect();
+                neighbours.sort_by(|a, b| a.name.cmp(&b.name));
+                for next in neighbours {
+                    if visited.insert(next.id) {
+                        queue.push_back(next.id);
+                    }
+                }
+            }
+        }
+
+        let columns = (order.len() as f64).sqrt().ceil() as usize;
+        let rows = order.len().div_ceil(columns);
+        let nodes = order
+            .iter()
+            .enumerate()
+            .map(|(index, id)| MapNode {
+                id: *id,
+                name: locations[id].name.clone(),
+                column: index % columns,
+                row: index / columns,
+                is_current: Some(*id) == current,
+            })
+            .collect();
+
+        let position: HashMap<_, _> = order.iter().enumerate().map(|(i, id)| (*id, i)).collect();
+        let mut edges = Vec::new();
+        let mut drawn = HashSet::new();
+        for id in &order {
+            for connection in &locations[id].connections {
+                let to = connection.destination_id;
+                if !locations.contains_key(&to) || !drawn.insert((*id, to)) {
+                    continue;
+                }
+                let bidirectional = locations[&to]
+                    .connections
+                    .iter()
+                    .any(|c| c.destination_id == *id);
+                if bidirectional {
+                    drawn.insert((to, *id));
+                }
+                let mut parts = Vec::new();
+                if let Some(direction) = &connection.direction {
+                    parts.push(direction.clone());
+                }
+                if connection.travel_time_minutes > 0 {
+                    parts.push(format_travel_time(connection.travel_time_minutes));
+                }
+                edges.push(MapEdge {
+                    from: *id,
+                    to,
+                    label: parts.join(", "),
+                    bidirectional,
+                });
+            }
+        }
+        edges.sort_by_key(|e| (position[&e.from], position[&e.to]));
+
+        Self {
+            nodes,
+            edges,
+            columns,
+            rows,
+        }
+    }
+
+    /// Look up a node by location id.
+    pub fn node(&self, id: LocationId) -> Option<&MapNode> {
+        self.nodes.iter().find(|n| n.id == id)
+    }
+}
+
+/// Format a travel time as "45 min", "2 h" or "1 h 30 min".
+fn format_travel_time(minutes: u32) -> String {
+    match (minutes / 60, minutes % 60) {
+        (0, m) => format!("{m} min"),
+        (h, 0) => format!("{h} h"),
+        (h, m) => format!("{h} h {m} min"),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::rules::{apply_effects, Intent, RulesEngine};
+    use crate::world::{create_sample_fighter, Location, LocationType};
+
+    fn world_with(names: &[&str]) -> GameWorld {
+        let mut world = GameWorld::new("Test Campaign", create_sample_fighter("Roland"));
+        for name in names {
+            let location = Location::new(*name, LocationType::Wilderness);
+            world.known_locations.insert(location.id, location);
+        }
+        world
+    }
+
+    fn connect(world: &mut GameWorld, from: &str, to: &str, bidirectional: bool) {
+        let intent = Intent::ConnectLocations {
+            from_location: from.to_string(),
+            to_location: to.to_string(),
+            direction: Some("east".to_string()),
+            travel_time_minutes: Some(90),
+            bidirectional,
+        };
+        let resolution = RulesEngine::new().resolve(world, intent);
+        apply_effects(world, &resolution.effects);
+    }
+
+    #[test]
+    fn test_bidirectional_connection_is_one_edge() {
+        let mut world = world_with(&["Phandalin", "Wave Echo Cave"]);
+        connect(&mut world, "Phandalin", "Wave Echo Cave", true);
+
+        let map = LocationMap::from_world(&world);
+        assert_eq!(map.edges.len(), 1);
+        let edge = &map.edges[0];
+        assert!(edge.bidirectional);
+        assert_eq!(map.node(edge.from).unwrap().name, "Phandalin");
+        assert_eq!(edge.label, "east, 1 h 30 min");
+    }
+
+    #[test]
+    fn test_one_way_connection() {
+        let mut world = world_with(&["Phandalin", "Wave Echo Cave"]);
+        connect(&mut world, "Wave Echo Cave", "Phandalin", false);
+
+        let map = LocationMap::from_world(&world);
+        assert_eq!(map.edges.len(), 1);
+        assert!(!map.edges[0].bidirectional);
+        assert_eq!(map.node(map.edges[0].to).unwrap().name, "Phandalin");
+    }
+
+    #[test]
+    fn test_layout_starts_at_current_location() {
+        let mut world = world_with(&["Cragmaw Hideout", "Phandalin", "Neverwinter"]);
+        connect(&mut world, "Phandalin", "Neverwinter", true);
+        let phandalin = world
+            .known_locations
+            .values()
+            .find(|l| l.name == "Phandalin")
+            .unwrap()
+            .clone();
+        world.current_location = phandalin;
+
+        let map = LocationMap::from_world(&world);
+        assert_eq!(map.nodes.len(), 4);
+        assert_eq!(map.columns, 2);
+        assert_eq!(map.rows, 2);
+        assert_eq!(map.nodes[0].name, "Phandalin");
+        assert!(map.nodes[0].is_current);
+        assert_eq!(map.nodes[1].name, "Neverwinter");
+        assert_eq!((map.nodes[1].column, map.nodes[1].row), (1, 0));
+        assert_eq!(map, LocationMap::from_world(&world));
+    }
+}
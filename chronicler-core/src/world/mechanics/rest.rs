@@ -8,31 +8,39 @@
 //! - Class-specific resource recovery
 //! - Condition removal (exhaustion, unconscious)
 
-use crate::world::{Character, CharacterClass, Condition, RechargeType};
+use crate::world::{Character, Condition, LifeState, RechargeType};
+
+/// Long-rest features that give back one expended use on a short rest.
+const ONE_USE_ON_SHORT_REST: [&str; 3] = ["Rage", "Channel Divinity", "Wild Shape"];
 
 /// Apply short rest recovery to a character.
 ///
 /// Short rest (1 hour):
-/// - Warlocks recover all spell slots (Pact Magic)
+/// - Warlocks recover their Pact Magic slots
 /// - Features that recharge on short rest are restored
+/// - Rage, Channel Divinity, and Wild Shape regain one use
 /// - Class-specific resources that recharge on short rest are restored
 pub fn apply_short_rest(character: &mut Character) {
-    // Warlocks recover all spell slots on short rest (Pact Magic)
-    let is_warlock = character
-        .classes
-        .iter()
-        .any(|c| c.class == CharacterClass::Warlock);
-    if is_warlock {
-        if let Some(ref mut spellcasting) = character.spellcasting {
-            spellcasting.spell_slots.recover_all();
-        }
+    // Warlocks recover their Pact Magic slots on short rest
+    if let Some(pact) = character
+        .spellcasting
+        .as_mut()
+        .and_then(|spellcasting| spellcasting.pact_slots.as_mut())
+    {
+        pact.recover_all();
     }
 
     // Reset feature uses that recharge on short rest
     for feature in &mut character.features {
         if let Some(ref mut uses) = feature.uses {
-            if matches!(uses.recharge, RechargeType::ShortRest) {
-                uses.current = uses.maximum;
+            match uses.recharge {
+                RechargeType::ShortRest => uses.current = uses.maximum,
+                RechargeType::LongRest
+                    if ONE_USE_ON_SHORT_REST.contains(&feature.name.as_str()) =>
+                {
+                    uses.current = (uses.current + 1).min(uses.maximum);
+                }
+                _ => {}
             }
         }
     }
@@ -48,22 +56,21 @@ pub fn apply_short_rest(character: &mut Character) {
 /// Apply long rest recovery to a character.
 ///
 /// Long rest (8 hours):
+/// - Reduce exhaustion by 1 level
 /// - Full HP recovery
+/// - Temporary HP is lost
 /// - Remove Unconscious condition
-/// - Reduce exhaustion by 1 level
 /// - Recover half of total hit dice
 /// - Recover all spell slots
 /// - Features that recharge on short or long rest are restored
 /// - Class-specific resources that recharge on long rest are restored
+///
+/// A dead character gets none of this.
 pub fn apply_long_rest(character: &mut Character) {
-    // Full HP recovery
-    let max_hp = character.hit_points.maximum;
-    character.hit_points.current = max_hp;
-
-    // Remove Unconscious condition if present (they're now healed)
-    character
-        .conditions
-        .retain(|c| c.condition != Condition::Unconscious);
+    // No amount of rest brings back the dead
+    if character.is_dead() {
+        return;
+    }
 
     // Reduce exhaustion by 1 level (if any)
     for condition in &mut character.conditions {
@@ -78,12 +85,23 @@ pub fn apply_long_rest(character: &mut Character) {
         .conditions
         .retain(|c| !matches!(c.condition, Condition::Exhaustion(0)));
 
+    // Full HP recovery (up to the maximum still allowed by any remaining exhaustion)
+    character.hit_points.current = character.effective_max_hp();
+    character.hit_points.temporary = 0;
+
+    // Remove Unconscious condition if present (they're now healed)
+    character.remove_condition(Condition::Unconscious);
+    character.life_state = LifeState::Alive;
+
     // Recover half hit dice
     character.hit_dice.recover_half();
 
     // Recover spell slots
     if let Some(ref mut spellcasting) = character.spellcasting {
         spellcasting.spell_slots.recover_all();
+        if let Some(pact) = spellcasting.pact_slots.as_mut() {
+            pact.recover_all();
+        }
     }
 
     // Reset feature uses (both short rest and long rest features)
@@ -115,8 +133,8 @@ mod tests {
     use crate::dice::DieType;
     use crate::world::Ability;
     use crate::world::{
-        AbilityScores, ArmorClass, ArmorType, ClassLevel, Feature, FeatureUses, HitPoints,
-        ProficiencyLevel, Skill, Subclass,
+        AbilityScores, ArmorClass, ArmorType, CharacterClass, ClassLevel, Feature, FeatureUses,
+        HitPoints, ProficiencyLevel, Skill, Subclass,
     };
 
     fn create_test_fighter() -> Character {
@@ -200,6 +218,16 @@ mod tests {
         assert_eq!(character.hit_points.current, character.hit_points.maximum);
     }
 
+    #[test]
+    fn test_long_rest_clears_temp_hp() {
+        let mut character = create_test_fighter();
+        character.hit_points.temporary = 8;
+
+        apply_long_rest(&mut character);
+
+        assert_eq!(character.hit_points.temporary, 0);
+    }
+
     #[test]
     fn test_long_rest_recovers_features() {
         let mut character = create_test_fighter();
@@ -223,4 +251,143 @@ mod tests {
         assert_eq!(character.features[1].uses.as_ref().unwrap().current, 1);
         assert_eq!(character.features[2].uses.as_ref().unwrap().current, 2);
     }
+
+    /// A level 14 character with two levels in each class that has a
+    /// rest-dependent resource, all of it spent.
+    fn create_spent_multiclass() -> Character {
+        let mut character = Character::new("Test Multiclass");
+        character.level = 14;
+        character.hit_points = HitPoints::new(100);
+        character.hit_points.current = 40;
+
+        let classes = [
+            (CharacterClass::Barbarian, DieType::D12),
+            (CharacterClass::Cleric, DieType::D8),
+            (CharacterClass::Druid, DieType::D8),
+            (CharacterClass::Fighter, DieType::D10),
+            (CharacterClass::Monk, DieType::D8),
+            (CharacterClass::Paladin, DieType::D10),
+            (CharacterClass::Sorcerer, DieType::D6),
+        ];
+        for (class, die) in classes {
+            character.classes.push(ClassLevel {
+                class,
+                level: 2,
+                subclass: None,
+            });
+            character.class_resources.initialize_for_class(class, 2);
+            character.hit_dice.add(die, 2);
+            for _ in 0..2 {
+                character.hit_dice.spend(die);
+            }
+        }
+
+        for (name, maximum) in [("Rage", 2), ("Channel Divinity", 2), ("Wild Shape", 2)] {
+            character.features.push(Feature {
+                name: name.to_string(),
+                description: String::new(),
+                source: "Test".to_string(),
+                uses: Some(FeatureUses {
+                    current: 0,
+                    maximum,
+                    recharge: RechargeType::LongRest,
+                }),
+            });
+        }
+
+        let resources = &mut character.class_resources;
+        resources.rage_active = true;
+        resources.ki_points = 0;
+        resources.sorcery_points = 0;
+        resources.lay_on_hands_pool = 0;
+        resources.channel_divinity_used = true;
+        resources.action_surge_used = true;
+        resources.second_wind_used = true;
+        resources.wild_shape_form = Some("Wolf".to_string());
+        resources.wild_shape_hp = Some(11);
+
+        let mut spellcasting = crate::world::SpellcastingData {
+            ability: Ability::Wisdom,
+            spells_known: Vec::new(),
+            spells_prepared: Vec::new(),
+            cantrips_known: Vec::new(),
+            spell_slots: crate::world::SpellSlots::new(),
+            pact_slots: None,
+        };
+        spellcasting.spell_slots.slots[0].total = 4;
+        spellcasting.spell_slots.slots[0].used = 4;
+        spellcasting.spell_slots.slots[1].total = 2;
+        spellcasting.spell_slots.slots[1].used = 1;
+        character.spellcasting = Some(spellcasting);
+
+        character
+    }
+
+    fn feature_uses(character: &Character, name: &str) -> u8 {
+        character
+            .features
+            .iter()
+            .find(|f| f.name == name)
+            .and_then(|f| f.uses.as_ref())
+            .map(|uses| uses.current)
+            .unwrap()
+    }
+
+    #[test]
+    fn test_short_rest_multiclass_resources() {
+        let mut character = create_spent_multiclass();
+
+        apply_short_rest(&mut character);
+
+        let resources = &character.class_resources;
+        assert_eq!(resources.ki_points, 2);
+        assert!(!resources.channel_divinity_used);
+        assert!(!resources.action_surge_used);
+        assert!(!resources.second_wind_used);
+        for name in ["Rage", "Channel Divinity", "Wild Shape"] {
+            assert_eq!(feature_uses(&character, name), 1, "{name}");
+        }
+
+        // Long-rest resources stay spent
+        assert_eq!(resources.sorcery_points, 0);
+        assert_eq!(resources.lay_on_hands_pool, 0);
+        let slots = &character.spellcasting.as_ref().unwrap().spell_slots;
+        assert_eq!(slots.slots[0].available(), 0);
+        assert_eq!(character.hit_dice.remaining.values().sum::<u8>(), 0);
+        assert_eq!(character.hit_points.current, 40);
+    }
+
+    #[test]
+    fn test_long_rest_multiclass_resources() {
+        let mut character = create_spent_multiclass();
+
+        apply_long_rest(&mut character);
+
+        let resources = &character.class_resources;
+        assert_eq!(resources.ki_points, 2);
+        assert_eq!(resources.sorcery_points, 2);
+        assert_eq!(resources.lay_on_hands_pool, 10);
+        assert!(!resources.rage_active);
+        assert!(!resources.channel_divinity_used);
+        assert!(!resources.action_surge_used);
+        assert!(!resources.second_wind_used);
+        assert!(resources.wild_shape_form.is_none());
+        assert!(resources.wild_shape_hp.is_none());
+        for name in ["Rage", "Channel Divinity", "Wild Shape"] {
+            assert_eq!(feature_uses(&character, name), 2, "{name}");
+        }
+
+        let slots = &character.spellcasting.as_ref().unwrap().spell_slots;
+        assert_eq!(slots.slots[0].available(), 4);
+        assert_eq!(slots.slots[1].available(), 2);
+
+        // Half of the 14 hit dice come back, biggest first
+        let remaining = &character.hit_dice.remaining;
+        assert_eq!(remaining.values().sum::<u8>(), 7);
+        assert_eq!(remaining[&DieType::D12], 2);
+        assert_eq!(remaining[&DieType::D10], 4);
+        assert_eq!(remaining[&DieType::D8], 1);
+        assert_eq!(remaining[&DieType::D6], 0);
+        assert_eq!(character.hit_points.current, 100);
+    }
 }
@@ -15,11 +15,18 @@
 //! - [`races`]: Character races
 //! - [`backgrounds`]: Character backgrounds
 //! - [`character`]: Character and NPC types
+//! - [`sheet`]: Portable character sheet export and import
+//! - [`factions`]: Faction reputation
 //! - [`locations`]: Locations and connections
+//! - [`map`]: A drawable graph of known locations
+//! - [`names`]: Forgiving lookup of creatures by name
 //! - [`quests`]: Quests and objectives
 //! - [`combat`]: Combat state and combatants
 //! - [`time`]: In-game time tracking
+//! - [`weather`]: Weather and light conditions
 //! - [`game_world`]: The complete game world state
+//! - [`history`]: Undo/redo snapshots of the world
+//! - [`integrity`]: Checks for references to entities that no longer exist
 
 use serde::{Deserialize, Serialize};
 use std::fmt;
@@ -37,16 +44,23 @@ mod combat;
 mod conditions;
 mod defense;
 mod equipment;
+mod factions;
 mod game_world;
 mod health;
+mod history;
+mod integrity;
 mod locations;
+mod map;
 pub mod mechanics;
+mod names;
 mod quests;
 mod races;
+mod sheet;
 mod skills;
 mod spellcasting;
 mod subclasses;
 mod time;
+mod weather;
 
 // ============================================================================
 // ID Types
@@ -101,26 +115,29 @@ pub use abilities::{Ability, AbilityScores};
 pub use skills::{ProficiencyLevel, Skill};
 
 // Conditions
-pub use conditions::{ActiveCondition, Condition};
+pub use conditions::{ActiveCondition, Condition, OngoingEffect, OngoingKind};
 
 // Health
-pub use health::{DamageResult, DeathSaves, HitDice, HitPoints};
+pub use health::{DamageResult, DeathSaves, HitDice, HitPoints, LifeState, ResistanceLevel};
 
 // Defense
 pub use defense::{ArmorClass, ArmorType, Speed};
 
 // Classes
-pub use classes::{CharacterClass, ClassLevel, ClassResources, Feature, FeatureUses, RechargeType};
+pub use classes::{
+    multiclass_spell_slots, CharacterClass, ClassLevel, ClassResources, Feature, FeatureUses,
+    FightingStyle, RechargeType,
+};
 
 // Subclasses
 pub use subclasses::{Subclass, SubclassFeature};
 
 // Spellcasting
-pub use spellcasting::{SlotInfo, SpellSlots, SpellcastingData};
+pub use spellcasting::{ConcentrationState, PactMagic, SlotInfo, SpellSlots, SpellcastingData};
 
 // Equipment
 pub use equipment::{
-    ArmorItem, ConsumableEffect, ConsumableItem, Equipment, Inventory, Item, ItemType,
+    ArmorItem, ConsumableEffect, ConsumableItem, Encumbrance, Equipment, Inventory, Item, ItemType,
     WeaponDamageType, WeaponItem, WeaponProperty,
 };
 
@@ -131,23 +148,49 @@ pub use races::RaceType;
 pub use backgrounds::Background;
 
 // Character
-pub use character::{Character, Disposition, Race, NPC};
+pub use character::{
+    Character, DialogueTurn, Disposition, Race, MAX_ATTUNED_ITEMS, MAX_DIALOGUE_TURNS, NPC,
+};
+
+// Character sheets
+pub use sheet::SheetError;
+
+// Integrity checks
+pub use integrity::IntegrityWarning;
+
+// Factions
+pub use factions::{clamp_reputation, ReputationTier, MAX_REPUTATION, MIN_REPUTATION};
 
 // Locations
-pub use locations::{Location, LocationConnection, LocationType};
+pub use locations::{opposite_direction, Location, LocationConnection, LocationType};
+
+// Map
+pub use map::{LocationMap, MapEdge, MapNode};
 
 // Quests
-pub use quests::{Quest, QuestObjective, QuestStatus};
+pub use quests::{
+    format_quest_log, ObjectiveState, ObjectiveView, Quest, QuestLogView, QuestObjective,
+    QuestSection, QuestStatus, QuestView,
+};
 
 // Combat
-pub use combat::{CombatState, Combatant};
+pub use combat::{CombatState, Combatant, HealthStatus, LairAction};
 
 // Time
 pub use time::GameTime;
 
+// Weather
+pub use weather::{LightLevel, Weather, WEATHER_EASE_MINUTES};
+
+// Names
+pub use names::{find_by_name, name_matches, NameLookupError};
+
 // Game World
 pub use game_world::{
     create_sample_barbarian, create_sample_bard, create_sample_cleric, create_sample_druid,
     create_sample_fighter, create_sample_monk, create_sample_paladin, create_sample_sorcerer,
     GameMode, GameWorld, NarrativeEntry, NarrativeType,
 };
+
+// History
+pub use history::{WorldHistory, DEFAULT_UNDO_DEPTH};
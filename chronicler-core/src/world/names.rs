@@ -0,0 +1,115 @@
+//! Forgiving name lookup.
+//!
+//! The DM often refers to creatures by a shortened name ("goblin" for
+//! "Goblin Archer"). A query matches a name when it is a case-insensitive
+//! substring of it, or when every word of the query starts a word of the
+//! name ("gob arch"). A single exact match always wins over partial ones.
+
+use thiserror::Error;
+
+/// Why a name lookup didn't find exactly one match.
+#[derive(Debug, Clone, PartialEq, Eq, Error)]
+pub enum NameLookupError {
+    /// Nothing matched the query.
+    #[error("No one named '{query}' was found.")]
+    NotFound { query: String },
+    /// Several names matched the query: more than one exactly, or several
+    /// partially and none exactly.
+    #[error("'{query}' is ambiguous: it could mean {}. Use the full name.", matches.join(", "))]
+    Ambiguous { query: String, matches: Vec<String> },
+}
+
+/// Whether `query` loosely matches `name`.
+pub fn name_matches(query: &str, name: &str) -> bool {
+    let query = query.trim().to_lowercase();
+    let name = name.to_lowercase();
+    if query.is_empty() {
+        return false;
+    }
+    if name.contains(&query) {
+        return true;
+    }
+    let words: Vec<&str> = name.split_whitespace().collect();
+    query
+        .split_whitespace()
+        .all(|token| words.iter().any(|word| word.starts_with(token)))
+}
+
+/// Find the one candidate whose name matches `query`.
+///
+/// An exact (case-insensitive) match is taken even when other names also match
+/// partially. Several exact matches, such as two combatants both called
+/// "Goblin", are ambiguous.
+pub fn find_by_name<'a, T>(
+    query: &str,
+    candidates: impl IntoIterator<Item = (&'a str, T)>,
+) -> Result<T, NameLookupError> {
+    let mut exact = Vec::new();
+    let mut partial = Vec::new();
+    for (name, value) in candidates {
+        if name.eq_ignore_ascii_case(query.trim()) {
+            exact.push((name, value));
+        } else if name_matches(query, name) {
+            partial.push((name, value));
+        }
+    }
+    if !exact.is_empty() {
+        partial = exact;
+    }
+
+    match partial.len() {
+        0 => Err(NameLookupError::NotFound {
+            query: query.to_string(),
+        }),
+        1 => Ok(partial.remove(0).1),
+        _ => Err(NameLookupError::Ambiguous {
+            query: query.to_string(),
+            matches: partial.iter().map(|(name, _)| name.to_string()).collect(),
+        }),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_partial_and_token_matches() {
+        assert!(name_matches("goblin", "Goblin Archer"));
+        assert!(name_matches("archer", "Goblin Archer"));
+        assert!(name_matches("gob arch", "Goblin Archer"));
+        assert!(!name_matches("orc", "Goblin Archer"));
+        assert!(!name_matches("", "Goblin Archer"));
+
+        let names = [("Goblin Archer", 1), ("Orc", 2)];
+        assert_eq!(find_by_name("goblin", names), Ok(1));
+    }
+
+    #[test]
+    fn test_exact_match_beats_partial_matches() {
+        let names = [("Goblin Archer", 1), ("Goblin", 2), ("Goblin Boss", 3)];
+        assert_eq!(find_by_name("goblin", names), Ok(2));
+
+        let err = find_by_name("goblin", [("Goblin Archer", 1), ("Goblin Boss", 3)]).unwrap_err();
+        assert_eq!(
+            err,
+            NameLookupError::Ambiguous {
+                query: "goblin".to_string(),
+                matches: vec!["Goblin Archer".to_string(), "Goblin Boss".to_string()],
+            }
+        );
+        assert!(err.to_string().contains("Goblin Archer, Goblin Boss"));
+    }
+
+    #[test]
+    fn test_several_exact_matches_are_ambiguous() {
+        let names = [("Goblin", 1), ("Goblin", 2), ("Goblin Boss", 3)];
+        assert_eq!(
+            find_by_name("goblin", names),
+            Err(NameLookupError::Ambiguous {
+                query: "goblin".to_string(),
+                matches: vec!["Goblin".to_string(), "Goblin".to_string()],
+            })
+        );
+    }
+}
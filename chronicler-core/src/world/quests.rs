@@ -51,6 +51,110 @@ pub struct QuestObjective {
     pub optional: bool,
 }
 
+/// How an objective should be presented in a quest log.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum ObjectiveState {
+    /// Still to do
+    Active,
+    /// Still to do, but not required
+    Optional,
+    /// Done
+    Completed,
+    /// Never done, and the quest has failed or been abandoned
+    Failed,
+}
+
+/// An objective ready for display.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ObjectiveView {
+    pub description: String,
+    pub state: ObjectiveState,
+}
+
+/// A quest ready for display.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct QuestView {
+    pub name: String,
+    pub description: String,
+    pub giver: Option<String>,
+    pub objectives: Vec<ObjectiveView>,
+    pub rewards: Vec<String>,
+}
+
+/// Quests sharing a status, under a heading.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct QuestSection {
+    pub status: QuestStatus,
+    pub title: &'static str,
+    pub quests: Vec<QuestView>,
+}
+
+/// A quest log grouped by status, for rendering in any front end.
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct QuestLogView {
+    /// Non-empty sections in the order active, completed, failed, abandoned.
+    pub sections: Vec<QuestSection>,
+}
+
+impl QuestLogView {
+    /// Whether there are no quests at all.
+    pub fn is_empty(&self) -> bool {
+        self.sections.is_empty()
+    }
+}
+
+/// Group quests by status and work out how each objective should appear.
+pub fn format_quest_log(quests: &[Quest]) -> QuestLogView {
+    let groups = [
+        (QuestStatus::Active, "Active Quests"),
+        (QuestStatus::Completed, "Completed Quests"),
+        (QuestStatus::Failed, "Failed Quests"),
+        (QuestStatus::Abandoned, "Abandoned Quests"),
+    ];
+
+    let sections = groups
+        .into_iter()
+        .map(|(status, title)| QuestSection {
+            status,
+            title,
+            quests: quests
+                .iter()
+                .filter(|q| q.status == status)
+                .map(quest_view)
+                .collect(),
+        })
+        .filter(|section| !section.quests.is_empty())
+        .collect();
+
+    QuestLogView { sections }
+}
+
+fn quest_view(quest: &Quest) -> QuestView {
+    let ended = matches!(quest.status, QuestStatus::Failed | QuestStatus::Abandoned);
+    QuestView {
+        name: quest.name.clone(),
+        description: quest.description.clone(),
+        giver: quest.giver.clone(),
+        objectives: quest
+            .objectives
+            .iter()
+            .map(|o| ObjectiveView {
+                description: o.description.clone(),
+                state: if o.completed {
+                    ObjectiveState::Completed
+                } else if ended {
+                    ObjectiveState::Failed
+                } else if o.optional {
+                    ObjectiveState::Optional
+                } else {
+                    ObjectiveState::Active
+                },
+            })
+            .collect(),
+        rewards: quest.rewards.clone(),
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -231,4 +335,123 @@ mod tests {
             }
         }
     }
+
+    // ========== Quest Log View Tests ==========
+
+    fn quest_with(status: QuestStatus, objectives: &[(&str, bool, bool)]) -> Quest {
+        let mut quest = Quest::new(format!("{status:?} quest"), "Details");
+        quest.status = status;
+        quest.rewards = vec!["50 gold".to_string()];
+        quest.objectives = objectives
+            .iter()
+            .map(|&(description, completed, optional)| QuestObjective {
+                description: description.to_string(),
+                completed,
+                optional,
+            })
+            .collect();
+        quest
+    }
+
+    fn states(view: &QuestView) -> Vec<ObjectiveState> {
+        view.objectives.iter().map(|o| o.state).collect()
+    }
+
+    #[test]
+    fn test_quest_log_groups_by_status_in_order() {
+        let quests = vec![
+            quest_with(QuestStatus::Failed, &[]),
+            quest_with(QuestStatus::Active, &[]),
+            quest_with(QuestStatus::Abandoned, &[]),
+            quest_with(QuestStatus::Completed, &[]),
+        ];
+
+        let log = format_quest_log(&quests);
+        let statuses: Vec<QuestStatus> = log.sections.iter().map(|s| s.status).collect();
+        assert_eq!(
+            statuses,
+            vec![
+                QuestStatus::Active,
+                QuestStatus::Completed,
+                QuestStatus::Failed,
+                QuestStatus::Abandoned
+            ]
+        );
+        assert_eq!(log.sections[0].title, "Active Quests");
+        assert_eq!(log.sections[0].quests[0].rewards, vec!["50 gold"]);
+    }
+
+    #[test]
+    fn test_quest_log_skips_empty_sections() {
+        assert!(format_quest_log(&[]).is_empty());
+
+        let log = format_quest_log(&[quest_with(QuestStatus::Completed, &[])]);
+        assert_eq!(log.sections.len(), 1);
+        assert_eq!(log.sections[0].status, QuestStatus::Completed);
+    }
+
+    #[test]
+    fn test_active_quest_objective_states() {
+        let quest = quest_with(
+            QuestStatus::Active,
+            &[
+                ("Find the map", true, false),
+                ("Cross the river", false, false),
+                ("Recruit the ranger", false, true),
+                ("Feed the horses", true, true),
+            ],
+        );
+
+        let log = format_quest_log(&[quest]);
+        assert_eq!(
+            states(&log.sections[0].quests[0]),
+            vec![
+                ObjectiveState::Completed,
+                ObjectiveState::Active,
+                ObjectiveState::Optional,
+                ObjectiveState::Completed
+            ]
+        );
+    }
+
+    #[test]
+    fn test_ended_quest_objective_states() {
+        for status in [QuestStatus::Failed, QuestStatus::Abandoned] {
+            let quest = quest_with(
+                status,
+                &[
+                    ("Guard the caravan", true, false),
+                    ("Save the merchant", false, false),
+                    ("Find the cat", false, true),
+                ],
+            );
+
+            let log = format_quest_log(&[quest]);
+            assert_eq!(
+                states(&log.sections[0].quests[0]),
+                vec![
+                    ObjectiveState::Completed,
+                    ObjectiveState::Failed,
+                    ObjectiveState::Failed
+                ]
+            );
+        }
+    }
+
+    #[test]
+    fn test_completed_quest_keeps_skipped_optional_objective() {
+        let quest = quest_with(
+            QuestStatus::Completed,
+            &[
+                ("Slay the wyrm", true, false),
+                ("Take a scale", false, true),
+            ],
+        );
+
+        let log = format_quest_log(&[quest]);
+        assert_eq!(
+            states(&log.sections[0].quests[0]),
+            vec![ObjectiveState::Completed, ObjectiveState::Optional]
+        );
+    }
 }
@@ -131,6 +131,19 @@ impl RaceType {
         }
     }
 
+    /// Whether the race can see in dim light and darkness.
+    pub fn has_darkvision(&self) -> bool {
+        matches!(
+            self,
+            RaceType::Elf
+                | RaceType::Dwarf
+                | RaceType::HalfOrc
+                | RaceType::HalfElf
+                | RaceType::Tiefling
+                | RaceType::Gnome
+        )
+    }
+
     pub fn all() -> &'static [RaceType] {
         &[
             RaceType::Human,
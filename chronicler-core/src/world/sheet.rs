@@ -0,0 +1,307 @@
+//! Portable character sheets.
+//!
+//! A sheet is a self-describing JSON document (or a read-only Markdown
+//! rendering) that players can share or back up outside the save system.
+
+use serde::{Deserialize, Serialize};
+use thiserror::Error;
+
+use super::{Ability, Character, ProficiencyLevel};
+
+/// Format tag written into every exported sheet.
+const SHEET_FORMAT: &str = "chronicler-character-sheet";
+
+/// Current sheet format version.
+const SHEET_VERSION: u32 = 1;
+
+/// Why a character sheet couldn't be imported.
+#[derive(Debug, Error)]
+pub enum SheetError {
+    #[error("Malformed character sheet JSON: {0}")]
+    Json(#[from] serde_json::Error),
+
+    #[error("Not a character sheet (format '{found}', expected '{SHEET_FORMAT}')")]
+    WrongFormat { found: String },
+
+    #[error("Unsupported sheet version {found} (this build reads version {SHEET_VERSION})")]
+    UnsupportedVersion { found: u32 },
+
+    #[error("Invalid character sheet: {0}")]
+    Invalid(String),
+}
+
+/// The on-disk shape of an exported sheet.
+#[derive(Serialize, Deserialize)]
+struct CharacterSheet {
+    format: String,
+    version: u32,
+    character: Character,
+}
+
+impl Character {
+    /// Export the character as a portable JSON sheet.
+    pub fn to_sheet_json(&self) -> String {
+        let sheet = CharacterSheet {
+            format: SHEET_FORMAT.to_string(),
+            version: SHEET_VERSION,
+            character: self.clone(),
+        };
+        serde_json::to_string_pretty(&sheet).expect("characters always serialize")
+    }
+
+    /// Import a character from a JSON sheet produced by [`Character::to_sheet_json`].
+    pub fn from_sheet_json(json: &str) -> Result<Character, SheetError> {
+        let sheet: CharacterSheet = serde_json::from_str(json)?;
+        if sheet.format != SHEET_FORMAT {
+            return Err(SheetError::WrongFormat {
+                found: sheet.format,
+            });
+        }
+        if sheet.version != SHEET_VERSION {
+            return Err(SheetError::UnsupportedVersion {
+                found: sheet.version,
+            });
+        }
+
+        let character = sheet.character;
+        if character.name.trim().is_empty() {
+            return Err(SheetError::Invalid("the character has no name".to_string()));
+        }
+        if !(1..=20).contains(&character.level) {
+            return Err(SheetError::Invalid(format!(
+                "level {} is outside 1-20",
+                character.level
+            )));
+        }
+        for ability in Ability::all() {
+            let score = character.ability_scores.get(ability);
+            if !(1..=30).contains(&score) {
+                return Err(SheetError::Invalid(format!(
+                    "{} score {} is outside 1-30",
+                    ability.name(),
+                    score
+                )));
+            }
+        }
+        if character.hit_points.maximum <= 0 {
+            return Err(SheetError::Invalid(
+                "hit point maximum must be positive".to_string(),
+            ));
+        }
+        Ok(character)
+    }
+
+    /// Render a human-readable Markdown character sheet.
+    pub fn to_markdown(&self) -> String {
+        let mut md = format!("# {}\n\n", self.name);
+
+        let classes: Vec<String> = self
+            .classes
+            .iter()
+            .map(|c| match c.subclass {
+                Some(subclass) => format!("{} ({}) {}", c.class.name(), subclass.name(), c.level),
+                None => format!("{} {}", c.class.name(), c.level),
+            })
+            .collect();
+        md.push_str(&format!(
+            "Level {} {} {} | Background: {}\n\n",
+            self.level,
+            self.race.name,
+            classes.join(" / "),
+            self.background_name
+        ));
+        md.push_str(&format!(
+            "**HP** {}/{} | **AC** {} | **Speed** {} ft | **Proficiency** +{}\n\n",
+            self.hit_points.current,
+            self.hit_points.maximum,
+            self.current_ac(),
+            self.speed.walk,
+            self.proficiency_bonus()
+        ));
+
+        md.push_str("## Abilities\n\n| Ability | Score | Modifier | Save |\n|---|---|---|---|\n");
+        for ability in Ability::all() {
+            let save = self.saving_throw_modifier(ability);
+            let proficient = if self.saving_throw_proficiencies.contains(&ability) {
+                " *"
+            } else {
+                ""
+            };
+            md.push_str(&format!(
+                "| {} | {} | {:+} | {:+}{} |\n",
+                ability.name(),
+                self.ability_scores.get(ability),
+                self.ability_scores.modifier(ability),
+                save,
+                proficient
+            ));
+        }
+
+        md.push_str("\n## Proficiencies\n\n");
+        let mut skills: Vec<String> = self
+            .skill_proficiencies
+            .iter()
+            .filter(|(_, level)| **level != ProficiencyLevel::None)
+            .map(|(skill, level)| {
+                let tag = match level {
+                    ProficiencyLevel::Expertise => " (expertise)",
+                    ProficiencyLevel::Half => " (half)",
+                    _ => "",
+                };
+                format!("{} {:+}{}", skill.name(), self.skill_modifier(*skill), tag)
+            })
+            .collect();
+        skills.sort();
+        push_list(&mut md, "Skills", &skills);
+        let mut tools: Vec<String> = self.tool_proficiencies.iter().cloned().collect();
+        tools.sort();
+        push_list(&mut md, "Tools", &tools);
+        push_list(&mut md, "Languages", &self.languages);
+
+        md.push_str("\n## Equipment\n\n");
+        let equipped = [
+            ("Armor", self.equipment.armor.as_ref().map(|a| &a.base.name)),
+            ("Shield", self.equipment.shield.as_ref().map(|s| &s.name)),
+            (
+                "Main hand",
+                self.equipment.main_hand.as_ref().map(|w| &w.base.name),
+            ),
+            (
+                "Off hand",
+                self.equipment.off_hand.as_ref().map(|i| &i.name),
+            ),
+        ];
+        for (slot, name) in equipped {
+            if let Some(name) = name {
+                md.push_str(&format!("- {}: {}\n", slot, name));
+            }
+        }
+        for item in &self.inventory.items {
+            if item.quantity > 1 {
+                md.push_str(&format!("- {} x{}\n", item.name, item.quantity));
+            } else {
+                md.push_str(&format!("- {}\n", item.name));
+            }
+        }
+        md.push_str(&format!(
+            "- {} gp, {} sp\n",
+            self.inventory.gold, self.inventory.silver
+        ));
+
+        if let Some(ref spellcasting) = self.spellcasting {
+            md.push_str("\n## Spells\n\n");
+            md.push_str(&format!(
+                "Spellcasting ability: {} | Save DC {} | Attack {:+}\n\n",
+                spellcasting.ability.name(),
+                spellcasting.spell_save_dc(&self.ability_scores, self.proficiency_bonus()),
+                spellcasting.spell_attack_bonus(&self.ability_scores, self.proficiency_bonus())
+            ));
+            push_list(&mut md, "Cantrips", &spellcasting.cantrips_known);
+            push_list(&mut md, "Known", &spellcasting.spells_known);
+            push_list(&mut md, "Prepared", &spellcasting.spells_prepared);
+            let slots: Vec<String> = (1..=9)
+                .filter(|level| spellcasting.spell_slots.slots[*level as usize - 1].total > 0)
+                .map(|level| {
+                    format!(
+                        "{}: {}",
+                        level,
+                        spellcasting.spell_slots.slots[level as usize - 1].available()
+                    )
+                })
+                .collect();
+            push_list(&mut md, "Slots available", &slots);
+        }
+
+        if !self.features.is_empty() {
+            md.push_str("\n## Features\n\n");
+            for feature in &self.features {
+                md.push_str(&format!(
+                    "- **{}**: {}\n",
+                    feature.name, feature.description
+                ));
+            }
+        }
+
+        if let Some(ref backstory) = self.backstory {
+            md.push_str(&format!("\n## Backstory\n\n{}\n", backstory));
+        }
+
+        md
+    }
+}
+
+/// Append a "- **Label**: a, b, c" line, skipping empty lists.
+fn push_list(md: &mut String, label: &str, entries: &[String]) {
+    if !entries.is_empty() {
+        md.push_str(&format!("- **{}**: {}\n", label, entries.join(", ")));
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::world::create_sample_fighter;
+
+    #[test]
+    fn test_sheet_round_trip() {
+        let character = create_sample_fighter("Roland");
+        let imported = Character::from_sheet_json(&character.to_sheet_json()).unwrap();
+
+        assert_eq!(imported.id, character.id);
+        assert_eq!(imported.name, character.name);
+        assert_eq!(imported.level, character.level);
+        for ability in Ability::all() {
+            assert_eq!(
+                imported.ability_scores.get(ability),
+                character.ability_scores.get(ability)
+            );
+        }
+        assert_eq!(imported.hit_points.maximum, character.hit_points.maximum);
+        assert_eq!(imported.skill_proficiencies, character.skill_proficiencies);
+        assert_eq!(
+            imported.saving_throw_proficiencies,
+            character.saving_throw_proficiencies
+        );
+        assert_eq!(imported.current_ac(), character.current_ac());
+        assert_eq!(
+            imported.inventory.items.len(),
+            character.inventory.items.len()
+        );
+        assert_eq!(imported.features.len(), character.features.len());
+        assert_eq!(imported.to_markdown(), character.to_markdown());
+    }
+
+    #[test]
+    fn test_sheet_rejects_malformed_json() {
+        let err = Character::from_sheet_json("{ not json").unwrap_err();
+        assert!(matches!(err, SheetError::Json(_)));
+        assert!(err
+            .to_string()
+            .starts_with("Malformed character sheet JSON"));
+    }
+
+    #[test]
+    fn test_sheet_rejects_wrong_format_and_invalid_scores() {
+        let character = create_sample_fighter("Roland");
+        let mut sheet: serde_json::Value =
+            serde_json::from_str(&character.to_sheet_json()).unwrap();
+
+        sheet["format"] = "something-else".into();
+        let err = Character::from_sheet_json(&sheet.to_string()).unwrap_err();
+        assert!(matches!(err, SheetError::WrongFormat { .. }));
+
+        sheet["format"] = SHEET_FORMAT.into();
+        sheet["character"]["ability_scores"]["strength"] = 0.into();
+        let err = Character::from_sheet_json(&sheet.to_string()).unwrap_err();
+        assert!(err.to_string().contains("Strength score 0"));
+    }
+
+    #[test]
+    fn test_markdown_lists_sections() {
+        let md = create_sample_fighter("Roland").to_markdown();
+        assert!(md.starts_with("# Roland\n"));
+        assert!(md.contains("## Abilities"));
+        assert!(md.contains("## Equipment"));
+        assert!(md.contains("## Features"));
+    }
+}
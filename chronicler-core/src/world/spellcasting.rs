@@ -15,9 +15,52 @@ pub struct SpellcastingData {
     pub spells_prepared: Vec<String>,
     pub cantrips_known: Vec<String>,
     pub spell_slots: SpellSlots,
+    /// Warlock Pact Magic slots, tracked apart from regular slots
+    #[serde(default)]
+    pub pact_slots: Option<PactMagic>,
 }
 
 impl SpellcastingData {
+    /// Slots of the given level available to cast with, counting pact slots.
+    pub fn available_slots(&self, level: u8) -> u8 {
+        let regular = if (1..=9).contains(&level) {
+            self.spell_slots.slots[level as usize - 1].available()
+        } else {
+            0
+        };
+        let pact = self
+            .pact_slots
+            .as_ref()
+            .filter(|pact| pact.slot_level == level)
+            .map_or(0, |pact| pact.available());
+        regular + pact
+    }
+
+    /// Spend a slot of the given level, preferring a pact slot.
+    pub fn use_slot(&mut self, level: u8) -> bool {
+        if let Some(pact) = self.pact_slots.as_mut() {
+            if pact.slot_level == level && pact.available() > 0 {
+                pact.used += 1;
+                return true;
+            }
+        }
+        self.spell_slots.use_slot(level)
+    }
+
+    /// Regain one expended slot of the given level, preferring a pact slot.
+    pub fn restore_slot(&mut self, level: u8) {
+        if let Some(pact) = self.pact_slots.as_mut() {
+            if pact.slot_level == level && pact.used > 0 {
+                pact.used -= 1;
+                return;
+            }
+        }
+        if (1..=9).contains(&level) {
+            let slot = &mut self.spell_slots.slots[level as usize - 1];
+            slot.used = slot.used.saturating_sub(1);
+        }
+    }
+
     pub fn spell_save_dc(&self, ability_scores: &AbilityScores, proficiency: i8) -> u8 {
         let ability_mod = ability_scores.modifier(self.ability);
         (8 + proficiency + ability_mod).max(0) as u8
@@ -77,6 +120,52 @@ impl SlotInfo {
     }
 }
 
+/// Warlock Pact Magic: a few slots, all of the same level, that recover on a short rest.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+pub struct PactMagic {
+    pub slot_level: u8,
+    pub total: u8,
+    pub used: u8,
+}
+
+impl PactMagic {
+    /// Pact Magic slots for a Warlock of the given level (D&D 5e SRD).
+    pub fn for_warlock_level(level: u8) -> Option<Self> {
+        let (total, slot_level) = match level {
+            0 => return None,
+            1 => (1, 1),
+            2 => (2, 1),
+            3..=4 => (2, 2),
+            5..=6 => (2, 3),
+            7..=8 => (2, 4),
+            9..=10 => (2, 5),
+            11..=16 => (3, 5),
+            _ => (4, 5),
+        };
+        Some(Self {
+            slot_level,
+            total,
+            used: 0,
+        })
+    }
+
+    pub fn available(&self) -> u8 {
+        self.total.saturating_sub(self.used)
+    }
+
+    pub fn recover_all(&mut self) {
+        self.used = 0;
+    }
+}
+
+/// A spell the character is currently concentrating on.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct ConcentrationState {
+    pub spell_name: String,
+    /// Slot level the spell was cast at (0 for cantrips)
+    pub slot_level: u8,
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -213,6 +302,7 @@ mod tests {
             spells_prepared: vec!["Fireball".to_string()],
             cantrips_known: vec!["Fire Bolt".to_string()],
             spell_slots: SpellSlots::new(),
+            pact_slots: None,
         };
 
         let scores = AbilityScores::new(10, 10, 10, 18, 10, 10); // INT 18 (+4)
@@ -230,6 +320,7 @@ mod tests {
             spells_prepared: vec!["Guiding Bolt".to_string()],
             cantrips_known: vec!["Sacred Flame".to_string()],
             spell_slots: SpellSlots::new(),
+            pact_slots: None,
         };
 
         let scores = AbilityScores::new(10, 10, 10, 10, 16, 10); // WIS 16 (+3)
@@ -247,6 +338,7 @@ mod tests {
             spells_prepared: vec![],
             cantrips_known: vec![],
             spell_slots: SpellSlots::new(),
+            pact_slots: None,
         };
 
         // Extremely low stats (shouldn't happen in practice but test bounds)
@@ -265,6 +357,7 @@ mod tests {
             spells_prepared: vec![],
             cantrips_known: vec![],
             spell_slots: SpellSlots::new(),
+            pact_slots: None,
         };
 
         let scores = AbilityScores::new(10, 10, 10, 16, 10, 10); // INT 16 (+3)
@@ -282,6 +375,7 @@ mod tests {
             spells_prepared: vec![],
             cantrips_known: vec![],
             spell_slots: SpellSlots::new(),
+            pact_slots: None,
         };
 
         let scores = AbilityScores::new(10, 10, 10, 10, 20, 10); // WIS 20 (+5)
@@ -299,6 +393,7 @@ mod tests {
             spells_prepared: vec![],
             cantrips_known: vec![],
             spell_slots: SpellSlots::new(),
+            pact_slots: None,
         };
 
         let scores = AbilityScores::new(10, 10, 10, 10, 10, 8); // CHA 8 (-1)
@@ -0,0 +1,118 @@
+//! Weather and light conditions.
+
+use serde::{Deserialize, Serialize};
+
+/// Minutes of game time before the weather eases by one step.
+pub const WEATHER_EASE_MINUTES: u32 = 8 * 60;
+
+/// The current weather.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default, Serialize, Deserialize)]
+pub enum Weather {
+    #[default]
+    Clear,
+    Overcast,
+    Rain,
+    HeavyRain,
+    Fog,
+    Snow,
+    Storm,
+}
+
+impl Weather {
+    pub fn name(&self) -> &'static str {
+        match self {
+            Weather::Clear => "clear",
+            Weather::Overcast => "overcast",
+            Weather::Rain => "rain",
+            Weather::HeavyRain => "heavy rain",
+            Weather::Fog => "fog",
+            Weather::Snow => "snow",
+            Weather::Storm => "storm",
+        }
+    }
+
+    /// Parse a weather name, case-insensitively.
+    pub fn from_name(name: &str) -> Option<Self> {
+        match name.trim().to_lowercase().replace(['_', '-'], " ").as_str() {
+            "clear" | "sunny" => Some(Weather::Clear),
+            "overcast" | "cloudy" => Some(Weather::Overcast),
+            "rain" => Some(Weather::Rain),
+            "heavy rain" => Some(Weather::HeavyRain),
+            "fog" | "mist" => Some(Weather::Fog),
+            "snow" => Some(Weather::Snow),
+            "storm" | "thunderstorm" | "blizzard" => Some(Weather::Storm),
+            _ => None,
+        }
+    }
+
+    /// Whether the weather obscures vision enough to hamper Perception.
+    pub fn obscures_vision(&self) -> bool {
+        matches!(
+            self,
+            Weather::HeavyRain | Weather::Fog | Weather::Snow | Weather::Storm
+        )
+    }
+
+    /// The weather one step calmer, as a front passes.
+    pub fn eased(&self) -> Self {
+        match self {
+            Weather::Storm => Weather::HeavyRain,
+            Weather::HeavyRain => Weather::Rain,
+            Weather::Rain | Weather::Snow => Weather::Overcast,
+            Weather::Overcast | Weather::Fog | Weather::Clear => Weather::Clear,
+        }
+    }
+}
+
+/// How well lit an outdoor area is.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum LightLevel {
+    Bright,
+    Dim,
+    Darkness,
+}
+
+impl LightLevel {
+    /// Natural light at an hour of the day: dim around dawn and dusk.
+    pub fn at_hour(hour: u8) -> Self {
+        match hour {
+            6..=17 => LightLevel::Bright,
+            5 | 18 | 19 => LightLevel::Dim,
+            _ => LightLevel::Darkness,
+        }
+    }
+
+    pub fn name(&self) -> &'static str {
+        match self {
+            LightLevel::Bright => "bright light",
+            LightLevel::Dim => "dim light",
+            LightLevel::Darkness => "darkness",
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_weather_eases_to_clear() {
+        let mut weather = Weather::Storm;
+        let mut steps = 0;
+        while weather != Weather::Clear {
+            weather = weather.eased();
+            steps += 1;
+        }
+        assert_eq!(steps, 4);
+        assert_eq!(Weather::from_name("Heavy_Rain"), Some(Weather::HeavyRain));
+        assert_eq!(Weather::from_name("sleet"), None);
+    }
+
+    #[test]
+    fn test_light_follows_the_sun() {
+        assert_eq!(LightLevel::at_hour(12), LightLevel::Bright);
+        assert_eq!(LightLevel::at_hour(19), LightLevel::Dim);
+        assert_eq!(LightLevel::at_hour(23), LightLevel::Darkness);
+        assert_eq!(LightLevel::at_hour(3), LightLevel::Darkness);
+    }
+}
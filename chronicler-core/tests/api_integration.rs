@@ -67,6 +67,7 @@ fn create_sample_wizard(name: &str) -> Character {
                 SlotInfo { total: 0, used: 0 },
             ],
         },
+        pact_slots: None,
     });
 
     // Set hit points
@@ -127,6 +128,7 @@ fn create_sample_cleric(name: &str) -> Character {
                 SlotInfo { total: 0, used: 0 },
             ],
         },
+        pact_slots: None,
     });
 
     // Set hit points
@@ -0,0 +1,134 @@
+//! In-memory caching of deterministic completions.
+
+use std::collections::hash_map::DefaultHasher;
+use std::collections::{HashMap, VecDeque};
+use std::hash::{Hash, Hasher};
+
+use crate::api_types::ApiRequest;
+use crate::types::{Request, Response};
+
+/// A least-recently-used cache of responses keyed by request hash.
+#[derive(Debug)]
+pub(crate) struct ResponseCache {
+    capacity: usize,
+    entries: HashMap<u64, Response>,
+    /// Keys from least to most recently used.
+    order: VecDeque<u64>,
+}
+
+impl ResponseCache {
+    pub(crate) fn new(capacity: usize) -> Self {
+        Self {
+            capacity,
+            entries: HashMap::new(),
+            order: VecDeque::new(),
+        }
+    }
+
+    pub(crate) fn get(&mut self, key: u64) -> Option<Response> {
+        let response = self.entries.get(&key)?.clone();
+        self.touch(key);
+        Some(response)
+    }
+
+    pub(crate) fn insert(&mut self, key: u64, response: Response) {
+        if self.capacity == 0 {
+            return;
+        }
+        if self.entries.insert(key, response).is_some() {
+            self.touch(key);
+            return;
+        }
+        self.order.push_back(key);
+        while self.order.len() > self.capacity {
+            if let Some(oldest) = self.order.pop_front() {
+                self.entries.remove(&oldest);
+            }
+        }
+    }
+
+    fn touch(&mut self, key: u64) {
+        if let Some(pos) = self.order.iter().position(|k| *k == key) {
+            self.order.remove(pos);
+        }
+        self.order.push_back(key);
+    }
+}
+
+/// The cache key for a request, or `None` if its response must not be cached.
+///
+/// Only zero-temperature requests are deterministic enough to reuse. The key
+/// covers everything sent to the API: model, system prompt, messages and tools.
+pub(crate) fn cache_key(request: &Request, api_request: &ApiRequest) -> Option<u64> {
+    if request.bypass_cache || request.temperature != Some(0.0) || api_request.stream {
+        return None;
+    }
+    let body = serde_json::to_string(api_request).ok()?;
+    let mut hasher = DefaultHasher::new();
+    body.hash(&mut hasher);
+    Some(hasher.finish())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{Claude, Message, StopReason, Usage};
+
+    fn response(text: &str) -> Response {
+        Response {
+            id: text.to_string(),
+            model: "test".to_string(),
+            content: vec![crate::ContentBlock::Text {
+                text: text.to_string(),
+            }],
+            stop_reason: StopReason::EndTurn,
+            usage: Usage {
+                input_tokens: 0,
+                output_tokens: 0,
+            },
+        }
+    }
+
+    fn key(request: &Request) -> Option<u64> {
+        let client = Claude::new("test-key");
+        cache_key(request, &client.build_api_request(request, false))
+    }
+
+    #[test]
+    fn test_only_deterministic_requests_are_cacheable() {
+        let request = Request::new(vec![Message::user("Classify this")]).with_temperature(0.0);
+        assert!(key(&request).is_some());
+        assert_eq!(key(&request), key(&request.clone()));
+
+        let changed = Request::new(vec![Message::user("Classify that")]).with_temperature(0.0);
+        assert_ne!(key(&request), key(&changed));
+
+        assert!(key(&request.clone().with_temperature(0.7)).is_none());
+        assert!(key(&Request::new(vec![Message::user("Classify this")])).is_none());
+        assert!(key(&request.clone().with_cache_bypass()).is_none());
+
+        let client = Claude::new("test-key");
+        assert!(cache_key(&request, &client.build_api_request(&request, true)).is_none());
+    }
+
+    #[test]
+    fn test_cache_hits_and_evicts_least_recently_used() {
+        let mut cache = ResponseCache::new(2);
+        assert!(cache.get(1).is_none());
+
+        cache.insert(1, response("one"));
+        cache.insert(2, response("two"));
+        assert_eq!(cache.get(1).unwrap().text(), "one");
+
+        // 2 is now the least recently used, so it is evicted.
+        cache.insert(3, response("three"));
+        assert_eq!(cache.entries.len(), 2);
+        assert!(cache.get(2).is_none());
+        assert_eq!(cache.get(1).unwrap().text(), "one");
+        assert_eq!(cache.get(3).unwrap().text(), "three");
+
+        let mut disabled = ResponseCache::new(0);
+        disabled.insert(1, response("one"));
+        assert!(disabled.get(1).is_none());
+    }
+}
@@ -1,12 +1,16 @@
 //! Claude API client implementation.
 
 use futures::StreamExt;
-use reqwest::header::{HeaderMap, HeaderValue, CONTENT_TYPE};
+use reqwest::header::{HeaderMap, HeaderValue, CONTENT_TYPE, RETRY_AFTER};
 use std::pin::Pin;
+use std::sync::{Arc, Mutex};
+use std::time::Duration;
 use tokio_stream::Stream;
 
 use crate::api_types::{ApiContent, ApiMessage, ApiRequest, ApiResponse, ApiTool, ApiToolChoice};
+use crate::cache::{cache_key, ResponseCache};
 use crate::error::Error;
+use crate::retry::RetryPolicy;
 use crate::streaming::parse_sse_events_buffered;
 use crate::types::{
     ContentBlock, Message, Request, Response, Role, StopReason, StreamEvent, ToolChoice,
@@ -42,6 +46,8 @@ pub struct Claude {
     client: reqwest::Client,
     api_key: String,
     pub(crate) model: String,
+    retry_policy: RetryPolicy,
+    cache: Option<Arc<Mutex<ResponseCache>>>,
 }
 
 impl Claude {
@@ -55,12 +61,14 @@ impl Claude {
     pub fn new(api_key: impl Into<String>) -> Self {
         Self {
             client: reqwest::Client::builder()
-                .timeout(std::time::Duration::from_secs(120))
-                .connect_timeout(std::time::Duration::from_secs(30))
+                .timeout(Duration::from_secs(120))
+                .connect_timeout(Duration::from_secs(30))
                 .build()
                 .expect("Failed to build HTTP client"),
             api_key: api_key.into(),
             model: DEFAULT_MODEL.to_string(),
+            retry_policy: RetryPolicy::default(),
+            cache: None,
         }
     }
 
@@ -84,6 +92,25 @@ impl Claude {
         self
     }
 
+    /// Sets how transient failures (rate limits, server errors, network
+    /// errors) are retried. Use [`RetryPolicy::none`] to disable retries.
+    pub fn with_retry_policy(mut self, policy: RetryPolicy) -> Self {
+        self.retry_policy = policy;
+        self
+    }
+
+    /// Caches up to `capacity` responses to zero-temperature requests.
+    ///
+    /// Identical deterministic requests (same model, system prompt, messages
+    /// and tools) are then answered from memory. Streaming and nonzero
+    /// temperature requests are never cached, and a single request can skip
+    /// the cache with [`Request::with_cache_bypass`]. Clones of the client
+    /// share the cache.
+    pub fn with_response_cache(mut self, capacity: usize) -> Self {
+        self.cache = Some(Arc::new(Mutex::new(ResponseCache::new(capacity))));
+        self
+    }
+
     /// Sends a completion request and returns the full response.
     ///
     /// This is the primary method for non-streaming interactions with Claude.
@@ -94,32 +121,30 @@ impl Claude {
     /// Returns an error if the network request fails or the API returns an error.
     pub async fn complete(&self, request: Request) -> Result<Response, Error> {
         let api_request = self.build_api_request(&request, false);
-        let headers = self.build_headers()?;
-
-        let response = self
-            .client
-            .post(format!("{API_BASE}/messages"))
-            .headers(headers)
-            .json(&api_request)
-            .send()
-            .await
-            .map_err(|e| Error::Network(e.to_string()))?;
-
-        if !response.status().is_success() {
-            let status = response.status().as_u16();
-            let body = response.text().await.unwrap_or_default();
-            return Err(Error::Api {
-                status,
-                message: body,
-            });
+        let cached = self
+            .cache
+            .as_ref()
+            .and_then(|cache| Some((cache, cache_key(&request, &api_request)?)));
+        if let Some((cache, key)) = cached {
+            if let Some(response) = cache.lock().ok().and_then(|mut c| c.get(key)) {
+                return Ok(response);
+            }
         }
 
+        let response = self.send(&api_request).await?;
+
         let api_response: ApiResponse = response
             .json()
             .await
             .map_err(|e| Error::Parse(e.to_string()))?;
 
-        Ok(self.parse_response(api_response))
+        let response = self.parse_response(api_response);
+        if let Some((cache, key)) = cached {
+            if let Ok(mut cache) = cache.lock() {
+                cache.insert(key, response.clone());
+            }
+        }
+        Ok(response)
     }
 
     /// Sends a completion request and returns a stream of response events.
@@ -131,25 +156,7 @@ impl Claude {
         request: Request,
     ) -> Result<Pin<Box<dyn Stream<Item = Result<StreamEvent, Error>> + Send>>, Error> {
         let api_request = self.build_api_request(&request, true);
-        let headers = self.build_headers()?;
-
-        let response = self
-            .client
-            .post(format!("{API_BASE}/messages"))
-            .headers(headers)
-            .json(&api_request)
-            .send()
-            .await
-            .map_err(|e| Error::Network(e.to_string()))?;
-
-        if !response.status().is_success() {
-            let status = response.status().as_u16();
-            let body = response.text().await.unwrap_or_default();
-            return Err(Error::Api {
-                status,
-                message: body,
-            });
-        }
+        let response = self.send(&api_request).await?;
 
         // Use scan to maintain a buffer for incomplete SSE events across chunks
         let stream = response
@@ -238,6 +245,40 @@ impl Claude {
         }
     }
 
+    /// Post a request to the Messages API, retrying transient failures.
+    async fn send(&self, api_request: &ApiRequest) -> Result<reqwest::Response, Error> {
+        let headers = self.build_headers()?;
+        self.retry_policy
+            .run(|| async {
+                let response = self
+                    .client
+                    .post(format!("{API_BASE}/messages"))
+                    .headers(headers.clone())
+                    .json(api_request)
+                    .send()
+                    .await
+                    .map_err(|e| Error::Network(e.to_string()))?;
+
+                if !response.status().is_success() {
+                    let status = response.status().as_u16();
+                    let retry_after = response
+                        .headers()
+                        .get(RETRY_AFTER)
+                        .and_then(|v| v.to_str().ok())
+                        .and_then(|v| v.trim().parse::<u64>().ok())
+                        .map(Duration::from_secs);
+                    let body = response.text().await.unwrap_or_default();
+                    return Err(Error::Api {
+                        status,
+                        message: body,
+                        retry_after,
+                    });
+                }
+                Ok(response)
+            })
+            .await
+    }
+
     fn build_headers(&self) -> Result<HeaderMap, Error> {
         let mut headers = HeaderMap::new();
         headers.insert(CONTENT_TYPE, HeaderValue::from_static("application/json"));
@@ -250,7 +291,7 @@ impl Claude {
         Ok(headers)
     }
 
-    fn build_api_request(&self, request: &Request, stream: bool) -> ApiRequest {
+    pub(crate) fn build_api_request(&self, request: &Request, stream: bool) -> ApiRequest {
         let messages: Vec<ApiMessage> = request
             .messages
             .iter()
@@ -1,5 +1,6 @@
 //! Error types for the Claude API client.
 
+use std::time::Duration;
 use thiserror::Error;
 
 /// Errors that can occur when using the Claude client.
@@ -12,7 +13,12 @@ pub enum Error {
     Network(String),
 
     #[error("API error (status {status}): {message}")]
-    Api { status: u16, message: String },
+    Api {
+        status: u16,
+        message: String,
+        /// How long the API asked us to wait before retrying, if it said.
+        retry_after: Option<Duration>,
+    },
 
     #[error("Failed to parse response: {0}")]
     Parse(String),
@@ -20,3 +26,23 @@ pub enum Error {
     #[error("Invalid configuration: {0}")]
     Config(String),
 }
+
+impl Error {
+    /// Whether the request may succeed if sent again: rate limits, server
+    /// errors and network failures.
+    pub fn is_retryable(&self) -> bool {
+        match self {
+            Error::Network(_) => true,
+            Error::Api { status, .. } => *status == 408 || *status == 429 || *status >= 500,
+            _ => false,
+        }
+    }
+
+    /// The wait the API asked for before retrying, if any.
+    pub fn retry_after(&self) -> Option<Duration> {
+        match self {
+            Error::Api { retry_after, .. } => *retry_after,
+            _ => None,
+        }
+    }
+}
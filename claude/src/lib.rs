@@ -4,15 +4,22 @@
 //! - Non-streaming and streaming completions
 //! - Tool use support
 //! - Proper SSE parsing for streaming responses
+//! - Retries with exponential backoff for transient failures
+//! - Optional caching of deterministic (zero-temperature) completions
 
 mod api_types;
+mod cache;
 mod client;
 mod error;
+mod retry;
 mod streaming;
+mod tokens;
 mod types;
 
 pub use client::Claude;
 pub use error::Error;
+pub use retry::RetryPolicy;
+pub use tokens::{CharEstimate, TokenCounter};
 pub use types::{
     ContentBlock, Message, Request, Response, Role, StopReason, StreamEvent, Tool, ToolChoice,
     ToolResult, ToolUse, Usage,
@@ -0,0 +1,184 @@
+//! Retrying transient API failures with exponential backoff.
+
+use std::collections::hash_map::RandomState;
+use std::future::Future;
+use std::hash::{BuildHasher, Hasher};
+use std::time::Duration;
+
+use crate::error::Error;
+
+/// How the client retries requests that fail with transient errors.
+///
+/// Rate limits (429), server errors (5xx) and network failures are retried;
+/// everything else (bad requests, authentication) fails immediately. Delays
+/// double on each attempt up to `max_delay`, and a `retry-after` header from
+/// the API takes precedence when present.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct RetryPolicy {
+    /// Maximum number of retries after the first attempt.
+    pub max_retries: u32,
+    /// Delay before the first retry.
+    pub base_delay: Duration,
+    /// Upper bound on any single delay.
+    pub max_delay: Duration,
+    /// Randomize each delay between half and all of its value, so many
+    /// clients don't retry in lockstep.
+    pub jitter: bool,
+}
+
+impl Default for RetryPolicy {
+    fn default() -> Self {
+        Self {
+            max_retries: 3,
+            base_delay: Duration::from_millis(500),
+            max_delay: Duration::from_secs(30),
+            jitter: true,
+        }
+    }
+}
+
+impl RetryPolicy {
+    /// A policy that never retries.
+    pub fn none() -> Self {
+        Self {
+            max_retries: 0,
+            ..Self::default()
+        }
+    }
+
+    /// The delay before retry number `attempt` (starting at 0).
+    pub fn delay_for(&self, attempt: u32, retry_after: Option<Duration>) -> Duration {
+        if let Some(hint) = retry_after {
+            return hint.min(self.max_delay);
+        }
+        let factor = 2u32.saturating_pow(attempt);
+        let delay = self.base_delay.saturating_mul(factor).min(self.max_delay);
+        if self.jitter {
+            let random = RandomState::new().build_hasher().finish();
+            let fraction = 0.5 + (random % 1000) as f64 / 2000.0;
+            delay.mul_f64(fraction)
+        } else {
+            delay
+        }
+    }
+
+    /// Run an operation, retrying it on retryable errors.
+    pub async fn run<T, F, Fut>(&self, mut operation: F) -> Result<T, Error>
+    where
+        F: FnMut() -> Fut,
+        Fut: Future<Output = Result<T, Error>>,
+    {
+        let mut attempt = 0;
+        loop {
+            match operation().await {
+                Err(e) if e.is_retryable() && attempt < self.max_retries => {
+                    tokio::time::sleep(self.delay_for(attempt, e.retry_after())).await;
+                    attempt += 1;
+                }
+                result => return result,
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::cell::Cell;
+
+    fn fast_policy(max_retries: u32) -> RetryPolicy {
+        RetryPolicy {
+            max_retries,
+            base_delay: Duration::from_millis(1),
+            max_delay: Duration::from_millis(5),
+            jitter: false,
+        }
+    }
+
+    fn overloaded() -> Error {
+        Error::Api {
+            status: 529,
+            message: "overloaded".to_string(),
+            retry_after: None,
+        }
+    }
+
+    #[tokio::test]
+    async fn test_retries_until_success() {
+        let calls = Cell::new(0);
+        let result = fast_policy(3)
+            .run(|| {
+                calls.set(calls.get() + 1);
+                let n = calls.get();
+                async move {
+                    if n <= 2 {
+                        Err(overloaded())
+                    } else {
+                        Ok("done")
+                    }
+                }
+            })
+            .await;
+
+        assert_eq!(result.unwrap(), "done");
+        assert_eq!(calls.get(), 3);
+    }
+
+    #[tokio::test]
+    async fn test_gives_up_after_max_retries() {
+        let calls = Cell::new(0);
+        let result: Result<(), Error> = fast_policy(2)
+            .run(|| {
+                calls.set(calls.get() + 1);
+                async { Err(Error::Network("connection reset".to_string())) }
+            })
+            .await;
+
+        assert!(matches!(result, Err(Error::Network(_))));
+        assert_eq!(calls.get(), 3);
+    }
+
+    #[tokio::test]
+    async fn test_non_retryable_errors_fail_fast() {
+        let calls = Cell::new(0);
+        let result: Result<(), Error> = fast_policy(3)
+            .run(|| {
+                calls.set(calls.get() + 1);
+                async {
+                    Err(Error::Api {
+                        status: 401,
+                        message: "invalid x-api-key".to_string(),
+                        retry_after: None,
+                    })
+                }
+            })
+            .await;
+
+        assert!(result.is_err());
+        assert_eq!(calls.get(), 1);
+    }
+
+    #[test]
+    fn test_delay_backs_off_and_honors_retry_after() {
+        let policy = RetryPolicy {
+            max_retries: 5,
+            base_delay: Duration::from_millis(100),
+            max_delay: Duration::from_secs(1),
+            jitter: false,
+        };
+        assert_eq!(policy.delay_for(0, None), Duration::from_millis(100));
+        assert_eq!(policy.delay_for(2, None), Duration::from_millis(400));
+        assert_eq!(policy.delay_for(10, None), Duration::from_secs(1));
+        assert_eq!(
+            policy.delay_for(0, Some(Duration::from_millis(750))),
+            Duration::from_millis(750)
+        );
+
+        let jittered = RetryPolicy {
+            jitter: true,
+            ..policy
+        };
+        let delay = jittered.delay_for(1, None);
+        assert!(delay >= Duration::from_millis(100) && delay <= Duration::from_millis(200));
+    }
+}
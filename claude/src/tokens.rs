@@ -0,0 +1,66 @@
+//! Approximate token counting for keeping requests within a context budget.
+
+/// Counts how many tokens a piece of text will use.
+pub trait TokenCounter {
+    /// The number of tokens in `text`.
+    fn count(&self, text: &str) -> usize;
+}
+
+/// Estimates tokens as one per four characters, rounded up.
+///
+/// This is close enough for English prose to keep requests under budget
+/// without calling the API's token counting endpoint.
+#[derive(Debug, Clone, Copy, Default)]
+pub struct CharEstimate;
+
+impl TokenCounter for CharEstimate {
+    fn count(&self, text: &str) -> usize {
+        text.chars().count().div_ceil(4)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{ContentBlock, Message, Request, Role, Tool};
+    use serde_json::json;
+
+    #[test]
+    fn test_char_estimate_is_monotonic() {
+        let mut previous = 0;
+        for len in 0..200 {
+            let count = CharEstimate.count(&"x".repeat(len));
+            assert!(count >= previous);
+            previous = count;
+        }
+        assert_eq!(CharEstimate.count(""), 0);
+        assert_eq!(CharEstimate.count("abcd"), 1);
+        assert_eq!(CharEstimate.count("abcde"), 2);
+    }
+
+    #[test]
+    fn test_request_estimate_counts_everything() {
+        let bare = Request::new(vec![Message::user("Hello there")]);
+        let with_system = bare.clone().with_system("You are a dungeon master.");
+        let with_tools = with_system.clone().with_tools(vec![Tool {
+            name: "roll_dice".to_string(),
+            description: "Roll some dice".to_string(),
+            input_schema: json!({"type": "object"}),
+        }]);
+        let mut with_result = with_tools.clone();
+        with_result.messages.push(Message {
+            role: Role::User,
+            content: vec![ContentBlock::ToolResult {
+                tool_use_id: "t1".to_string(),
+                content: "You rolled a 17".to_string(),
+                is_error: false,
+            }],
+        });
+
+        let counts: Vec<usize> = [bare, with_system, with_tools, with_result]
+            .iter()
+            .map(|r| r.estimated_tokens(&CharEstimate))
+            .collect();
+        assert!(counts.windows(2).all(|w| w[0] < w[1]), "{counts:?}");
+    }
+}
@@ -1,5 +1,10 @@
 //! Public types for the Claude API client.
 
+use crate::tokens::TokenCounter;
+
+/// Rough token cost of an image, which depends on its size.
+const IMAGE_TOKENS: usize = 1600;
+
 /// A completion request to send to Claude.
 ///
 /// Use builder methods to configure the request. At minimum, provide messages via [`Request::new`].
@@ -22,6 +27,8 @@ pub struct Request {
     pub temperature: Option<f32>,
     pub tools: Option<Vec<Tool>>,
     pub tool_choice: Option<ToolChoice>,
+    /// Skip the client's response cache for this request.
+    pub bypass_cache: bool,
 }
 
 impl Request {
@@ -35,6 +42,7 @@ impl Request {
             temperature: None,
             tools: None,
             tool_choice: None,
+            bypass_cache: false,
         }
     }
 
@@ -73,6 +81,35 @@ impl Request {
         self.tool_choice = Some(tool_choice);
         self
     }
+    /// Always sends this request to the API, even if the client has a cached
+    /// response for it.
+    pub fn with_cache_bypass(mut self) -> Self {
+        self.bypass_cache = true;
+        self
+    }
+
+    /// Estimates how many input tokens this request will use: the system
+    /// prompt, every message and the tool definitions.
+    pub fn estimated_tokens(&self, counter: &dyn TokenCounter) -> usize {
+        let system = self.system.as_deref().map_or(0, |s| counter.count(s));
+        let messages: usize = self
+            .messages
+            .iter()
+            .flat_map(|m| &m.content)
+            .map(|block| block.estimated_tokens(counter))
+            .sum();
+        let tools: usize = self
+            .tools
+            .iter()
+            .flatten()
+            .map(|tool| {
+                counter.count(&tool.name)
+                    + counter.count(&tool.description)
+                    + counter.count(&tool.input_schema.to_string())
+            })
+            .sum();
+        system + messages + tools
+    }
 }
 
 /// A message in the conversation.
@@ -136,6 +173,19 @@ pub enum ContentBlock {
 }
 
 impl ContentBlock {
+    /// Estimates how many tokens this block will use.
+    pub fn estimated_tokens(&self, counter: &dyn TokenCounter) -> usize {
+        match self {
+            ContentBlock::Text { text } => counter.count(text),
+            ContentBlock::Image { .. } => IMAGE_TOKENS,
+            ContentBlock::ToolUse { name, input, .. } => {
+                counter.count(name) + counter.count(&input.to_string())
+            }
+            ContentBlock::ToolResult { content, .. } => counter.count(content),
+            ContentBlock::Thinking { thinking } => counter.count(thinking),
+        }
+    }
+
     /// Extract text from a Text content block.
     pub fn as_text(&self) -> Option<&str> {
         if let ContentBlock::Text { text } = self {